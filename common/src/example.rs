@@ -148,6 +148,7 @@ pub fn create_bucket_replication_config_example(dst_bucket: &BucketName) -> Repl
                 and_operator: Some(AndOperator {
                     prefix: Some(String::from("TaxDocs")),
                     tags: Some(tags),
+                    ..Default::default()
                 }),
                 ..Default::default()
             }),
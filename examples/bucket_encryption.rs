@@ -32,7 +32,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         client.get_bucket_encryption(bucket)?.build().send().await?;
     log::info!("encryption before: config={:?}", resp.config());
 
-    let config = SseConfig::default();
+    let config = SseConfig::s3();
     log::info!("going to set encryption config={config:?}");
 
     let _resp: PutBucketEncryptionResponse = client
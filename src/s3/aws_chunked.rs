@@ -747,6 +747,32 @@ mod tests {
         assert!(output_str.contains("X-Amz-Checksum-CRC64NVME:"));
     }
 
+    #[tokio::test]
+    async fn test_aws_chunked_encoder_trailer_matches_checksum() {
+        let chunks = vec![
+            Ok::<_, std::io::Error>(Bytes::from("streamed ")),
+            Ok(Bytes::from("without ")),
+            Ok(Bytes::from("a known length")),
+        ];
+        let stream = futures_util::stream::iter(chunks);
+
+        let mut encoder = AwsChunkedEncoder::new(stream, ChecksumAlgorithm::CRC32C);
+        let mut output = Vec::new();
+        while let Some(chunk) = encoder.next().await {
+            output.extend_from_slice(&chunk.unwrap());
+        }
+        let output_str = String::from_utf8(output).unwrap();
+
+        let expected = crate::s3::utils::compute_checksum(
+            ChecksumAlgorithm::CRC32C,
+            b"streamed without a known length",
+        );
+        assert!(
+            output_str.ends_with(&format!("0\r\nX-Amz-Checksum-CRC32C:{expected}\r\n\r\n")),
+            "unexpected trailer in {output_str:?}"
+        );
+    }
+
     #[test]
     fn test_calculate_encoded_length() {
         // Simple case: 100 bytes, 64KB chunks
@@ -14,7 +14,7 @@
 // limitations under the License.
 
 use crate::s3::builders::{
-    ContentStream, MAX_MULTIPART_COUNT, ObjectContent, Size, calc_part_info,
+    ContentStream, MAX_MULTIPART_COUNT, MIN_PART_SIZE, ObjectContent, Size, calc_part_info,
 };
 use crate::s3::client::MinioClient;
 use crate::s3::error::ValidationErr;
@@ -22,11 +22,15 @@ use crate::s3::error::{Error, IoError};
 use crate::s3::header_constants::*;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::{AppendObjectResponse, StatObjectResponse};
-use crate::s3::response_traits::HasObjectSize;
+use crate::s3::response_traits::{HasChecksumHeaders, HasObjectSize};
 use crate::s3::segmented_bytes::SegmentedBytes;
 use crate::s3::sse::Sse;
-use crate::s3::types::{BucketName, ObjectKey, Region, S3Api, S3Request, ToS3Request};
-use crate::s3::utils::{ChecksumAlgorithm, check_sse, compute_checksum_sb};
+use crate::s3::types::{
+    BucketName, ObjectKey, Region, RequestHeaders, S3Api, S3Request, ToS3Request,
+};
+use crate::s3::utils::{ChecksumAlgorithm, b64_encode, check_sse, compute_checksum_sb};
+use base64::Engine;
+use crc_fast::{CrcAlgorithm, Digest as CrcFastDigest, checksum_combine};
 use http::Method;
 use std::sync::Arc;
 use typed_builder::TypedBuilder;
@@ -74,6 +78,12 @@ pub struct AppendObject {
     checksum_algorithm: Option<ChecksumAlgorithm>,
 }
 
+impl RequestHeaders for AppendObject {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 impl S3Api for AppendObject {
     type S3Response = AppendObjectResponse;
 }
@@ -96,7 +106,7 @@ pub type AppendObjectBldr = AppendObjectBuilder<(
 
 impl ToS3Request for AppendObject {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_sse(&self.sse, &self.client)?;
+        check_sse(&self.sse, &self.client, "AppendObject")?;
 
         let mut headers: Multimap = self.extra_headers.unwrap_or_default();
         headers.add(X_AMZ_WRITE_OFFSET_BYTES, self.offset_bytes.to_string());
@@ -172,6 +182,12 @@ pub struct AppendObjectContent {
     checksum_algorithm: Option<ChecksumAlgorithm>,
 }
 
+impl RequestHeaders for AppendObjectContent {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type for [`AppendObjectContent`] that is returned by [`MinioClient::append_object_content`](crate::s3::client::MinioClient::append_object_content).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
@@ -192,7 +208,7 @@ pub type AppendObjectContentBldr = AppendObjectContentBuilder<(
 
 impl AppendObjectContent {
     pub async fn send(mut self) -> Result<AppendObjectResponse, Error> {
-        check_sse(&self.sse, &self.client)?;
+        check_sse(&self.sse, &self.client, "AppendObject")?;
 
         self.content_stream = std::mem::take(&mut self.input_content)
             .to_content_stream()
@@ -329,3 +345,327 @@ impl AppendObjectContent {
     }
 }
 // endregion: append-object-content
+
+// region: append-object-stream
+
+/// Default number of bytes sent per request by [`AppendObjectStream`].
+pub const DEFAULT_APPEND_CHUNK_SIZE: u64 = MIN_PART_SIZE;
+
+/// Argument builder that appends content to an existing object in chunks.
+///
+/// This struct constructs the parameters required for the [`Client::append_object_stream`](crate::s3::client::MinioClient::append_object_stream) method.
+///
+/// Unlike [`AppendObjectContent`], the content is never buffered beyond one chunk,
+/// which suits tailing a growing file through [`ObjectContent::new_from_reader`].
+/// Each chunk of at most `chunk_size` bytes is sent as an [`AppendObject`] request
+/// with the running `x-amz-write-offset-bytes`.
+#[derive(TypedBuilder)]
+pub struct AppendObjectStream {
+    #[builder(!default)] // force required
+    client: MinioClient,
+    #[builder(default, setter(into))]
+    extra_headers: Option<Multimap>,
+    #[builder(default, setter(into))]
+    extra_query_params: Option<Multimap>,
+    #[builder(default, setter(into))]
+    region: Option<Region>,
+    #[builder(setter(into), !default)]
+    bucket: BucketName,
+    #[builder(setter(into), !default)]
+    object: ObjectKey,
+    #[builder(default)]
+    sse: Option<Arc<dyn Sse>>,
+    #[builder(!default, setter(into))]
+    input_content: ObjectContent,
+    /// Maximum number of bytes sent per request (defaults to
+    /// [`DEFAULT_APPEND_CHUNK_SIZE`]; values below 1 are treated as 1).
+    #[builder(default = DEFAULT_APPEND_CHUNK_SIZE)]
+    chunk_size: u64,
+    /// Current size of the object. When not set, it is retrieved with a
+    /// `stat_object` request.
+    #[builder(default, setter(strip_option))]
+    offset_bytes: Option<u64>,
+    /// Optional checksum algorithm, computed and verified per chunk.
+    #[builder(default, setter(into))]
+    checksum_algorithm: Option<ChecksumAlgorithm>,
+    /// Keeps a running CRC32C of the whole object across the appends. Each chunk
+    /// is sent with its CRC32C, and after the last append the full-object CRC32C
+    /// reported by the server must match the running value, or sending fails
+    /// with [`ValidationErr::UploadVerificationFailed`].
+    ///
+    /// Appending to a non-empty object continues from its full-object CRC32C, so
+    /// the object must already have one. Cannot be combined with a
+    /// `checksum_algorithm` other than CRC32C.
+    #[builder(default)]
+    full_object_checksum: bool,
+}
+
+impl RequestHeaders for AppendObjectStream {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
+/// Builder type for [`AppendObjectStream`] that is returned by [`MinioClient::append_object_stream`](crate::s3::client::MinioClient::append_object_stream).
+///
+/// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
+pub type AppendObjectStreamBldr = AppendObjectStreamBuilder<(
+    (MinioClient,),
+    (),
+    (),
+    (),
+    (BucketName,),
+    (ObjectKey,),
+    (),
+    (ObjectContent,),
+    (),
+    (),
+    (),
+    (),
+)>;
+
+impl AppendObjectStream {
+    /// Appends the content chunk by chunk and returns the final size of the object.
+    ///
+    /// Content that is empty from the start appends nothing.
+    pub async fn send(mut self) -> Result<u64, Error> {
+        check_sse(&self.sse, &self.client, "AppendObject")?;
+        let checksum_algorithm = match self.checksum_algorithm {
+            Some(v) if self.full_object_checksum && v != ChecksumAlgorithm::CRC32C => {
+                return Err(ValidationErr::InvalidChecksum(format!(
+                    "full-object checksum of appends uses CRC32C, not {}",
+                    v.as_str()
+                ))
+                .into());
+            }
+            _ if self.full_object_checksum => Some(ChecksumAlgorithm::CRC32C),
+            v => v,
+        };
+        // CRC32C of the object content so far, once the first chunk is read.
+        let mut crc32c: Option<u64> = None;
+
+        let mut content_stream = std::mem::take(&mut self.input_content)
+            .to_content_stream()
+            .await
+            .map_err(IoError::from)?;
+        let chunk_size = self.chunk_size.max(1) as usize;
+
+        let mut offset_bytes: Option<u64> = self.offset_bytes;
+        loop {
+            let chunk = content_stream
+                .read_upto(chunk_size)
+                .await
+                .map_err(IoError::from)?;
+            if chunk.is_empty() {
+                break;
+            }
+            let offset = match offset_bytes {
+                Some(v) => v,
+                None => self
+                    .client
+                    .stat_object(&self.bucket, &self.object)?
+                    .region(self.region.clone())
+                    .build()
+                    .send()
+                    .await?
+                    .size()?,
+            };
+            if self.full_object_checksum {
+                let base = match crc32c {
+                    Some(v) => v,
+                    None => self.base_crc32c(offset).await?,
+                };
+                let mut digest = CrcFastDigest::new(CrcAlgorithm::Crc32Iscsi);
+                for data in chunk.iter() {
+                    digest.update(data.as_ref());
+                }
+                crc32c = Some(checksum_combine(
+                    CrcAlgorithm::Crc32Iscsi,
+                    base,
+                    digest.finalize(),
+                    chunk.len() as u64,
+                ));
+            }
+            let resp: AppendObjectResponse = AppendObject::builder()
+                .client(self.client.clone())
+                .extra_headers(self.extra_headers.clone())
+                .extra_query_params(self.extra_query_params.clone())
+                .bucket(&self.bucket)
+                .object(&self.object)
+                .region(self.region.clone())
+                .sse(self.sse.clone())
+                .data(Arc::new(chunk))
+                .offset_bytes(offset)
+                .checksum_algorithm(checksum_algorithm)
+                .build()
+                .send()
+                .await?;
+            offset_bytes = Some(resp.object_size());
+        }
+
+        if let Some(crc) = crc32c {
+            let stat = self.stat_with_checksum().await?;
+            let expected = b64_encode((crc as u32).to_be_bytes());
+            let actual = stat
+                .checksum()
+                .filter(|v| v.algorithm == ChecksumAlgorithm::CRC32C && !v.is_composite())
+                .map(|v| v.value)
+                .unwrap_or_default();
+            if actual != expected {
+                return Err(ValidationErr::UploadVerificationFailed {
+                    object: self.object.to_string(),
+                    field: "full-object CRC32C".into(),
+                    expected,
+                    actual,
+                }
+                .into());
+            }
+        }
+
+        match offset_bytes {
+            Some(v) => Ok(v),
+            None => Ok(self
+                .client
+                .stat_object(&self.bucket, &self.object)?
+                .region(self.region)
+                .build()
+                .send()
+                .await?
+                .size()?),
+        }
+    }
+
+    async fn stat_with_checksum(&self) -> Result<StatObjectResponse, Error> {
+        self.client
+            .stat_object(&self.bucket, &self.object)?
+            .region(self.region.clone())
+            .build()
+            .header(X_AMZ_CHECKSUM_MODE, "ENABLED")?
+            .send()
+            .await
+    }
+
+    /// Returns the CRC32C of the `size` bytes the appends continue from.
+    async fn base_crc32c(&self, size: u64) -> Result<u64, Error> {
+        if size == 0 {
+            return Ok(0);
+        }
+        let checksum = self
+            .stat_with_checksum()
+            .await?
+            .checksum()
+            .filter(|v| v.algorithm == ChecksumAlgorithm::CRC32C && !v.is_composite())
+            .ok_or_else(|| {
+                ValidationErr::InvalidChecksum(format!(
+                    "object '{}' has no full-object CRC32C to continue from",
+                    self.object
+                ))
+            })?;
+        let bytes: [u8; 4] = base64::engine::general_purpose::STANDARD
+            .decode(&checksum.value)
+            .ok()
+            .and_then(|v| v.try_into().ok())
+            .ok_or_else(|| {
+                ValidationErr::InvalidChecksum(format!(
+                    "malformed CRC32C '{}' of object '{}'",
+                    checksum.value, self.object
+                ))
+            })?;
+        Ok(u32::from_be_bytes(bytes) as u64)
+    }
+}
+// endregion: append-object-stream
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{MockExecutor, test_client};
+    use crate::s3::utils::crc32c;
+    use std::sync::Mutex;
+
+    fn decode_crc32c(value: &str) -> u64 {
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(value)
+            .unwrap();
+        u32::from_be_bytes(bytes.try_into().unwrap()) as u64
+    }
+
+    /// Appends chunks to an object holding `content` and keeps its full-object
+    /// CRC32C by combining the CRC32C sent with each chunk, as a server would.
+    /// With `corrupt` set, a wrong full-object CRC32C is reported. Returns the
+    /// executor and the size and CRC32C of the object.
+    fn append_executor(content: &[u8], corrupt: bool) -> (MockExecutor, Arc<Mutex<(u64, u64)>>) {
+        let crc = crc_fast::checksum(CrcAlgorithm::Crc32Iscsi, content);
+        let state = Arc::new(Mutex::new((content.len() as u64, crc)));
+        let object = Arc::clone(&state);
+        let executor = MockExecutor::new(move |req| {
+            let mut state = object.lock().unwrap();
+            if req.method == http::Method::PUT {
+                let offset: u64 = req
+                    .header(X_AMZ_WRITE_OFFSET_BYTES)
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                assert_eq!(offset, state.0);
+                let len: u64 = req.header(CONTENT_LENGTH).unwrap().parse().unwrap();
+                let crc = decode_crc32c(req.header(X_AMZ_CHECKSUM_CRC32C).unwrap());
+                *state = (
+                    state.0 + len,
+                    checksum_combine(CrcAlgorithm::Crc32Iscsi, state.1, crc, len),
+                );
+                http::Response::builder()
+                    .status(200)
+                    .header(X_AMZ_OBJECT_SIZE, state.0)
+                    .body(String::new())
+            } else {
+                let mut resp = http::Response::builder()
+                    .status(200)
+                    .header(CONTENT_LENGTH, state.0)
+                    .header("etag", "\"etag\"")
+                    .header("last-modified", "Sun, 23 Dec 2012 00:00:00 GMT");
+                if req.header(X_AMZ_CHECKSUM_MODE).is_some() {
+                    let crc = state.1 as u32 ^ u32::from(corrupt);
+                    resp = resp
+                        .header(X_AMZ_CHECKSUM_CRC32C, b64_encode(crc.to_be_bytes()))
+                        .header(X_AMZ_CHECKSUM_TYPE, "FULL_OBJECT");
+                }
+                resp.body(String::new())
+            }
+            .unwrap()
+        });
+        (executor, state)
+    }
+
+    fn append(executor: &MockExecutor) -> AppendObjectStream {
+        AppendObjectStream::builder()
+            .client(test_client(executor.clone()))
+            .bucket(BucketName::new("bucket").unwrap())
+            .object(ObjectKey::new("object").unwrap())
+            .input_content(ObjectContent::from("bbbbccccdd"))
+            .chunk_size(4)
+            .full_object_checksum(true)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_append_object_stream_full_object_checksum() {
+        let (executor, state) = append_executor(b"aaaa", false);
+
+        assert_eq!(append(&executor).send().await.unwrap(), 14);
+
+        let crc = state.lock().unwrap().1 as u32;
+        assert_eq!(b64_encode(crc.to_be_bytes()), crc32c(b"aaaabbbbccccdd"));
+    }
+
+    #[tokio::test]
+    async fn test_append_object_stream_full_object_checksum_mismatch() {
+        let (executor, _) = append_executor(b"aaaa", true);
+
+        assert!(matches!(
+            append(&executor).send().await,
+            Err(Error::Validation(
+                ValidationErr::UploadVerificationFailed { .. }
+            ))
+        ));
+    }
+}
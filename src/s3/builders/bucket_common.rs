@@ -15,7 +15,7 @@
 
 use crate::s3::client::MinioClient;
 use crate::s3::multimap_ext::Multimap;
-use crate::s3::types::{BucketName, Region};
+use crate::s3::types::{BucketName, Region, RequestHeaders};
 use std::marker::PhantomData;
 use typed_builder::TypedBuilder;
 
@@ -37,3 +37,9 @@ pub struct BucketCommon<T> {
     #[builder(default)]
     _operation: PhantomData<T>,
 }
+
+impl<T> RequestHeaders for BucketCommon<T> {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
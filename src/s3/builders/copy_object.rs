@@ -30,7 +30,8 @@ use crate::s3::types::Directive;
 use crate::s3::types::PartInfo;
 use crate::s3::types::Retention;
 use crate::s3::types::{
-    BucketName, ObjectKey, Region, S3Api, S3Request, ToS3Request, UploadId, VersionId,
+    BucketName, ObjectKey, Region, RequestHeaders, S3Api, S3Request, Tags, ToS3Request, UploadId,
+    VersionId,
 };
 use crate::s3::utils::{
     ChecksumAlgorithm, UtcTime, check_sse, check_ssec, encode_tags, to_http_header_value,
@@ -38,7 +39,6 @@ use crate::s3::utils::{
 };
 use async_recursion::async_recursion;
 use http::Method;
-use std::collections::HashMap;
 use std::sync::Arc;
 use typed_builder::TypedBuilder;
 
@@ -74,6 +74,12 @@ pub struct UploadPartCopy {
     checksum_algorithm: Option<crate::s3::utils::ChecksumAlgorithm>,
 }
 
+impl RequestHeaders for UploadPartCopy {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 impl S3Api for UploadPartCopy {
     type S3Response = UploadPartCopyResponse;
 }
@@ -155,7 +161,7 @@ pub struct CopyObjectInternal {
     #[builder(default, setter(into))]
     sse: Option<Arc<dyn Sse>>,
     #[builder(default, setter(into))]
-    tags: Option<HashMap<String, String>>,
+    tags: Option<Tags>,
     #[builder(default, setter(into))]
     retention: Option<Retention>,
     #[builder(default)]
@@ -198,8 +204,8 @@ pub type CopyObjectInternalBldr = CopyObjectInternalBuilder<(
 
 impl ToS3Request for CopyObjectInternal {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_sse(&self.sse, &self.client)?;
-        check_ssec(&self.source.ssec, &self.client)?;
+        check_sse(&self.sse, &self.client, "CopyObject")?;
+        check_ssec(&self.source.ssec, &self.client, "CopyObject source")?;
 
         let mut headers = self.headers;
         {
@@ -212,6 +218,16 @@ impl ToS3Request for CopyObjectInternal {
             if let Some(v) = self.sse {
                 headers.add_multimap(v.headers());
             }
+            let tagging_directive = match (&self.tags, self.tagging_directive) {
+                (Some(_), Some(Directive::Copy)) => {
+                    return Err(ValidationErr::InvalidCopyDirective(
+                        "tags cannot be set with the COPY tagging directive".into(),
+                    ));
+                }
+                // The server ignores x-amz-tagging unless the directive is REPLACE.
+                (Some(_), None) => Some(Directive::Replace),
+                (_, v) => v,
+            };
             if let Some(v) = self.tags {
                 let tagging = encode_tags(&v);
                 if !tagging.is_empty() {
@@ -231,7 +247,7 @@ impl ToS3Request for CopyObjectInternal {
             if let Some(v) = self.metadata_directive {
                 headers.add(X_AMZ_METADATA_DIRECTIVE, v.to_string());
             }
-            if let Some(v) = self.tagging_directive {
+            if let Some(v) = tagging_directive {
                 headers.add(X_AMZ_TAGGING_DIRECTIVE, v.to_string());
             }
 
@@ -313,8 +329,12 @@ pub struct CopyObject {
     user_metadata: Option<Multimap>,
     #[builder(default, setter(into))]
     sse: Option<Arc<dyn Sse>>,
+    /// Tags to set on the destination object.
+    ///
+    /// Setting tags without a `tagging_directive` implies [`Directive::Replace`];
+    /// combining them with [`Directive::Copy`] is rejected.
     #[builder(default, setter(into))]
-    tags: Option<HashMap<String, String>>,
+    tags: Option<Tags>,
     #[builder(default, setter(into))]
     retention: Option<Retention>,
     #[builder(default = false)]
@@ -323,6 +343,8 @@ pub struct CopyObject {
     source: CopySource,
     #[builder(default, setter(into))]
     metadata_directive: Option<Directive>,
+    /// Whether the destination takes the source object's tags ([`Directive::Copy`],
+    /// the server default) or the tags given in `tags` ([`Directive::Replace`]).
     #[builder(default, setter(into))]
     tagging_directive: Option<Directive>,
     /// Optional checksum algorithm for data integrity verification during copy.
@@ -332,6 +354,31 @@ pub struct CopyObject {
     /// SHA1, SHA256, CRC64NVME. The checksum value is included in response headers for verification.
     #[builder(default, setter(into))]
     checksum_algorithm: Option<crate::s3::utils::ChecksumAlgorithm>,
+    /// SSE-C key the source object is encrypted with; overrides the key of `source`.
+    ///
+    /// Together with `ssec` this rotates the key of an SSE-C object: the server
+    /// decrypts the source with this key and encrypts the destination with the new one.
+    #[builder(default, setter(into))]
+    ssec_source: Option<SseCustomerKey>,
+    /// SSE-C key to encrypt the destination object with; cannot be combined with `sse`.
+    #[builder(default, setter(into))]
+    ssec: Option<SseCustomerKey>,
+    /// Sets the `Expires` header of the destination object.
+    ///
+    /// Requires `metadata_directive` to be [`Directive::Replace`], as the server
+    /// otherwise keeps the headers of the source object.
+    #[builder(default, setter(into))]
+    expires: Option<UtcTime>,
+    /// Enables an S3 Bucket Key for SSE-KMS encryption of the destination object
+    /// (`x-amz-server-side-encryption-bucket-key-enabled`).
+    #[builder(default = false)]
+    bucket_key_enabled: bool,
+}
+
+impl RequestHeaders for CopyObject {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
 }
 
 /// Builder type for [`CopyObject`] that is returned by [`MinioClient::copy_object`](crate::s3::client::MinioClient::copy_object).
@@ -354,6 +401,10 @@ pub type CopyObjectBldr = CopyObjectBuilder<(
     (),
     (),
     (),
+    (),
+    (),
+    (),
+    (),
 )>;
 
 impl CopyObject {
@@ -361,9 +412,27 @@ impl CopyObject {
     ///
     /// Functionally related to the [S3Api::send()](crate::s3::types::S3Api::send) method, but
     /// specifically tailored for the `CopyObject` operation.
-    pub async fn send(self) -> Result<CopyObjectResponse, Error> {
-        check_sse(&self.sse, &self.client)?;
-        check_ssec(&self.source.ssec, &self.client)?;
+    pub async fn send(mut self) -> Result<CopyObjectResponse, Error> {
+        if self.expires.is_some() && !matches!(self.metadata_directive, Some(Directive::Replace)) {
+            return Err(ValidationErr::InvalidCopyDirective(
+                "expires requires the REPLACE metadata directive".into(),
+            )
+            .into());
+        }
+        if let Some(key) = self.ssec_source.take() {
+            self.source.ssec = Some(key);
+        }
+        if let Some(key) = self.ssec.take() {
+            if self.sse.is_some() {
+                return Err(ValidationErr::InvalidSseConfig(
+                    "ssec cannot be combined with sse".into(),
+                )
+                .into());
+            }
+            self.sse = Some(Arc::new(key));
+        }
+        check_sse(&self.sse, &self.client, "CopyObject")?;
+        check_ssec(&self.source.ssec, &self.client, "CopyObject source")?;
 
         let source = self.source.clone();
 
@@ -374,6 +443,7 @@ impl CopyObject {
             .extra_query_params(source.extra_query_params)
             .region(source.region)
             .version_id(source.version_id)
+            .ssec(source.ssec)
             .match_etag(source.match_etag)
             .not_match_etag(source.not_match_etag)
             .modified_since(source.modified_since)
@@ -386,25 +456,15 @@ impl CopyObject {
             || self.source.length.is_some()
             || stat_resp.size()? > MAX_PART_SIZE
         {
-            if let Some(v) = &self.metadata_directive {
-                match v {
-                    Directive::Copy => {
-                        return Err(ValidationErr::InvalidCopyDirective(
-                            "COPY metadata directive is not applicable to source object size greater than 5 GiB".into()
-                        ).into());
-                    }
-                    _ => todo!(), // Nothing to do.
-                }
+            if let Some(Directive::Copy) = self.metadata_directive {
+                return Err(ValidationErr::InvalidCopyDirective(
+                    "COPY metadata directive is not applicable to source object size greater than 5 GiB".into()
+                ).into());
             }
-            if let Some(v) = &self.tagging_directive {
-                match v {
-                    Directive::Copy => {
-                        return Err(ValidationErr::InvalidCopyDirective(
-                            "COPY tagging directive is not applicable to source object size greater than 5 GiB".into()
-                        ).into());
-                    }
-                    _ => todo!(), // Nothing to do.
-                }
+            if let Some(Directive::Copy) = self.tagging_directive {
+                return Err(ValidationErr::InvalidCopyDirective(
+                    "COPY tagging directive is not applicable to source object size greater than 5 GiB".into()
+                ).into());
             }
 
             let src: ComposeSource = {
@@ -423,13 +483,26 @@ impl CopyObject {
             };
             let sources: Vec<ComposeSource> = vec![src];
 
+            let headers = if self.expires.is_some() || self.bucket_key_enabled {
+                let mut headers = self.headers.unwrap_or_default();
+                if let Some(expires) = self.expires {
+                    headers.add(EXPIRES, to_http_header_value(expires));
+                }
+                if self.bucket_key_enabled {
+                    headers.add(X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED, "true");
+                }
+                Some(headers)
+            } else {
+                self.headers
+            };
+
             let resp: ComposeObjectResponse = self
                 .client
                 .compose_object(&self.source.bucket, &self.source.object, sources)?
                 .extra_headers(self.extra_headers)
                 .extra_query_params(self.extra_query_params)
                 .region(self.region)
-                .headers(self.headers)
+                .headers(headers)
                 .user_metadata(self.user_metadata)
                 .sse(self.sse)
                 .tags(self.tags)
@@ -442,13 +515,20 @@ impl CopyObject {
             let resp: CopyObjectResponse = resp; // retype to CopyObjectResponse
             Ok(resp)
         } else {
+            let mut headers = self.headers.unwrap_or_default();
+            if let Some(expires) = self.expires {
+                headers.add(EXPIRES, to_http_header_value(expires));
+            }
+            if self.bucket_key_enabled {
+                headers.add(X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED, "true");
+            }
             let resp: CopyObjectInternalResponse = self
                 .client
                 .copy_object_internal(self.bucket.clone(), self.object.clone())
                 .extra_headers(self.extra_headers)
                 .extra_query_params(self.extra_query_params)
                 .region(self.region)
-                .headers(self.headers.unwrap_or_default())
+                .headers(headers)
                 .user_metadata(self.user_metadata)
                 .sse(self.sse)
                 .tags(self.tags)
@@ -489,7 +569,7 @@ pub struct ComposeObjectInternal {
     #[builder(default, setter(into))]
     sse: Option<Arc<dyn Sse>>,
     #[builder(default, setter(into))]
-    tags: Option<HashMap<String, String>>,
+    tags: Option<Tags>,
     #[builder(default, setter(into))]
     retention: Option<Retention>,
     #[builder(default)]
@@ -749,7 +829,7 @@ impl ComposeObjectInternal {
 pub struct ComposeObject {
     #[builder(!default)] // force required
     client: MinioClient,
-    #[builder(default)]
+    #[builder(default, setter(into))]
     extra_headers: Option<Multimap>,
     #[builder(default, setter(into))]
     extra_query_params: Option<Multimap>,
@@ -766,7 +846,7 @@ pub struct ComposeObject {
     #[builder(default, setter(into))]
     sse: Option<Arc<dyn Sse>>,
     #[builder(default, setter(into))]
-    tags: Option<HashMap<String, String>>,
+    tags: Option<Tags>,
     #[builder(default, setter(into))]
     retention: Option<Retention>,
     #[builder(default)]
@@ -777,6 +857,12 @@ pub struct ComposeObject {
     checksum_algorithm: Option<ChecksumAlgorithm>,
 }
 
+impl RequestHeaders for ComposeObject {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type for [`ComposeObject`] that is returned by [`MinioClient::compose_object`](crate::s3::client::MinioClient::compose_object).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
@@ -799,7 +885,7 @@ pub type ComposeObjectBldr = ComposeObjectBuilder<(
 
 impl ComposeObject {
     pub async fn send(self) -> Result<ComposeObjectResponse, Error> {
-        check_sse(&self.sse, &self.client)?;
+        check_sse(&self.sse, &self.client, "ComposeObject")?;
 
         let (res, upload_id): (Result<ComposeObjectResponse, Error>, Option<UploadId>) = self
             .client
@@ -1059,7 +1145,7 @@ fn into_headers_copy_object(
     headers: Option<Multimap>,
     user_metadata: Option<Multimap>,
     sse: Option<Arc<dyn Sse>>,
-    tags: Option<HashMap<String, String>>,
+    tags: Option<Tags>,
     retention: Option<Retention>,
     legal_hold: bool,
 ) -> Multimap {
@@ -1140,6 +1226,247 @@ fn calculate_part_ranges(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::s3::client::test_support::{MockExecutor, test_client, test_client_builder};
+    use crate::s3::creds::StaticProvider;
+    use crate::s3::http::BaseUrl;
+    use std::collections::HashMap;
+
+    fn copy_object_internal(
+        tags: Option<Tags>,
+        tagging_directive: Option<Directive>,
+    ) -> Result<S3Request, ValidationErr> {
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let provider = StaticProvider::new("minioadmin", "minioadmin", None);
+        let client = MinioClient::new(base_url, Some(provider), None, None).unwrap();
+        CopyObjectInternal::builder()
+            .client(client)
+            .bucket(BucketName::new("bucket").unwrap())
+            .object(ObjectKey::new("dst").unwrap())
+            .source(
+                CopySource::builder()
+                    .bucket(BucketName::new("bucket").unwrap())
+                    .object(ObjectKey::new("src").unwrap())
+                    .build(),
+            )
+            .tags(tags)
+            .tagging_directive(tagging_directive)
+            .build()
+            .to_s3request()
+    }
+
+    #[test]
+    fn test_copy_object_tagging_directive_headers() {
+        let tags = Tags::from(HashMap::from([("project".to_string(), "a b".to_string())]));
+
+        let request = copy_object_internal(Some(tags.clone()), Some(Directive::Replace)).unwrap();
+        let headers = request.headers();
+        assert_eq!(
+            headers.get(X_AMZ_TAGGING_DIRECTIVE).map(String::as_str),
+            Some("REPLACE")
+        );
+        assert_eq!(
+            headers.get(X_AMZ_TAGGING).map(String::as_str),
+            Some("project=a%20b")
+        );
+
+        // Tags without a directive imply REPLACE.
+        let request = copy_object_internal(Some(tags.clone()), None).unwrap();
+        assert_eq!(
+            request
+                .headers()
+                .get(X_AMZ_TAGGING_DIRECTIVE)
+                .map(String::as_str),
+            Some("REPLACE")
+        );
+
+        let request = copy_object_internal(None, Some(Directive::Copy)).unwrap();
+        let headers = request.headers();
+        assert_eq!(
+            headers.get(X_AMZ_TAGGING_DIRECTIVE).map(String::as_str),
+            Some("COPY")
+        );
+        assert!(!headers.contains_key(X_AMZ_TAGGING));
+
+        let request = copy_object_internal(None, None).unwrap();
+        assert!(!request.headers().contains_key(X_AMZ_TAGGING_DIRECTIVE));
+
+        assert!(matches!(
+            copy_object_internal(Some(tags), Some(Directive::Copy)),
+            Err(ValidationErr::InvalidCopyDirective(_))
+        ));
+    }
+
+    /// Answers HEAD with a small object and PUT with a copy result.
+    fn copy_executor() -> MockExecutor {
+        MockExecutor::new(|req| {
+            let resp = http::Response::builder()
+                .status(200)
+                .header("etag", "\"etag\"");
+            if req.method == Method::HEAD {
+                resp.header(CONTENT_LENGTH, 5)
+                    .header("last-modified", "Wed, 15 Jan 2025 00:00:00 GMT")
+                    .body(String::new())
+            } else {
+                resp.body(
+                    "<CopyObjectResult><ETag>\"etag\"</ETag>\
+                     <LastModified>2025-01-15T00:00:00.000Z</LastModified></CopyObjectResult>"
+                        .to_string(),
+                )
+            }
+            .unwrap()
+        })
+    }
+
+    /// Returns the headers of the first request sent with `method`.
+    fn headers_of(executor: &MockExecutor, method: Method) -> http::HeaderMap {
+        executor
+            .requests()
+            .into_iter()
+            .find(|r| r.method == method)
+            .unwrap()
+            .headers
+    }
+
+    fn rotate_key(url: &str, executor: MockExecutor) -> CopyObject {
+        let client = test_client_builder(url.parse().unwrap())
+            .http_executor(executor)
+            .build()
+            .unwrap();
+        CopyObject::builder()
+            .client(client)
+            .bucket(BucketName::new("bucket").unwrap())
+            .object(ObjectKey::new("object").unwrap())
+            .source(
+                CopySource::builder()
+                    .bucket(BucketName::new("bucket").unwrap())
+                    .object(ObjectKey::new("object").unwrap())
+                    .build(),
+            )
+            .metadata_directive(Directive::Copy)
+            .ssec_source(SseCustomerKey::new([1_u8; 32]).unwrap())
+            .ssec(SseCustomerKey::new([2_u8; 32]).unwrap())
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_copy_object_ssec_key_rotation() {
+        let executor = copy_executor();
+        rotate_key("https://localhost:9000", executor.clone())
+            .send()
+            .await
+            .unwrap();
+
+        let head = headers_of(&executor, Method::HEAD);
+        assert_eq!(
+            head[X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5],
+            "4Funlf7OsLF0HL+vKU+fkg=="
+        );
+
+        let put = headers_of(&executor, Method::PUT);
+        assert_eq!(
+            put[X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM],
+            "AES256"
+        );
+        assert_eq!(
+            put[X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY],
+            "AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE="
+        );
+        assert_eq!(
+            put[X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5],
+            "4Funlf7OsLF0HL+vKU+fkg=="
+        );
+        assert_eq!(
+            put[X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM],
+            "AES256"
+        );
+        assert_eq!(
+            put[X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY],
+            "AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI="
+        );
+        assert_eq!(
+            put[X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5],
+            "F3apf5enN9TQbA8CaOS4/g=="
+        );
+    }
+
+    #[tokio::test]
+    async fn test_copy_object_ssec_key_rotation_requires_tls() {
+        let executor = copy_executor();
+        let err = rotate_key("http://localhost:9000", executor.clone())
+            .send()
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, Error::Validation(ValidationErr::SseTlsRequired { .. })),
+            "{err:?}"
+        );
+        assert!(executor.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_copy_object_ssec_conflicts_with_sse() {
+        let executor = copy_executor();
+        let mut copy = rotate_key("https://localhost:9000", executor.clone());
+        copy.sse = Some(Arc::new(crate::s3::types::sse::SseS3::new()));
+        assert!(matches!(
+            copy.send().await,
+            Err(Error::Validation(ValidationErr::InvalidSseConfig(_)))
+        ));
+        assert!(executor.requests().is_empty());
+    }
+
+    async fn copy_with_expires(
+        executor: MockExecutor,
+        metadata_directive: Option<Directive>,
+    ) -> Result<CopyObjectResponse, Error> {
+        test_client(executor)
+            .copy_object("bucket", "dst")
+            .unwrap()
+            .source(
+                CopySource::builder()
+                    .bucket(BucketName::new("bucket").unwrap())
+                    .object(ObjectKey::new("src").unwrap())
+                    .build(),
+            )
+            .metadata_directive(metadata_directive)
+            .expires(
+                crate::s3::utils::from_http_header_value("Tue, 01 Jan 2030 00:00:00 GMT").unwrap(),
+            )
+            .bucket_key_enabled(true)
+            .build()
+            .send()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_copy_object_expires_and_bucket_key() {
+        let executor = copy_executor();
+        copy_with_expires(executor.clone(), Some(Directive::Replace))
+            .await
+            .unwrap();
+        let put = headers_of(&executor, Method::PUT);
+        assert_eq!(put[EXPIRES], "Tue, 01 Jan 2030 00:00:00 GMT");
+        assert_eq!(put[X_AMZ_METADATA_DIRECTIVE], "REPLACE");
+        assert_eq!(put[X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED], "true");
+    }
+
+    #[tokio::test]
+    async fn test_copy_object_expires_requires_replace() {
+        let executor = copy_executor();
+        for directive in [None, Some(Directive::Copy)] {
+            let err = copy_with_expires(executor.clone(), directive)
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    Error::Validation(ValidationErr::InvalidCopyDirective(_))
+                ),
+                "{err:?}"
+            );
+        }
+        assert!(executor.requests().is_empty());
+    }
 
     #[test]
     fn test_calculate_part_ranges_single_part() {
@@ -20,7 +20,7 @@ use crate::s3::header_constants::*;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::CreateBucketResponse;
 use crate::s3::segmented_bytes::SegmentedBytes;
-use crate::s3::types::{BucketName, Region, S3Api, S3Request, ToS3Request};
+use crate::s3::types::{BucketName, Region, RequestHeaders, S3Api, S3Request, ToS3Request};
 use http::Method;
 use std::sync::Arc;
 use typed_builder::TypedBuilder;
@@ -44,6 +44,12 @@ pub struct CreateBucket {
     object_lock: bool,
 }
 
+impl RequestHeaders for CreateBucket {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 impl S3Api for CreateBucket {
     type S3Response = CreateBucketResponse;
 }
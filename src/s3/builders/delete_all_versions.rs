@@ -0,0 +1,92 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::empty_bucket::delete_listed;
+use crate::s3::client::MinioClient;
+use crate::s3::error::{Error, ValidationErr};
+use crate::s3::response::DeleteAllVersionsResponse;
+use crate::s3::types::{BucketName, ObjectKey, Region};
+use typed_builder::TypedBuilder;
+
+/// Argument builder for removing all versions and delete markers of a single object.
+///
+/// Versions are listed and removed in batches with the
+/// [`DeleteObjects`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObjects.html)
+/// S3 API operation.
+///
+/// This struct constructs the parameters required for the [`Client::delete_all_versions`](crate::s3::client::MinioClient::delete_all_versions) method.
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct DeleteAllVersions {
+    #[builder(!default)] // force required
+    client: MinioClient,
+
+    #[builder(default, setter(into))]
+    region: Option<Region>,
+    #[builder(setter(into), !default)]
+    bucket: BucketName,
+    #[builder(setter(into), !default)]
+    object: ObjectKey,
+
+    /// Also remove versions protected by GOVERNANCE mode retention. Versions under
+    /// COMPLIANCE mode retention or a legal hold are never removed.
+    #[builder(default)]
+    bypass_governance: bool,
+
+    /// List the versions that would be removed without deleting anything.
+    #[builder(default)]
+    dry_run: bool,
+}
+
+/// Builder type for [`DeleteAllVersions`] that is returned by [`MinioClient::delete_all_versions`](crate::s3::client::MinioClient::delete_all_versions).
+///
+/// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
+pub type DeleteAllVersionsBldr =
+    DeleteAllVersionsBuilder<((MinioClient,), (), (BucketName,), (ObjectKey,), (), ())>;
+
+impl DeleteAllVersions {
+    /// Removes all versions of the object and returns the number of deleted entries.
+    /// If any version cannot be removed, the remaining versions are still processed
+    /// and [`ValidationErr::VersionsNotDeleted`] is returned.
+    ///
+    /// In dry-run mode all versions are still listed, but no delete request is
+    /// sent; the response lists the versions that would be removed.
+    pub async fn send(self) -> Result<DeleteAllVersionsResponse, Error> {
+        let outcome = delete_listed(
+            &self.client,
+            &self.bucket,
+            &self.region,
+            Some(&self.object),
+            self.bypass_governance,
+            self.dry_run,
+        )
+        .await?;
+
+        match outcome.first_failure {
+            None => Ok(DeleteAllVersionsResponse::new(
+                self.bucket,
+                self.object,
+                outcome.deleted,
+                outcome.would_delete,
+            )),
+            Some(first) => Err(ValidationErr::VersionsNotDeleted {
+                bucket: self.bucket.to_string(),
+                object: self.object.to_string(),
+                failed: outcome.failed,
+                first,
+            }
+            .into()),
+        }
+    }
+}
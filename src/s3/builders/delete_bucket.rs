@@ -18,7 +18,6 @@ use crate::s3::builders::{BucketCommon, BucketCommonBuilder};
 use crate::s3::error::ValidationErr;
 use crate::s3::response::DeleteBucketResponse;
 use crate::s3::types::{BucketName, S3Api, S3Request, ToS3Request};
-use crate::s3::utils::check_bucket_name;
 use http::Method;
 
 /// Argument builder for the [`DeleteBucket`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteBucket.html) S3 API operation.
@@ -43,7 +42,7 @@ pub type DeleteBucketBldr =
 
 impl ToS3Request for DeleteBucket {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_bucket_name(&self.bucket, true)?;
+        self.client.check_bucket_name(&self.bucket)?;
 
         Ok(S3Request::builder()
             .client(self.client)
@@ -18,7 +18,7 @@ use crate::s3::builders::{BucketCommon, BucketCommonBuilder};
 use crate::s3::error::ValidationErr;
 use crate::s3::response::DeleteBucketLifecycleResponse;
 use crate::s3::types::{BucketName, S3Api, S3Request, ToS3Request};
-use crate::s3::utils::{check_bucket_name, insert};
+use crate::s3::utils::insert;
 use http::Method;
 
 /// Argument builder for the [`DeleteBucketLifecycle`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteBucketLifecycle.html) S3 API operation.
@@ -44,7 +44,7 @@ pub type DeleteBucketLifecycleBldr = BucketCommonBuilder<
 
 impl ToS3Request for DeleteBucketLifecycle {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_bucket_name(&self.bucket, true)?;
+        self.client.check_bucket_name(&self.bucket)?;
 
         Ok(S3Request::builder()
             .client(self.client)
@@ -46,6 +46,7 @@ pub type DeleteObjectLockConfigBldr = BucketCommonBuilder<
 impl ToS3Request for DeleteObjectLockConfig {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
         let config = ObjectLockConfig {
+            enabled: true,
             retention_mode: None,
             retention_duration_days: None,
             retention_duration_years: None,
@@ -14,15 +14,20 @@
 // limitations under the License.
 
 use crate::s3::client::MinioClient;
-use crate::s3::error::{Error, ValidationErr};
+use crate::s3::error::{Error, S3ServerError, ValidationErr};
 use crate::s3::header_constants::*;
+use crate::s3::minio_error_response::MinioErrorCode;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
-use crate::s3::response::{DeleteError, DeleteObjectResponse, DeleteObjectsResponse};
+use crate::s3::response::{
+    DeleteError, DeleteObjectResponse, DeleteObjectsResponse, GetObjectLegalHoldResponse,
+    GetObjectRetentionResponse,
+};
 use crate::s3::segmented_bytes::SegmentedBytes;
 use crate::s3::types::{
-    BucketName, ListEntry, ObjectKey, Region, S3Api, S3Request, ToS3Request, ToStream, VersionId,
+    BucketName, ETag, FromS3Response, ListEntry, ObjectKey, Region, RequestHeaders, RetentionMode,
+    S3Api, S3Request, ToS3Request, ToStream, VersionId,
 };
-use crate::s3::utils::{check_bucket_name, check_object_name, insert, md5sum_hash};
+use crate::s3::utils::{UtcTime, check_object_name, insert, md5sum_hash, utc_now};
 use async_trait::async_trait;
 use bytes::Bytes;
 use futures_util::stream::iter;
@@ -49,6 +54,18 @@ pub struct ObjectToDelete {
     version_id: Option<VersionId>,
 }
 
+impl ObjectToDelete {
+    /// The key of the object.
+    pub fn key(&self) -> &ObjectKey {
+        &self.key
+    }
+
+    /// The version of the object, if any.
+    pub fn version_id(&self) -> Option<&VersionId> {
+        self.version_id.as_ref()
+    }
+}
+
 /// A String key can be converted into a `ObjectToDelete` with `version_id` set to `None`.
 impl TryFrom<String> for ObjectToDelete {
     type Error = ValidationErr;
@@ -186,6 +203,10 @@ impl From<DeleteError> for ObjectToDelete {
 /// Argument builder for the [`DeleteObject`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObject.html) S3 API operation.
 ///
 /// This struct constructs the parameters required for the `delete_object` method.
+///
+/// Setting [`if_match`](DeleteObjectBuilder::if_match) deletes the object only when
+/// its current ETag matches; otherwise the server responds with
+/// [`MinioErrorCode::PreconditionFailed`] and the object is kept.
 #[derive(Debug, Clone, TypedBuilder)]
 pub struct DeleteObject {
     #[builder(!default)] // force required
@@ -202,10 +223,129 @@ pub struct DeleteObject {
     object: ObjectToDelete,
     #[builder(default)]
     bypass_governance_mode: bool,
+    /// Before deleting, check the object's retention and legal hold and fail with
+    /// [`ValidationErr::ObjectLocked`] instead of sending a delete that the server
+    /// would reject. GOVERNANCE retention only counts when `bypass_governance_mode`
+    /// is not set.
+    #[builder(default)]
+    ensure_unlocked: bool,
+    /// Only delete the object if it has this ETag (`If-Match`).
+    #[builder(default, setter(into))]
+    if_match: Option<ETag>,
+}
+
+impl RequestHeaders for DeleteObject {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
 }
 
+#[async_trait]
 impl S3Api for DeleteObject {
     type S3Response = DeleteObjectResponse;
+
+    async fn send(self) -> Result<Self::S3Response, Error> {
+        if self.ensure_unlocked {
+            self.check_unlocked().await?;
+        }
+        let mut req: S3Request = self.to_s3request()?;
+        let resp: Result<reqwest::Response, Error> = req.execute().await;
+        Self::S3Response::from_s3response(req, resp).await
+    }
+}
+
+impl DeleteObject {
+    async fn check_unlocked(&self) -> Result<(), Error> {
+        let retention = self
+            .client
+            .get_object_retention(&self.bucket, &self.object.key)?
+            .region(self.region.clone())
+            .version_id(self.object.version_id.clone())
+            .build()
+            .send()
+            .await;
+        let retention: GetObjectRetentionResponse = match retention {
+            Err(Error::S3Server(S3ServerError::S3Error(e)))
+                if matches!(e.code(), MinioErrorCode::NoSuchKey) =>
+            {
+                // Nothing to protect; let the delete proceed as usual.
+                return Ok(());
+            }
+            // The bucket has object lock disabled, so nothing can be locked.
+            Err(e) if is_object_lock_not_configured(&e) => return Ok(()),
+            v => v?,
+        };
+        let legal_hold: Result<GetObjectLegalHoldResponse, Error> = self
+            .client
+            .get_object_legal_hold(&self.bucket, &self.object.key)?
+            .region(self.region.clone())
+            .version_id(self.object.version_id.clone())
+            .build()
+            .send()
+            .await;
+        let legal_hold = match legal_hold {
+            Ok(v) => v.enabled()?,
+            Err(e) if is_object_lock_not_configured(&e) => false,
+            Err(e) => return Err(e),
+        };
+
+        match object_lock_error(
+            &self.object.key,
+            retention.retention_mode()?,
+            retention.retain_until_date()?,
+            legal_hold,
+            self.bypass_governance_mode,
+            utc_now(),
+        ) {
+            Some(e) => Err(e.into()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns whether `err` reports that no retention or legal hold applies, because
+/// the object has none set or its bucket has object lock disabled.
+fn is_object_lock_not_configured(err: &Error) -> bool {
+    let Error::S3Server(S3ServerError::S3Error(e)) = err else {
+        return false;
+    };
+    match e.code() {
+        MinioErrorCode::NoSuchObjectLockConfiguration => true,
+        MinioErrorCode::OtherError(code) => {
+            code == "invalidrequest" || code == "objectlockconfigurationnotfounderror"
+        }
+        _ => false,
+    }
+}
+
+/// Returns the error to report when an object with the given lock state cannot be deleted.
+fn object_lock_error(
+    object: &ObjectKey,
+    mode: Option<RetentionMode>,
+    retain_until: Option<UtcTime>,
+    legal_hold: bool,
+    bypass_governance_mode: bool,
+    now: UtcTime,
+) -> Option<ValidationErr> {
+    let retained = match (&mode, retain_until) {
+        (Some(RetentionMode::COMPLIANCE), Some(until)) => until > now,
+        (Some(RetentionMode::GOVERNANCE), Some(until)) => until > now && !bypass_governance_mode,
+        _ => false,
+    };
+    if !retained && !legal_hold {
+        return None;
+    }
+    let (mode, retain_until) = if retained {
+        (mode, retain_until)
+    } else {
+        (None, None)
+    };
+    Some(ValidationErr::ObjectLocked {
+        object: object.to_string(),
+        mode,
+        retain_until,
+        legal_hold,
+    })
 }
 
 /// Builder type for [`DeleteObject`] that is returned by [`MinioClient::delete_object`](crate::s3::client::MinioClient::delete_object).
@@ -219,11 +359,13 @@ pub type DeleteObjectBldr = DeleteObjectBuilder<(
     (BucketName,),
     (ObjectToDelete,),
     (),
+    (),
+    (),
 )>;
 
 impl ToS3Request for DeleteObject {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_bucket_name(&self.bucket, true)?;
+        self.client.check_bucket_name(&self.bucket)?;
         check_object_name(&self.object.key)?;
 
         let mut query_params: Multimap = self.extra_query_params.unwrap_or_default();
@@ -233,6 +375,10 @@ impl ToS3Request for DeleteObject {
         if self.bypass_governance_mode {
             headers.add(X_AMZ_BYPASS_GOVERNANCE_RETENTION, "true");
         }
+        if let Some(v) = self.if_match {
+            let etag = v.as_str().trim_matches('"');
+            headers.add(IF_MATCH, format!("\"{etag}\""));
+        }
 
         Ok(S3Request::builder()
             .client(self.client)
@@ -279,6 +425,12 @@ pub struct DeleteObjects {
     verbose_mode: bool,
 }
 
+impl RequestHeaders for DeleteObjects {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 impl S3Api for DeleteObjects {
     type S3Response = DeleteObjectsResponse;
 }
@@ -299,7 +451,7 @@ pub type DeleteObjectsBldr = DeleteObjectsBuilder<(
 
 impl ToS3Request for DeleteObjects {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_bucket_name(&self.bucket, true)?;
+        self.client.check_bucket_name(&self.bucket)?;
         if self.objects.len() > MAX_DELETE_OBJECTS {
             return Err(ValidationErr::TooManyDeleteObjects(self.objects.len()));
         }
@@ -396,6 +548,12 @@ pub struct DeleteObjectsStreaming {
     region: Option<Region>,
 }
 
+impl RequestHeaders for DeleteObjectsStreaming {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 impl DeleteObjectsStreaming {
     pub fn new(client: MinioClient, bucket: BucketName, objects: impl Into<ObjectsStream>) -> Self {
         Self {
@@ -442,6 +600,16 @@ impl DeleteObjectsStreaming {
         self
     }
 
+    /// Pages through all objects of the stream and returns the ones that would be
+    /// deleted, without sending any delete request.
+    pub async fn dry_run(mut self) -> Vec<ObjectToDelete> {
+        let mut objects = Vec::new();
+        while let Some(object) = self.objects.items.next().await {
+            objects.push(object);
+        }
+        objects
+    }
+
     async fn next_request(&mut self) -> Result<Option<DeleteObjects>, ValidationErr> {
         let mut objects = Vec::new();
         while let Some(object) = self.objects.items.next().await {
@@ -497,6 +665,7 @@ impl ToStream for DeleteObjectsStreaming {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::s3::client::test_support::{MockExecutor, test_client};
     use crate::s3::creds::StaticProvider;
     use crate::s3::http::BaseUrl;
 
@@ -549,4 +718,208 @@ mod tests {
 
         assert!(streaming.next_request().await.unwrap().is_none());
     }
+
+    /// A dry run must drain every object of the stream, across batch boundaries.
+    #[tokio::test]
+    async fn dry_run_returns_all_objects() {
+        let items: Vec<ObjectToDelete> = (0..=MAX_DELETE_OBJECTS).map(dummy_object).collect();
+        let streaming = DeleteObjectsStreaming::new(
+            dummy_client(),
+            BucketName::new("test-bucket").unwrap(),
+            items.into_iter(),
+        );
+
+        let objects = streaming.dry_run().await;
+        assert_eq!(objects.len(), MAX_DELETE_OBJECTS + 1);
+        assert_eq!(objects[0].key().as_str(), "obj-0");
+        assert_eq!(
+            objects[MAX_DELETE_OBJECTS].key().as_str(),
+            format!("obj-{MAX_DELETE_OBJECTS}")
+        );
+    }
+
+    #[test]
+    fn object_lock_error_reflects_retention_and_legal_hold() {
+        let key = ObjectKey::new("obj").unwrap();
+        let now = utc_now();
+        let future = Some(now + chrono::Duration::days(1));
+        let past = Some(now - chrono::Duration::days(1));
+
+        let err = object_lock_error(
+            &key,
+            Some(RetentionMode::COMPLIANCE),
+            future,
+            false,
+            true,
+            now,
+        );
+        assert!(matches!(
+            err,
+            Some(ValidationErr::ObjectLocked {
+                mode: Some(RetentionMode::COMPLIANCE),
+                retain_until: Some(_),
+                legal_hold: false,
+                ..
+            })
+        ));
+
+        // Expired retention and bypassed governance do not block the delete.
+        assert!(
+            object_lock_error(
+                &key,
+                Some(RetentionMode::COMPLIANCE),
+                past,
+                false,
+                false,
+                now
+            )
+            .is_none()
+        );
+        assert!(
+            object_lock_error(
+                &key,
+                Some(RetentionMode::GOVERNANCE),
+                future,
+                false,
+                true,
+                now
+            )
+            .is_none()
+        );
+        assert!(
+            object_lock_error(
+                &key,
+                Some(RetentionMode::GOVERNANCE),
+                future,
+                false,
+                false,
+                now
+            )
+            .is_some()
+        );
+
+        let err = object_lock_error(&key, None, None, true, true, now);
+        assert!(matches!(
+            err,
+            Some(ValidationErr::ObjectLocked {
+                mode: None,
+                legal_hold: true,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn delete_object_if_match_requires_current_etag() {
+        // Deletes the object only if `If-Match` carries its current ETag "abc".
+        let client = test_client(MockExecutor::new(|req| {
+            if req.header(IF_MATCH).is_none_or(|v| v == "\"abc\"") {
+                http::Response::builder().status(204).body(String::new())
+            } else {
+                http::Response::builder()
+                    .status(412)
+                    .header(http::header::CONTENT_TYPE, "application/xml")
+                    .body("<Error><Code>PreconditionFailed</Code><Message>At least one of the pre-conditions you specified did not hold</Message></Error>".into())
+            }
+            .unwrap()
+        }));
+        let delete = |etag: &str| {
+            client
+                .delete_object("bucket", "object")
+                .unwrap()
+                .if_match(ETag::new(etag).unwrap())
+                .build()
+                .send()
+        };
+
+        match delete("stale").await {
+            Err(Error::S3Server(S3ServerError::S3Error(e))) => {
+                assert_eq!(e.code(), MinioErrorCode::PreconditionFailed)
+            }
+            v => panic!("expected PreconditionFailed, got {v:?}"),
+        }
+        delete("abc").await.unwrap();
+    }
+
+    /// Answers retention and legal hold requests with `lock_error` when set, or
+    /// with the given legal hold status, and deletes with 204.
+    fn object_lock_executor(
+        lock_error: Option<(u16, &'static str)>,
+        legal_hold: &'static str,
+    ) -> MockExecutor {
+        MockExecutor::new(move |req| {
+            let query = req.url.query().unwrap_or_default();
+            let resp = http::Response::builder();
+            match lock_error {
+                _ if req.method == Method::DELETE => resp.status(204).body(String::new()),
+                Some((status, code)) => resp
+                    .status(status)
+                    .header(http::header::CONTENT_TYPE, "application/xml")
+                    .body(format!("<Error><Code>{code}</Code><Message>{code}</Message></Error>")),
+                None if query.starts_with("legal-hold") => resp
+                    .status(200)
+                    .body(format!("<LegalHold><Status>{legal_hold}</Status></LegalHold>")),
+                None => resp.status(404).header(http::header::CONTENT_TYPE, "application/xml").body(
+                    "<Error><Code>NoSuchObjectLockConfiguration</Code><Message>none</Message></Error>".to_string(),
+                ),
+            }
+            .unwrap()
+        })
+    }
+
+    async fn delete_unlocked(executor: &MockExecutor) -> Result<DeleteObjectResponse, Error> {
+        test_client(executor.clone())
+            .delete_object("bucket", "object")
+            .unwrap()
+            .ensure_unlocked(true)
+            .build()
+            .send()
+            .await
+    }
+
+    fn deleted(executor: &MockExecutor) -> bool {
+        executor
+            .requests()
+            .iter()
+            .any(|r| r.method == Method::DELETE)
+    }
+
+    #[tokio::test]
+    async fn ensure_unlocked_deletes_unlocked_object() {
+        // Neither retention nor legal hold was ever set on the object.
+        let executor = object_lock_executor(Some((404, "NoSuchObjectLockConfiguration")), "OFF");
+        delete_unlocked(&executor).await.unwrap();
+        assert!(deleted(&executor));
+
+        // Only retention is unset; the legal hold is explicitly off.
+        let executor = object_lock_executor(None, "OFF");
+        delete_unlocked(&executor).await.unwrap();
+        assert!(deleted(&executor));
+    }
+
+    #[tokio::test]
+    async fn ensure_unlocked_deletes_in_bucket_without_object_lock() {
+        for code in ["InvalidRequest", "ObjectLockConfigurationNotFoundError"] {
+            let executor = object_lock_executor(Some((400, code)), "OFF");
+            delete_unlocked(&executor).await.unwrap();
+            assert!(deleted(&executor), "{code}");
+        }
+    }
+
+    #[tokio::test]
+    async fn ensure_unlocked_rejects_legal_hold() {
+        let executor = object_lock_executor(None, "ON");
+        let err = delete_unlocked(&executor).await.unwrap_err();
+        assert!(
+            matches!(
+                err,
+                Error::Validation(ValidationErr::ObjectLocked {
+                    legal_hold: true,
+                    ..
+                })
+            ),
+            "{err:?}"
+        );
+        assert!(!deleted(&executor));
+    }
 }
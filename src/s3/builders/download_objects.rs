@@ -0,0 +1,235 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::client::MinioClient;
+use crate::s3::error::{Error, IoError};
+use crate::s3::response::{
+    DownloadError, DownloadObjectsResponse, DownloadResult, DownloadedObject,
+};
+use crate::s3::types::{BucketName, Region, ResponseHeaderOverrides, S3Api, ToStream};
+use futures_util::StreamExt;
+use std::path::{Component, Path, PathBuf};
+use typed_builder::TypedBuilder;
+
+/// Argument builder for downloading all objects under a prefix into a local
+/// directory.
+///
+/// Objects are listed recursively and each one is written to `local_dir`, at
+/// the path of its key relative to the directory part of the prefix; `/` in
+/// keys creates nested directories. Keys ending in `/` (pseudo-folders) are
+/// skipped.
+///
+/// This struct constructs the parameters required for the [`Client::download_objects_by_prefix`](crate::s3::client::MinioClient::download_objects_by_prefix) method.
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct DownloadObjects {
+    #[builder(!default)] // force required
+    client: MinioClient,
+
+    #[builder(default, setter(into))]
+    region: Option<Region>,
+    #[builder(setter(into), !default)]
+    bucket: BucketName,
+    #[builder(setter(into), !default)]
+    prefix: String,
+    #[builder(setter(into), !default)]
+    local_dir: PathBuf,
+
+    /// Maximum number of keys returned per listing request.
+    #[builder(default, setter(into))]
+    max_keys: Option<u16>,
+    /// Flushes each downloaded file to disk with `sync_all` before it is
+    /// renamed into place.
+    #[builder(default = false)]
+    sync_on_close: bool,
+    /// Response headers for the server to replace, such as `Content-Disposition`
+    /// to force a download.
+    #[builder(default)]
+    response_headers: ResponseHeaderOverrides,
+}
+
+/// Builder type for [`DownloadObjects`] that is returned by [`MinioClient::download_objects_by_prefix`](crate::s3::client::MinioClient::download_objects_by_prefix).
+///
+/// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
+pub type DownloadObjectsBldr = DownloadObjectsBuilder<(
+    (MinioClient,),
+    (),
+    (BucketName,),
+    (String,),
+    (PathBuf,),
+    (),
+    (),
+    (),
+)>;
+
+impl DownloadObjects {
+    /// Downloads every object under the prefix. A failure to list objects is
+    /// returned as an error; a failure to download an individual object is
+    /// reported as a [`failed`](crate::s3::response::BatchResult::failed) item
+    /// of the result and the remaining objects are still downloaded.
+    pub async fn send(self) -> Result<DownloadObjectsResponse, Error> {
+        let mut stream = self
+            .client
+            .list_objects(&self.bucket)?
+            .region(self.region.clone())
+            .prefix(Some(self.prefix.clone()))
+            .max_keys(self.max_keys)
+            .recursive(true)
+            .build()
+            .to_stream()
+            .await;
+
+        let base = match self.prefix.rfind('/') {
+            Some(i) => &self.prefix[..=i],
+            None => "",
+        };
+
+        let mut results = Vec::new();
+        while let Some(items) = stream.next().await {
+            for entry in items?.contents {
+                if entry.name.ends_with('/') {
+                    continue;
+                }
+                let result = match local_path(&self.local_dir, base, &entry.name) {
+                    Some(path) => match self.download(&entry.name, &path).await {
+                        Ok(size) => DownloadResult::Downloaded(DownloadedObject {
+                            name: entry.name,
+                            path,
+                            size,
+                        }),
+                        Err(e) => DownloadResult::Error(DownloadError {
+                            object_name: entry.name,
+                            message: e.to_string(),
+                        }),
+                    },
+                    None => DownloadResult::Error(DownloadError {
+                        message: format!(
+                            "key {} cannot be mapped to a path below {}",
+                            entry.name,
+                            self.local_dir.display()
+                        ),
+                        object_name: entry.name,
+                    }),
+                };
+                results.push(result);
+            }
+        }
+
+        Ok(DownloadObjectsResponse::new(self.bucket, results))
+    }
+
+    async fn download(&self, object: &str, path: &Path) -> Result<u64, Error> {
+        let resp = self
+            .client
+            .get_object(&self.bucket, object)?
+            .region(self.region.clone())
+            .response_headers(self.response_headers.clone())
+            .build()
+            .send()
+            .await?;
+        resp.content()?
+            .to_file_with_sync(path, self.sync_on_close)
+            .await
+            .map_err(|e| IoError::from(e).into())
+    }
+}
+
+/// Maps `key` to a path below `local_dir`, after removing `base` from its start.
+/// Returns `None` if the key would escape `local_dir`, e.g. through a `..` segment.
+fn local_path(local_dir: &Path, base: &str, key: &str) -> Option<PathBuf> {
+    let relative = Path::new(key.strip_prefix(base).unwrap_or(key));
+    let mut path = local_dir.to_path_buf();
+    for component in relative.components() {
+        match component {
+            Component::Normal(c) => path.push(c),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    (path != local_dir).then_some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{MockExecutor, test_client};
+
+    #[test]
+    fn local_path_mirrors_key_below_prefix_directory() {
+        let dir = Path::new("/tmp/out");
+        assert_eq!(
+            local_path(dir, "photos/", "photos/2024/a.jpg"),
+            Some(PathBuf::from("/tmp/out/2024/a.jpg"))
+        );
+        assert_eq!(
+            local_path(dir, "", "a/b//c.txt"),
+            Some(PathBuf::from("/tmp/out/a/b/c.txt"))
+        );
+    }
+
+    #[test]
+    fn local_path_rejects_keys_escaping_local_dir() {
+        let dir = Path::new("/tmp/out");
+        assert_eq!(local_path(dir, "", "../etc/passwd"), None);
+        assert_eq!(local_path(dir, "", "a/../../b"), None);
+        assert_eq!(local_path(dir, "", "/etc/passwd"), None);
+        assert_eq!(local_path(dir, "", "."), None);
+    }
+
+    #[tokio::test]
+    async fn download_objects_sends_response_header_overrides() {
+        let executor = MockExecutor::new(|req| {
+            let resp = http::Response::builder().status(200);
+            if req.url.query().unwrap_or_default().contains("list-type=2") {
+                resp.body(
+                    "<ListBucketResult><Name>bucket</Name><Prefix>docs/</Prefix><KeyCount>1</KeyCount>\
+                     <MaxKeys>1000</MaxKeys><IsTruncated>false</IsTruncated><Contents><Key>docs/a.txt</Key>\
+                     <LastModified>2024-01-01T00:00:00.000Z</LastModified><ETag>&quot;abc&quot;</ETag>\
+                     <Size>5</Size></Contents></ListBucketResult>"
+                        .to_string(),
+                )
+            } else {
+                resp.header(http::header::CONTENT_LENGTH, 5)
+                    .body("hello".to_string())
+            }
+            .unwrap()
+        });
+        let dir = std::env::temp_dir().join(format!("minio-download-{}", uuid::Uuid::new_v4()));
+
+        let resp = test_client(executor.clone())
+            .download_objects_by_prefix("bucket", "docs/", &dir)
+            .unwrap()
+            .response_headers(ResponseHeaderOverrides {
+                content_type: Some("text/plain".into()),
+                ..Default::default()
+            })
+            .build()
+            .send()
+            .await
+            .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(resp.result().succeeded().count(), 1, "{resp:?}");
+        let get = &executor.requests()[1];
+        assert_eq!(get.url.path(), "/bucket/docs/a.txt");
+        assert!(
+            get.url
+                .query()
+                .unwrap_or_default()
+                .contains("response-content-type=text%2Fplain"),
+            "{}",
+            get.url
+        );
+    }
+}
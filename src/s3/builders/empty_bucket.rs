@@ -0,0 +1,176 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::builders::ObjectToDelete;
+use crate::s3::client::MinioClient;
+use crate::s3::error::{Error, ValidationErr};
+use crate::s3::response::{DeleteObjectsResponse, DeleteResult, EmptyBucketResponse};
+use crate::s3::types::{BucketName, ObjectKey, Region, ToStream};
+use futures_util::StreamExt;
+use typed_builder::TypedBuilder;
+
+/// Argument builder for removing every object from a bucket, including all
+/// object versions and delete markers of versioned buckets.
+///
+/// Objects are listed and removed in batches with the
+/// [`DeleteObjects`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObjects.html)
+/// S3 API operation. The bucket itself is not removed; call
+/// [`MinioClient::delete_bucket`](crate::s3::client::MinioClient::delete_bucket) afterwards.
+///
+/// This struct constructs the parameters required for the [`Client::empty_bucket`](crate::s3::client::MinioClient::empty_bucket) method.
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct EmptyBucket {
+    #[builder(!default)] // force required
+    client: MinioClient,
+
+    #[builder(default, setter(into))]
+    region: Option<Region>,
+    #[builder(setter(into), !default)]
+    bucket: BucketName,
+
+    /// Also remove objects protected by GOVERNANCE mode retention. Objects under
+    /// COMPLIANCE mode retention or a legal hold are never removed.
+    #[builder(default)]
+    bypass_governance: bool,
+
+    /// List the objects that would be removed without deleting anything.
+    #[builder(default)]
+    dry_run: bool,
+}
+
+/// Builder type for [`EmptyBucket`] that is returned by [`MinioClient::empty_bucket`](crate::s3::client::MinioClient::empty_bucket).
+///
+/// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
+pub type EmptyBucketBldr = EmptyBucketBuilder<((MinioClient,), (), (BucketName,), (), ())>;
+
+impl EmptyBucket {
+    /// Removes all objects and returns the number of deleted entries. If any
+    /// object cannot be removed, e.g. because it is locked, the remaining objects
+    /// are still processed and [`ValidationErr::BucketNotEmptied`] is returned.
+    ///
+    /// In dry-run mode all objects are still listed, but no delete request is
+    /// sent; the response lists the objects and versions that would be removed.
+    pub async fn send(self) -> Result<EmptyBucketResponse, Error> {
+        let outcome = delete_listed(
+            &self.client,
+            &self.bucket,
+            &self.region,
+            None,
+            self.bypass_governance,
+            self.dry_run,
+        )
+        .await?;
+
+        match outcome.first_failure {
+            None => Ok(EmptyBucketResponse::new(
+                self.bucket,
+                outcome.deleted,
+                outcome.would_delete,
+            )),
+            Some(first) => Err(ValidationErr::BucketNotEmptied {
+                bucket: self.bucket.to_string(),
+                failed: outcome.failed,
+                first,
+            }
+            .into()),
+        }
+    }
+}
+
+/// Result of [`delete_listed`].
+pub(crate) struct DeleteListedOutcome {
+    pub(crate) deleted: u64,
+    pub(crate) would_delete: Vec<ObjectToDelete>,
+    pub(crate) failed: usize,
+    pub(crate) first_failure: Option<String>,
+}
+
+/// Lists all objects, versions and delete markers of `bucket` and deletes them
+/// in batches. When `object` is set, only the versions of that key are deleted.
+/// In dry-run mode the listing is still paged through completely, but the
+/// entries are collected instead of deleted.
+pub(crate) async fn delete_listed(
+    client: &MinioClient,
+    bucket: &BucketName,
+    region: &Option<Region>,
+    object: Option<&ObjectKey>,
+    bypass_governance: bool,
+    dry_run: bool,
+) -> Result<DeleteListedOutcome, Error> {
+    let is_express = client.is_minio_express().await;
+
+    let mut stream = client
+        .list_objects(bucket)?
+        .region(region.clone())
+        .prefix(object.map(|o| o.to_string()))
+        .include_versions(!is_express)
+        .recursive(true)
+        .build()
+        .to_stream()
+        .await;
+
+    let mut outcome = DeleteListedOutcome {
+        deleted: 0,
+        would_delete: Vec::new(),
+        failed: 0,
+        first_failure: None,
+    };
+
+    while let Some(items) = stream.next().await {
+        let objects: Vec<ObjectToDelete> = items?
+            .contents
+            .into_iter()
+            .filter(|entry| object.is_none_or(|o| entry.name == o.as_str()))
+            .map(ObjectToDelete::from)
+            .collect();
+        if dry_run {
+            outcome.would_delete.extend(objects);
+            continue;
+        }
+
+        let mut resp = client
+            .delete_objects_streaming(bucket, objects.into_iter())?
+            .region(region.clone())
+            // Express does not support governance mode
+            .bypass_governance_mode(bypass_governance && !is_express)
+            .to_stream()
+            .await;
+
+        while let Some(item) = resp.next().await {
+            let resp: DeleteObjectsResponse = item?;
+            for result in resp.result()? {
+                match result {
+                    DeleteResult::Deleted(_) => outcome.deleted += 1,
+                    DeleteResult::Error(e) => {
+                        outcome.failed += 1;
+                        outcome
+                            .first_failure
+                            .get_or_insert_with(|| match &e.version_id {
+                                Some(v) => {
+                                    format!(
+                                        "{}?versionId={v}: {} {}",
+                                        e.object_name, e.code, e.message
+                                    )
+                                }
+                                None => format!("{}: {} {}", e.object_name, e.code, e.message),
+                            });
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(outcome)
+}
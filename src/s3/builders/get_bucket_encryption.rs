@@ -14,37 +14,71 @@
 // limitations under the License.
 
 use crate::s3::MinioClient;
-use crate::s3::builders::{BucketCommon, BucketCommonBuilder};
-use crate::s3::error::ValidationErr;
+use crate::s3::client::bucket_config_cache::{BucketConfigKind, send_cached};
+use crate::s3::error::{Error, ValidationErr};
+use crate::s3::multimap_ext::Multimap;
 use crate::s3::response::GetBucketEncryptionResponse;
-use crate::s3::types::{BucketName, S3Api, S3Request, ToS3Request};
-use crate::s3::utils::{check_bucket_name, insert};
+use crate::s3::types::{BucketName, Region, RequestHeaders, S3Api, S3Request, ToS3Request};
+use crate::s3::utils::insert;
+use async_trait::async_trait;
 use http::Method;
+use typed_builder::TypedBuilder;
 
 /// Argument builder for the [`GetBucketEncryption`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetBucketEncryption.html) S3 API operation.
 ///
 /// This struct constructs the parameters required for the [`Client::get_bucket_encryption`](crate::s3::client::MinioClient::get_bucket_encryption) method.
-pub type GetBucketEncryption = BucketCommon<GetBucketEncryptionPhantomData>;
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct GetBucketEncryption {
+    #[builder(!default)] // force required
+    client: MinioClient,
+    #[builder(default, setter(into))]
+    extra_headers: Option<Multimap>,
+    #[builder(default, setter(into))]
+    extra_query_params: Option<Multimap>,
+    #[builder(default)]
+    region: Option<Region>,
+    #[builder(setter(into), !default)]
+    bucket: BucketName,
+    /// Bypass the client's bucket configuration cache; see
+    /// [`MinioClientBuilder::bucket_config_cache_ttl`](crate::s3::client::MinioClientBuilder::bucket_config_cache_ttl).
+    #[builder(setter(strip_bool))]
+    no_cache: bool,
+}
 
-#[doc(hidden)]
-#[derive(Clone, Debug)]
-pub struct GetBucketEncryptionPhantomData;
+impl RequestHeaders for GetBucketEncryption {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
 
+#[async_trait]
 impl S3Api for GetBucketEncryption {
     type S3Response = GetBucketEncryptionResponse;
+
+    async fn send(self) -> Result<Self::S3Response, Error> {
+        let client = self.client.clone();
+        let bucket = self.bucket.clone();
+        let no_cache = self.no_cache;
+        send_cached(
+            self,
+            &client,
+            &bucket,
+            BucketConfigKind::Encryption,
+            no_cache,
+        )
+        .await
+    }
 }
 
 /// Builder type for [`GetBucketEncryption`] that is returned by [`MinioClient::get_bucket_encryption`](crate::s3::client::MinioClient::get_bucket_encryption).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
-pub type GetBucketEncryptionBldr = BucketCommonBuilder<
-    GetBucketEncryptionPhantomData,
-    ((MinioClient,), (), (), (), (BucketName,), ()),
->;
+pub type GetBucketEncryptionBldr =
+    GetBucketEncryptionBuilder<((MinioClient,), (), (), (), (BucketName,), ())>;
 
 impl ToS3Request for GetBucketEncryption {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_bucket_name(&self.bucket, true)?;
+        self.client.check_bucket_name(&self.bucket)?;
 
         Ok(S3Request::builder()
             .client(self.client)
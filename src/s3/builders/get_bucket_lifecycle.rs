@@ -14,11 +14,15 @@
 // limitations under the License.
 
 use crate::s3::client::MinioClient;
-use crate::s3::error::ValidationErr;
+use crate::s3::client::bucket_config_cache::{BucketConfigKind, send_cached};
+use crate::s3::error::{Error, ValidationErr};
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::GetBucketLifecycleResponse;
-use crate::s3::types::{BucketName, Region, S3Api, S3Request, ToS3Request};
+use crate::s3::types::{
+    BucketName, FromS3Response, Region, RequestHeaders, S3Api, S3Request, ToS3Request,
+};
 use crate::s3::utils::insert;
+use async_trait::async_trait;
 use http::Method;
 use typed_builder::TypedBuilder;
 
@@ -39,17 +43,48 @@ pub struct GetBucketLifecycle {
     bucket: BucketName,
     #[builder(default)]
     with_updated_at: bool,
+    /// Bypass the client's bucket configuration cache; see
+    /// [`MinioClientBuilder::bucket_config_cache_ttl`](crate::s3::client::MinioClientBuilder::bucket_config_cache_ttl).
+    /// Requests with `with_updated_at` are never cached.
+    #[builder(setter(strip_bool))]
+    no_cache: bool,
 }
 
+impl RequestHeaders for GetBucketLifecycle {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
+#[async_trait]
 impl S3Api for GetBucketLifecycle {
     type S3Response = GetBucketLifecycleResponse;
+
+    async fn send(self) -> Result<Self::S3Response, Error> {
+        if self.with_updated_at {
+            let mut req = self.to_s3request()?;
+            let resp = req.execute().await;
+            return Self::S3Response::from_s3response(req, resp).await;
+        }
+        let client = self.client.clone();
+        let bucket = self.bucket.clone();
+        let no_cache = self.no_cache;
+        send_cached(
+            self,
+            &client,
+            &bucket,
+            BucketConfigKind::Lifecycle,
+            no_cache,
+        )
+        .await
+    }
 }
 
 /// Builder type for [`GetBucketLifecycle`] that is returned by [`MinioClient::get_bucket_lifecycle`](crate::s3::client::MinioClient::get_bucket_lifecycle).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
 pub type GetBucketLifecycleBldr =
-    GetBucketLifecycleBuilder<((MinioClient,), (), (), (), (BucketName,), ())>;
+    GetBucketLifecycleBuilder<((MinioClient,), (), (), (), (BucketName,), (), ())>;
 
 impl ToS3Request for GetBucketLifecycle {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
@@ -17,7 +17,7 @@ use crate::s3::client::MinioClient;
 use crate::s3::error::ValidationErr;
 use crate::s3::multimap_ext::Multimap;
 use crate::s3::response::GetBucketTaggingResponse;
-use crate::s3::types::{BucketName, Region, S3Api, S3Request, ToS3Request};
+use crate::s3::types::{BucketName, Region, RequestHeaders, S3Api, S3Request, ToS3Request};
 use crate::s3::utils::insert;
 use http::Method;
 use typed_builder::TypedBuilder;
@@ -39,6 +39,12 @@ pub struct GetBucketTagging {
     bucket: BucketName,
 }
 
+impl RequestHeaders for GetBucketTagging {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 impl S3Api for GetBucketTagging {
     type S3Response = GetBucketTaggingResponse;
 }
@@ -13,17 +13,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::s3::builders::StatObject;
 use crate::s3::client::MinioClient;
-use crate::s3::error::ValidationErr;
+use crate::s3::error::{Error, IoError, ValidationErr};
 use crate::s3::header_constants::*;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::GetObjectResponse;
+use crate::s3::response_traits::{HasEtagFromHeaders, HasS3Fields};
 use crate::s3::sse::{Sse, SseCustomerKey};
-use crate::s3::types::{BucketName, ObjectKey, Region, S3Api, S3Request, ToS3Request, VersionId};
-use crate::s3::utils::{UtcTime, check_ssec, to_http_header_value};
+use crate::s3::types::{
+    BucketName, FromS3Response, ObjectKey, Region, RequestHeaders, ResponseHeaderOverrides, S3Api,
+    S3Request, ToS3Request, VersionId,
+};
+use crate::s3::utils::{UtcTime, add_request_payer, check_ssec, to_http_header_value};
+use async_std::io::{Write, WriteExt};
+use async_trait::async_trait;
+use futures_util::{StreamExt, TryStreamExt, stream};
 use http::Method;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
 use typed_builder::TypedBuilder;
 
+/// Number of attempts made to download a single segment in [`GetObject::parallel_download`].
+pub const MAX_SEGMENT_ATTEMPTS: usize = 3;
+
 /// Argument builder for the [`GetObject`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetObject.html) S3 API operation.
 ///
 /// This struct constructs the parameters required for the [`Client::get_object`](crate::s3::client::MinioClient::get_object) method.
@@ -47,6 +61,10 @@ pub struct GetObject {
     offset: Option<u64>,
     #[builder(default, setter(into))]
     length: Option<u64>,
+    /// Downloads the last `suffix_length` bytes of the object (`Range: bytes=-N`).
+    /// Cannot be combined with `offset` or `length`.
+    #[builder(default, setter(into))]
+    suffix_length: Option<u64>,
     #[builder(default, setter(into))]
     ssec: Option<SseCustomerKey>,
 
@@ -59,6 +77,33 @@ pub struct GetObject {
     modified_since: Option<UtcTime>,
     #[builder(default, setter(into))]
     unmodified_since: Option<UtcTime>,
+
+    /// Sends `x-amz-request-payer: requester`, required for requester-pays buckets.
+    #[builder(default = false)]
+    request_payer: bool,
+
+    /// Decompresses the content returned by
+    /// [`GetObjectResponse::content`] when the object is stored with
+    /// `Content-Encoding: gzip` or `deflate`; see
+    /// [`GetObjectResponse::with_decompression`].
+    #[builder(default = false)]
+    auto_decompress: bool,
+
+    /// Flushes the downloaded file to disk with `sync_all` before
+    /// [`parallel_download`](Self::parallel_download) renames it into place.
+    #[builder(default = false)]
+    sync_on_close: bool,
+
+    /// Response headers for the server to replace, such as `Content-Disposition`
+    /// to force a download.
+    #[builder(default)]
+    response_headers: ResponseHeaderOverrides,
+}
+
+impl RequestHeaders for GetObject {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
 }
 
 /// Builder type alias for [`GetObject`].
@@ -79,18 +124,313 @@ pub type GetObjectBldr = GetObjectBuilder<(
     (),
     (),
     (),
+    (),
+    (),
+    (),
+    (),
+    (),
 )>;
 
+#[async_trait]
 impl S3Api for GetObject {
     type S3Response = GetObjectResponse;
+
+    async fn send(self) -> Result<Self::S3Response, Error> {
+        let auto_decompress = self.auto_decompress;
+        let mut req: S3Request = self.to_s3request()?;
+        let resp = req.execute().await;
+        Ok(GetObjectResponse::from_s3response(req, resp)
+            .await?
+            .with_decompression(auto_decompress))
+    }
+}
+
+impl GetObject {
+    /// Downloads exactly `length` bytes starting at `offset` into `writer`.
+    ///
+    /// The `Content-Range` header of the response must match the requested
+    /// range; a server that ignores the range or truncates it at the end of the
+    /// object results in an [`InvalidRange`](ValidationErr::InvalidRange) error.
+    /// The body is checked to contain exactly `length` bytes. Offset, length and
+    /// suffix length set on the builder are replaced. Returns the number of bytes
+    /// written.
+    pub async fn download_range_to<W>(
+        self,
+        writer: &mut W,
+        offset: u64,
+        length: u64,
+    ) -> Result<u64, Error>
+    where
+        W: Write + Unpin + ?Sized,
+    {
+        let (mut stream, _) = self.get_range(offset, length).await?.into_boxed_stream()?;
+        let mut written: u64 = 0;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(IoError::from)?;
+            if written + chunk.len() as u64 > length {
+                return Err(ValidationErr::TooMuchData(length).into());
+            }
+            writer.write_all(&chunk).await.map_err(IoError::from)?;
+            written += chunk.len() as u64;
+        }
+        if written != length {
+            return Err(ValidationErr::InsufficientData {
+                expected: length,
+                got: written,
+            }
+            .into());
+        }
+        writer.flush().await.map_err(IoError::from)?;
+        Ok(written)
+    }
+
+    /// Sends a GET for `length` bytes at `offset` and checks that the
+    /// `Content-Range` of the response matches.
+    async fn get_range(mut self, offset: u64, length: u64) -> Result<GetObjectResponse, Error> {
+        if length == 0 {
+            return Err(
+                ValidationErr::InvalidRange("length must be greater than zero".into()).into(),
+            );
+        }
+        let end = offset + length - 1;
+        self.offset = Some(offset);
+        self.length = Some(length);
+        self.suffix_length = None;
+
+        let resp = self.send().await?;
+        if resp.content_range().map(|v| (v.start, v.end)) != Some((offset, end)) {
+            let content_range = resp
+                .headers()
+                .get(CONTENT_RANGE)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default();
+            return Err(ValidationErr::InvalidRange(format!(
+                "requested bytes {offset}-{end}, but server returned Content-Range '{content_range}'"
+            ))
+            .into());
+        }
+        Ok(resp)
+    }
+
+    /// Downloads the object into `file_path` using concurrent ranged GETs.
+    ///
+    /// The object is split into segments of `segment_size` bytes (or into
+    /// `num_segments` equal segments if `segment_size` is zero), of which at
+    /// most `num_segments` are downloaded at once. The chunks of each segment
+    /// are written at their offset in the file as they arrive, so memory use
+    /// does not grow with the segment size and segments may complete in any
+    /// order. A failed segment is retried on its own from where it stopped, up
+    /// to [`MAX_SEGMENT_ATTEMPTS`] times; errors returned by the server are not
+    /// retried.
+    ///
+    /// Segments are pinned to the ETag reported by the initial stat, so a
+    /// concurrent overwrite of the object fails the download instead of
+    /// producing a mix of two versions. As with
+    /// [`ObjectContent::to_file`](crate::s3::builders::ObjectContent::to_file), data
+    /// is written to a temporary file that is renamed to `file_path` once all
+    /// segments are complete; set `sync_on_close` to also flush it to disk first.
+    /// File operations run on a blocking thread pool. Returns the object size.
+    pub async fn parallel_download(
+        mut self,
+        file_path: &Path,
+        num_segments: usize,
+        segment_size: u64,
+    ) -> Result<u64, Error> {
+        let num_segments = num_segments.max(1);
+        let stat = StatObject::builder()
+            .client(self.client.clone())
+            .extra_headers(self.extra_headers.clone())
+            .extra_query_params(self.extra_query_params.clone())
+            .region(self.region.clone())
+            .bucket(self.bucket.clone())
+            .object(self.object.clone())
+            .version_id(self.version_id.clone().map(VersionId::new).transpose()?)
+            .ssec(self.ssec.clone())
+            .match_etag(self.match_etag.clone())
+            .request_payer(self.request_payer)
+            .build()
+            .send()
+            .await?;
+        let size = stat.size()?;
+        if self.match_etag.is_none() {
+            self.match_etag = stat.etag().ok().map(|v| v.to_string());
+        }
+
+        let parent_dir = file_path
+            .parent()
+            .filter(|v| !v.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let file_name = file_path.file_name().ok_or_else(|| {
+            IoError::from(std::io::Error::other(
+                "could not get filename-component of path",
+            ))
+        })?;
+        let mut tmp_file_name = file_name.to_os_string();
+        tmp_file_name.push(format!("_{}", uuid::Uuid::new_v4().simple()));
+        let tmp_file_path = parent_dir.join(tmp_file_name);
+
+        let file = {
+            let tmp_file_path = tmp_file_path.clone();
+            run_file_op(move || {
+                let file = File::create(&tmp_file_path)?;
+                file.set_len(size)?;
+                Ok(file)
+            })
+            .await?
+        };
+        let file = Arc::new(file);
+
+        let segment_size = match segment_size {
+            0 => size.div_ceil(num_segments as u64).max(1),
+            v => v,
+        };
+        let segments = (0..size)
+            .step_by(segment_size as usize)
+            .map(|offset| (offset, segment_size.min(size - offset)));
+
+        let result = stream::iter(segments.map(Ok::<_, Error>))
+            .try_for_each_concurrent(num_segments, |(offset, length)| {
+                let request = self.clone();
+                let file = Arc::clone(&file);
+                async move { request.download_segment(file, offset, length).await }
+            })
+            .await;
+        let result = match result {
+            Ok(()) if self.sync_on_close => run_file_op(move || file.sync_all())
+                .await
+                .map_err(Error::from),
+            v => {
+                drop(file);
+                v
+            }
+        };
+
+        match result {
+            Ok(()) => {
+                async_std::fs::rename(&tmp_file_path, file_path)
+                    .await
+                    .map_err(IoError::from)?;
+                Ok(size)
+            }
+            Err(e) => {
+                let _ = async_std::fs::remove_file(&tmp_file_path).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn download_segment(
+        self,
+        file: Arc<File>,
+        offset: u64,
+        length: u64,
+    ) -> Result<(), Error> {
+        let end = offset + length;
+        let mut pos = offset;
+        let mut attempt = 1;
+        loop {
+            match self.clone().write_range_at(&file, &mut pos, end).await {
+                Ok(()) => return Ok(()),
+                Err(e @ Error::S3Server(_)) => return Err(e),
+                Err(e) if attempt >= MAX_SEGMENT_ATTEMPTS => return Err(e),
+                Err(e) => {
+                    log::debug!(
+                        "retrying segment at offset {offset} of {}/{} from {pos} after attempt {attempt}: {e}",
+                        self.bucket,
+                        self.object
+                    );
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// Downloads bytes `*pos..end` and writes each chunk at its offset in
+    /// `file` as it arrives, advancing `*pos` past the written data, so a
+    /// retry resumes where a failed attempt stopped.
+    async fn write_range_at(self, file: &Arc<File>, pos: &mut u64, end: u64) -> Result<(), Error> {
+        let start = *pos;
+        let (mut stream, _) = self
+            .get_range(start, end - start)
+            .await?
+            .into_boxed_stream()?;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(IoError::from)?;
+            if *pos + chunk.len() as u64 > end {
+                return Err(ValidationErr::TooMuchData(end - start).into());
+            }
+            #[cfg(test)]
+            tests::on_segment_write(chunk.len());
+            let file = Arc::clone(file);
+            let offset = *pos;
+            let len = chunk.len() as u64;
+            run_file_op(move || write_all_at(&file, &chunk, offset)).await?;
+            *pos += len;
+        }
+        if *pos != end {
+            return Err(ValidationErr::InsufficientData {
+                expected: end - start,
+                got: *pos - start,
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// Runs a blocking file operation on the blocking thread pool.
+async fn run_file_op<T, F>(op: F) -> Result<T, IoError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> std::io::Result<T> + Send + 'static,
+{
+    async_std::task::spawn_blocking(move || {
+        #[cfg(test)]
+        tests::on_file_op();
+        op()
+    })
+    .await
+    .map_err(IoError::from)
+}
+
+#[cfg(unix)]
+fn write_all_at(file: &File, buf: &[u8], offset: u64) -> std::io::Result<()> {
+    std::os::unix::fs::FileExt::write_all_at(file, buf, offset)
+}
+
+#[cfg(windows)]
+fn write_all_at(file: &File, mut buf: &[u8], mut offset: u64) -> std::io::Result<()> {
+    use std::os::windows::fs::FileExt;
+    while !buf.is_empty() {
+        let n = file.seek_write(buf, offset)?;
+        if n == 0 {
+            return Err(std::io::ErrorKind::WriteZero.into());
+        }
+        buf = &buf[n..];
+        offset += n as u64;
+    }
+    Ok(())
 }
 
 impl ToS3Request for GetObject {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_ssec(&self.ssec, &self.client)?;
+        check_ssec(&self.ssec, &self.client, "GetObject")?;
 
         let mut headers: Multimap = self.extra_headers.unwrap_or_default();
         {
+            if let Some(n) = self.suffix_length {
+                if self.offset.is_some() || self.length.is_some() {
+                    return Err(ValidationErr::InvalidRange(
+                        "suffix_length cannot be combined with offset or length".into(),
+                    ));
+                }
+                if n == 0 {
+                    return Err(ValidationErr::InvalidRange(
+                        "suffix_length must be greater than zero".into(),
+                    ));
+                }
+                headers.add(RANGE, format!("bytes=-{n}"));
+            }
             {
                 let (offset, length): (Option<u64>, Option<u64>) = match self.length {
                     Some(_) => (Some(self.offset.unwrap_or(0_u64)), self.length),
@@ -128,6 +468,8 @@ impl ToS3Request for GetObject {
             if let Some(v) = &self.ssec {
                 headers.add_multimap(v.headers());
             }
+
+            add_request_payer(&mut headers, self.request_payer);
         }
 
         let mut query_params: Multimap = self.extra_query_params.unwrap_or_default();
@@ -135,6 +477,7 @@ impl ToS3Request for GetObject {
             .version_id
             .map(|v| VersionId::new(v).expect("valid version id"));
         query_params.add_version(version_id);
+        self.response_headers.add_to(&mut query_params);
 
         Ok(S3Request::builder()
             .client(self.client)
@@ -147,3 +490,458 @@ impl ToS3Request for GetObject {
             .build())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{MockExecutor, test_client};
+    use crate::s3::creds::StaticProvider;
+    use crate::s3::http::BaseUrl;
+    use crate::s3::response_traits::{HasRequestCharged, HasTaggingCount};
+    use crate::s3::types::FromS3Response;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+    use std::thread::ThreadId;
+    use std::time::{Duration, Instant};
+
+    fn dummy_client() -> MinioClient {
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let provider = StaticProvider::new("minioadmin", "minioadmin", None);
+        MinioClient::new(base_url, Some(provider), None, None).unwrap()
+    }
+
+    fn get_object(request_payer: bool) -> GetObject {
+        GetObject::builder()
+            .client(dummy_client())
+            .bucket(BucketName::new("bucket").unwrap())
+            .object(ObjectKey::new("object").unwrap())
+            .request_payer(request_payer)
+            .build()
+    }
+
+    #[test]
+    fn test_get_object_request_payer_header() {
+        let request = get_object(true).to_s3request().unwrap();
+        assert_eq!(
+            request
+                .headers()
+                .get(X_AMZ_REQUEST_PAYER)
+                .map(String::as_str),
+            Some("requester")
+        );
+
+        let request = get_object(false).to_s3request().unwrap();
+        assert!(!request.headers().contains_key(X_AMZ_REQUEST_PAYER));
+    }
+
+    #[test]
+    fn test_get_object_response_header_overrides() {
+        let expires = crate::s3::utils::from_iso8601utc("2025-01-15T00:00:00.000Z").unwrap();
+        let request = GetObject::builder()
+            .client(dummy_client())
+            .bucket(BucketName::new("bucket").unwrap())
+            .object(ObjectKey::new("object").unwrap())
+            .response_headers(ResponseHeaderOverrides {
+                content_type: Some("text/plain".into()),
+                content_disposition: Some("attachment; filename=\"a.txt\"".into()),
+                cache_control: Some("no-cache".into()),
+                expires: Some(expires),
+                ..Default::default()
+            })
+            .build()
+            .to_s3request()
+            .unwrap();
+
+        let query = &request.query_params;
+        let get = |k: &str| query.get(k).map(String::as_str);
+        assert_eq!(get("response-content-type"), Some("text/plain"));
+        assert_eq!(
+            get("response-content-disposition"),
+            Some("attachment; filename=\"a.txt\"")
+        );
+        assert_eq!(get("response-cache-control"), Some("no-cache"));
+        assert_eq!(
+            get("response-expires"),
+            Some("Wed, 15 Jan 2025 00:00:00 GMT")
+        );
+        assert_eq!(get("response-content-language"), None);
+    }
+
+    #[test]
+    fn test_get_object_suffix_range() {
+        let builder = |suffix_length: u64| {
+            GetObject::builder()
+                .client(dummy_client())
+                .bucket(BucketName::new("bucket").unwrap())
+                .object(ObjectKey::new("object").unwrap())
+                .suffix_length(suffix_length)
+        };
+        let request = builder(100).build().to_s3request().unwrap();
+        assert_eq!(
+            request.headers().get(RANGE).map(String::as_str),
+            Some("bytes=-100")
+        );
+
+        for request in [
+            builder(100).offset(10).build(),
+            builder(100).length(10).build(),
+        ] {
+            assert!(matches!(
+                request.to_s3request(),
+                Err(ValidationErr::InvalidRange(_))
+            ));
+        }
+        assert!(matches!(
+            builder(0).build().to_s3request(),
+            Err(ValidationErr::InvalidRange(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_object_request_charged() {
+        let request = get_object(true).to_s3request().unwrap();
+        let http_resp = http::Response::builder()
+            .status(200)
+            .header("x-amz-request-charged", "requester")
+            .body("")
+            .unwrap();
+        let resp = GetObjectResponse::from_s3response(request, Ok(http_resp.into()))
+            .await
+            .unwrap();
+        assert!(resp.request_charged());
+    }
+
+    #[tokio::test]
+    async fn test_get_object_tagging_count() {
+        for (header, expected) in [(Some("2"), Some(2)), (None, None)] {
+            let request = get_object(false).to_s3request().unwrap();
+            let mut http_resp = http::Response::builder().status(200);
+            if let Some(v) = header {
+                http_resp = http_resp.header("x-amz-tagging-count", v);
+            }
+            let resp =
+                GetObjectResponse::from_s3response(request, Ok(http_resp.body("").unwrap().into()))
+                    .await
+                    .unwrap();
+            assert_eq!(resp.tagging_count(), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_object_chunked_without_content_length() {
+        let request = get_object(false).to_s3request().unwrap();
+        let chunks = ["hello, ", "chunked ", "world"]
+            .map(|v| Ok::<_, std::io::Error>(bytes::Bytes::from_static(v.as_bytes())));
+        let http_resp = http::Response::builder()
+            .status(200)
+            .header("transfer-encoding", "chunked")
+            .body(reqwest::Body::wrap_stream(stream::iter(chunks)))
+            .unwrap();
+        let resp = GetObjectResponse::from_s3response(request, Ok(http_resp.into()))
+            .await
+            .unwrap();
+        assert_eq!(resp.object_size(), None);
+
+        let content = resp.content().unwrap();
+        let data = content.to_segmented_bytes().await.unwrap().to_bytes();
+        assert_eq!(data, "hello, chunked world");
+    }
+
+    #[derive(Debug, Default)]
+    struct CaptureHostHook {
+        captured: std::sync::Mutex<Option<(String, String)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::s3::client::RequestHooks for CaptureHostHook {
+        fn name(&self) -> &'static str {
+            "capture-host"
+        }
+
+        async fn before_signing_mut(
+            &self,
+            _method: &http::Method,
+            url: &mut crate::s3::http::Url,
+            _region: &str,
+            headers: &mut Multimap,
+            _query_params: &Multimap,
+            _bucket: Option<&BucketName>,
+            _object: Option<&ObjectKey>,
+            _body: Option<&crate::s3::segmented_bytes::SegmentedBytes>,
+            _extensions: &mut http::Extensions,
+        ) -> Result<(), Error> {
+            let host = headers.get(HOST).cloned().unwrap_or_default();
+            *self.captured.lock().unwrap() = Some((url.host_header_value(), host));
+            // Abort before any network traffic.
+            Err(ValidationErr::UrlBuildError("captured".into()).into())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_object_endpoint_override() {
+        let hook = std::sync::Arc::new(CaptureHostHook::default());
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let client = crate::s3::client::MinioClientBuilder::new(base_url)
+            .provider(Some(StaticProvider::new("minioadmin", "minioadmin", None)))
+            .hook(hook.clone())
+            .build()
+            .unwrap();
+        let endpoint: BaseUrl = "http://gateway.example.com:9100".parse().unwrap();
+
+        let result = GetObject::builder()
+            .client(client)
+            .bucket(BucketName::new("bucket").unwrap())
+            .object(ObjectKey::new("object").unwrap())
+            .build()
+            .endpoint_override(endpoint)
+            .send()
+            .await;
+        assert!(result.is_err());
+
+        let (url_host, host_header) = hook.captured.lock().unwrap().clone().unwrap();
+        assert_eq!(url_host, "gateway.example.com:9100");
+        assert_eq!(host_header, "gateway.example.com:9100");
+    }
+
+    #[test]
+    fn test_ssec_over_http_names_operation() {
+        let err = GetObject::builder()
+            .client(dummy_client())
+            .bucket(BucketName::new("bucket").unwrap())
+            .object(ObjectKey::new("object").unwrap())
+            .ssec(Some(SseCustomerKey::new([7_u8; 32]).unwrap()))
+            .build()
+            .to_s3request()
+            .unwrap_err();
+        assert!(
+            matches!(&err, ValidationErr::SseTlsRequired { operation, sse }
+                if operation == "GetObject" && sse == "SSE-C"),
+            "{err:?}"
+        );
+    }
+
+    async fn gzip(data: &[u8]) -> Vec<u8> {
+        use futures_util::AsyncReadExt;
+        let mut out = Vec::new();
+        async_compression::futures::bufread::GzipEncoder::new(data)
+            .read_to_end(&mut out)
+            .await
+            .unwrap();
+        out
+    }
+
+    async fn download(
+        body: Vec<u8>,
+        encoding: Option<&'static str>,
+        auto_decompress: bool,
+    ) -> std::io::Result<Vec<u8>> {
+        let executor = MockExecutor::new(move |_| {
+            let mut resp = http::Response::builder()
+                .status(200)
+                .header(CONTENT_LENGTH, body.len());
+            if let Some(encoding) = encoding {
+                resp = resp.header(CONTENT_ENCODING, encoding);
+            }
+            resp.body(body.clone()).unwrap()
+        });
+        let content = GetObject::builder()
+            .client(test_client(executor))
+            .bucket(BucketName::new("bucket").unwrap())
+            .object(ObjectKey::new("object").unwrap())
+            .auto_decompress(auto_decompress)
+            .build()
+            .send()
+            .await
+            .unwrap()
+            .content()
+            .unwrap();
+        Ok(content.to_segmented_bytes().await?.to_bytes().to_vec())
+    }
+
+    #[tokio::test]
+    async fn test_get_object_auto_decompress_gzip() {
+        let compressed = gzip(b"hello, world").await;
+        let data = download(compressed.clone(), Some("gzip"), true)
+            .await
+            .unwrap();
+        assert_eq!(data, b"hello, world");
+
+        // Without the option the stored bytes are returned.
+        let data = download(compressed.clone(), Some("gzip"), false)
+            .await
+            .unwrap();
+        assert_eq!(data, compressed);
+    }
+
+    #[tokio::test]
+    async fn test_get_object_auto_decompress_leaves_raw_object() {
+        let data = download(b"hello, world".to_vec(), None, true)
+            .await
+            .unwrap();
+        assert_eq!(data, b"hello, world");
+    }
+
+    #[tokio::test]
+    async fn test_get_object_auto_decompress_mislabeled_encoding() {
+        let err = download(b"not gzip at all".to_vec(), Some("gzip"), true)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    /// Serves `data` as the object, honoring `Range` requests.
+    fn object_executor(data: bytes::Bytes) -> MockExecutor {
+        chunked_object_executor(data, usize::MAX)
+    }
+
+    /// Like [`object_executor`], but streams bodies in chunks of `chunk_size` bytes.
+    fn chunked_object_executor(data: bytes::Bytes, chunk_size: usize) -> MockExecutor {
+        MockExecutor::new(move |req| {
+            let len = data.len() as u64;
+            let resp = http::Response::builder().header("etag", "\"abc\"");
+            let (resp, body) = match req.header(RANGE) {
+                Some(range) => {
+                    let (start, end) = range.trim_start_matches("bytes=").split_once('-').unwrap();
+                    let (start, end): (u64, u64) = (start.parse().unwrap(), end.parse().unwrap());
+                    (
+                        resp.status(206)
+                            .header(CONTENT_RANGE, format!("bytes {start}-{end}/{len}")),
+                        data.slice(start as usize..=end as usize),
+                    )
+                }
+                None if req.method == Method::HEAD => (
+                    resp.status(200).header(CONTENT_LENGTH, len),
+                    bytes::Bytes::new(),
+                ),
+                None => (resp.status(200), data.clone()),
+            };
+            let chunks: Vec<std::io::Result<bytes::Bytes>> = body
+                .chunks(chunk_size.min(body.len()).max(1))
+                .map(|v| Ok(body.slice_ref(v)))
+                .collect();
+            resp.body(reqwest::Body::wrap_stream(stream::iter(chunks)))
+                .unwrap()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_download_range_to_ignores_suffix_length() {
+        let client = test_client(object_executor(bytes::Bytes::from_static(b"hello, world")));
+        let mut buf: Vec<u8> = Vec::new();
+        let written = client
+            .get_object("bucket", "object")
+            .unwrap()
+            .suffix_length(5)
+            .build()
+            .download_range_to(&mut buf, 7, 5)
+            .await
+            .unwrap();
+        assert_eq!(written, 5);
+        assert_eq!(buf, b"world");
+    }
+
+    /// Serializes the tests that inspect the file operation hooks.
+    static FILE_OP_TEST_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+    static FILE_OP_DELAY_MS: AtomicU64 = AtomicU64::new(0);
+    static FILE_OP_THREADS: Mutex<Vec<ThreadId>> = Mutex::new(Vec::new());
+    static SEGMENT_WRITES: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+
+    /// Called before every file operation of `parallel_download`; records the
+    /// calling thread and simulates a slow filesystem.
+    pub(super) fn on_file_op() {
+        FILE_OP_THREADS
+            .lock()
+            .unwrap()
+            .push(std::thread::current().id());
+        std::thread::sleep(Duration::from_millis(
+            FILE_OP_DELAY_MS.load(Ordering::Relaxed),
+        ));
+    }
+
+    /// Called with the size of every chunk `parallel_download` writes.
+    pub(super) fn on_segment_write(len: usize) {
+        SEGMENT_WRITES.lock().unwrap().push(len);
+    }
+
+    #[tokio::test]
+    async fn test_parallel_download_writes_chunks_as_they_arrive() {
+        const CHUNK_SIZE: usize = 16 * 1024;
+        let _lock = FILE_OP_TEST_LOCK.lock().await;
+
+        for size in [512 * 1024, 4 * 1024 * 1024] {
+            let data: Vec<u8> = (0..size).map(|i| (i % 251) as u8).collect();
+            let client = test_client(chunked_object_executor(
+                bytes::Bytes::from(data.clone()),
+                CHUNK_SIZE,
+            ));
+            let path =
+                std::env::temp_dir().join(format!("minio-download-{}", uuid::Uuid::new_v4()));
+            SEGMENT_WRITES.lock().unwrap().clear();
+
+            // Two segments of half the object each.
+            let written = client
+                .get_object("bucket", "object")
+                .unwrap()
+                .build()
+                .parallel_download(&path, 2, 0)
+                .await
+                .unwrap();
+            assert_eq!(written, size as u64);
+            assert!(std::fs::read(&path).unwrap() == data);
+            std::fs::remove_file(&path).unwrap();
+
+            let writes = SEGMENT_WRITES.lock().unwrap().clone();
+            assert_eq!(writes.len(), size / CHUNK_SIZE);
+            assert!(writes.iter().all(|&v| v <= CHUNK_SIZE), "{writes:?}");
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_parallel_download_file_ops_run_off_runtime_thread() {
+        let _lock = FILE_OP_TEST_LOCK.lock().await;
+        let data: Vec<u8> = (0..256 * 1024).map(|i| (i % 251) as u8).collect();
+        let client = test_client(object_executor(bytes::Bytes::from(data.clone())));
+        let path = std::env::temp_dir().join(format!("minio-download-{}", uuid::Uuid::new_v4()));
+        let runtime_thread = std::thread::current().id();
+
+        let done = Arc::new(AtomicBool::new(false));
+        let ticker = tokio::spawn({
+            let done = Arc::clone(&done);
+            async move {
+                let mut max_gap = Duration::ZERO;
+                let mut last = Instant::now();
+                while !done.load(Ordering::Relaxed) {
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                    max_gap = max_gap.max(last.elapsed());
+                    last = Instant::now();
+                }
+                max_gap
+            }
+        });
+
+        FILE_OP_DELAY_MS.store(300, Ordering::Relaxed);
+        let size = client
+            .get_object("bucket", "object")
+            .unwrap()
+            .sync_on_close(true)
+            .build()
+            .parallel_download(&path, 4, 64 * 1024)
+            .await;
+        FILE_OP_DELAY_MS.store(0, Ordering::Relaxed);
+        done.store(true, Ordering::Relaxed);
+        let max_gap = ticker.await.unwrap();
+
+        assert_eq!(size.unwrap(), data.len() as u64);
+        assert!(std::fs::read(&path).unwrap() == data);
+        std::fs::remove_file(&path).unwrap();
+
+        // create, four segment writes and sync_all
+        let threads = FILE_OP_THREADS.lock().unwrap().clone();
+        assert!(threads.len() >= 6, "only {} file operations", threads.len());
+        assert!(!threads.contains(&runtime_thread));
+        assert!(
+            max_gap < Duration::from_millis(150),
+            "runtime stalled for {max_gap:?}"
+        );
+    }
+}
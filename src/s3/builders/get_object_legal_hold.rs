@@ -17,7 +17,9 @@ use crate::s3::client::MinioClient;
 use crate::s3::error::ValidationErr;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::GetObjectLegalHoldResponse;
-use crate::s3::types::{BucketName, ObjectKey, Region, S3Api, S3Request, ToS3Request, VersionId};
+use crate::s3::types::{
+    BucketName, ObjectKey, Region, RequestHeaders, S3Api, S3Request, ToS3Request, VersionId,
+};
 use crate::s3::utils::insert;
 use http::Method;
 use typed_builder::TypedBuilder;
@@ -43,6 +45,12 @@ pub struct GetObjectLegalHold {
     version_id: Option<VersionId>,
 }
 
+impl RequestHeaders for GetObjectLegalHold {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 pub type GetObjectLegalHoldBldr =
     GetObjectLegalHoldBuilder<((MinioClient,), (), (), (), (BucketName,), (ObjectKey,), ())>;
 
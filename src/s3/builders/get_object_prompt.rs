@@ -19,7 +19,9 @@ use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::GetObjectPromptResponse;
 use crate::s3::segmented_bytes::SegmentedBytes;
 use crate::s3::sse::SseCustomerKey;
-use crate::s3::types::{BucketName, ObjectKey, Region, S3Api, S3Request, ToS3Request, VersionId};
+use crate::s3::types::{
+    BucketName, ObjectKey, Region, RequestHeaders, S3Api, S3Request, ToS3Request, VersionId,
+};
 use crate::s3::utils::check_ssec;
 use bytes::Bytes;
 use http::Method;
@@ -57,6 +59,12 @@ pub struct GetObjectPrompt {
     ssec: Option<SseCustomerKey>,
 }
 
+impl RequestHeaders for GetObjectPrompt {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 pub type GetObjectPromptBldr = GetObjectPromptBuilder<(
     (MinioClient,),
     (),
@@ -77,7 +85,7 @@ impl S3Api for GetObjectPrompt {
 impl ToS3Request for GetObjectPrompt {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
         {
-            check_ssec(&self.ssec, &self.client)?;
+            check_ssec(&self.ssec, &self.client, "GetObjectPrompt")?;
             if self.client.is_aws_host() {
                 return Err(ValidationErr::UnsupportedAwsApi("ObjectPrompt".into()));
             }
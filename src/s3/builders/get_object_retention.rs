@@ -17,7 +17,9 @@ use crate::s3::client::MinioClient;
 use crate::s3::error::ValidationErr;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::GetObjectRetentionResponse;
-use crate::s3::types::{BucketName, ObjectKey, Region, S3Api, S3Request, ToS3Request, VersionId};
+use crate::s3::types::{
+    BucketName, ObjectKey, Region, RequestHeaders, S3Api, S3Request, ToS3Request, VersionId,
+};
 use crate::s3::utils::insert;
 use http::Method;
 use typed_builder::TypedBuilder;
@@ -43,6 +45,12 @@ pub struct GetObjectRetention {
     version_id: Option<VersionId>,
 }
 
+impl RequestHeaders for GetObjectRetention {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type alias for [`GetObjectRetention`].
 ///
 /// Constructed via [`GetObjectRetention::builder()`](GetObjectRetention::builder) and used to build a [`GetObjectRetention`] instance.
@@ -13,15 +13,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::s3::client::MinioClient;
-use crate::s3::creds::Credentials;
+use crate::s3::client::{DEFAULT_REGION, MinioClient};
 use crate::s3::error::Error;
 use crate::s3::header_constants::*;
+use crate::s3::http::BaseUrl;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::GetPresignedObjectUrlResponse;
-use crate::s3::signer::presign_v4;
-use crate::s3::types::{BucketName, ObjectKey, Region, VersionId};
-use crate::s3::utils::{UtcTime, utc_now};
+use crate::s3::signer::{SignatureVersion, presign_v2, presign_v4};
+use crate::s3::types::{BucketName, ObjectKey, Region, ResponseHeaderOverrides, VersionId};
+use crate::s3::utils::UtcTime;
 use http::Method;
 use typed_builder::TypedBuilder;
 
@@ -50,6 +50,13 @@ pub struct GetPresignedObjectUrl {
     expiry_seconds: Option<u32>,
     #[builder(default, setter(into))]
     request_time: Option<UtcTime>,
+    /// Endpoint used instead of the client's base URL; the URL is signed for its host.
+    #[builder(default, setter(into))]
+    endpoint_override: Option<BaseUrl>,
+    /// Response headers for the server to replace, such as `Content-Disposition`
+    /// to force a download.
+    #[builder(default)]
+    response_headers: ResponseHeaderOverrides,
 }
 
 /// Builder type alias for [`GetPresignedObjectUrl`].
@@ -65,22 +72,33 @@ pub type GetPresignedObjectUrlBldr = GetPresignedObjectUrlBuilder<(
     (Method,),
     (),
     (),
+    (),
+    (),
 )>;
 
 impl GetPresignedObjectUrl {
     /// Sends the request to generate a presigned URL for an S3 object.
     pub async fn send(self) -> Result<GetPresignedObjectUrlResponse, Error> {
-        let region_str: String = self
-            .client
-            .get_region_cached(self.bucket.clone(), &self.region)
-            .await?;
-
-        let region = Region::new(&region_str)?;
+        let region = match (&self.endpoint_override, &self.region) {
+            (Some(_), Some(r)) => r.clone(),
+            (Some(e), None) if !e.region.is_empty() => e.region.clone(),
+            (Some(_), None) => DEFAULT_REGION.clone(),
+            (None, _) => Region::new(
+                &self
+                    .client
+                    .get_region_cached(self.bucket.clone(), &self.region)
+                    .await?,
+            )?,
+        };
 
         let mut query_params: Multimap = self.extra_query_params.unwrap_or_default();
         query_params.add_version(self.version_id.clone());
+        self.response_headers.add_to(&mut query_params);
 
-        let mut url = self.client.shared.base_url.build_url(
+        let base_url = self
+            .client
+            .endpoint_for(self.endpoint_override.as_ref(), &region);
+        let mut url = base_url.build_url(
             &self.method,
             &region,
             &query_params,
@@ -88,29 +106,49 @@ impl GetPresignedObjectUrl {
             Some(&self.object),
         )?;
 
-        if let Some(p) = &self.client.shared.provider {
-            let creds: Credentials = p.fetch();
+        if let Some(creds) = self.client.credentials().await? {
             if let Some(t) = creds.session_token {
                 query_params.add(X_AMZ_SECURITY_TOKEN, t);
             }
 
             let date = match self.request_time {
                 Some(v) => v,
-                _ => utc_now(),
+                _ => self.client.shared.clock.now(),
             };
 
-            presign_v4(
-                &self.client.shared.signing_key_cache,
-                &self.method,
-                &url.host_header_value(),
-                &url.path,
-                &region,
-                &mut query_params,
-                &creds.access_key,
-                &creds.secret_key,
-                date,
-                self.expiry_seconds.unwrap_or(DEFAULT_EXPIRY_SECONDS),
-            );
+            let expires = self.expiry_seconds.unwrap_or(DEFAULT_EXPIRY_SECONDS);
+            match self.client.signature_version_for(base_url) {
+                SignatureVersion::V2 => {
+                    let virtual_host = base_url.is_virtual_host_style(
+                        &self.method,
+                        &query_params,
+                        &self.bucket,
+                        Some(&self.object),
+                    );
+                    presign_v2(
+                        &self.method,
+                        &url.path,
+                        virtual_host.then_some(self.bucket.as_str()),
+                        &mut query_params,
+                        &creds.access_key,
+                        &creds.secret_key,
+                        date,
+                        expires,
+                    );
+                }
+                SignatureVersion::V4 => presign_v4(
+                    &self.client.shared.signing_key_cache,
+                    &self.method,
+                    &url.host_header_value(),
+                    &url.path,
+                    &region,
+                    &mut query_params,
+                    &creds.access_key,
+                    &creds.secret_key,
+                    date,
+                    expires,
+                ),
+            }
 
             url.query = query_params;
         }
@@ -124,3 +162,126 @@ impl GetPresignedObjectUrl {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::creds::StaticProvider;
+    use crate::s3::utils::from_iso8601utc;
+
+    fn presign(base_url: &str, endpoint_override: Option<BaseUrl>) -> GetPresignedObjectUrl {
+        let provider = StaticProvider::new("minioadmin", "minioadmin", None);
+        let client =
+            MinioClient::new(base_url.parse().unwrap(), Some(provider), None, None).unwrap();
+        GetPresignedObjectUrl::builder()
+            .client(client)
+            .bucket(BucketName::new("bucket").unwrap())
+            .object(ObjectKey::new("object").unwrap())
+            .method(Method::GET)
+            .region(Region::new("us-east-1").unwrap())
+            .request_time(from_iso8601utc("2025-01-01T00:00:00.000Z").unwrap())
+            .endpoint_override(endpoint_override)
+            .build()
+    }
+
+    fn signature(url: &str) -> &str {
+        url.split(['?', '&'])
+            .find_map(|v| v.strip_prefix("X-Amz-Signature="))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_presigned_url_signature_v2() {
+        let client =
+            crate::s3::client::MinioClientBuilder::new("http://localhost:9000".parse().unwrap())
+                .provider(Some(StaticProvider::new("minioadmin", "minioadmin", None)))
+                .signature_version(SignatureVersion::V2)
+                .build()
+                .unwrap();
+        let resp = GetPresignedObjectUrl::builder()
+            .client(client)
+            .bucket(BucketName::new("bucket").unwrap())
+            .object(ObjectKey::new("object").unwrap())
+            .method(Method::GET)
+            .region(Region::new("us-east-1").unwrap())
+            .request_time(from_iso8601utc("2025-01-01T00:00:00.000Z").unwrap())
+            .expiry_seconds(3600)
+            .build()
+            .send()
+            .await
+            .unwrap();
+
+        let (path, query) = resp.url.split_once('?').unwrap();
+        assert_eq!(path, "http://localhost:9000/bucket/object");
+        let mut params: Vec<&str> = query.split('&').collect();
+        params.sort_unstable();
+        assert_eq!(params.len(), 3, "{query}");
+        assert_eq!(params[0], "AWSAccessKeyId=minioadmin");
+        assert_eq!(params[1], "Expires=1735693200");
+        assert!(params[2].starts_with("Signature="), "{query}");
+    }
+
+    #[tokio::test]
+    async fn test_presigned_url_endpoint_override() {
+        let endpoint: BaseUrl = "https://gateway.example.com".parse().unwrap();
+        let overridden = presign("http://localhost:9000", Some(endpoint))
+            .send()
+            .await
+            .unwrap();
+        assert!(
+            overridden
+                .url
+                .starts_with("https://gateway.example.com/bucket/object?"),
+            "{}",
+            overridden.url
+        );
+
+        // Signing covers the host, so the URL must match one from a client on that host.
+        let direct = presign("https://gateway.example.com", None)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(signature(&overridden.url), signature(&direct.url));
+    }
+
+    #[tokio::test]
+    async fn test_presigned_url_response_header_overrides() {
+        let plain = presign("http://localhost:9000", None).send().await.unwrap();
+        let provider = StaticProvider::new("minioadmin", "minioadmin", None);
+        let client = MinioClient::new(
+            "http://localhost:9000".parse().unwrap(),
+            Some(provider),
+            None,
+            None,
+        )
+        .unwrap();
+        let overridden = GetPresignedObjectUrl::builder()
+            .client(client)
+            .bucket(BucketName::new("bucket").unwrap())
+            .object(ObjectKey::new("object").unwrap())
+            .method(Method::GET)
+            .region(Region::new("us-east-1").unwrap())
+            .request_time(from_iso8601utc("2025-01-01T00:00:00.000Z").unwrap())
+            .response_headers(ResponseHeaderOverrides {
+                content_disposition: Some("attachment; filename=\"a b.txt\"".into()),
+                content_type: Some("application/octet-stream".into()),
+                ..Default::default()
+            })
+            .build()
+            .send()
+            .await
+            .unwrap();
+
+        let url = &overridden.url;
+        assert!(
+            url.contains("response-content-disposition=attachment%3B%20filename%3D%22a%20b.txt%22"),
+            "{url}"
+        );
+        assert!(
+            url.contains("response-content-type=application%2Foctet-stream"),
+            "{url}"
+        );
+        // The overrides are part of the canonical query string, so they are signed.
+        assert_ne!(signature(url), signature(&plain.url));
+    }
+}
@@ -19,7 +19,7 @@ use crate::s3::error::{Error, ValidationErr};
 use crate::s3::header_constants::*;
 use crate::s3::signer::{SigningKeyCache, post_presign_v4};
 use crate::s3::types::{BucketName, Region};
-use crate::s3::utils::{UtcTime, b64_encode, to_amz_date, to_iso8601utc, to_signer_date, utc_now};
+use crate::s3::utils::{UtcTime, b64_encode, to_amz_date, to_iso8601utc, to_signer_date};
 use serde_json::{Value, json};
 use std::collections::HashMap;
 use std::sync::RwLock;
@@ -44,7 +44,7 @@ impl GetPresignedPolicyFormData {
             .await?;
         let region = Region::new(&region_str)?;
 
-        let creds: Credentials = self.client.shared.provider.as_ref().unwrap().fetch();
+        let creds: Credentials = self.client.credentials().await?.unwrap();
         self.policy
             .form_data(
                 &self.client.shared.signing_key_cache,
@@ -52,6 +52,7 @@ impl GetPresignedPolicyFormData {
                 creds.secret_key,
                 creds.session_token,
                 region,
+                self.client.shared.clock.now(),
             )
             .map_err(Error::Validation)
     }
@@ -312,6 +313,7 @@ impl PostPolicy {
         secret_key: String,
         session_token: Option<String>,
         region: Region,
+        date: UtcTime,
     ) -> Result<HashMap<String, String>, ValidationErr> {
         if region.is_empty() {
             return Err(ValidationErr::PostPolicyError(
@@ -347,7 +349,6 @@ impl PostPolicy {
             ]));
         }
 
-        let date = utc_now();
         let credential = PostPolicy::get_credential_string(&access_key, &date, &region);
         let amz_date = to_amz_date(date);
         conditions.push(json!([
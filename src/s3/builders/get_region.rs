@@ -18,7 +18,7 @@ use crate::s3::client::MinioClient;
 use crate::s3::error::ValidationErr;
 use crate::s3::multimap_ext::Multimap;
 use crate::s3::response::GetRegionResponse;
-use crate::s3::types::{BucketName, S3Api, S3Request, ToS3Request};
+use crate::s3::types::{BucketName, RequestHeaders, S3Api, S3Request, ToS3Request};
 use crate::s3::utils::insert;
 use http::Method;
 use typed_builder::TypedBuilder;
@@ -38,6 +38,12 @@ pub struct GetRegion {
     bucket: BucketName,
 }
 
+impl RequestHeaders for GetRegion {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type alias for [`GetRegion`].
 ///
 /// Constructed via [`GetRegion::builder()`](GetRegion::builder) and used to build a [`GetRegion`] instance.
@@ -15,9 +15,9 @@
 
 use crate::s3::client::MinioClient;
 use crate::s3::error::ValidationErr;
-use crate::s3::multimap_ext::Multimap;
+use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::ListBucketsResponse;
-use crate::s3::types::{S3Api, S3Request, ToS3Request};
+use crate::s3::types::{Region, RequestHeaders, S3Api, S3Request, ToS3Request};
 use http::Method;
 use typed_builder::TypedBuilder;
 
@@ -32,12 +32,31 @@ pub struct ListBuckets {
     extra_headers: Option<Multimap>,
     #[builder(default, setter(into))]
     extra_query_params: Option<Multimap>,
+    /// Only lists buckets whose name starts with this prefix.
+    #[builder(default, setter(into))]
+    prefix: Option<String>,
+    /// Only lists buckets located in this region.
+    #[builder(default, setter(into))]
+    bucket_region: Option<Region>,
+    /// Maximum number of buckets returned in one response; enables paging.
+    #[builder(default, setter(into))]
+    max_buckets: Option<u32>,
+    /// Continues a listing from the
+    /// [`continuation_token`](ListBucketsResponse::continuation_token) of a previous response.
+    #[builder(default, setter(into))]
+    continuation_token: Option<String>,
+}
+
+impl RequestHeaders for ListBuckets {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
 }
 
 /// Builder type alias for [`ListBuckets`].
 ///
 /// Constructed via [`ListBuckets::builder()`](ListBuckets::builder) and used to build a [`ListBuckets`] instance.
-pub type ListBucketsBldr = ListBucketsBuilder<((MinioClient,), (), ())>;
+pub type ListBucketsBldr = ListBucketsBuilder<((MinioClient,), (), (), (), (), (), ())>;
 
 impl S3Api for ListBuckets {
     type S3Response = ListBucketsResponse;
@@ -45,11 +64,116 @@ impl S3Api for ListBuckets {
 
 impl ToS3Request for ListBuckets {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
+        let mut query_params: Multimap = self.extra_query_params.unwrap_or_default();
+        if let Some(v) = self.prefix {
+            query_params.add("prefix", v);
+        }
+        if let Some(v) = self.bucket_region {
+            query_params.add("bucket-region", v.as_str());
+        }
+        if let Some(v) = self.max_buckets {
+            query_params.add("max-buckets", v.to_string());
+        }
+        if let Some(v) = self.continuation_token {
+            query_params.add("continuation-token", v);
+        }
+
         Ok(S3Request::builder()
             .client(self.client)
             .method(Method::GET)
-            .query_params(self.extra_query_params.unwrap_or_default())
+            .query_params(query_params)
             .headers(self.extra_headers.unwrap_or_default())
             .build())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{MockExecutor, test_client};
+
+    #[test]
+    fn test_list_buckets_query_params() {
+        let client = test_client(MockExecutor::default());
+        let req = client
+            .list_buckets()
+            .prefix("logs-".to_string())
+            .bucket_region(Region::new("eu-west-1").unwrap())
+            .max_buckets(100)
+            .continuation_token("token-1".to_string())
+            .build()
+            .to_s3request()
+            .unwrap();
+        assert_eq!(req.query_params.get("prefix"), Some(&"logs-".to_string()));
+        assert_eq!(
+            req.query_params.get("bucket-region"),
+            Some(&"eu-west-1".to_string())
+        );
+        assert_eq!(
+            req.query_params.get("max-buckets"),
+            Some(&"100".to_string())
+        );
+        assert_eq!(
+            req.query_params.get("continuation-token"),
+            Some(&"token-1".to_string())
+        );
+
+        let req = client.list_buckets().build().to_s3request().unwrap();
+        assert!(req.query_params.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_buckets_paging() {
+        let executor = MockExecutor::scripted(vec![
+            http::Response::new(
+                "<ListAllMyBucketsResult><Buckets>\
+             <Bucket><Name>logs-a</Name><CreationDate>2024-01-01T00:00:00.000Z</CreationDate></Bucket>\
+             <Bucket><Name>logs-b</Name><CreationDate>2024-01-01T00:00:00.000Z</CreationDate></Bucket>\
+             </Buckets><ContinuationToken>token-1</ContinuationToken><Prefix>logs-</Prefix>\
+             </ListAllMyBucketsResult>",
+            ),
+            http::Response::new(
+                "<ListAllMyBucketsResult><Buckets>\
+             <Bucket><Name>logs-c</Name><CreationDate>2024-01-01T00:00:00.000Z</CreationDate></Bucket>\
+             </Buckets><Prefix>logs-</Prefix></ListAllMyBucketsResult>",
+            ),
+        ]);
+        let client = test_client(executor.clone());
+
+        let mut names = Vec::new();
+        let mut token = None;
+        loop {
+            let resp = client
+                .list_buckets()
+                .prefix("logs-".to_string())
+                .max_buckets(2)
+                .continuation_token(token)
+                .build()
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(resp.prefix().unwrap().as_deref(), Some("logs-"));
+            names.extend(
+                resp.buckets()
+                    .unwrap()
+                    .into_iter()
+                    .map(|b| b.name().to_string()),
+            );
+            token = resp.continuation_token().unwrap();
+            if token.is_none() {
+                break;
+            }
+        }
+        assert_eq!(names, ["logs-a", "logs-b", "logs-c"]);
+
+        let urls: Vec<String> = executor
+            .requests()
+            .into_iter()
+            .map(|r| r.url.to_string())
+            .collect();
+        assert_eq!(urls.len(), 2);
+        assert!(!urls[0].contains("continuation-token"));
+        assert!(urls[1].contains("continuation-token=token-1"));
+        assert!(urls.iter().all(|v| v.contains("max-buckets=2")));
+    }
+}
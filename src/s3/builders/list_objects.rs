@@ -19,10 +19,14 @@ use crate::s3::response::ListObjectsResponse;
 use crate::s3::response::list_objects::{
     ListObjectVersionsResponse, ListObjectsV1Response, ListObjectsV2Response,
 };
-use crate::s3::types::{BucketName, Region, S3Api, S3Request, ToS3Request, ToStream};
-use crate::s3::utils::{check_bucket_name, insert};
+use crate::s3::response_traits::HasTagging;
+use crate::s3::types::{
+    BucketName, ListEntry, Region, RequestHeaders, S3Api, S3Request, ToS3Request, ToStream,
+    VersionId,
+};
+use crate::s3::utils::{add_request_payer, insert};
 use async_trait::async_trait;
-use futures_util::{Stream, StreamExt, stream as futures_stream};
+use futures_util::{Stream, StreamExt, TryStreamExt, stream as futures_stream};
 use http::Method;
 use typed_builder::TypedBuilder;
 
@@ -41,6 +45,16 @@ fn add_common_list_objects_query_params(
     }
 }
 
+/// Adds the request-payer header to the extra headers when requested.
+fn with_request_payer(headers: Option<Multimap>, request_payer: bool) -> Option<Multimap> {
+    if !request_payer {
+        return headers;
+    }
+    let mut headers = headers.unwrap_or_default();
+    add_request_payer(&mut headers, true);
+    Some(headers)
+}
+
 /// Helper function delimiter based on recursive flag when delimiter is not provided.
 fn delim_helper(delim: Option<String>, recursive: bool) -> Option<String> {
     if delim.is_some() {
@@ -93,8 +107,9 @@ impl ToStream for ListObjectsV1 {
                         // Update the marker for the next request
                         args_for_next_request.marker.clone_from(&resp.next_marker);
 
-                        // Determine if there are more results to fetch
-                        is_done = !resp.is_truncated;
+                        // Determine if there are more results to fetch; without a
+                        // marker the same page would be requested again.
+                        is_done = !resp.is_truncated || resp.next_marker.is_none();
 
                         // Return the response and prepare for the next iteration
                         Some((Ok(resp), (args_for_next_request, is_done)))
@@ -112,7 +127,7 @@ impl S3Api for ListObjectsV1 {
 
 impl ToS3Request for ListObjectsV1 {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_bucket_name(&self.bucket, true)?;
+        self.client.check_bucket_name(&self.bucket)?;
 
         let mut query_params: Multimap = self.extra_query_params.unwrap_or_default();
         {
@@ -143,7 +158,7 @@ impl From<ListObjects> for ListObjectsV1 {
     fn from(value: ListObjects) -> Self {
         ListObjectsV1 {
             client: value.client,
-            extra_headers: value.extra_headers,
+            extra_headers: with_request_payer(value.extra_headers, value.request_payer),
             extra_query_params: value.extra_query_params,
             region: value.region,
             bucket: value.bucket,
@@ -201,8 +216,9 @@ impl ToStream for ListObjectsV2 {
                             .continuation_token
                             .clone_from(&resp.next_continuation_token);
 
-                        // Determine if there are more results to fetch
-                        is_done = !resp.is_truncated;
+                        // Determine if there are more results to fetch; without a
+                        // token the same page would be requested again.
+                        is_done = !resp.is_truncated || resp.next_continuation_token.is_none();
 
                         // Return the response and prepare for the next iteration
                         Some((Ok(resp), (args_for_next_request, is_done)))
@@ -220,7 +236,7 @@ impl S3Api for ListObjectsV2 {
 
 impl ToS3Request for ListObjectsV2 {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_bucket_name(&self.bucket, true)?;
+        self.client.check_bucket_name(&self.bucket)?;
 
         let mut query_params: Multimap = self.extra_query_params.unwrap_or_default();
         {
@@ -261,7 +277,7 @@ impl From<ListObjects> for ListObjectsV2 {
     fn from(value: ListObjects) -> Self {
         ListObjectsV2 {
             client: value.client,
-            extra_headers: value.extra_headers,
+            extra_headers: with_request_payer(value.extra_headers, value.request_payer),
             extra_query_params: value.extra_query_params,
             region: value.region,
             bucket: value.bucket,
@@ -271,7 +287,7 @@ impl From<ListObjects> for ListObjectsV2 {
             prefix: value.prefix,
             start_after: value.start_after,
             continuation_token: value.continuation_token,
-            fetch_owner: value.fetch_owner,
+            fetch_owner: value.fetch_owner || value.fetch_metadata,
             include_user_metadata: value.include_user_metadata,
         }
     }
@@ -344,7 +360,7 @@ impl S3Api for ListObjectVersions {
 
 impl ToS3Request for ListObjectVersions {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_bucket_name(&self.bucket, true)?;
+        self.client.check_bucket_name(&self.bucket)?;
 
         let mut query_params: Multimap = insert(self.extra_query_params, "versions");
         {
@@ -381,7 +397,7 @@ impl From<ListObjects> for ListObjectVersions {
     fn from(value: ListObjects) -> Self {
         Self {
             client: value.client,
-            extra_headers: value.extra_headers,
+            extra_headers: with_request_payer(value.extra_headers, value.request_payer),
             extra_query_params: value.extra_query_params,
             region: value.region,
             bucket: value.bucket,
@@ -440,7 +456,8 @@ pub struct ListObjects {
     marker: Option<String>,
 
     // Options specific to ListObjectsV2.
-    /// Used only with ListObjectsV2
+    /// Used only with ListObjectsV2. Lists keys after this one, e.g. to resume a
+    /// listing from the last key seen.
     #[builder(default, setter(into))]
     start_after: Option<String>,
 
@@ -448,7 +465,8 @@ pub struct ListObjects {
     #[builder(default, setter(into))]
     continuation_token: Option<String>,
 
-    /// Used only with ListObjectsV2
+    /// Used only with ListObjectsV2. Requests the owner of each object, returned in
+    /// `owner_id` and `owner_name` of the entries.
     #[builder(default)]
     fetch_owner: bool,
 
@@ -476,6 +494,11 @@ pub struct ListObjects {
     ///   lexicographical order based on their key names.
     /// * For directory buckets (S3-Express), ListObjectsV2 returns objects
     ///   in an unspecified order implementation-dependent order.
+    ///
+    /// ListObjectsV1 pages with `marker`/`NextMarker` instead of continuation
+    /// tokens and omits `list-type=2`, for S3-compatible servers that do not
+    /// support ListObjectsV2. `start_after`, `continuation_token`, `fetch_owner`
+    /// and `include_user_metadata` are ignored; use `marker` to start listing.
     #[builder(default)]
     use_api_v1: bool,
 
@@ -483,8 +506,38 @@ pub struct ListObjects {
     /// `use_api_v1` is set.
     #[builder(default)]
     include_versions: bool,
+
+    /// Sends `x-amz-request-payer: requester`, required for requester-pays buckets.
+    #[builder(default)]
+    request_payer: bool,
+
+    /// Fetch the user metadata and tags of every listed object, filling
+    /// `user_metadata` and `user_tags` of each [`ListEntry`]. Also enables
+    /// `fetch_owner`. Defaults to false.
+    ///
+    /// This costs a HEAD and a GetObjectTagging request per object; at most
+    /// `fetch_metadata_concurrency` objects are fetched at once, and a page is
+    /// yielded once all its objects are fetched.
+    #[builder(default)]
+    fetch_metadata: bool,
+
+    /// Maximum number of objects whose metadata is fetched concurrently when
+    /// `fetch_metadata` is set (defaults to [`DEFAULT_FETCH_METADATA_CONCURRENCY`];
+    /// values below 1 are treated as 1).
+    #[builder(default = DEFAULT_FETCH_METADATA_CONCURRENCY)]
+    fetch_metadata_concurrency: usize,
+}
+
+impl RequestHeaders for ListObjects {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
 }
 
+/// Default number of objects whose metadata is fetched concurrently by
+/// [`ListObjects`] with `fetch_metadata`.
+pub const DEFAULT_FETCH_METADATA_CONCURRENCY: usize = 8;
+
 /// Builder type alias for [`ListObjects`].
 ///
 /// Constructed via [`ListObjects::builder()`](ListObjects::builder) and used to build a [`ListObjects`] instance.
@@ -508,23 +561,360 @@ pub type ListObjectBldr = ListObjectsBuilder<(
     (),
     (),
     (),
+    (),
+    (),
+    (),
 )>;
 
+/// Fills `user_metadata` and `user_tags` of the object entries, fetching at
+/// most `concurrency` objects at once. Prefixes and delete markers are kept as is.
+async fn fetch_entries_metadata(
+    client: &MinioClient,
+    bucket: &BucketName,
+    region: &Option<Region>,
+    entries: Vec<ListEntry>,
+    concurrency: usize,
+) -> Result<Vec<ListEntry>, Error> {
+    futures_stream::iter(entries)
+        .map(|mut entry| async move {
+            if entry.is_prefix || entry.is_delete_marker {
+                return Ok(entry);
+            }
+            let version_id: Option<VersionId> =
+                entry.version_id.as_deref().map(str::parse).transpose()?;
+            let stat = client
+                .stat_object(bucket, entry.name.as_str())?
+                .version_id(version_id.clone())
+                .region(region.clone())
+                .build()
+                .send()
+                .await?;
+            let tagging = client
+                .get_object_tagging(bucket, entry.name.as_str())?
+                .version_id(version_id)
+                .region(region.clone())
+                .build()
+                .send()
+                .await?;
+            entry.user_metadata = Some(stat.user_metadata()?);
+            entry.user_tags = Some(tagging.tags()?);
+            Ok(entry)
+        })
+        .buffered(concurrency.max(1))
+        .try_collect()
+        .await
+}
+
 #[async_trait]
 impl ToStream for ListObjects {
     type Item = ListObjectsResponse;
 
     async fn to_stream(self) -> Box<dyn Stream<Item = Result<Self::Item, Error>> + Unpin + Send> {
-        if self.use_api_v1 {
-            let stream = ListObjectsV1::from(self).to_stream().await;
-            Box::new(stream.map(|v| v.map(|v| v.into())))
-        } else if self.include_versions {
-            let stream = ListObjectVersions::from(self).to_stream().await;
-            Box::new(stream.map(|v| v.map(|v| v.into())))
-        } else {
-            let stream = ListObjectsV2::from(self).to_stream().await;
-            Box::new(stream.map(|v| v.map(|v| v.into())))
-        }
+        let fetch_metadata = self.fetch_metadata.then(|| {
+            (
+                self.client.clone(),
+                self.bucket.clone(),
+                self.region.clone(),
+                self.fetch_metadata_concurrency,
+            )
+        });
+        let stream: Box<dyn Stream<Item = Result<Self::Item, Error>> + Unpin + Send> =
+            if self.use_api_v1 {
+                let stream = ListObjectsV1::from(self).to_stream().await;
+                Box::new(stream.map(|v| v.map(|v| v.into())))
+            } else if self.include_versions {
+                let stream = ListObjectVersions::from(self).to_stream().await;
+                Box::new(stream.map(|v| v.map(|v| v.into())))
+            } else {
+                let stream = ListObjectsV2::from(self).to_stream().await;
+                Box::new(stream.map(|v| v.map(|v| v.into())))
+            };
+
+        let Some((client, bucket, region, concurrency)) = fetch_metadata else {
+            return stream;
+        };
+        Box::new(Box::pin(stream.then(move |page| {
+            let (client, bucket, region) = (client.clone(), bucket.clone(), region.clone());
+            async move {
+                let mut page: ListObjectsResponse = page?;
+                let entries = std::mem::take(&mut page.contents);
+                page.contents =
+                    fetch_entries_metadata(&client, &bucket, &region, entries, concurrency).await?;
+                Ok(page)
+            }
+        })))
     }
 }
 // endregion: list-objects
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{MockServer, raw_response};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn xml_ok(body: &str) -> String {
+        raw_response("200 OK", "Content-Type: application/xml\r\n", body)
+    }
+
+    fn contents(keys: &[&str]) -> String {
+        keys.iter()
+            .map(|k| {
+                format!(
+                    "<Contents><Key>{k}</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified><ETag>&quot;abc&quot;</ETag><Size>1</Size></Contents>"
+                )
+            })
+            .collect()
+    }
+
+    async fn collect_names(client: &MinioClient, use_api_v1: bool) -> Vec<String> {
+        let mut stream = client
+            .list_objects("bucket")
+            .unwrap()
+            .recursive(true)
+            .use_api_v1(use_api_v1)
+            .build()
+            .to_stream()
+            .await;
+        let mut names = Vec::new();
+        while let Some(resp) = stream.next().await {
+            names.extend(resp.unwrap().contents.into_iter().map(|v| v.name));
+        }
+        names
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_v1_paging() {
+        // Without a delimiter, V1 responses carry no NextMarker; the last key is used.
+        let page1 = format!(
+            "<ListBucketResult><Name>bucket</Name><Prefix></Prefix><Marker></Marker><MaxKeys>2</MaxKeys><IsTruncated>true</IsTruncated>{}</ListBucketResult>",
+            contents(&["a", "b"])
+        );
+        let page2 = format!(
+            "<ListBucketResult><Name>bucket</Name><Prefix></Prefix><Marker>b</Marker><MaxKeys>2</MaxKeys><IsTruncated>false</IsTruncated>{}</ListBucketResult>",
+            contents(&["c"])
+        );
+        let server = MockServer::scripted(vec![xml_ok(&page1), xml_ok(&page2)]).await;
+        let client = server.client();
+
+        assert_eq!(collect_names(&client, true).await, ["a", "b", "c"]);
+
+        let requests = server.request_lines();
+        assert_eq!(requests.len(), 2);
+        assert!(requests.iter().all(|v| !v.contains("list-type")));
+        assert!(!requests[0].contains("marker="));
+        assert!(requests[1].contains("marker=b"));
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_v2_paging() {
+        let page1 = format!(
+            "<ListBucketResult><Name>bucket</Name><Prefix></Prefix><KeyCount>2</KeyCount><MaxKeys>2</MaxKeys><IsTruncated>true</IsTruncated><NextContinuationToken>token-1</NextContinuationToken>{}</ListBucketResult>",
+            contents(&["a", "b"])
+        );
+        let page2 = format!(
+            "<ListBucketResult><Name>bucket</Name><Prefix></Prefix><KeyCount>1</KeyCount><MaxKeys>2</MaxKeys><IsTruncated>false</IsTruncated><ContinuationToken>token-1</ContinuationToken>{}</ListBucketResult>",
+            contents(&["c"])
+        );
+        let server = MockServer::scripted(vec![xml_ok(&page1), xml_ok(&page2)]).await;
+        let client = server.client();
+
+        assert_eq!(collect_names(&client, false).await, ["a", "b", "c"]);
+
+        let requests = server.request_lines();
+        assert_eq!(requests.len(), 2);
+        assert!(requests.iter().all(|v| v.contains("list-type=2")));
+        assert!(!requests[0].contains("continuation-token"));
+        assert!(requests[1].contains("continuation-token=token-1"));
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_v2_manual_paging() {
+        let page1 = format!(
+            "<ListBucketResult><Name>bucket</Name><Prefix></Prefix><KeyCount>2</KeyCount><MaxKeys>2</MaxKeys><IsTruncated>true</IsTruncated><NextContinuationToken>token-1</NextContinuationToken>{}</ListBucketResult>",
+            contents(&["a", "b"])
+        );
+        let page2 = format!(
+            "<ListBucketResult><Name>bucket</Name><Prefix></Prefix><KeyCount>1</KeyCount><MaxKeys>2</MaxKeys><IsTruncated>false</IsTruncated><ContinuationToken>token-1</ContinuationToken>{}</ListBucketResult>",
+            contents(&["c"])
+        );
+        let server = MockServer::scripted(vec![xml_ok(&page1), xml_ok(&page2)]).await;
+        let client = server.client();
+        let first_page = |token: Option<String>| {
+            let client = client.clone();
+            async move {
+                client
+                    .list_objects("bucket")
+                    .unwrap()
+                    .recursive(true)
+                    .max_keys(2)
+                    .continuation_token(token)
+                    .build()
+                    .to_stream()
+                    .await
+                    .next()
+                    .await
+                    .unwrap()
+                    .unwrap()
+            }
+        };
+
+        let resp = first_page(None).await;
+        assert!(resp.is_truncated);
+        assert_eq!(resp.key_count, Some(2));
+        assert_eq!(resp.max_keys, Some(2));
+        assert_eq!(resp.next_continuation_token.as_deref(), Some("token-1"));
+
+        let resp = first_page(resp.next_continuation_token).await;
+        assert!(!resp.is_truncated);
+        assert_eq!(resp.key_count, Some(1));
+        assert_eq!(resp.continuation_token.as_deref(), Some("token-1"));
+        assert_eq!(resp.next_continuation_token, None);
+
+        let requests = server.request_lines();
+        assert!(requests[0].contains("max-keys=2"));
+        assert!(requests[1].contains("continuation-token=token-1"));
+    }
+
+    async fn list_v2_entries(client: &MinioClient, fetch_owner: bool) -> Vec<ListEntry> {
+        let mut stream = client
+            .list_objects("bucket")
+            .unwrap()
+            .recursive(true)
+            .start_after("photos/2024/b.jpg".to_string())
+            .fetch_owner(fetch_owner)
+            .build()
+            .to_stream()
+            .await;
+        let mut entries = Vec::new();
+        while let Some(resp) = stream.next().await {
+            entries.extend(resp.unwrap().contents);
+        }
+        entries
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_v2_start_after_fetch_owner() {
+        let page = "<ListBucketResult><Name>bucket</Name><KeyCount>1</KeyCount><IsTruncated>false</IsTruncated><StartAfter>photos/2024/b.jpg</StartAfter><Contents><Key>photos/2024/c.jpg</Key><LastModified>2024-01-01T00:00:00.000Z</LastModified><ETag>&quot;abc&quot;</ETag><Size>1</Size><Owner><ID>02d6176db174dc93cb1b899f7c6078f08654445fe8cf1b6ce98d8855f66bdbf4</ID><DisplayName>minio</DisplayName></Owner></Contents></ListBucketResult>";
+        let server = MockServer::scripted(vec![xml_ok(page)]).await;
+        let client = server.client();
+
+        let entries = list_v2_entries(&client, true).await;
+
+        let requests = server.request_lines();
+        assert!(requests[0].contains("start-after=photos%2F2024%2Fb.jpg"));
+        assert!(requests[0].contains("fetch-owner=true"));
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].owner_id.as_deref(),
+            Some("02d6176db174dc93cb1b899f7c6078f08654445fe8cf1b6ce98d8855f66bdbf4")
+        );
+        assert_eq!(entries[0].owner_name.as_deref(), Some("minio"));
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_v2_without_fetch_owner() {
+        let page = format!(
+            "<ListBucketResult><Name>bucket</Name><KeyCount>1</KeyCount><IsTruncated>false</IsTruncated>{}</ListBucketResult>",
+            contents(&["photos/2024/c.jpg"])
+        );
+        let server = MockServer::scripted(vec![xml_ok(&page)]).await;
+        let client = server.client();
+
+        let entries = list_v2_entries(&client, false).await;
+
+        let requests = server.request_lines();
+        assert!(requests[0].contains("start-after=photos%2F2024%2Fb.jpg"));
+        assert!(!requests[0].contains("fetch-owner"));
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].owner_id, None);
+        assert_eq!(entries[0].owner_name, None);
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_v1_marker_from_common_prefix() {
+        // A page ending with a common prefix and no NextMarker continues after that prefix.
+        let page1 = format!(
+            "<ListBucketResult><Name>bucket</Name><Prefix></Prefix><Delimiter>/</Delimiter><MaxKeys>2</MaxKeys><IsTruncated>true</IsTruncated>{}<CommonPrefixes><Prefix>dir/</Prefix></CommonPrefixes></ListBucketResult>",
+            contents(&["a"])
+        );
+        let page2 = "<ListBucketResult><Name>bucket</Name><Prefix></Prefix><Delimiter>/</Delimiter><MaxKeys>2</MaxKeys><IsTruncated>false</IsTruncated></ListBucketResult>";
+        let server = MockServer::scripted(vec![xml_ok(&page1), xml_ok(page2)]).await;
+        let client = server.client();
+
+        let mut stream = client
+            .list_objects("bucket")
+            .unwrap()
+            .use_api_v1(true)
+            .build()
+            .to_stream()
+            .await;
+        let resp = stream.next().await.unwrap().unwrap();
+        assert_eq!(resp.next_marker.as_deref(), Some("dir/"));
+        assert!(stream.next().await.unwrap().is_ok());
+        assert!(stream.next().await.is_none());
+
+        assert!(server.request_lines()[1].contains("marker=dir%2F"));
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_fetch_metadata() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let (cur, max) = (in_flight.clone(), max_in_flight.clone());
+        let server = MockServer::start(move |req| {
+            let (cur, max) = (cur.clone(), max.clone());
+            async move {
+                let line = req.line;
+                if line.contains("list-type=2") {
+                    return xml_ok(&format!(
+                        "<ListBucketResult><Name>bucket</Name><IsTruncated>false</IsTruncated>{}</ListBucketResult>",
+                        contents(&["a", "b", "c", "d", "e"])
+                    ));
+                }
+                max.fetch_max(cur.fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                cur.fetch_sub(1, Ordering::SeqCst);
+                let key = line.split(['/', ' ', '?']).nth(3).unwrap().to_string();
+                if line.starts_with("HEAD ") {
+                    raw_response(
+                        "200 OK",
+                        &format!("ETag: \"abc\"\r\nx-amz-meta-name: {key}\r\n"),
+                        "",
+                    )
+                } else {
+                    xml_ok(&format!(
+                        "<Tagging><TagSet><Tag><Key>name</Key><Value>{key}</Value></Tag></TagSet></Tagging>"
+                    ))
+                }
+            }
+        })
+        .await;
+        let client = server.client();
+
+        let mut stream = client
+            .list_objects("bucket")
+            .unwrap()
+            .recursive(true)
+            .fetch_metadata(true)
+            .fetch_metadata_concurrency(2)
+            .build()
+            .to_stream()
+            .await;
+        let mut entries = Vec::new();
+        while let Some(resp) = stream.next().await {
+            entries.extend(resp.unwrap().contents);
+        }
+
+        assert_eq!(entries.len(), 5);
+        for entry in &entries {
+            let expected = HashMap::from([("name".to_string(), entry.name.clone())]);
+            assert_eq!(entry.user_metadata.as_ref(), Some(&expected));
+            assert_eq!(entry.user_tags.as_ref(), Some(&expected));
+        }
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+    }
+}
@@ -18,7 +18,7 @@ use crate::s3::error::{Error, ValidationErr};
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::ListenBucketNotificationResponse;
 use crate::s3::types::NotificationRecords;
-use crate::s3::types::{BucketName, Region, S3Api, S3Request, ToS3Request};
+use crate::s3::types::{BucketName, Region, RequestHeaders, S3Api, S3Request, ToS3Request};
 use async_trait::async_trait;
 use futures_util::Stream;
 use http::Method;
@@ -47,6 +47,12 @@ pub struct ListenBucketNotification {
     events: Option<Vec<String>>,
 }
 
+impl RequestHeaders for ListenBucketNotification {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type alias for [`ListenBucketNotification`].
 ///
 /// Constructed via [`ListenBucketNotification::builder()`](ListenBucketNotification::builder) and used to build a [`ListenBucketNotification`] instance.
@@ -20,6 +20,7 @@ mod bucket_common;
 mod bucket_exists;
 mod copy_object;
 mod create_bucket;
+mod delete_all_versions;
 mod delete_bucket;
 mod delete_bucket_encryption;
 mod delete_bucket_lifecycle;
@@ -30,6 +31,8 @@ mod delete_bucket_tagging;
 mod delete_object_lock_config;
 mod delete_object_tagging;
 mod delete_objects;
+mod download_objects;
+mod empty_bucket;
 mod get_bucket_encryption;
 mod get_bucket_lifecycle;
 mod get_bucket_notification;
@@ -49,6 +52,9 @@ mod get_region;
 mod list_buckets;
 mod list_objects;
 mod listen_bucket_notification;
+mod move_object;
+mod multipart_upload_guard;
+mod object_exists;
 mod put_bucket_encryption;
 mod put_bucket_lifecycle;
 mod put_bucket_notification;
@@ -57,10 +63,12 @@ mod put_bucket_replication;
 mod put_bucket_tagging;
 mod put_bucket_versioning;
 mod put_object;
+mod put_object_fan_out;
 mod put_object_legal_hold;
 mod put_object_lock_config;
 mod put_object_retention;
 mod put_object_tagging;
+mod reencrypt_object;
 mod select_object_content;
 mod stat_object;
 
@@ -70,6 +78,7 @@ pub use bucket_common::*;
 pub use bucket_exists::*;
 pub use copy_object::*;
 pub use create_bucket::*;
+pub use delete_all_versions::*;
 pub use delete_bucket::*;
 pub use delete_bucket_encryption::*;
 pub use delete_bucket_lifecycle::*;
@@ -80,6 +89,8 @@ pub use delete_bucket_tagging::*;
 pub use delete_object_lock_config::*;
 pub use delete_object_tagging::*;
 pub use delete_objects::*;
+pub use download_objects::*;
+pub use empty_bucket::*;
 pub use get_bucket_encryption::*;
 pub use get_bucket_lifecycle::*;
 pub use get_bucket_notification::*;
@@ -99,6 +110,9 @@ pub use get_region::*;
 pub use list_buckets::*;
 pub use list_objects::*;
 pub use listen_bucket_notification::*;
+pub use move_object::*;
+pub use multipart_upload_guard::*;
+pub use object_exists::*;
 pub use put_bucket_encryption::*;
 pub use put_bucket_lifecycle::*;
 pub use put_bucket_notification::*;
@@ -107,9 +121,11 @@ pub use put_bucket_replication::*;
 pub use put_bucket_tagging::*;
 pub use put_bucket_versioning::*;
 pub use put_object::*;
+pub use put_object_fan_out::*;
 pub use put_object_legal_hold::*;
 pub use put_object_lock_config::*;
 pub use put_object_retention::*;
 pub use put_object_tagging::*;
+pub use reencrypt_object::*;
 pub use select_object_content::*;
 pub use stat_object::*;
@@ -0,0 +1,326 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::builders::{ComposeSource, CopySource, MAX_PART_SIZE, ObjectToDelete};
+use crate::s3::client::MinioClient;
+use crate::s3::error::{Error, ValidationErr};
+use crate::s3::header_constants::CONTENT_TYPE;
+use crate::s3::multimap_ext::{Multimap, MultimapExt};
+use crate::s3::response::{CopyObjectResponse, StatObjectResponse};
+use crate::s3::response_traits::{HasS3Fields, HasTagging, HasVersion};
+use crate::s3::types::{BucketName, ObjectKey, Region, S3Api, Tags, VersionId};
+use typed_builder::TypedBuilder;
+
+/// Argument builder for moving an object with a server-side copy followed by a delete of the source.
+///
+/// This struct constructs the parameters required for the [`Client::move_object`](crate::s3::client::MinioClient::move_object) method.
+///
+/// S3 has no rename operation; the object is copied with
+/// [`CopyObject`](crate::s3::builders::CopyObject), which keeps its user metadata
+/// and tags, and the source is deleted afterwards. Sources larger than 5 GiB are
+/// copied with [`ComposeObject`](crate::s3::builders::ComposeObject), and their
+/// user metadata, content type and tags are set on the copy explicitly.
+///
+/// If deleting the source fails, the copy is deleted again and the error of the
+/// source delete is returned. The move is therefore not atomic: a concurrent
+/// reader may observe both objects.
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct MoveObject {
+    #[builder(!default)] // force required
+    client: MinioClient,
+    /// Region of the destination bucket; looked up if not set.
+    #[builder(default, setter(into))]
+    region: Option<Region>,
+    /// Region of the source bucket; looked up if not set.
+    #[builder(default, setter(into))]
+    source_region: Option<Region>,
+    #[builder(setter(into))]
+    source_bucket: BucketName,
+    #[builder(setter(into))]
+    source_object: ObjectKey,
+    /// Version of the source object to move; that version is permanently deleted.
+    #[builder(default, setter(into))]
+    source_version_id: Option<VersionId>,
+    #[builder(setter(into))]
+    bucket: BucketName,
+    #[builder(setter(into))]
+    object: ObjectKey,
+}
+
+/// Builder type for [`MoveObject`] that is returned by [`MinioClient::move_object`](crate::s3::client::MinioClient::move_object).
+///
+/// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
+pub type MoveObjectBldr = MoveObjectBuilder<(
+    (MinioClient,),
+    (),
+    (),
+    (BucketName,),
+    (ObjectKey,),
+    (),
+    (BucketName,),
+    (ObjectKey,),
+)>;
+
+impl MoveObject {
+    /// Copies the source object to the destination and deletes the source.
+    ///
+    /// Returns the response of the copy, which describes the new object.
+    pub async fn send(self) -> Result<CopyObjectResponse, Error> {
+        if self.source_bucket == self.bucket
+            && self.source_object == self.object
+            && self.source_version_id.is_none()
+        {
+            return Err(ValidationErr::InvalidMoveDestination(format!(
+                "source and destination are the same object '{}/{}'",
+                self.bucket, self.object
+            ))
+            .into());
+        }
+
+        let stat: StatObjectResponse = self
+            .client
+            .stat_object(&self.source_bucket, &self.source_object)?
+            .region(self.source_region.clone())
+            .version_id(self.source_version_id.clone())
+            .build()
+            .send()
+            .await?;
+        let copy = if stat.size()? > MAX_PART_SIZE {
+            self.copy_multipart(&stat).await?
+        } else {
+            let source = CopySource::builder()
+                .region(self.source_region.clone())
+                .bucket(self.source_bucket.clone())
+                .object(self.source_object.clone())
+                .version_id(self.source_version_id.clone())
+                .build();
+            self.client
+                .copy_object(&self.bucket, &self.object)?
+                .region(self.region.clone())
+                .source(source)
+                .build()
+                .send()
+                .await?
+        };
+
+        let source = ObjectToDelete::from((self.source_object, self.source_version_id));
+        let deleted = self
+            .client
+            .delete_object(&self.source_bucket, source)?
+            .region(self.source_region)
+            .build()
+            .send()
+            .await;
+
+        if let Err(e) = deleted {
+            // Remove exactly the version created by the copy, so that a
+            // versioned destination is not left with a delete marker.
+            let target = ObjectToDelete::from((self.object.clone(), copy.version_id()));
+            if let Err(rollback) = self
+                .client
+                .delete_object(&self.bucket, target)?
+                .region(self.region)
+                .build()
+                .send()
+                .await
+            {
+                log::warn!(
+                    "failed to remove copy {}/{} after failed move: {rollback}",
+                    self.bucket,
+                    self.object
+                );
+            }
+            return Err(e);
+        }
+        Ok(copy)
+    }
+
+    /// Copies a source larger than 5 GiB with a multipart copy, which does not
+    /// carry over the metadata and tags of the source.
+    async fn copy_multipart(&self, stat: &StatObjectResponse) -> Result<CopyObjectResponse, Error> {
+        let mut user_metadata = Multimap::new();
+        for (k, v) in stat.user_metadata()? {
+            user_metadata.add(format!("x-amz-meta-{k}"), v);
+        }
+        let mut headers = Multimap::new();
+        if let Some(v) = stat.headers().get(CONTENT_TYPE) {
+            headers.add(CONTENT_TYPE, v.to_str().map_err(ValidationErr::from)?);
+        }
+        let tags = self
+            .client
+            .get_object_tagging(&self.source_bucket, &self.source_object)?
+            .region(self.source_region.clone())
+            .version_id(self.source_version_id.clone())
+            .build()
+            .send()
+            .await?
+            .tags()?;
+
+        let source = ComposeSource::builder()
+            .region(self.source_region.clone())
+            .bucket(self.source_bucket.clone())
+            .object(self.source_object.clone())
+            .version_id(self.source_version_id.clone())
+            .build();
+        let resp: CopyObjectResponse = self
+            .client
+            .compose_object(&self.bucket, &self.object, vec![source])?
+            .region(self.region.clone())
+            .headers(headers)
+            .user_metadata(user_metadata)
+            .tags(Tags::from(tags))
+            .build()
+            .send()
+            .await?;
+        Ok(resp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{
+        MockExecutor, RecordedRequest, test_client, test_client_builder,
+    };
+    use crate::s3::header_constants::{AUTHORIZATION, CONTENT_LENGTH, X_AMZ_TAGGING};
+    use http::Method;
+
+    /// Answers the requests of a move of a `size` byte source object with
+    /// `Content-Type: text/plain`, `x-amz-meta-color: blue` and tag `k=v`.
+    fn move_executor(size: u64) -> MockExecutor {
+        MockExecutor::new(move |req| {
+            let query = req.url.query().unwrap_or_default();
+            let resp = http::Response::builder()
+                .status(200)
+                .header("etag", "\"etag\"");
+            let body = match req.method {
+                Method::HEAD => {
+                    return resp
+                        .header(CONTENT_LENGTH, size)
+                        .header(CONTENT_TYPE, "text/plain")
+                        .header("x-amz-meta-color", "blue")
+                        .header("last-modified", "Wed, 15 Jan 2025 00:00:00 GMT")
+                        .body(String::new())
+                        .unwrap();
+                }
+                Method::DELETE => return resp.status(204).body(String::new()).unwrap(),
+                Method::GET => {
+                    "<Tagging><TagSet><Tag><Key>k</Key><Value>v</Value></Tag></TagSet></Tagging>"
+                }
+                Method::PUT if query.contains("partNumber") => {
+                    "<CopyPartResult><ETag>\"etag\"</ETag>\
+                     <LastModified>2025-01-15T00:00:00.000Z</LastModified></CopyPartResult>"
+                }
+                Method::PUT => {
+                    "<CopyObjectResult><ETag>\"etag\"</ETag>\
+                     <LastModified>2025-01-15T00:00:00.000Z</LastModified></CopyObjectResult>"
+                }
+                _ if query.starts_with("uploads") => {
+                    "<InitiateMultipartUploadResult><Bucket>bucket</Bucket><Key>dst</Key>\
+                     <UploadId>upload-1</UploadId></InitiateMultipartUploadResult>"
+                }
+                _ => {
+                    "<CompleteMultipartUploadResult><Bucket>bucket</Bucket><Key>dst</Key>\
+                     <ETag>\"etag-1\"</ETag></CompleteMultipartUploadResult>"
+                }
+            };
+            resp.body(body.to_string()).unwrap()
+        })
+    }
+
+    fn move_object(client: MinioClient) -> MoveObjectBldr {
+        client
+            .move_object("src-bucket", "src", "bucket", "dst")
+            .unwrap()
+    }
+
+    fn scope(req: &RecordedRequest) -> &str {
+        let auth = req.header(AUTHORIZATION).unwrap();
+        auth.split('/').nth(2).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_move_object_onto_itself_is_rejected() {
+        let executor = MockExecutor::default();
+        let result = test_client(executor.clone())
+            .move_object("bucket", "object", "bucket", "object")
+            .unwrap()
+            .build()
+            .send()
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::Validation(ValidationErr::InvalidMoveDestination(_)))
+        ));
+        assert!(executor.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_move_object_uses_region_of_each_bucket() {
+        let executor = move_executor(5);
+        let client = test_client_builder("http://localhost:9000".parse().unwrap())
+            .skip_region_lookup(false)
+            .http_executor(executor.clone())
+            .build()
+            .unwrap();
+        move_object(client)
+            .region(Region::new("us-west-1").unwrap())
+            .source_region(Region::new("eu-west-1").unwrap())
+            .build()
+            .send()
+            .await
+            .unwrap();
+
+        let requests = executor.requests();
+        assert_eq!(requests.len(), 4);
+        for req in requests {
+            let expected = match req.url.path() {
+                "/src-bucket/src" => "eu-west-1",
+                "/bucket/dst" => "us-west-1",
+                path => panic!("unexpected request to {path}"),
+            };
+            assert_eq!(scope(&req), expected, "{} {}", req.method, req.url);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_move_object_large_source_keeps_metadata_and_tags() {
+        let executor = move_executor(MAX_PART_SIZE + 1);
+        move_object(test_client(executor.clone()))
+            .build()
+            .send()
+            .await
+            .unwrap();
+
+        let requests = executor.requests();
+        let create = requests
+            .iter()
+            .find(|r| r.url.query() == Some("uploads="))
+            .unwrap();
+        assert_eq!(create.url.path(), "/bucket/dst");
+        assert_eq!(create.header(CONTENT_TYPE), Some("text/plain"));
+        assert_eq!(create.header("x-amz-meta-color"), Some("blue"));
+        assert_eq!(create.header(X_AMZ_TAGGING), Some("k=v"));
+
+        let writes: Vec<_> = requests
+            .iter()
+            .filter(|r| matches!(r.method, Method::PUT | Method::POST))
+            .collect();
+        assert!(writes.iter().all(|r| r.url.path() == "/bucket/dst"));
+        let delete = requests.last().unwrap();
+        assert_eq!(delete.method, Method::DELETE);
+        assert_eq!(delete.url.path(), "/src-bucket/src");
+    }
+}
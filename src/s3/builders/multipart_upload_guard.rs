@@ -0,0 +1,155 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::builders::AbortMultipartUpload;
+use crate::s3::error::Error;
+use crate::s3::response::AbortMultipartUploadResponse;
+use crate::s3::types::S3Api;
+
+/// Aborts a multipart upload when dropped before [`complete()`](Self::complete)
+/// is called, so that cancelled uploads do not leave orphaned parts behind.
+///
+/// The abort on drop is best-effort: it is spawned on the current tokio runtime
+/// and its result is ignored. Without a runtime, nothing is sent.
+///
+/// # Example
+///
+/// ```no_run
+/// use minio::s3::MinioClient;
+/// use minio::s3::builders::MultipartUploadGuard;
+/// use minio::s3::creds::StaticProvider;
+/// use minio::s3::http::BaseUrl;
+/// use minio::s3::types::S3Api;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let base_url = "http://localhost:9000/".parse::<BaseUrl>().unwrap();
+///     let static_provider = StaticProvider::new("minioadmin", "minioadmin", None);
+///     let client = MinioClient::new(base_url, Some(static_provider), None, None).unwrap();
+///     let upload_id = client
+///         .create_multipart_upload("bucket-name", "large-object")
+///         .unwrap().build().send().await.unwrap()
+///         .upload_id().await.unwrap();
+///     let guard = MultipartUploadGuard::new(
+///         client
+///             .abort_multipart_upload("bucket-name", "large-object", upload_id)
+///             .unwrap()
+///             .build(),
+///     );
+///     // upload the parts and complete the upload; an early return or a
+///     // cancelled task aborts the upload
+///     guard.complete();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MultipartUploadGuard {
+    abort: Option<AbortMultipartUpload>,
+}
+
+impl MultipartUploadGuard {
+    /// Creates a guard that sends `abort` unless disarmed.
+    pub fn new(abort: AbortMultipartUpload) -> Self {
+        Self { abort: Some(abort) }
+    }
+
+    /// Disarms the guard after the upload was completed.
+    pub fn complete(mut self) {
+        self.abort = None;
+    }
+
+    /// Disarms the guard and aborts the upload, waiting for the result.
+    pub async fn abort(mut self) -> Result<AbortMultipartUploadResponse, Error> {
+        let abort = self.abort.take().expect("guard is armed until consumed");
+        abort.send().await
+    }
+}
+
+impl Drop for MultipartUploadGuard {
+    fn drop(&mut self) {
+        let Some(abort) = self.abort.take() else {
+            return;
+        };
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    if let Err(e) = abort.send().await {
+                        log::warn!("failed to abort dropped multipart upload: {e}");
+                    }
+                });
+            }
+            Err(_) => log::warn!("no tokio runtime to abort dropped multipart upload"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{MockExecutor, test_client};
+    use std::time::Duration;
+
+    fn guard() -> (MultipartUploadGuard, MockExecutor) {
+        let executor = MockExecutor::new(|_| {
+            http::Response::builder()
+                .status(204)
+                .body(String::new())
+                .unwrap()
+        });
+        let abort = test_client(executor.clone())
+            .abort_multipart_upload("bucket", "object", "upload-1")
+            .unwrap()
+            .build();
+        (MultipartUploadGuard::new(abort), executor)
+    }
+
+    /// Returns the method, path and query of every request sent so far.
+    fn sent(executor: &MockExecutor) -> Vec<String> {
+        executor
+            .requests()
+            .iter()
+            .map(|r| {
+                format!(
+                    "{} {}?{}",
+                    r.method,
+                    r.url.path(),
+                    r.url.query().unwrap_or_default()
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_drop_aborts_upload() {
+        let (guard, executor) = guard();
+        drop(guard);
+
+        for _ in 0..100 {
+            if !executor.requests().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(sent(&executor), ["DELETE /bucket/object?uploadId=upload-1"]);
+    }
+
+    #[tokio::test]
+    async fn test_complete_disarms_guard() {
+        let (guard, executor) = guard();
+        guard.complete();
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(executor.requests().is_empty());
+    }
+}
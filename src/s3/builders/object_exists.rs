@@ -0,0 +1,102 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::client::MinioClient;
+use crate::s3::error::ValidationErr;
+use crate::s3::multimap_ext::{Multimap, MultimapExt};
+use crate::s3::response::ObjectExistsResponse;
+use crate::s3::sse::{Sse, SseCustomerKey};
+use crate::s3::types::{
+    BucketName, ObjectKey, Region, RequestHeaders, S3Api, S3Request, ToS3Request, VersionId,
+};
+use crate::s3::utils::check_ssec;
+use http::Method;
+use typed_builder::TypedBuilder;
+
+/// Argument builder for checking whether an object exists, using the
+/// [`HeadObject`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_HeadObject.html) S3 API operation.
+///
+/// This struct constructs the parameters required for the [`Client::object_exists`](crate::s3::client::MinioClient::object_exists) method.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ObjectExists {
+    #[builder(!default)] // force required
+    client: MinioClient,
+
+    #[builder(default, setter(into))]
+    extra_headers: Option<Multimap>,
+    #[builder(default, setter(into))]
+    extra_query_params: Option<Multimap>,
+    #[builder(default, setter(into))]
+    region: Option<Region>,
+    #[builder(setter(into), !default)]
+    bucket: BucketName,
+    #[builder(setter(into), !default)]
+    object: ObjectKey,
+
+    #[builder(default, setter(into))]
+    version_id: Option<VersionId>,
+    /// Key of an SSE-C encrypted object; without it the server rejects the
+    /// request and an error is returned rather than `false`.
+    #[builder(default, setter(into))]
+    ssec: Option<SseCustomerKey>,
+}
+
+impl RequestHeaders for ObjectExists {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
+/// Builder type for [`ObjectExists`] that is returned by [`MinioClient::object_exists`](crate::s3::client::MinioClient::object_exists).
+///
+/// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
+pub type ObjectExistsBldr = ObjectExistsBuilder<(
+    (MinioClient,),
+    (),
+    (),
+    (),
+    (BucketName,),
+    (ObjectKey,),
+    (),
+    (),
+)>;
+
+impl S3Api for ObjectExists {
+    type S3Response = ObjectExistsResponse;
+}
+
+impl ToS3Request for ObjectExists {
+    fn to_s3request(self) -> Result<S3Request, ValidationErr> {
+        check_ssec(&self.ssec, &self.client, "ObjectExists")?;
+
+        let mut headers: Multimap = self.extra_headers.unwrap_or_default();
+        if let Some(v) = self.ssec {
+            headers.add_multimap(v.headers());
+        }
+
+        let mut query_params: Multimap = self.extra_query_params.unwrap_or_default();
+        query_params.add_version(self.version_id);
+
+        Ok(S3Request::builder()
+            .client(self.client)
+            .method(Method::HEAD)
+            .region(self.region)
+            .bucket(self.bucket)
+            .object(self.object)
+            .query_params(query_params)
+            .headers(headers)
+            .build())
+    }
+}
@@ -19,7 +19,7 @@ use crate::s3::multimap_ext::Multimap;
 use crate::s3::response::PutBucketEncryptionResponse;
 use crate::s3::segmented_bytes::SegmentedBytes;
 use crate::s3::types::SseConfig;
-use crate::s3::types::{BucketName, Region, S3Api, S3Request, ToS3Request};
+use crate::s3::types::{BucketName, Region, RequestHeaders, S3Api, S3Request, ToS3Request};
 use crate::s3::utils::insert;
 use bytes::Bytes;
 use http::Method;
@@ -42,10 +42,16 @@ pub struct PutBucketEncryption {
     #[builder(!default, setter(into))] // force required + accept Into<String>
     #[builder(!default)]
     bucket: BucketName,
-    #[builder(default)]
+    #[builder(default = SseConfig::s3())]
     sse_config: SseConfig,
 }
 
+impl RequestHeaders for PutBucketEncryption {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type alias for [`PutBucketEncryption`].
 ///
 /// Constructed via [`PutBucketEncryption::builder()`](PutBucketEncryption::builder) and used to build a [`PutBucketEncryption`] instance.
@@ -58,12 +64,13 @@ impl S3Api for PutBucketEncryption {
 
 impl ToS3Request for PutBucketEncryption {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
+        self.sse_config.validate()?;
         let bytes: Bytes = self.sse_config.to_xml().into();
         let body = Arc::new(SegmentedBytes::from(bytes));
 
         Ok(S3Request::builder()
             .client(self.client)
-            .method(Method::GET)
+            .method(Method::PUT)
             .region(self.region)
             .bucket(self.bucket)
             .query_params(insert(self.extra_query_params, "encryption"))
@@ -72,3 +79,46 @@ impl ToS3Request for PutBucketEncryption {
             .build())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::creds::StaticProvider;
+    use crate::s3::http::BaseUrl;
+    use xmltree::Element;
+
+    fn dummy_client() -> MinioClient {
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let provider = StaticProvider::new("minioadmin", "minioadmin", None);
+        MinioClient::new(base_url, Some(provider), None, None).unwrap()
+    }
+
+    #[test]
+    fn test_put_bucket_encryption_dsse_body() {
+        let config = SseConfig::dsse(Some("my-key".into()));
+        let request = PutBucketEncryption::builder()
+            .client(dummy_client())
+            .bucket(BucketName::new("my-bucket").unwrap())
+            .sse_config(config.clone())
+            .build()
+            .to_s3request()
+            .unwrap();
+
+        let body = request.body().unwrap().to_bytes();
+        let root = Element::parse(body.as_ref()).unwrap();
+        assert_eq!(SseConfig::from_xml(&root).unwrap(), config);
+    }
+
+    #[test]
+    fn test_put_bucket_encryption_uses_put() {
+        let request = PutBucketEncryption::builder()
+            .client(dummy_client())
+            .bucket(BucketName::new("my-bucket").unwrap())
+            .build()
+            .to_s3request()
+            .unwrap();
+
+        assert_eq!(request.method(), &Method::PUT);
+        assert!(request.query_params.contains_key("encryption"));
+    }
+}
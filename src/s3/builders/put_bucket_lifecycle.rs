@@ -20,7 +20,7 @@ use crate::s3::lifecycle_config::LifecycleConfig;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::PutBucketLifecycleResponse;
 use crate::s3::segmented_bytes::SegmentedBytes;
-use crate::s3::types::{BucketName, Region, S3Api, S3Request, ToS3Request};
+use crate::s3::types::{BucketName, Region, RequestHeaders, S3Api, S3Request, ToS3Request};
 use crate::s3::utils::{insert, md5sum_hash};
 use bytes::Bytes;
 use http::Method;
@@ -47,6 +47,12 @@ pub struct PutBucketLifecycle {
     life_cycle_config: LifecycleConfig,
 }
 
+impl RequestHeaders for PutBucketLifecycle {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type alias for [`PutBucketLifecycle`].
 ///
 /// Constructed via [`PutBucketLifecycle::builder()`](PutBucketLifecycle::builder) and used to build a [`PutBucketLifecycle`] instance.
@@ -19,7 +19,7 @@ use crate::s3::multimap_ext::Multimap;
 use crate::s3::response::PutBucketNotificationResponse;
 use crate::s3::segmented_bytes::SegmentedBytes;
 use crate::s3::types::NotificationConfig;
-use crate::s3::types::{BucketName, Region, S3Api, S3Request, ToS3Request};
+use crate::s3::types::{BucketName, Region, RequestHeaders, S3Api, S3Request, ToS3Request};
 use crate::s3::utils::insert;
 use bytes::Bytes;
 use http::Method;
@@ -45,6 +45,12 @@ pub struct PutBucketNotification {
     notification_config: NotificationConfig,
 }
 
+impl RequestHeaders for PutBucketNotification {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type alias for [`PutBucketNotification`].
 ///
 /// Constructed via [`PutBucketNotification::builder()`](PutBucketNotification::builder) and used to build a [`PutBucketNotification`] instance.
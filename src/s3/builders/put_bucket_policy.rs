@@ -19,7 +19,7 @@ use crate::s3::error::ValidationErr;
 use crate::s3::multimap_ext::Multimap;
 use crate::s3::response::PutBucketPolicyResponse;
 use crate::s3::segmented_bytes::SegmentedBytes;
-use crate::s3::types::{BucketName, Region, S3Api, S3Request, ToS3Request};
+use crate::s3::types::{BucketName, Region, RequestHeaders, S3Api, S3Request, ToS3Request};
 use crate::s3::utils::insert;
 use bytes::Bytes;
 use http::Method;
@@ -45,6 +45,12 @@ pub struct PutBucketPolicy {
     config: BucketPolicyConfig,
 }
 
+impl RequestHeaders for PutBucketPolicy {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type for [`PutBucketPolicy`] that is returned by [`MinioClient::put_bucket_policy`](crate::s3::client::MinioClient::put_bucket_policy).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
@@ -13,13 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::s3::builders::VersioningStatus;
 use crate::s3::client::MinioClient;
-use crate::s3::error::ValidationErr;
+use crate::s3::error::{Error, ValidationErr};
 use crate::s3::multimap_ext::Multimap;
-use crate::s3::response::PutBucketReplicationResponse;
+use crate::s3::response::{GetBucketVersioningResponse, PutBucketReplicationResponse};
 use crate::s3::segmented_bytes::SegmentedBytes;
-use crate::s3::types::{BucketName, Region, ReplicationConfig, S3Api, S3Request, ToS3Request};
+use crate::s3::types::{
+    BucketName, FromS3Response, Region, ReplicationConfig, RequestHeaders, S3Api, S3Request,
+    ToS3Request,
+};
 use crate::s3::utils::insert;
+use async_trait::async_trait;
 use bytes::Bytes;
 use http::Method;
 use std::sync::Arc;
@@ -42,16 +47,55 @@ pub struct PutBucketReplication {
     bucket: BucketName,
     #[builder(default)]
     replication_config: ReplicationConfig,
+    /// Before sending, reads the versioning state of the bucket and fails with
+    /// [`Error::VersioningRequired`] unless it is enabled, which replication requires.
+    #[builder(default)]
+    require_versioning: bool,
+}
+
+impl RequestHeaders for PutBucketReplication {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
 }
 
 /// Builder type for [`PutBucketReplication`] that is returned by [`MinioClient::put_bucket_replication`](crate::s3::client::MinioClient::put_bucket_replication).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
 pub type PutBucketReplicationBldr =
-    PutBucketReplicationBuilder<((MinioClient,), (), (), (), (BucketName,), ())>;
+    PutBucketReplicationBuilder<((MinioClient,), (), (), (), (BucketName,), (), ())>;
 
+#[async_trait]
 impl S3Api for PutBucketReplication {
     type S3Response = PutBucketReplicationResponse;
+
+    async fn send(self) -> Result<Self::S3Response, Error> {
+        if self.require_versioning {
+            self.check_versioning().await?;
+        }
+        let mut req: S3Request = self.to_s3request()?;
+        let resp: Result<reqwest::Response, Error> = req.execute().await;
+        Self::S3Response::from_s3response(req, resp).await
+    }
+}
+
+impl PutBucketReplication {
+    async fn check_versioning(&self) -> Result<(), Error> {
+        let resp: GetBucketVersioningResponse = self
+            .client
+            .get_bucket_versioning(&self.bucket)?
+            .region(self.region.clone())
+            .build()
+            .send()
+            .await?;
+        match resp.status()? {
+            Some(VersioningStatus::Enabled) => Ok(()),
+            status => Err(Error::VersioningRequired {
+                bucket: self.bucket.to_string(),
+                status: status.map_or("never enabled".into(), |v| v.to_string()),
+            }),
+        }
+    }
 }
 
 impl ToS3Request for PutBucketReplication {
@@ -70,3 +114,60 @@ impl ToS3Request for PutBucketReplication {
             .build())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{MockExecutor, test_client};
+
+    /// Puts a replication config on a bucket whose versioning status is
+    /// `status` and returns the methods of the requests other than the
+    /// versioning lookup.
+    async fn put_replication(status: Option<&'static str>) -> (Result<(), Error>, Vec<Method>) {
+        let executor = MockExecutor::new(move |req| {
+            let body = if req.url.query() == Some("versioning=") {
+                let status = status
+                    .map(|v| format!("<Status>{v}</Status>"))
+                    .unwrap_or_default();
+                format!("<VersioningConfiguration>{status}</VersioningConfiguration>")
+            } else {
+                String::new()
+            };
+            http::Response::new(body)
+        });
+        let resp = test_client(executor.clone())
+            .put_bucket_replication("bucket")
+            .unwrap()
+            .require_versioning(true)
+            .build()
+            .send()
+            .await
+            .map(|_| ());
+        let methods = executor
+            .requests()
+            .into_iter()
+            .filter(|r| r.url.query() != Some("versioning="))
+            .map(|r| r.method)
+            .collect();
+        (resp, methods)
+    }
+
+    #[tokio::test]
+    async fn test_require_versioning() {
+        let (resp, methods) = put_replication(Some("Enabled")).await;
+        resp.unwrap();
+        assert_eq!(methods, [Method::PUT]);
+
+        for (status, expected) in [(None, "never enabled"), (Some("Suspended"), "Suspended")] {
+            let (resp, methods) = put_replication(status).await;
+            match resp {
+                Err(Error::VersioningRequired { bucket, status }) => {
+                    assert_eq!(bucket, "bucket");
+                    assert_eq!(status, expected);
+                }
+                v => panic!("unexpected result: {v:?}"),
+            }
+            assert!(methods.is_empty());
+        }
+    }
+}
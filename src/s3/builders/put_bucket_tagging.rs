@@ -18,11 +18,10 @@ use crate::s3::error::ValidationErr;
 use crate::s3::multimap_ext::Multimap;
 use crate::s3::response::PutBucketTaggingResponse;
 use crate::s3::segmented_bytes::SegmentedBytes;
-use crate::s3::types::{BucketName, Region, S3Api, S3Request, ToS3Request};
+use crate::s3::types::{BucketName, Region, RequestHeaders, S3Api, S3Request, Tags, ToS3Request};
 use crate::s3::utils::insert;
 use bytes::Bytes;
 use http::Method;
-use std::collections::HashMap;
 use std::sync::Arc;
 use typed_builder::TypedBuilder;
 
@@ -41,8 +40,14 @@ pub struct PutBucketTagging {
     region: Option<Region>,
     #[builder(setter(into), !default)]
     bucket: BucketName,
-    #[builder(default)]
-    tags: HashMap<String, String>,
+    #[builder(default, setter(into))]
+    tags: Tags,
+}
+
+impl RequestHeaders for PutBucketTagging {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
 }
 
 /// Builder type for [`PutBucketTagging`] that is returned by [`MinioClient::put_bucket_tagging`](crate::s3::client::MinioClient::put_bucket_tagging).
@@ -57,25 +62,7 @@ impl S3Api for PutBucketTagging {
 
 impl ToS3Request for PutBucketTagging {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        let data: String = {
-            let mut data = String::from("<Tagging>");
-            if !self.tags.is_empty() {
-                data.push_str("<TagSet>");
-                for (key, value) in self.tags.iter() {
-                    data.push_str("<Tag>");
-                    data.push_str("<Key>");
-                    data.push_str(key);
-                    data.push_str("</Key>");
-                    data.push_str("<Value>");
-                    data.push_str(value);
-                    data.push_str("</Value>");
-                    data.push_str("</Tag>");
-                }
-                data.push_str("</TagSet>");
-            }
-            data.push_str("</Tagging>");
-            data
-        };
+        let data: String = self.tags.to_xml();
         let body = Arc::new(SegmentedBytes::from(Bytes::from(data)));
 
         Ok(S3Request::builder()
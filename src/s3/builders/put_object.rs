@@ -14,26 +14,37 @@
 // limitations under the License.
 
 use super::ObjectContent;
-use crate::s3::builders::{ContentStream, Size};
+use crate::s3::builders::{ContentStream, MultipartUploadGuard, Size, StatObject};
 use crate::s3::client::MinioClient;
 use crate::s3::error::{Error, IoError, ValidationErr};
 use crate::s3::header_constants::*;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::{
     AbortMultipartUploadResponse, CompleteMultipartUploadResponse, CreateMultipartUploadResponse,
-    PutObjectContentResponse, PutObjectResponse, UploadPartResponse,
+    PutObjectContentResponse, PutObjectResponse, StatObjectResponse, UploadPartResponse,
+};
+use crate::s3::response_traits::{
+    HasChecksumHeaders, HasEtagFromHeaders, HasObject, HasS3Fields, HasVersion,
 };
-use crate::s3::response_traits::{HasChecksumHeaders, HasEtagFromHeaders};
 use crate::s3::segmented_bytes::SegmentedBytes;
-use crate::s3::sse::Sse;
+use crate::s3::sse::{Sse, SseCustomerKey};
 use crate::s3::types::PartInfo;
 use crate::s3::types::Retention;
-use crate::s3::types::{BucketName, ObjectKey, Region, S3Api, S3Request, ToS3Request, UploadId};
-use crate::s3::utils::{ChecksumAlgorithm, check_sse, compute_checksum_sb, insert};
-use crate::s3::utils::{encode_tags, md5sum_hash, to_iso8601utc, url_encode};
-use bytes::{Bytes, BytesMut};
+use crate::s3::types::{
+    BucketName, ETag, ObjectKey, Region, RequestHeaders, S3Api, S3Request, Tags, ToS3Request,
+    UploadId,
+};
+use crate::s3::utils::{
+    ChecksumAlgorithm, UtcTime, add_request_payer, check_sse, compute_checksum_sb,
+    compute_composite_checksum, get_text_default, insert, to_http_header_value,
+};
+use crate::s3::utils::{encode_tags, hex_encode, md5sum_hash, to_iso8601utc, url_encode};
+use base64::Engine;
+use bytes::{Buf, Bytes, BytesMut};
+use futures_util::StreamExt;
+use futures_util::stream::FuturesUnordered;
 use http::Method;
-use std::{collections::HashMap, sync::Arc};
+use std::sync::Arc;
 use typed_builder::TypedBuilder;
 // region: multipart-upload
 
@@ -60,7 +71,7 @@ pub struct CreateMultipartUpload {
     #[builder(default, setter(into))]
     sse: Option<Arc<dyn Sse>>,
     #[builder(default, setter(into))]
-    tags: Option<HashMap<String, String>>,
+    tags: Option<Tags>,
     #[builder(default, setter(into))]
     retention: Option<Retention>,
     #[builder(default = false)]
@@ -74,6 +85,19 @@ pub struct CreateMultipartUpload {
     /// The checksum is included in response headers for verification.
     #[builder(default, setter(into))]
     checksum_algorithm: Option<ChecksumAlgorithm>,
+    /// Sets the `Expires` header stored with the object.
+    #[builder(default, setter(into))]
+    expires: Option<UtcTime>,
+    /// Enables an S3 Bucket Key for SSE-KMS encryption of the object
+    /// (`x-amz-server-side-encryption-bucket-key-enabled`).
+    #[builder(default = false)]
+    bucket_key_enabled: bool,
+}
+
+impl RequestHeaders for CreateMultipartUpload {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
 }
 
 /// Builder type for [`CreateMultipartUpload`] that is returned by [`MinioClient::create_multipart_upload`](crate::s3::client::MinioClient::create_multipart_upload).
@@ -93,6 +117,8 @@ pub type CreateMultipartUploadBldr = CreateMultipartUploadBuilder<(
     (),
     (),
     (),
+    (),
+    (),
 )>;
 
 impl S3Api for CreateMultipartUpload {
@@ -101,6 +127,7 @@ impl S3Api for CreateMultipartUpload {
 
 impl ToS3Request for CreateMultipartUpload {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
+        check_sse(&self.sse, &self.client, "CreateMultipartUpload")?;
         let mut headers: Multimap = into_headers_put_object(
             self.extra_headers,
             self.user_metadata,
@@ -114,6 +141,12 @@ impl ToS3Request for CreateMultipartUpload {
         if let Some(algorithm) = self.checksum_algorithm {
             headers.add(X_AMZ_CHECKSUM_ALGORITHM, algorithm.as_str().to_string());
         }
+        if let Some(expires) = self.expires {
+            headers.add(EXPIRES, to_http_header_value(expires));
+        }
+        if self.bucket_key_enabled {
+            headers.add(X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED, "true");
+        }
 
         Ok(S3Request::builder()
             .client(self.client)
@@ -152,6 +185,12 @@ pub struct AbortMultipartUpload {
     upload_id: UploadId,
 }
 
+impl RequestHeaders for AbortMultipartUpload {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type for [`AbortMultipartUpload`] that is returned by [`MinioClient::abort_multipart_upload`](crate::s3::client::MinioClient::abort_multipart_upload).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
@@ -220,6 +259,18 @@ pub struct CompleteMultipartUpload {
     /// and all UploadPart operations. Supported algorithms: CRC32, CRC32C, SHA1, SHA256, CRC64NVME.
     #[builder(default, setter(into))]
     checksum_algorithm: Option<ChecksumAlgorithm>,
+    /// Only complete the upload if the existing object has this ETag (`If-Match`).
+    #[builder(default, setter(into))]
+    if_match: Option<ETag>,
+    /// Only complete the upload if no object exists under the key (`If-None-Match: *`).
+    #[builder(default = false)]
+    if_none_match_star: bool,
+}
+
+impl RequestHeaders for CompleteMultipartUpload {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
 }
 
 /// Builder type for [`CompleteMultipartUpload`] that is returned by [`MinioClient::complete_multipart_upload`](crate::s3::client::MinioClient::complete_multipart_upload).
@@ -235,6 +286,8 @@ pub type CompleteMultipartUploadBldr = CompleteMultipartUploadBuilder<(
     (UploadId,),
     (Vec<PartInfo>,),
     (),
+    (),
+    (),
 )>;
 
 impl S3Api for CompleteMultipartUpload {
@@ -301,6 +354,7 @@ impl ToS3Request for CompleteMultipartUpload {
             if let Some(algorithm) = self.checksum_algorithm {
                 headers.add(X_AMZ_CHECKSUM_ALGORITHM, algorithm.as_str().to_string());
             }
+            add_conditional_write_headers(&mut headers, self.if_match, self.if_none_match_star);
         }
         let mut query_params: Multimap = self.extra_query_params.unwrap_or_default();
         query_params.add("uploadId", self.upload_id.as_str());
@@ -340,7 +394,7 @@ pub struct UploadPart {
     #[builder(default, setter(into))]
     sse: Option<Arc<dyn Sse>>,
     #[builder(default, setter(into))]
-    tags: Option<HashMap<String, String>>,
+    tags: Option<Tags>,
     #[builder(default, setter(into))]
     retention: Option<Retention>,
     #[builder(default = false)]
@@ -389,6 +443,25 @@ pub struct UploadPart {
     /// Defaults to false for backwards compatibility.
     #[builder(default = false)]
     use_signed_streaming: bool,
+
+    #[builder(via_mutators(init = None), mutators(
+        /// Sends a base64-encoded checksum of the data computed by the caller,
+        /// instead of computing one locally; takes precedence over `checksum_algorithm`.
+        ///
+        /// A SHA256 checksum is also used as the payload hash for signing, so the
+        /// data is not hashed at all. For other algorithms the payload is still hashed,
+        /// unless the client [skips payload hashing](crate::s3::client::MinioClient::skips_payload_hashing).
+        pub fn precomputed_checksum(&mut self, algorithm: ChecksumAlgorithm, value: impl Into<String>) {
+            self.precomputed_checksum = Some((algorithm, value.into()));
+        }
+    ))]
+    precomputed_checksum: Option<(ChecksumAlgorithm, String)>,
+}
+
+impl RequestHeaders for UploadPart {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
 }
 
 /// Builder type for [`UploadPart`] that is returned by [`MinioClient::upload_part`](crate::s3::client::MinioClient::upload_part).
@@ -413,6 +486,7 @@ pub type UploadPartBldr = UploadPartBuilder<(
     (),
     (),
     (),
+    (Option<(ChecksumAlgorithm, String)>,),
 )>;
 
 impl S3Api for UploadPart {
@@ -436,6 +510,11 @@ impl ToS3Request for UploadPart {
                     "part number must be between 1 and {MAX_MULTIPART_COUNT}"
                 )));
             }
+            let operation = match self.upload_id {
+                Some(_) => "UploadPart",
+                None => "PutObject",
+            };
+            check_sse(&self.sse, &self.client, operation)?;
         }
 
         let mut headers: Multimap = into_headers_put_object(
@@ -448,15 +527,24 @@ impl ToS3Request for UploadPart {
             self.content_type,
         )?;
 
+        let mut payload_sha256: Option<String> = None;
+        let checksum_algorithm = match self.precomputed_checksum {
+            Some((algorithm, value)) => {
+                payload_sha256 = add_precomputed_checksum(&mut headers, algorithm, value)?;
+                None
+            }
+            None => self.checksum_algorithm,
+        };
+
         // Determine if we're using trailing checksums
-        let trailing_checksum = if self.use_trailing_checksum && self.checksum_algorithm.is_some() {
-            self.checksum_algorithm
+        let trailing_checksum = if self.use_trailing_checksum && checksum_algorithm.is_some() {
+            checksum_algorithm
         } else {
             None
         };
 
         // For upfront checksums (not trailing), compute and add to headers
-        if let Some(algorithm) = self.checksum_algorithm
+        if let Some(algorithm) = checksum_algorithm
             && !self.use_trailing_checksum
         {
             let checksum_value = compute_checksum_sb(algorithm, &self.data);
@@ -491,6 +579,7 @@ impl ToS3Request for UploadPart {
             .object(self.object)
             .headers(headers)
             .body(self.data)
+            .payload_sha256(payload_sha256)
             .trailing_checksum(trailing_checksum)
             .use_signed_streaming(self.use_signed_streaming)
             .build())
@@ -504,15 +593,66 @@ impl ToS3Request for UploadPart {
 /// Argument builder for the [`PutObject`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_PutObject.html) S3 API operation.
 ///
 /// This struct constructs the parameters required for the `put_object` method.
+///
+/// # Conditional writes
+///
+/// Setting [`if_none_match_star`](PutObjectBuilder::if_none_match_star) makes the write
+/// succeed only when no object exists under the key, which gives an atomic
+/// create-if-absent primitive. Setting [`if_match`](PutObjectBuilder::if_match) makes
+/// the write succeed only when the current object has the given ETag. When the
+/// condition does not hold, the server responds with
+/// [`MinioErrorCode::PreconditionFailed`](crate::s3::minio_error_response::MinioErrorCode::PreconditionFailed)
+/// (412), or with [`MinioErrorCode::ConditionalRequestConflict`](crate::s3::minio_error_response::MinioErrorCode::ConditionalRequestConflict)
+/// (409) when a concurrent conditional write to the same key is in progress.
+/// [`MinioErrorCode::is_precondition_failed`](crate::s3::minio_error_response::MinioErrorCode::is_precondition_failed)
+/// is true for both, so callers can treat them alike.
 #[derive(Debug, Clone, TypedBuilder)]
 pub struct PutObject {
     pub(crate) inner: UploadPart,
+    /// Only write the object if the existing object has this ETag (`If-Match`).
+    #[builder(default, setter(into))]
+    if_match: Option<ETag>,
+    /// Only write the object if no object exists under the key (`If-None-Match: *`).
+    #[builder(default = false)]
+    if_none_match_star: bool,
+    /// Sends `x-amz-request-payer: requester`, required for requester-pays buckets.
+    #[builder(default = false)]
+    request_payer: bool,
+    /// Sets the `Expires` header stored with the object.
+    #[builder(default, setter(into))]
+    expires: Option<UtcTime>,
+    /// Enables an S3 Bucket Key for SSE-KMS encryption of the object
+    /// (`x-amz-server-side-encryption-bucket-key-enabled`).
+    #[builder(default = false)]
+    bucket_key_enabled: bool,
+    #[builder(via_mutators(init = None), mutators(
+        /// Sends a base64-encoded checksum of the data computed by the caller,
+        /// instead of computing one locally; see [`UploadPartBuilder::precomputed_checksum`].
+        pub fn precomputed_checksum(&mut self, algorithm: ChecksumAlgorithm, value: impl Into<String>) {
+            self.precomputed_checksum = Some((algorithm, value.into()));
+        }
+    ))]
+    precomputed_checksum: Option<(ChecksumAlgorithm, String)>,
+}
+
+impl RequestHeaders for PutObject {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.inner.extra_headers
+    }
 }
 
 /// Builder type for [`PutObject`] that is returned by [`MinioClient::put_object`](crate::s3::client::MinioClient::put_object).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
-pub type PutObjectBldr = PutObjectBuilder<((UploadPart,),)>;
+pub type PutObjectBldr = PutObjectBuilder<(
+    (UploadPart,),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (Option<(ChecksumAlgorithm, String)>,),
+)>;
 
 impl S3Api for PutObject {
     type S3Response = PutObjectResponse;
@@ -520,7 +660,28 @@ impl S3Api for PutObject {
 
 impl ToS3Request for PutObject {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        self.inner.to_s3request()
+        let mut inner = self.inner;
+        if self.precomputed_checksum.is_some() {
+            inner.precomputed_checksum = self.precomputed_checksum;
+        }
+        if self.if_match.is_some()
+            || self.if_none_match_star
+            || self.request_payer
+            || self.expires.is_some()
+            || self.bucket_key_enabled
+        {
+            let mut headers: Multimap = inner.extra_headers.take().unwrap_or_default();
+            add_conditional_write_headers(&mut headers, self.if_match, self.if_none_match_star);
+            add_request_payer(&mut headers, self.request_payer);
+            if let Some(expires) = self.expires {
+                headers.add(EXPIRES, to_http_header_value(expires));
+            }
+            if self.bucket_key_enabled {
+                headers.add(X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED, "true");
+            }
+            inner.extra_headers = Some(headers);
+        }
+        inner.to_s3request()
     }
 }
 
@@ -550,7 +711,7 @@ pub struct PutObjectContent {
     #[builder(default, setter(into))]
     sse: Option<Arc<dyn Sse>>,
     #[builder(default, setter(into))]
-    tags: Option<HashMap<String, String>>,
+    tags: Option<Tags>,
     #[builder(default, setter(into))]
     retention: Option<Retention>,
     #[builder(default = false)]
@@ -559,12 +720,21 @@ pub struct PutObjectContent {
     ///
     /// If not specified, defaults to [`DEFAULT_PART_SIZE`] (64 MiB).
     /// For objects large enough that the default would exceed [`MAX_MULTIPART_COUNT`] parts,
-    /// the part size is automatically scaled up to fit within the limit.
+    /// the part size is automatically scaled up to fit within the limit. An explicit part
+    /// size that is too small for a known content size is raised the same way, with a warning.
     /// When the total content size is unknown (for example, when streaming), this value must
     /// be set explicitly; otherwise part calculation fails with `MissingPartSize`.
     /// Must be between [`MIN_PART_SIZE`] (5 MiB) and [`MAX_PART_SIZE`] (5 GiB) if explicitly set.
     #[builder(default, setter(into))]
     part_size: Size,
+    /// Maximum number of parts uploaded concurrently in a multipart upload.
+    ///
+    /// Parts are still read from the content in order, so up to this many parts
+    /// are held in memory at once. Defaults to 1 (sequential upload). Transient
+    /// failures of a single part are retried according to the client's
+    /// [`RetryConfig`](crate::s3::client::RetryConfig).
+    #[builder(default = 1)]
+    max_concurrent_parts: usize,
     #[builder(default, setter(into))]
     content_type: Option<String>,
     #[builder(default, setter(into))]
@@ -591,6 +761,34 @@ pub struct PutObjectContent {
     #[builder(default = false)]
     use_signed_streaming: bool,
 
+    /// Only write the object if the existing object has this ETag (`If-Match`).
+    ///
+    /// For multipart uploads the condition is evaluated when the upload is completed.
+    #[builder(default, setter(into))]
+    if_match: Option<ETag>,
+    /// Only write the object if no object exists under the key (`If-None-Match: *`).
+    ///
+    /// For multipart uploads the condition is evaluated when the upload is completed.
+    #[builder(default = false)]
+    if_none_match_star: bool,
+    /// Sends `x-amz-request-payer: requester` on every request of the upload,
+    /// required for requester-pays buckets.
+    #[builder(default = false)]
+    request_payer: bool,
+    /// After the upload, stats the object and checks that its size, ETag and,
+    /// if `checksum_algorithm` is set, checksum match the upload; a mismatch is
+    /// returned as [`ValidationErr::UploadVerificationFailed`]. For multipart
+    /// uploads, the checksum is compared with the composite checksum of the parts.
+    #[builder(default = false)]
+    verify_after_upload: bool,
+    /// Sets the `Expires` header stored with the object.
+    #[builder(default, setter(into))]
+    expires: Option<UtcTime>,
+    /// Enables an S3 Bucket Key for SSE-KMS encryption of the object
+    /// (`x-amz-server-side-encryption-bucket-key-enabled`).
+    #[builder(default = false)]
+    bucket_key_enabled: bool,
+
     // source data
     #[builder(!default, setter(into))] // force required + accept Into<String>
     input_content: ObjectContent,
@@ -603,6 +801,12 @@ pub struct PutObjectContent {
     part_count: Option<u16>,
 }
 
+impl RequestHeaders for PutObjectContent {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type for [`PutObjectContent`] that is returned by [`MinioClient::put_object_content`](crate::s3::client::MinioClient::put_object_content).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
@@ -623,12 +827,25 @@ pub type PutObjectContentBldr = PutObjectContentBuilder<(
     (),
     (),
     (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
     (ObjectContent,),
 )>;
 
 impl PutObjectContent {
     pub async fn send(mut self) -> Result<PutObjectContentResponse, Error> {
-        check_sse(&self.sse, &self.client)?;
+        check_sse(&self.sse, &self.client, "PutObject")?;
+
+        if self.request_payer {
+            let mut headers: Multimap = self.extra_headers.take().unwrap_or_default();
+            add_request_payer(&mut headers, true);
+            self.extra_headers = Some(headers);
+        }
 
         let input_content = std::mem::take(&mut self.input_content);
         self.content_stream = input_content
@@ -651,6 +868,18 @@ impl PutObjectContent {
             .await
             .map_err(IoError::from)?;
 
+        // With a known size, the first read must fill the part (or the whole object
+        // when it is smaller than the part size).
+        if let Some(declared) = object_size.value()
+            && (seg_bytes.len() as u64) < part_size.min(declared)
+        {
+            return Err(ValidationErr::ContentLengthMismatch {
+                declared,
+                actual: seg_bytes.len() as u64,
+            }
+            .into());
+        }
+
         // In the first part read, if:
         //
         //   - object_size is unknown AND we got less than the part size, OR
@@ -662,6 +891,22 @@ impl PutObjectContent {
         {
             let size = seg_bytes.len() as u64;
 
+            // The declared size has been read; anything left means the stream is too long.
+            if let Some(declared) = object_size.value() {
+                let extra = self
+                    .content_stream
+                    .read_upto(1)
+                    .await
+                    .map_err(IoError::from)?;
+                if !extra.is_empty() {
+                    return Err(ValidationErr::ContentLengthMismatch {
+                        declared,
+                        actual: size + extra.len() as u64,
+                    }
+                    .into());
+                }
+            }
+
             let resp: PutObjectResponse = PutObject::builder()
                 .inner(UploadPart {
                     client: self.client.clone(),
@@ -682,18 +927,25 @@ impl PutObjectContent {
                     checksum_algorithm: self.checksum_algorithm,
                     use_trailing_checksum: self.use_trailing_checksum,
                     use_signed_streaming: self.use_signed_streaming,
+                    precomputed_checksum: None,
                 })
+                .if_match(self.if_match.clone())
+                .if_none_match_star(self.if_none_match_star)
+                .expires(self.expires)
+                .bucket_key_enabled(self.bucket_key_enabled)
                 .build()
                 .send()
                 .await?;
 
-            Ok(PutObjectContentResponse::new(resp, size))
-        } else if let Some(expected) = object_size.value()
-            && (seg_bytes.len() as u64) < part_size
-        {
-            // Not enough data!
-            let got: u64 = seg_bytes.len() as u64;
-            Err(ValidationErr::InsufficientData { expected, got }.into())
+            let resp = PutObjectContentResponse::new(resp, size);
+            if self.verify_after_upload {
+                let checksum = self
+                    .checksum_algorithm
+                    .and_then(|alg| resp.get_checksum(alg).map(|v| (alg, v)));
+                let stat = self.stat_upload(&resp, checksum.is_some()).send().await?;
+                verify_upload(&resp, &stat, checksum)?;
+            }
+            Ok(resp)
         } else {
             // Otherwise, we start a multipart upload.
             let create_mpu_resp: CreateMultipartUploadResponse = CreateMultipartUpload::builder()
@@ -710,29 +962,33 @@ impl PutObjectContent {
                 .legal_hold(self.legal_hold)
                 .content_type(self.content_type.clone())
                 .checksum_algorithm(self.checksum_algorithm)
+                .expires(self.expires)
+                .bucket_key_enabled(self.bucket_key_enabled)
                 .build()
                 .send()
                 .await?;
 
-            let client = self.client.clone();
-            let bucket = self.bucket.clone();
-            let object = self.object.clone();
             let upload_id: UploadId = create_mpu_resp.upload_id().await?;
+            // Aborts the upload if this future is dropped before it finishes.
+            let guard = MultipartUploadGuard::new(
+                AbortMultipartUpload::builder()
+                    .client(self.client.clone())
+                    .bucket(self.bucket.clone())
+                    .object(self.object.clone())
+                    .upload_id(upload_id.clone())
+                    .build(),
+            );
 
             let mpu_res = self
-                .send_mpu(part_size, upload_id.clone(), object_size, seg_bytes)
+                .send_mpu(part_size, upload_id, object_size, seg_bytes)
                 .await;
 
-            if mpu_res.is_err() {
+            match mpu_res {
+                Ok(_) => guard.complete(),
                 // If we failed to complete the multipart upload, we should abort it.
-                let _ = AbortMultipartUpload::builder()
-                    .client(client)
-                    .bucket(bucket)
-                    .object(object)
-                    .upload_id(upload_id)
-                    .build()
-                    .send()
-                    .await;
+                Err(_) => {
+                    let _ = guard.abort().await;
+                }
             }
             mpu_res
         }
@@ -754,6 +1010,9 @@ impl PutObjectContent {
             Vec::new()
         };
 
+        let max_in_flight = self.max_concurrent_parts.max(1);
+        let mut in_flight = FuturesUnordered::new();
+
         let mut first_part = Some(first_part);
         let mut total_read = 0;
         while !done {
@@ -783,14 +1042,18 @@ impl PutObjectContent {
                 return Err(ValidationErr::TooManyParts(part_number as u64).into());
             }
 
-            if let Some(exp) = object_size.value()
-                && exp < total_read
+            if let Some(declared) = object_size.value()
+                && declared < total_read
             {
-                return Err(ValidationErr::TooMuchData(exp).into());
+                return Err(ValidationErr::ContentLengthMismatch {
+                    declared,
+                    actual: total_read,
+                }
+                .into());
             }
 
             // Upload the part now.
-            let resp: UploadPartResponse = UploadPart {
+            let upload_part = UploadPart {
                 client: self.client.clone(),
                 extra_headers: self.extra_headers.clone(),
                 extra_query_params: self.extra_query_params.clone(),
@@ -810,19 +1073,25 @@ impl PutObjectContent {
                 checksum_algorithm: self.checksum_algorithm,
                 use_trailing_checksum: self.use_trailing_checksum,
                 use_signed_streaming: self.use_signed_streaming,
+                precomputed_checksum: None,
+            };
+            let checksum_algorithm = self.checksum_algorithm;
+            in_flight.push(async move {
+                let resp: UploadPartResponse = upload_part.send().await?;
+                let checksum =
+                    checksum_algorithm.and_then(|alg| resp.get_checksum(alg).map(|v| (alg, v)));
+                Ok::<_, Error>(PartInfo::new(
+                    part_number,
+                    resp.etag()?,
+                    buffer_size,
+                    checksum,
+                ))
+            });
+            if in_flight.len() >= max_in_flight
+                && let Some(part) = in_flight.next().await
+            {
+                parts.push(part?);
             }
-            .send()
-            .await?;
-
-            let checksum = self
-                .checksum_algorithm
-                .and_then(|alg| resp.get_checksum(alg).map(|v| (alg, v)));
-            parts.push(PartInfo::new(
-                part_number,
-                resp.etag()?,
-                buffer_size,
-                checksum,
-            ));
 
             // Finally, check if we are done.
             if buffer_size < part_size {
@@ -830,44 +1099,224 @@ impl PutObjectContent {
             }
         }
 
+        while let Some(part) = in_flight.next().await {
+            parts.push(part?);
+        }
+        parts.sort_by_key(|p| p.number);
+
         // Complete the multipart upload.
         let size = parts.iter().map(|p| p.size).sum();
 
-        if let Some(expected) = object_size.value()
-            && expected != size
+        if let Some(declared) = object_size.value()
+            && declared != size
         {
-            return Err(ValidationErr::InsufficientData {
-                expected,
-                got: size,
+            return Err(ValidationErr::ContentLengthMismatch {
+                declared,
+                actual: size,
             }
             .into());
         }
 
+        // Checksum-of-checksums the server is expected to report for the object.
+        let composite_checksum = match self.checksum_algorithm {
+            Some(alg) => parts
+                .iter()
+                .map(|p| p.checksum.as_ref().map(|(_, v)| v.as_str()))
+                .collect::<Option<Vec<&str>>>()
+                .map(|v| compute_composite_checksum(alg, &v))
+                .transpose()?,
+            None => None,
+        };
+
         let resp: CompleteMultipartUploadResponse = CompleteMultipartUpload {
-            client: self.client,
-            extra_headers: self.extra_headers,
-            extra_query_params: self.extra_query_params,
-            bucket: self.bucket,
-            object: self.object,
-            region: self.region,
+            client: self.client.clone(),
+            extra_headers: self.extra_headers.clone(),
+            extra_query_params: self.extra_query_params.clone(),
+            bucket: self.bucket.clone(),
+            object: self.object.clone(),
+            region: self.region.clone(),
             parts,
             upload_id,
             checksum_algorithm: self.checksum_algorithm,
+            if_match: self.if_match.clone(),
+            if_none_match_star: self.if_none_match_star,
         }
         .send()
         .await?;
 
-        Ok(PutObjectContentResponse::new(resp, size))
+        if let Some(computed) = &composite_checksum
+            && let Some(reported) = resp.checksum()
+            && reported.is_composite()
+            && Some(reported.algorithm) == self.checksum_algorithm
+            && reported.value != *computed
+        {
+            return Err(ValidationErr::ChecksumMismatch {
+                expected: reported.value,
+                computed: computed.clone(),
+            }
+            .into());
+        }
+
+        let resp = PutObjectContentResponse::new(
+            PutObjectResponse {
+                request: resp.request,
+                headers: resp.headers,
+                body: resp.body,
+            },
+            size,
+        );
+        if self.verify_after_upload {
+            let checksum = self.checksum_algorithm.zip(composite_checksum);
+            let stat = self.stat_upload(&resp, checksum.is_some()).send().await?;
+            verify_upload(&resp, &stat, checksum)?;
+        }
+        Ok(resp)
+    }
+
+    /// Returns the request to stat the uploaded object for [`verify_upload`].
+    ///
+    /// The stat carries only the SSE-C key and the request payer of the upload;
+    /// headers such as `x-amz-meta-*` or conditionals do not apply to a HEAD.
+    fn stat_upload(&self, resp: &PutObjectContentResponse, with_checksum: bool) -> StatObject {
+        let headers = with_checksum.then(|| {
+            let mut headers = Multimap::new();
+            headers.add(X_AMZ_CHECKSUM_MODE, "ENABLED");
+            headers
+        });
+        let ssec = self
+            .sse
+            .as_ref()
+            .and_then(|v| v.as_any().downcast_ref::<SseCustomerKey>())
+            .cloned();
+        StatObject::builder()
+            .client(self.client.clone())
+            .extra_headers(headers)
+            .region(self.region.clone())
+            .bucket(self.bucket.clone())
+            .object(self.object.clone())
+            .version_id(resp.version_id())
+            .ssec(ssec)
+            .request_payer(self.request_payer)
+            .build()
+    }
+}
+
+/// Compares size, ETag and checksum of the stat of an uploaded object with
+/// those of the upload.
+fn verify_upload(
+    resp: &PutObjectContentResponse,
+    stat: &StatObjectResponse,
+    checksum: Option<(ChecksumAlgorithm, String)>,
+) -> Result<(), Error> {
+    let mismatch = |field: &str, expected: String, actual: String| -> Error {
+        ValidationErr::UploadVerificationFailed {
+            object: resp.object().map(ToString::to_string).unwrap_or_default(),
+            field: field.into(),
+            expected,
+            actual,
+        }
+        .into()
+    };
+
+    let size = stat.size()?;
+    if size != resp.object_size() {
+        return Err(mismatch(
+            "size",
+            resp.object_size().to_string(),
+            size.to_string(),
+        ));
+    }
+
+    let expected_etag = uploaded_etag(resp)?;
+    let etag = stat.etag()?;
+    if !expected_etag.is_empty() && etag != expected_etag {
+        return Err(mismatch(
+            "ETag",
+            expected_etag.to_string(),
+            etag.to_string(),
+        ));
     }
+
+    if let Some((alg, expected)) = checksum {
+        let actual = stat
+            .checksum()
+            .filter(|v| v.algorithm == alg)
+            .map(|v| v.value)
+            .unwrap_or_default();
+        if actual != expected {
+            return Err(mismatch(
+                &format!("{} checksum", alg.as_str()),
+                expected,
+                actual,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the ETag of an upload, which `CompleteMultipartUpload` may only
+/// report in the response body.
+fn uploaded_etag(resp: &PutObjectContentResponse) -> Result<ETag, ValidationErr> {
+    let etag = resp.etag()?;
+    if !etag.is_empty() || resp.body().is_empty() {
+        return Ok(etag);
+    }
+    let root = xmltree::Element::parse(resp.body().clone().reader())?;
+    ETag::new(get_text_default(&root, "ETag").trim_matches('"'))
 }
 
 // endregion: put-object-content
 
+/// Adds a checksum computed by the caller. For a SHA256 checksum, returns its
+/// hex form, which the client sends as the payload hash instead of hashing the body.
+fn add_precomputed_checksum(
+    headers: &mut Multimap,
+    algorithm: ChecksumAlgorithm,
+    value: String,
+) -> Result<Option<String>, ValidationErr> {
+    let name = algorithm.as_str();
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(&value)
+        .map_err(|_| {
+            ValidationErr::InvalidChecksum(format!("{name} checksum '{value}' is not base64"))
+        })?;
+    let expected_len = match algorithm {
+        ChecksumAlgorithm::CRC32 | ChecksumAlgorithm::CRC32C => 4,
+        ChecksumAlgorithm::CRC64NVME => 8,
+        ChecksumAlgorithm::SHA1 => 20,
+        ChecksumAlgorithm::SHA256 => 32,
+    };
+    if decoded.len() != expected_len {
+        return Err(ValidationErr::InvalidChecksum(format!(
+            "{name} checksum '{value}' must be {expected_len} bytes, got {}",
+            decoded.len()
+        )));
+    }
+
+    headers.add(X_AMZ_CHECKSUM_ALGORITHM, name);
+    headers.add(algorithm.header_name(), value);
+    Ok((algorithm == ChecksumAlgorithm::SHA256).then(|| hex_encode(&decoded)))
+}
+
+fn add_conditional_write_headers(
+    headers: &mut Multimap,
+    if_match: Option<ETag>,
+    if_none_match_star: bool,
+) {
+    if let Some(v) = if_match {
+        let etag = v.as_str().trim_matches('"');
+        headers.insert(IF_MATCH.into(), format!("\"{etag}\""));
+    }
+    if if_none_match_star {
+        headers.insert(IF_NONE_MATCH.into(), "*".into());
+    }
+}
+
 fn into_headers_put_object(
     extra_headers: Option<Multimap>,
     user_metadata: Option<Multimap>,
     sse: Option<Arc<dyn Sse>>,
-    tags: Option<HashMap<String, String>>,
+    tags: Option<Tags>,
     retention: Option<Retention>,
     legal_hold: bool,
     content_type: Option<String>,
@@ -998,8 +1447,7 @@ pub fn calc_part_info(
             // Use the default part size unless the object is too large to fit in
             // MAX_MULTIPART_COUNT parts at that size; in that case, scale up.
             let mut psize = if object_size > DEFAULT_PART_SIZE * MAX_MULTIPART_COUNT as u64 {
-                let raw = (object_size as f64 / MAX_MULTIPART_COUNT as f64).ceil() as u64;
-                MIN_PART_SIZE * (raw as f64 / MIN_PART_SIZE as f64).ceil() as u64
+                min_part_size_for(object_size)
             } else {
                 DEFAULT_PART_SIZE
             };
@@ -1020,6 +1468,18 @@ pub fn calc_part_info(
         // If both object size and part size are known, validate the resulting
         // part count and return.
         (Size::Known(object_size), Size::Known(part_size)) => {
+            // A part size too small for the object is raised to the smallest size
+            // that keeps the upload within MAX_MULTIPART_COUNT parts.
+            let part_size = if object_size.div_ceil(part_size) > MAX_MULTIPART_COUNT as u64 {
+                let tuned = min_part_size_for(object_size);
+                log::warn!(
+                    "part size {part_size} needs more than {MAX_MULTIPART_COUNT} parts for object size {object_size}; using part size {tuned}"
+                );
+                tuned
+            } else {
+                part_size
+            };
+
             let part_count = (object_size as f64 / part_size as f64).ceil() as u16;
             if part_count == 0 || part_count > MAX_MULTIPART_COUNT {
                 return Err(ValidationErr::InvalidPartCount {
@@ -1034,9 +1494,19 @@ pub fn calc_part_info(
     }
 }
 
+/// Returns the smallest multiple of [`MIN_PART_SIZE`] that fits `object_size`
+/// into at most [`MAX_MULTIPART_COUNT`] parts.
+fn min_part_size_for(object_size: u64) -> u64 {
+    let raw = object_size.div_ceil(MAX_MULTIPART_COUNT as u64);
+    raw.div_ceil(MIN_PART_SIZE).max(1) * MIN_PART_SIZE
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::s3::client::test_support::{MockExecutor, test_client, test_client_builder};
+    use crate::s3::error::S3ServerError;
+    use crate::s3::minio_error_response::MinioErrorCode;
 
     /// Objects small enough to fit in MAX_MULTIPART_COUNT parts at DEFAULT_PART_SIZE
     /// must use DEFAULT_PART_SIZE (not the older MIN_PART_SIZE-rounded value).
@@ -1090,6 +1560,29 @@ mod tests {
         assert_eq!(count, Some(1));
     }
 
+    /// A user part size that would need more than MAX_MULTIPART_COUNT parts is
+    /// raised to the smallest MIN_PART_SIZE multiple that fits.
+    #[test]
+    fn calc_part_info_raises_too_small_part_size() {
+        const MIB: u64 = 1024 * 1024;
+        const GIB: u64 = 1024 * MIB;
+        let cases = [
+            // (object size, requested part size, chosen part size, part count)
+            (200 * GIB, 16 * MIB, 25 * MIB, 8192),
+            (1024 * GIB, MIN_PART_SIZE, 105 * MIB, 9987),
+            (MAX_OBJECT_SIZE, 64 * MIB, 525 * MIB, 9987),
+            // Requested part sizes that already fit are kept.
+            (100 * GIB, 16 * MIB, 16 * MIB, 6400),
+            (10_000 * MIN_PART_SIZE, MIN_PART_SIZE, MIN_PART_SIZE, 10_000),
+        ];
+        for (object_size, part_size, expected_psize, expected_count) in cases {
+            let (psize, count) =
+                calc_part_info(Size::Known(object_size), Size::Known(part_size)).unwrap();
+            assert_eq!(psize, expected_psize, "object size {object_size}");
+            assert_eq!(count, Some(expected_count), "object size {object_size}");
+        }
+    }
+
     quickcheck! {
         fn test_calc_part_info(object_size: Size, part_size: Size) -> bool {
             let res = calc_part_info(object_size, part_size);
@@ -1143,7 +1636,19 @@ mod tests {
                 (Size::Known(_), Size::Unknown, _) => false,
 
                 (Size::Known(object_size), Size::Known(part_size), res) => {
-                    if (part_size > object_size) || ((part_size * (MAX_MULTIPART_COUNT as u64)) < object_size) {
+                    if (part_size * (MAX_MULTIPART_COUNT as u64)) < object_size {
+                        // Too small part sizes are raised to fit MAX_MULTIPART_COUNT.
+                        return match res {
+                            Ok((psize, Some(part_count))) => {
+                                psize > part_size
+                                    && psize % MIN_PART_SIZE == 0
+                                    && part_count <= MAX_MULTIPART_COUNT
+                                    && psize * (part_count as u64) >= object_size
+                            }
+                            _ => false,
+                        }
+                    }
+                    if part_size > object_size {
                         return match res {
                             Err(ValidationErr::InvalidPartCount{object_size:v1, part_size:v2, part_count:v3}) => {
                                 (v1 == object_size) && (v2 == part_size) && (v3 == MAX_MULTIPART_COUNT)
@@ -1162,4 +1667,463 @@ mod tests {
             }
         }
     }
+
+    #[derive(Debug, Default)]
+    struct CaptureContentSha256Hook {
+        captured: std::sync::Mutex<Option<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::s3::client::RequestHooks for CaptureContentSha256Hook {
+        fn name(&self) -> &'static str {
+            "capture-content-sha256"
+        }
+
+        async fn before_signing_mut(
+            &self,
+            _method: &Method,
+            _url: &mut crate::s3::http::Url,
+            _region: &str,
+            headers: &mut Multimap,
+            _query_params: &Multimap,
+            _bucket: Option<&BucketName>,
+            _object: Option<&ObjectKey>,
+            _body: Option<&SegmentedBytes>,
+            _extensions: &mut http::Extensions,
+        ) -> Result<(), Error> {
+            *self.captured.lock().unwrap() = headers.get(X_AMZ_CONTENT_SHA256).cloned();
+            // Abort before any network traffic.
+            Err(ValidationErr::UrlBuildError("captured".into()).into())
+        }
+    }
+
+    async fn put_content_sha256(base_url: &str, skip_payload_hashing: bool) -> String {
+        let hook = Arc::new(CaptureContentSha256Hook::default());
+        let client = test_client_builder(base_url.parse().unwrap())
+            .skip_payload_hashing(skip_payload_hashing)
+            .hook(hook.clone())
+            .build()
+            .unwrap();
+
+        let data = SegmentedBytes::from(Bytes::from_static(b"hello"));
+        let result = client
+            .put_object("bucket", "object", data)
+            .unwrap()
+            .build()
+            .send()
+            .await;
+        assert!(result.is_err());
+
+        hook.captured.lock().unwrap().clone().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_put_object_skip_payload_hashing() {
+        let hello_sha256 = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+        assert_eq!(
+            put_content_sha256("https://localhost:9000", true).await,
+            UNSIGNED_PAYLOAD
+        );
+        assert_eq!(
+            put_content_sha256("https://localhost:9000", false).await,
+            hello_sha256
+        );
+        // Plain HTTP always signs the payload hash.
+        assert_eq!(
+            put_content_sha256("http://localhost:9000", true).await,
+            hello_sha256
+        );
+    }
+
+    #[derive(Debug, Default)]
+    struct CaptureHeadersHook {
+        captured: std::sync::Mutex<Option<Multimap>>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::s3::client::RequestHooks for CaptureHeadersHook {
+        fn name(&self) -> &'static str {
+            "capture-headers"
+        }
+
+        async fn before_signing_mut(
+            &self,
+            _method: &Method,
+            _url: &mut crate::s3::http::Url,
+            _region: &str,
+            headers: &mut Multimap,
+            _query_params: &Multimap,
+            _bucket: Option<&BucketName>,
+            _object: Option<&ObjectKey>,
+            _body: Option<&SegmentedBytes>,
+            _extensions: &mut http::Extensions,
+        ) -> Result<(), Error> {
+            *self.captured.lock().unwrap() = Some(headers.clone());
+            // Abort before any network traffic.
+            Err(ValidationErr::UrlBuildError("captured".into()).into())
+        }
+    }
+
+    fn capturing_client(hook: Arc<CaptureHeadersHook>) -> MinioClient {
+        test_client_builder("http://localhost:9000".parse().unwrap())
+            .hook(hook)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_put_object_precomputed_sha256() {
+        // SHA256 of "world", deliberately not of the uploaded data, so any
+        // locally computed hash would show up as a different value.
+        let checksum = "SG6kYiTRu0+2gPNPfJrZao8k7Ii+c+qOWmxlJg6cuKc=";
+        let hook = Arc::new(CaptureHeadersHook::default());
+        let client = capturing_client(hook.clone());
+
+        let data = SegmentedBytes::from(Bytes::from_static(b"hello"));
+        let result = client
+            .put_object("bucket", "object", data)
+            .unwrap()
+            .precomputed_checksum(ChecksumAlgorithm::SHA256, checksum)
+            .build()
+            .send()
+            .await;
+        assert!(result.is_err());
+
+        let headers = hook.captured.lock().unwrap().clone().unwrap();
+        let get = |k: &str| headers.get(k).map(String::as_str);
+        assert_eq!(get(X_AMZ_CHECKSUM_SHA256), Some(checksum));
+        assert_eq!(get(X_AMZ_CHECKSUM_ALGORITHM), Some("SHA256"));
+        assert_eq!(
+            get(X_AMZ_CONTENT_SHA256),
+            Some("486ea46224d1bb4fb680f34f7c9ad96a8f24ec88be73ea8e5a6c65260e9cb8a7")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_part_precomputed_checksum_overrides_algorithm() {
+        let checksum = "AAAAAA==";
+        let hook = Arc::new(CaptureHeadersHook::default());
+        let client = capturing_client(hook.clone());
+
+        let data = SegmentedBytes::from(Bytes::from_static(b"hello"));
+        let result = client
+            .upload_part("bucket", "object", "upload-id", 1, data)
+            .unwrap()
+            .checksum_algorithm(ChecksumAlgorithm::CRC32C)
+            .precomputed_checksum(ChecksumAlgorithm::CRC32C, checksum)
+            .build()
+            .send()
+            .await;
+        assert!(result.is_err());
+
+        let headers = hook.captured.lock().unwrap().clone().unwrap();
+        assert_eq!(
+            headers.get_vec(X_AMZ_CHECKSUM_CRC32C),
+            Some(&vec![checksum.to_string()])
+        );
+        assert_eq!(
+            headers.get_vec(X_AMZ_CHECKSUM_ALGORITHM),
+            Some(&vec!["CRC32C".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_precomputed_checksum_validation() {
+        let client = capturing_client(Arc::new(CaptureHeadersHook::default()));
+        for (algorithm, value) in [
+            (ChecksumAlgorithm::CRC32C, "not base64!"),
+            (ChecksumAlgorithm::SHA256, "AAAAAA=="),
+        ] {
+            let err = client
+                .upload_part("bucket", "object", "upload-id", 1, SegmentedBytes::new())
+                .unwrap()
+                .precomputed_checksum(algorithm, value)
+                .build()
+                .to_s3request()
+                .unwrap_err();
+            assert!(matches!(err, ValidationErr::InvalidChecksum(_)), "{err:?}");
+        }
+    }
+
+    async fn put_stream_with_declared_size(
+        chunks: &'static [&'static [u8]],
+        declared: u64,
+    ) -> Error {
+        let hook = Arc::new(CaptureContentSha256Hook::default());
+        let client = test_client_builder("http://localhost:9000".parse().unwrap())
+            .hook(hook.clone())
+            .build()
+            .unwrap();
+
+        let stream = futures_util::stream::iter(
+            chunks
+                .iter()
+                .map(|c| Ok::<_, std::io::Error>(Bytes::from_static(c))),
+        );
+        let err = client
+            .put_object_content(
+                "bucket",
+                "object",
+                ObjectContent::new_from_stream(stream, Some(declared)),
+            )
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap_err();
+
+        // The mismatch must be detected before any request is signed.
+        assert!(hook.captured.lock().unwrap().is_none());
+        err
+    }
+
+    #[tokio::test]
+    async fn test_put_object_content_stream_shorter_than_declared() {
+        let err = put_stream_with_declared_size(&[b"hello", b" world"], 20).await;
+        assert!(matches!(
+            err,
+            Error::Validation(ValidationErr::ContentLengthMismatch {
+                declared: 20,
+                actual: 11
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_put_object_content_stream_longer_than_declared() {
+        let err = put_stream_with_declared_size(&[b"hello", b" world"], 5).await;
+        assert!(matches!(
+            err,
+            Error::Validation(ValidationErr::ContentLengthMismatch {
+                declared: 5,
+                actual: 6
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sse_over_http_names_operation() {
+        use crate::s3::http::BaseUrl;
+        use crate::s3::sse::SseKms;
+
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let client = MinioClient::anonymous(base_url).unwrap();
+        let sse: Arc<dyn Sse> = Arc::new(SseKms::new("my-key", None));
+
+        let err = client
+            .put_object_content("bucket", "object", "data")
+            .unwrap()
+            .sse(Some(sse))
+            .build()
+            .send()
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::Validation(ValidationErr::SseTlsRequired { operation, sse })
+                if operation == "PutObject" && sse == "SSE-KMS"),
+            "{err:?}"
+        );
+    }
+
+    /// Answers PUT with ETag `"abc"` and HEAD with `head_etag`.
+    fn verify_executor(head_etag: &'static str) -> MockExecutor {
+        MockExecutor::new(move |req| {
+            let etag = match req.method {
+                http::Method::HEAD => head_etag,
+                _ => "\"abc\"",
+            };
+            http::Response::builder()
+                .status(200)
+                .header("ETag", etag)
+                .header("Content-Length", "5")
+                .body("")
+                .unwrap()
+        })
+    }
+
+    async fn put_verified(head_etag: &'static str) -> Result<PutObjectContentResponse, Error> {
+        test_client(verify_executor(head_etag))
+            .put_object_content("bucket", "object", "hello")
+            .unwrap()
+            .verify_after_upload(true)
+            .build()
+            .send()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_verify_after_upload_stat_headers() {
+        let executor = verify_executor("\"abc\"");
+        test_client(executor.clone())
+            .put_object_content("bucket", "object", "hello")
+            .unwrap()
+            .request_payer(true)
+            .verify_after_upload(true)
+            .build()
+            .header("x-amz-meta-color", "blue")
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+        let requests = executor.requests();
+        let head = requests
+            .iter()
+            .find(|r| r.method == http::Method::HEAD)
+            .unwrap();
+        assert_eq!(head.header(X_AMZ_REQUEST_PAYER), Some("requester"));
+        assert_eq!(head.header("x-amz-meta-color"), None);
+    }
+
+    #[tokio::test]
+    async fn test_verify_after_upload_matching_etag() {
+        let resp = put_verified("\"abc\"").await.unwrap();
+        assert_eq!(resp.object_size(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_verify_after_upload_etag_mismatch() {
+        let err = put_verified("\"def\"").await.unwrap_err();
+        assert!(
+            matches!(&err, Error::Validation(ValidationErr::UploadVerificationFailed { field, expected, actual, .. })
+                if field == "ETag" && expected == "abc" && actual == "def"),
+            "{err:?}"
+        );
+    }
+
+    /// Stores the `Expires` header of a PUT and returns it on HEAD.
+    fn expires_executor() -> MockExecutor {
+        let expires = std::sync::Mutex::new(None::<String>);
+        MockExecutor::new(move |req| {
+            let mut resp = http::Response::builder()
+                .status(200)
+                .header("ETag", "\"abc\"")
+                .header("Content-Length", "5");
+            if req.method == http::Method::PUT {
+                *expires.lock().unwrap() = req.header(EXPIRES).map(str::to_string);
+            } else if let Some(v) = expires.lock().unwrap().as_deref() {
+                resp = resp.header(EXPIRES, v);
+            }
+            resp.body("").unwrap()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_expires_round_trip() {
+        use crate::s3::response_traits::HasExpires;
+
+        let executor = expires_executor();
+        let client = test_client(executor.clone());
+        let expires =
+            crate::s3::utils::from_http_header_value("Tue, 01 Jan 2030 00:00:00 GMT").unwrap();
+
+        client
+            .put_object_content("bucket", "object", "hello")
+            .unwrap()
+            .expires(expires)
+            .build()
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(
+            executor.requests()[0].header(EXPIRES),
+            Some("Tue, 01 Jan 2030 00:00:00 GMT")
+        );
+
+        let resp = client
+            .stat_object("bucket", "object")
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.expires(), Some(expires));
+    }
+
+    #[tokio::test]
+    async fn test_put_object_if_absent_conflict() {
+        async fn put_if_absent(status: u16, body: &'static str) -> MinioErrorCode {
+            let client = test_client(MockExecutor::new(move |req| {
+                assert_eq!(req.header(IF_NONE_MATCH), Some("*"));
+                let mut resp = http::Response::builder().status(status);
+                if !body.is_empty() {
+                    resp = resp.header(CONTENT_TYPE, "application/xml");
+                }
+                resp.body(body.to_string()).unwrap()
+            }));
+            let err = client
+                .put_object("bucket", "object", SegmentedBytes::from("x".to_string()))
+                .unwrap()
+                .if_none_match_star(true)
+                .build()
+                .send()
+                .await
+                .unwrap_err();
+            match err {
+                Error::S3Server(S3ServerError::S3Error(e)) => e.code(),
+                e => panic!("expected an S3 error; got {e:?}"),
+            }
+        }
+
+        let code = put_if_absent(
+            409,
+            "<Error><Code>ConditionalRequestConflict</Code><Message>conflict</Message></Error>",
+        )
+        .await;
+        assert_eq!(code, MinioErrorCode::ConditionalRequestConflict);
+        assert!(code.is_precondition_failed());
+
+        let code = put_if_absent(409, "").await;
+        assert_eq!(code, MinioErrorCode::ConditionalRequestConflict);
+        assert!(code.is_precondition_failed());
+
+        let code = put_if_absent(412, "").await;
+        assert_eq!(code, MinioErrorCode::PreconditionFailed);
+        assert!(code.is_precondition_failed());
+    }
+
+    #[tokio::test]
+    async fn test_put_object_bucket_key_enabled() {
+        use crate::s3::response_traits::HasBucketKeyEnabled;
+
+        // Echoes the bucket key header of the request, as a server with SSE-KMS does.
+        let client = test_client(MockExecutor::new(|req| {
+            let mut resp = http::Response::builder()
+                .status(200)
+                .header("ETag", "\"abc\"");
+            if let Some(v) = req.header(X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED) {
+                resp = resp.header(X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED, v);
+            }
+            resp.body("").unwrap()
+        }));
+        for enabled in [true, false] {
+            let req = client
+                .put_object(
+                    "bucket",
+                    "object",
+                    SegmentedBytes::from("hello".to_string()),
+                )
+                .unwrap()
+                .bucket_key_enabled(enabled)
+                .build()
+                .to_s3request()
+                .unwrap();
+            assert_eq!(
+                req.headers()
+                    .get(X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED)
+                    .map(String::as_str),
+                enabled.then_some("true")
+            );
+
+            let resp = client
+                .put_object_content("bucket", "object", "hello")
+                .unwrap()
+                .bucket_key_enabled(enabled)
+                .build()
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(resp.bucket_key_enabled(), enabled);
+        }
+    }
 }
@@ -0,0 +1,294 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::builders::{ObjectContent, PostPolicy};
+use crate::s3::client::MinioClient;
+use crate::s3::error::{Error, IoError, ValidationErr};
+use crate::s3::multimap_ext::Multimap;
+use crate::s3::response::PutObjectFanOutResponse;
+use crate::s3::segmented_bytes::SegmentedBytes;
+use crate::s3::types::{BucketName, ObjectKey, Region};
+use bytes::Bytes;
+use http::Method;
+use serde::Serialize;
+use std::collections::HashMap;
+use typed_builder::TypedBuilder;
+use uuid::Uuid;
+
+/// Maximum number of objects a fan-out upload can create.
+pub const MAX_FAN_OUT_ENTRIES: usize = 1000;
+
+/// An object created by a [`PutObjectFanOut`] upload, with its own metadata.
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+pub struct FanOutEntry {
+    #[builder(setter(into))]
+    key: ObjectKey,
+    #[serde(rename = "metadata", skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    user_metadata: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    tags: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    content_encoding: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    content_disposition: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    content_language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    cache_control: Option<String>,
+}
+
+impl From<ObjectKey> for FanOutEntry {
+    fn from(key: ObjectKey) -> Self {
+        FanOutEntry::builder().key(key).build()
+    }
+}
+
+/// Argument builder for a MinIO fan-out upload, which stores one upload as many objects.
+///
+/// This struct constructs the parameters required for the [`Client::put_object_fan_out`](crate::s3::client::MinioClient::put_object_fan_out) method.
+///
+/// The content is sent once, as a POST policy upload carrying the list of
+/// entries in the `x-minio-fanout-list` form field; the server writes a copy of
+/// it under the key of every entry. This is a MinIO extension, and the content
+/// is held in memory while it is sent.
+#[derive(TypedBuilder)]
+pub struct PutObjectFanOut {
+    #[builder(!default)] // force required
+    client: MinioClient,
+    #[builder(default, setter(into))]
+    region: Option<Region>,
+    #[builder(setter(into))]
+    bucket: BucketName,
+    entries: Vec<FanOutEntry>,
+    #[builder(setter(into))]
+    content: ObjectContent,
+}
+
+/// Builder type for [`PutObjectFanOut`] that is returned by [`MinioClient::put_object_fan_out`](crate::s3::client::MinioClient::put_object_fan_out).
+///
+/// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
+pub type PutObjectFanOutBldr = PutObjectFanOutBuilder<(
+    (MinioClient,),
+    (),
+    (BucketName,),
+    (Vec<FanOutEntry>,),
+    (ObjectContent,),
+)>;
+
+impl PutObjectFanOut {
+    /// Uploads the content and returns the outcome for each entry.
+    ///
+    /// Entries the server failed to write are reported in the response, not as
+    /// an error of the request.
+    pub async fn send(self) -> Result<PutObjectFanOutResponse, Error> {
+        if self.entries.is_empty() || self.entries.len() > MAX_FAN_OUT_ENTRIES {
+            return Err(ValidationErr::InvalidFanOut(format!(
+                "expected 1 to {MAX_FAN_OUT_ENTRIES} entries, got {}",
+                self.entries.len()
+            ))
+            .into());
+        }
+        let Some(creds) = self.client.credentials().await? else {
+            return Err(ValidationErr::InvalidFanOut(
+                "anonymous clients cannot sign the upload policy".into(),
+            )
+            .into());
+        };
+
+        let region = Region::new(
+            &self
+                .client
+                .get_region_cached(self.bucket.clone(), &self.region)
+                .await?,
+        )?;
+        let now = self.client.shared.clock.now();
+        let key = format!("{:x}", now.timestamp_nanos_opt().unwrap_or_default());
+        let mut policy = PostPolicy::new(self.bucket.clone(), now + chrono::Duration::minutes(15))?;
+        policy.add_equals_condition("key", &key)?;
+        let mut fields = policy.form_data(
+            &self.client.shared.signing_key_cache,
+            creds.access_key,
+            creds.secret_key,
+            creds.session_token,
+            region.clone(),
+            now,
+        )?;
+        fields.insert("key".into(), key);
+
+        let mut fan_out_list = String::new();
+        for entry in &self.entries {
+            fan_out_list.push_str(&serde_json::to_string(entry).map_err(ValidationErr::from)?);
+            fan_out_list.push('\n');
+        }
+        fields.insert("x-minio-fanout-list".into(), fan_out_list);
+
+        let content = self
+            .content
+            .to_segmented_bytes()
+            .await
+            .map_err(IoError::from)?;
+        let boundary = format!("minio-fan-out-{}", Uuid::new_v4().simple());
+        let form = multipart_form(&boundary, fields, content);
+
+        let base_url = self.client.endpoint_for(None, &region);
+        let url = base_url.build_url(
+            &Method::POST,
+            &region,
+            &Multimap::new(),
+            Some(&self.bucket),
+            None,
+        )?;
+        let resp = self
+            .client
+            .execute_post_form(base_url, &url, &self.bucket, &boundary, form)
+            .await?;
+        let headers = resp.headers().clone();
+        let body = self.client.read_body(resp).await?;
+        PutObjectFanOutResponse::new(self.bucket, headers, &body)
+    }
+}
+
+/// Encodes `fields` and `content` as a `multipart/form-data` body; the content
+/// is the last part, as POST policy uploads require.
+fn multipart_form(
+    boundary: &str,
+    fields: HashMap<String, String>,
+    content: SegmentedBytes,
+) -> SegmentedBytes {
+    let mut fields: Vec<_> = fields.into_iter().collect();
+    fields.sort();
+    let mut head = String::new();
+    for (name, value) in fields {
+        head.push_str(&format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+        ));
+    }
+    head.push_str(&format!(
+        "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"fanout-content\"\r\n\
+         Content-Type: application/octet-stream\r\n\r\n"
+    ));
+
+    let mut form = SegmentedBytes::new();
+    form.append(Bytes::from(head));
+    form.extend(content);
+    form.append(Bytes::from(format!("\r\n--{boundary}--\r\n")));
+    form
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{MockExecutor, test_client};
+
+    fn fan_out_executor() -> MockExecutor {
+        MockExecutor::new(|_| {
+            http::Response::new(
+                "{\"key\":\"a\",\"etag\":\"e1\"}\n{\"key\":\"b\",\"etag\":\"e2\"}\n",
+            )
+        })
+    }
+
+    fn entries(keys: &[&str]) -> Vec<FanOutEntry> {
+        keys.iter()
+            .map(|k| FanOutEntry::from(ObjectKey::new(*k).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_fan_out_entry_json() {
+        let entry = FanOutEntry::builder()
+            .key(ObjectKey::new("a.jpg").unwrap())
+            .user_metadata(HashMap::from([("variant".into(), "small".into())]))
+            .content_type("image/jpeg".to_string())
+            .build();
+        assert_eq!(
+            serde_json::to_string(&entry).unwrap(),
+            r#"{"key":"a.jpg","metadata":{"variant":"small"},"contentType":"image/jpeg"}"#
+        );
+    }
+
+    #[test]
+    fn test_multipart_form() {
+        let fields = HashMap::from([
+            ("key".to_string(), "k".to_string()),
+            ("policy".to_string(), "p".to_string()),
+        ]);
+        let mut content = SegmentedBytes::new();
+        content.append(Bytes::from_static(b"data"));
+        let form = multipart_form("xyz", fields, content).to_bytes();
+        assert_eq!(
+            std::str::from_utf8(&form).unwrap(),
+            "--xyz\r\nContent-Disposition: form-data; name=\"key\"\r\n\r\nk\r\n\
+             --xyz\r\nContent-Disposition: form-data; name=\"policy\"\r\n\r\np\r\n\
+             --xyz\r\nContent-Disposition: form-data; name=\"file\"; filename=\"fanout-content\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\ndata\r\n--xyz--\r\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_put_object_fan_out_request() {
+        let executor = fan_out_executor();
+        let resp = test_client(executor.clone())
+            .put_object_fan_out("bucket", entries(&["a", "b"]), "data")
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+        let keys: Vec<_> = resp.results().iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, ["a", "b"]);
+
+        let request = &executor.requests()[0];
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.url.as_str(), "http://localhost:9000/bucket");
+        let content_type = request.header("content-type").unwrap();
+        assert!(
+            content_type.starts_with("multipart/form-data; boundary=minio-fan-out-"),
+            "{content_type}"
+        );
+        assert!(!request.headers.contains_key("authorization"));
+    }
+
+    #[tokio::test]
+    async fn test_put_object_fan_out_entry_count() {
+        let executor = fan_out_executor();
+        let keys: Vec<String> = (0..=MAX_FAN_OUT_ENTRIES).map(|i| format!("k{i}")).collect();
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        for entries in [entries(&[]), entries(&keys)] {
+            let err = test_client(executor.clone())
+                .put_object_fan_out("bucket", entries, "data")
+                .unwrap()
+                .build()
+                .send()
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(err, Error::Validation(ValidationErr::InvalidFanOut(_))),
+                "{err:?}"
+            );
+        }
+        assert!(executor.requests().is_empty());
+    }
+}
@@ -19,7 +19,9 @@ use crate::s3::header_constants::*;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::PutObjectLegalHoldResponse;
 use crate::s3::segmented_bytes::SegmentedBytes;
-use crate::s3::types::{BucketName, ObjectKey, Region, S3Api, S3Request, ToS3Request, VersionId};
+use crate::s3::types::{
+    BucketName, ObjectKey, Region, RequestHeaders, S3Api, S3Request, ToS3Request, VersionId,
+};
 use crate::s3::utils::{insert, md5sum_hash};
 use bytes::Bytes;
 use http::Method;
@@ -49,6 +51,12 @@ pub struct PutObjectLegalHold {
     legal_hold: Option<bool>,
 }
 
+impl RequestHeaders for PutObjectLegalHold {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type for [`PutObjectLegalHold`] that is returned by [`MinioClient::put_object_legal_hold`](crate::s3::client::MinioClient::put_object_legal_hold).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
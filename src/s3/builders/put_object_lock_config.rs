@@ -18,7 +18,9 @@ use crate::s3::error::ValidationErr;
 use crate::s3::multimap_ext::Multimap;
 use crate::s3::response::PutObjectLockConfigResponse;
 use crate::s3::segmented_bytes::SegmentedBytes;
-use crate::s3::types::{BucketName, ObjectLockConfig, Region, S3Api, S3Request, ToS3Request};
+use crate::s3::types::{
+    BucketName, ObjectLockConfig, Region, RequestHeaders, S3Api, S3Request, ToS3Request,
+};
 use crate::s3::utils::insert;
 use bytes::Bytes;
 use http::Method;
@@ -44,6 +46,12 @@ pub struct PutObjectLockConfig {
     config: ObjectLockConfig,
 }
 
+impl RequestHeaders for PutObjectLockConfig {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type for [`PutObjectLockConfig`] that is returned by [`MinioClient::put_object_lock_config`](crate::s3::client::MinioClient::put_object_lock_config).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
@@ -20,7 +20,8 @@ use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::PutObjectRetentionResponse;
 use crate::s3::segmented_bytes::SegmentedBytes;
 use crate::s3::types::{
-    BucketName, ObjectKey, Region, RetentionMode, S3Api, S3Request, ToS3Request, VersionId,
+    BucketName, ObjectKey, Region, RequestHeaders, RetentionMode, S3Api, S3Request, ToS3Request,
+    VersionId,
 };
 use crate::s3::utils::{UtcTime, insert, md5sum_hash, to_iso8601utc};
 use bytes::Bytes;
@@ -55,6 +56,12 @@ pub struct PutObjectRetention {
     retain_until_date: Option<UtcTime>,
 }
 
+impl RequestHeaders for PutObjectRetention {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type for [`PutObjectRetention`] that is returned by [`MinioClient::put_object_retention`](crate::s3::client::MinioClient::put_object_retention).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
@@ -18,11 +18,12 @@ use crate::s3::error::ValidationErr;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::PutObjectTaggingResponse;
 use crate::s3::segmented_bytes::SegmentedBytes;
-use crate::s3::types::{BucketName, ObjectKey, Region, S3Api, S3Request, ToS3Request, VersionId};
+use crate::s3::types::{
+    BucketName, ObjectKey, Region, RequestHeaders, S3Api, S3Request, Tags, ToS3Request, VersionId,
+};
 use crate::s3::utils::insert;
 use bytes::Bytes;
 use http::Method;
-use std::collections::HashMap;
 use std::sync::Arc;
 use typed_builder::TypedBuilder;
 
@@ -45,8 +46,14 @@ pub struct PutObjectTagging {
     object: ObjectKey,
     #[builder(default, setter(into))]
     version_id: Option<VersionId>,
-    #[builder(default)]
-    tags: HashMap<String, String>,
+    #[builder(default, setter(into))]
+    tags: Tags,
+}
+
+impl RequestHeaders for PutObjectTagging {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
 }
 
 /// Builder type for [`PutObjectTagging`] that is returned by [`MinioClient::put_object_tagging`](crate::s3::client::MinioClient::put_object_tagging).
@@ -72,25 +79,7 @@ impl ToS3Request for PutObjectTagging {
         let mut query_params: Multimap = insert(self.extra_query_params, "tagging");
         query_params.add_version(self.version_id);
 
-        let data: String = {
-            let mut data = String::from("<Tagging>");
-            if !self.tags.is_empty() {
-                data.push_str("<TagSet>");
-                for (key, value) in self.tags.iter() {
-                    data.push_str("<Tag>");
-                    data.push_str("<Key>");
-                    data.push_str(key);
-                    data.push_str("</Key>");
-                    data.push_str("<Value>");
-                    data.push_str(value);
-                    data.push_str("</Value>");
-                    data.push_str("</Tag>");
-                }
-                data.push_str("</TagSet>");
-            }
-            data.push_str("</Tagging>");
-            data
-        };
+        let data: String = self.tags.to_xml();
         let body = Arc::new(SegmentedBytes::from(Bytes::from(data)));
 
         Ok(S3Request::builder()
@@ -0,0 +1,132 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::builders::{CopySource, MAX_PART_SIZE, ObjectToDelete};
+use crate::s3::client::MinioClient;
+use crate::s3::error::Error;
+use crate::s3::response::{CopyObjectResponse, StatObjectResponse};
+use crate::s3::response_traits::HasVersion;
+use crate::s3::sse::{Sse, SseCustomerKey};
+use crate::s3::types::{BucketName, Directive, ObjectKey, Region, S3Api, VersionId};
+use std::sync::Arc;
+use typed_builder::TypedBuilder;
+
+/// Argument builder for re-encrypting an object in place with new server-side encryption settings.
+///
+/// This struct constructs the parameters required for the [`Client::reencrypt_object`](crate::s3::client::MinioClient::reencrypt_object) method.
+///
+/// The object is copied onto itself with the `COPY` metadata directive, so its
+/// metadata and tags are kept and only the encryption changes. An object encrypted
+/// with SSE-C can only be read with its key, so re-encrypting such an object needs
+/// both the old key in `source_ssec` and the new settings in `sse`.
+///
+/// On a versioned bucket the copy creates a new version, and the previous version
+/// remains stored with its old encryption. Set `delete_previous_version` to remove
+/// it once the copy succeeded.
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct ReencryptObject {
+    #[builder(!default)] // force required
+    client: MinioClient,
+    #[builder(default, setter(into))]
+    region: Option<Region>,
+    #[builder(setter(into), !default)]
+    bucket: BucketName,
+    #[builder(setter(into), !default)]
+    object: ObjectKey,
+    /// Version of the object to re-encrypt; defaults to the latest version.
+    #[builder(default, setter(into))]
+    version_id: Option<VersionId>,
+    /// The new encryption settings of the object.
+    #[builder(!default)]
+    sse: Arc<dyn Sse>,
+    /// The SSE-C key the object is currently encrypted with, if any.
+    #[builder(default, setter(into))]
+    source_ssec: Option<SseCustomerKey>,
+    /// Whether to permanently delete the re-encrypted version on a versioned bucket.
+    #[builder(default = false)]
+    delete_previous_version: bool,
+}
+
+/// Builder type for [`ReencryptObject`] that is returned by [`MinioClient::reencrypt_object`](crate::s3::client::MinioClient::reencrypt_object).
+///
+/// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
+pub type ReencryptObjectBldr = ReencryptObjectBuilder<(
+    (MinioClient,),
+    (),
+    (BucketName,),
+    (ObjectKey,),
+    (),
+    (Arc<dyn Sse>,),
+    (),
+    (),
+)>;
+
+impl ReencryptObject {
+    /// Copies the object onto itself with the new encryption settings.
+    ///
+    /// Returns the response of the copy, which describes the re-encrypted object.
+    pub async fn send(self) -> Result<CopyObjectResponse, Error> {
+        let stat: StatObjectResponse = self
+            .client
+            .stat_object(&self.bucket, &self.object)?
+            .region(self.region.clone())
+            .version_id(self.version_id.clone())
+            .ssec(self.source_ssec.clone())
+            .build()
+            .send()
+            .await?;
+        // Pin the version read by the stat, so that a concurrent write is not
+        // re-encrypted (or deleted) in its place.
+        let source_version = self.version_id.or_else(|| stat.version_id());
+        let size = stat.size()?;
+
+        let source = CopySource::builder()
+            .region(self.region.clone())
+            .bucket(self.bucket.clone())
+            .object(self.object.clone())
+            .version_id(source_version.clone())
+            .ssec(self.source_ssec)
+            .build();
+        // A multipart copy of a large object carries over the metadata itself
+        // and rejects an explicit COPY directive.
+        let metadata_directive = (size <= MAX_PART_SIZE).then_some(Directive::Copy);
+        let copy: CopyObjectResponse = self
+            .client
+            .copy_object(&self.bucket, &self.object)?
+            .region(self.region.clone())
+            .source(source)
+            .sse(Some(self.sse))
+            .metadata_directive(metadata_directive)
+            .build()
+            .send()
+            .await?;
+
+        if self.delete_previous_version
+            && let Some(previous) = source_version
+            && copy.version_id().as_ref() != Some(&previous)
+        {
+            self.client
+                .delete_object(
+                    &self.bucket,
+                    ObjectToDelete::from((self.object, Some(previous))),
+                )?
+                .region(self.region)
+                .build()
+                .send()
+                .await?;
+        }
+        Ok(copy)
+    }
+}
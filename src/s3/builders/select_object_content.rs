@@ -21,7 +21,8 @@ use crate::s3::response::SelectObjectContentResponse;
 use crate::s3::segmented_bytes::SegmentedBytes;
 use crate::s3::sse::SseCustomerKey;
 use crate::s3::types::{
-    BucketName, ObjectKey, Region, S3Api, S3Request, SelectRequest, ToS3Request, VersionId,
+    BucketName, ObjectKey, Region, RequestHeaders, S3Api, S3Request, SelectRequest, ToS3Request,
+    VersionId,
 };
 use crate::s3::utils::{check_ssec, insert, md5sum_hash};
 use async_trait::async_trait;
@@ -57,6 +58,12 @@ pub struct SelectObjectContent {
     request: SelectRequest,
 }
 
+impl RequestHeaders for SelectObjectContent {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
 /// Builder type for [`SelectObjectContent`] that is returned by [`MinioClient::select_object_content`](crate::s3::client::MinioClient::select_object_content).
 ///
 /// This type alias simplifies the complex generic signature generated by the `typed_builder` crate.
@@ -79,7 +86,7 @@ impl S3Api for SelectObjectContent {
 #[async_trait]
 impl ToS3Request for SelectObjectContent {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_ssec(&self.ssec, &self.client)?;
+        check_ssec(&self.ssec, &self.client, "SelectObjectContent")?;
 
         let bytes: Bytes = self.request.to_xml().into();
 
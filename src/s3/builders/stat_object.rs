@@ -14,15 +14,20 @@
 // limitations under the License.
 
 use crate::s3::client::MinioClient;
-use crate::s3::error::ValidationErr;
+use crate::s3::error::{Error, ValidationErr};
 use crate::s3::header_constants::*;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::StatObjectResponse;
 use crate::s3::sse::{Sse, SseCustomerKey};
-use crate::s3::types::{BucketName, ObjectKey, Region, S3Api, S3Request, ToS3Request, VersionId};
-use crate::s3::utils::{UtcTime, check_ssec, to_http_header_value};
+use crate::s3::types::{
+    BucketName, ObjectKey, Region, RequestHeaders, S3Api, S3Request, ToS3Request, VersionId,
+};
+use crate::s3::utils::{UtcTime, add_request_payer, check_ssec, to_http_header_value};
 use async_trait::async_trait;
+use futures_util::stream::iter;
+use futures_util::{Stream, StreamExt};
 use http::Method;
+use std::pin::Pin;
 use typed_builder::TypedBuilder;
 
 /// Argument builder for the [`HeadObject`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_HeadObject.html) S3 API operation.
@@ -60,10 +65,24 @@ pub struct StatObject {
     match_etag: Option<String>,
     #[builder(default, setter(into))]
     not_match_etag: Option<String>,
+    /// Sends `If-Modified-Since`; an unmodified object fails the request with
+    /// [`MinioErrorCode::NotModified`](crate::s3::minio_error_response::MinioErrorCode::NotModified).
     #[builder(default, setter(into))]
     modified_since: Option<UtcTime>,
+    /// Sends `If-Unmodified-Since`; a modified object fails the request with
+    /// [`MinioErrorCode::PreconditionFailed`](crate::s3::minio_error_response::MinioErrorCode::PreconditionFailed).
     #[builder(default, setter(into))]
     unmodified_since: Option<UtcTime>,
+
+    /// Sends `x-amz-request-payer: requester`, required for requester-pays buckets.
+    #[builder(default = false)]
+    request_payer: bool,
+}
+
+impl RequestHeaders for StatObject {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
 }
 
 /// Builder type for [`StatObject`] that is returned by [`MinioClient::stat_object`](crate::s3::client::MinioClient::stat_object).
@@ -82,6 +101,7 @@ pub type StatObjectBldr = StatObjectBuilder<(
     (),
     (),
     (),
+    (),
 )>;
 
 impl S3Api for StatObject {
@@ -91,7 +111,7 @@ impl S3Api for StatObject {
 #[async_trait]
 impl ToS3Request for StatObject {
     fn to_s3request(self) -> Result<S3Request, ValidationErr> {
-        check_ssec(&self.ssec, &self.client)?;
+        check_ssec(&self.ssec, &self.client, "StatObject")?;
 
         let mut headers: Multimap = self.extra_headers.unwrap_or_default();
         {
@@ -110,6 +130,7 @@ impl ToS3Request for StatObject {
             if let Some(v) = self.ssec {
                 headers.add_multimap(v.headers());
             }
+            add_request_payer(&mut headers, self.request_payer);
         }
 
         let mut query_params: Multimap = self.extra_query_params.unwrap_or_default();
@@ -126,3 +147,224 @@ impl ToS3Request for StatObject {
             .build())
     }
 }
+
+// region: stat-objects
+
+/// Default number of concurrent HEAD requests issued by [`StatObjects`].
+pub const DEFAULT_STAT_OBJECTS_CONCURRENCY: usize = 8;
+
+/// Stream of object keys to stat with [`StatObjects`].
+pub struct KeysStream {
+    items: Pin<Box<dyn Stream<Item = ObjectKey> + Send + Sync>>,
+}
+
+impl KeysStream {
+    pub fn from_stream(s: impl Stream<Item = ObjectKey> + Send + Sync + 'static) -> Self {
+        Self { items: Box::pin(s) }
+    }
+}
+
+impl<I> From<I> for KeysStream
+where
+    I: Iterator<Item = ObjectKey> + Send + Sync + 'static,
+{
+    fn from(keys: I) -> Self {
+        Self::from_stream(iter(keys))
+    }
+}
+
+/// Argument builder for statting many objects with bounded concurrency.
+///
+/// This struct constructs the parameters required for the
+/// [`Client::stat_objects`](crate::s3::client::MinioClient::stat_objects) method. Each key
+/// results in a [`HeadObject`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_HeadObject.html)
+/// request; at most [`concurrency`](Self::concurrency) of them are in flight at once.
+pub struct StatObjects {
+    client: MinioClient,
+
+    bucket: BucketName,
+    keys: KeysStream,
+    concurrency: usize,
+
+    extra_headers: Option<Multimap>,
+    extra_query_params: Option<Multimap>,
+    region: Option<Region>,
+}
+
+impl RequestHeaders for StatObjects {
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap> {
+        &mut self.extra_headers
+    }
+}
+
+impl StatObjects {
+    pub fn new(client: MinioClient, bucket: BucketName, keys: impl Into<KeysStream>) -> Self {
+        Self {
+            client,
+            bucket,
+            keys: keys.into(),
+            concurrency: DEFAULT_STAT_OBJECTS_CONCURRENCY,
+
+            extra_headers: None,
+            extra_query_params: None,
+            region: None,
+        }
+    }
+
+    /// Sets the maximum number of requests in flight (defaults to
+    /// [`DEFAULT_STAT_OBJECTS_CONCURRENCY`]; values below 1 are treated as 1).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn extra_headers(mut self, extra_headers: Option<Multimap>) -> Self {
+        self.extra_headers = extra_headers;
+        self
+    }
+
+    pub fn extra_query_params(mut self, extra_query_params: Option<Multimap>) -> Self {
+        self.extra_query_params = extra_query_params;
+        self
+    }
+
+    /// Sets the region for the requests.
+    pub fn region(mut self, region: Option<Region>) -> Self {
+        self.region = region;
+        self
+    }
+
+    /// Returns a stream with one item per input key, in input order.
+    ///
+    /// A failed stat, such as a missing object, is reported for its key and
+    /// does not end the stream.
+    pub async fn to_stream(
+        self,
+    ) -> Box<dyn Stream<Item = (ObjectKey, Result<StatObjectResponse, Error>)> + Unpin + Send> {
+        let Self {
+            client,
+            bucket,
+            keys,
+            concurrency,
+            extra_headers,
+            extra_query_params,
+            region,
+        } = self;
+
+        Box::new(
+            keys.items
+                .map(move |key| {
+                    let request = StatObject::builder()
+                        .client(client.clone())
+                        .bucket(bucket.clone())
+                        .object(key.clone())
+                        .region(region.clone())
+                        .extra_headers(extra_headers.clone())
+                        .extra_query_params(extra_query_params.clone())
+                        .build();
+                    async move { (key, request.send().await) }
+                })
+                .buffered(concurrency),
+        )
+    }
+}
+
+// endregion: stat-objects
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{MockServer, raw_response};
+    use crate::s3::creds::StaticProvider;
+    use crate::s3::error::S3ServerError;
+    use crate::s3::http::BaseUrl;
+    use crate::s3::minio_error_response::MinioErrorCode;
+    use chrono::{TimeZone, Utc};
+
+    /// Answers a single request with an empty `status` response.
+    async fn serve_once(status: &str) -> (MinioClient, MockServer) {
+        let server = MockServer::scripted(vec![raw_response(status, "", "")]).await;
+        (server.client(), server)
+    }
+
+    fn error_code(err: Error) -> MinioErrorCode {
+        match err {
+            Error::S3Server(S3ServerError::S3Error(e)) => e.code(),
+            e => panic!("unexpected error {e:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stat_object_not_modified() {
+        let (client, server) = serve_once("304 Not Modified").await;
+        let since = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 45).unwrap();
+
+        let err = client
+            .stat_object("bucket", "object")
+            .unwrap()
+            .modified_since(since)
+            .build()
+            .send()
+            .await
+            .unwrap_err();
+
+        assert_eq!(error_code(err), MinioErrorCode::NotModified);
+        let req = &server.requests()[0].head;
+        assert!(req.starts_with("head /bucket/object "), "{req}");
+        assert!(
+            req.contains("if-modified-since: mon, 15 jan 2024 10:30:45 gmt"),
+            "{req}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stat_object_precondition_failed() {
+        let (client, server) = serve_once("412 Precondition Failed").await;
+        let since = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 45).unwrap();
+
+        let err = client
+            .stat_object("bucket", "object")
+            .unwrap()
+            .unmodified_since(since)
+            .build()
+            .send()
+            .await
+            .unwrap_err();
+
+        assert_eq!(error_code(err), MinioErrorCode::PreconditionFailed);
+        let req = &server.requests()[0].head;
+        assert!(
+            req.contains("if-unmodified-since: mon, 15 jan 2024 10:30:45 gmt"),
+            "{req}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stat_objects_yields_every_key_on_error() {
+        // Nothing listens on port 1, so every request fails.
+        let base_url: BaseUrl = "http://127.0.0.1:1".parse().unwrap();
+        let provider = StaticProvider::new("minioadmin", "minioadmin", None);
+        let client = MinioClient::new(base_url, Some(provider), None, None).unwrap();
+        let keys: Vec<ObjectKey> = (0..5)
+            .map(|i| ObjectKey::new(format!("obj-{i}")).unwrap())
+            .collect();
+
+        let results: Vec<_> = StatObjects::new(
+            client,
+            BucketName::new("bucket").unwrap(),
+            keys.clone().into_iter(),
+        )
+        .concurrency(2)
+        .region(Some(Region::new("us-east-1").unwrap()))
+        .to_stream()
+        .await
+        .collect()
+        .await;
+
+        assert_eq!(results.len(), keys.len());
+        for ((key, result), expected) in results.iter().zip(&keys) {
+            assert_eq!(key, expected);
+            assert!(result.is_err());
+        }
+    }
+}
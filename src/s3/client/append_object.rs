@@ -17,7 +17,8 @@
 
 use super::MinioClient;
 use crate::s3::builders::{
-    AppendObject, AppendObjectBldr, AppendObjectContent, AppendObjectContentBldr, ObjectContent,
+    AppendObject, AppendObjectBldr, AppendObjectContent, AppendObjectContentBldr,
+    AppendObjectStream, AppendObjectStreamBldr, ObjectContent,
 };
 use crate::s3::error::ValidationErr;
 use crate::s3::segmented_bytes::SegmentedBytes;
@@ -138,4 +139,57 @@ impl MinioClient {
             .object(object.try_into().map_err(Into::into)?)
             .input_content(content))
     }
+
+    /// Creates an [`AppendObjectStream`] request builder to append content to the end
+    /// of an existing object in chunks, without buffering more than one chunk.
+    ///
+    /// To execute the request, call [`AppendObjectStream::send()`], which returns the
+    /// final size of the object.
+    ///
+    /// 🛈 This operation is not supported for regular non-express buckets.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::MinioClient;
+    /// use minio::s3::builders::ObjectContent;
+    /// use minio::s3::creds::StaticProvider;
+    /// use minio::s3::http::BaseUrl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let base_url = "http://localhost:9000/".parse::<BaseUrl>().unwrap();
+    ///     let static_provider = StaticProvider::new("minioadmin", "minioadmin", None);
+    ///     let client = MinioClient::new(base_url, Some(static_provider), None, None).unwrap();
+    ///     let file = async_std::fs::File::open("app.log").await.unwrap();
+    ///     let size: u64 = client
+    ///         .append_object_stream("bucket-name", "app.log", ObjectContent::new_from_reader(file, None))
+    ///         .unwrap()
+    ///         .chunk_size(1024 * 1024)
+    ///         .build()
+    ///         .send()
+    ///         .await
+    ///         .unwrap();
+    ///     println!("size of the final object is {size} bytes");
+    /// }
+    /// ```
+    pub fn append_object_stream<B, O, C>(
+        &self,
+        bucket: B,
+        object: O,
+        content: C,
+    ) -> Result<AppendObjectStreamBldr, ValidationErr>
+    where
+        B: TryInto<BucketName>,
+        B::Error: Into<ValidationErr>,
+        O: TryInto<ObjectKey>,
+        O::Error: Into<ValidationErr>,
+        C: Into<ObjectContent>,
+    {
+        Ok(AppendObjectStream::builder()
+            .client(self.clone())
+            .bucket(bucket.try_into().map_err(Into::into)?)
+            .object(object.try_into().map_err(Into::into)?)
+            .input_content(content))
+    }
 }
@@ -0,0 +1,239 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client-side cache of bucket configurations
+
+use crate::s3::client::MinioClient;
+use crate::s3::error::Error;
+use crate::s3::multimap_ext::Multimap;
+use crate::s3::types::{BucketName, FromS3Response, ObjectKey, S3Api};
+use dashmap::DashMap;
+use http::Method;
+use std::any::Any;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Bucket configurations whose `get_*` responses are cached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum BucketConfigKind {
+    Policy,
+    Lifecycle,
+    Encryption,
+}
+
+impl BucketConfigKind {
+    const ALL: [Self; 3] = [Self::Policy, Self::Lifecycle, Self::Encryption];
+
+    /// The sub-resource query parameter selecting this configuration.
+    fn query_param(self) -> &'static str {
+        match self {
+            Self::Policy => "policy",
+            Self::Lifecycle => "lifecycle",
+            Self::Encryption => "encryption",
+        }
+    }
+}
+
+type CacheEntry = (Instant, Arc<dyn Any + Send + Sync>);
+
+/// Caches `get_*` responses of bucket configurations for a fixed time to live.
+///
+/// Entries are keyed by bucket and configuration kind. An entry is removed when
+/// a request changing that configuration, or deleting the bucket, succeeds.
+#[derive(Debug, Default)]
+pub(crate) struct BucketConfigCache {
+    ttl: Option<Duration>,
+    entries: DashMap<(String, BucketConfigKind), CacheEntry>,
+}
+
+impl BucketConfigCache {
+    /// Creates a cache; `None` disables caching.
+    pub(crate) fn new(ttl: Option<Duration>) -> Self {
+        Self {
+            ttl,
+            entries: DashMap::new(),
+        }
+    }
+
+    fn get<T: Clone + 'static>(&self, bucket: &BucketName, kind: BucketConfigKind) -> Option<T> {
+        let ttl = self.ttl?;
+        let key = (bucket.to_string(), kind);
+        let value = {
+            let entry = self.entries.get(&key)?;
+            (entry.0.elapsed() < ttl).then(|| entry.1.downcast_ref::<T>().cloned())
+        };
+        match value {
+            Some(v) => v,
+            None => {
+                self.entries.remove(&key);
+                None
+            }
+        }
+    }
+
+    fn insert<T: Send + Sync + 'static>(
+        &self,
+        bucket: &BucketName,
+        kind: BucketConfigKind,
+        value: T,
+    ) {
+        if self.ttl.is_some() {
+            self.entries.insert(
+                (bucket.to_string(), kind),
+                (Instant::now(), Arc::new(value)),
+            );
+        }
+    }
+
+    /// Drops the entries affected by a successful request.
+    pub(crate) fn invalidate_after(
+        &self,
+        method: &Method,
+        bucket: Option<&BucketName>,
+        object: Option<&ObjectKey>,
+        query_params: &Multimap,
+    ) {
+        if self.entries.is_empty() || *method == Method::GET || *method == Method::HEAD {
+            return;
+        }
+        let (Some(bucket), None) = (bucket, object) else {
+            return;
+        };
+        let deletes_bucket = *method == Method::DELETE && query_params.is_empty();
+        for kind in BucketConfigKind::ALL {
+            if deletes_bucket || query_params.contains_key(kind.query_param()) {
+                self.entries.remove(&(bucket.to_string(), kind));
+            }
+        }
+    }
+}
+
+/// Sends `request`, serving the response from the client's bucket configuration
+/// cache when possible. With `no_cache` the cache is not read, but is refreshed
+/// with the response.
+pub(crate) async fn send_cached<R>(
+    request: R,
+    client: &MinioClient,
+    bucket: &BucketName,
+    kind: BucketConfigKind,
+    no_cache: bool,
+) -> Result<R::S3Response, Error>
+where
+    R: S3Api + Send,
+    R::S3Response: Clone + Send + Sync + 'static,
+{
+    let cache = &client.shared.bucket_config_cache;
+    if !no_cache && let Some(resp) = cache.get::<R::S3Response>(bucket, kind) {
+        return Ok(resp);
+    }
+    let mut req = request.to_s3request()?;
+    let resp = req.execute().await;
+    let resp = R::S3Response::from_s3response(req, resp).await?;
+    cache.insert(bucket, kind, resp.clone());
+    Ok(resp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::bucket_policy_config::BucketPolicyConfig;
+    use crate::s3::client::test_support::{MockServer, raw_response, test_client_builder};
+
+    const POLICY: &str = r#"{"Version":"2012-10-17","Statement":[]}"#;
+
+    /// Answers every request with 200, returning [`POLICY`] to GETs.
+    async fn serve() -> MockServer {
+        MockServer::start(|req| async move {
+            let body = if req.line.starts_with("GET ") {
+                POLICY
+            } else {
+                ""
+            };
+            raw_response("200 OK", "", body)
+        })
+        .await
+    }
+
+    fn client(server: &MockServer) -> MinioClient {
+        test_client_builder(server.base_url())
+            .bucket_config_cache_ttl(Some(Duration::from_secs(60)))
+            .build()
+            .unwrap()
+    }
+
+    async fn get_policy(client: &MinioClient, no_cache: bool) -> String {
+        let req = client.get_bucket_policy("bucket").unwrap();
+        let resp = if no_cache {
+            req.no_cache().build().send().await
+        } else {
+            req.build().send().await
+        };
+        resp.unwrap().config().unwrap().to_string()
+    }
+
+    fn count(server: &MockServer, prefix: &str) -> usize {
+        let requests = server.request_lines();
+        requests.iter().filter(|l| l.starts_with(prefix)).count()
+    }
+
+    #[tokio::test]
+    async fn cached_get_skips_network() {
+        let server = serve().await;
+        let client = client(&server);
+
+        assert_eq!(get_policy(&client, false).await, POLICY);
+        assert_eq!(get_policy(&client, false).await, POLICY);
+        assert_eq!(count(&server, "GET /bucket?policy"), 1);
+
+        get_policy(&client, true).await;
+        assert_eq!(count(&server, "GET /bucket?policy"), 2);
+    }
+
+    #[tokio::test]
+    async fn put_invalidates_cached_entry() {
+        let server = serve().await;
+        let client = client(&server);
+
+        get_policy(&client, false).await;
+        client
+            .put_bucket_policy("bucket")
+            .unwrap()
+            .config(BucketPolicyConfig::default())
+            .build()
+            .send()
+            .await
+            .unwrap();
+        get_policy(&client, false).await;
+
+        assert_eq!(
+            server.request_lines(),
+            [
+                "GET /bucket?policy= HTTP/1.1",
+                "PUT /bucket?policy= HTTP/1.1",
+                "GET /bucket?policy= HTTP/1.1"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn disabled_by_default() {
+        let server = serve().await;
+        let client = server.client();
+
+        get_policy(&client, false).await;
+        get_policy(&client, false).await;
+        assert_eq!(count(&server, "GET /bucket?policy"), 2);
+    }
+}
@@ -0,0 +1,255 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serializable connection profile for [`MinioClient`]
+
+use super::{MinioClient, MinioClientBuilder};
+use crate::s3::creds::StaticProvider;
+use crate::s3::error::{Error, ValidationErr};
+use crate::s3::http::BaseUrl;
+use crate::s3::types::Region;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::PathBuf;
+
+/// A secret string that is hidden from `Debug` and `Display` output.
+///
+/// The value is serialized as-is, so that profiles containing it can be stored
+/// and loaded again.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Redacted(String);
+
+impl Redacted {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Returns the secret value.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Redacted {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Redacted {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Redacted(***)")
+    }
+}
+
+impl fmt::Display for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+/// Connection settings of a [`MinioClient`] that can be stored in a config file.
+///
+/// # Example
+///
+/// ```
+/// use minio::s3::client::{ClientConfig, MinioClient};
+///
+/// let config: ClientConfig = serde_json::from_str(
+///     r#"{"endpoint": "localhost:9000", "access_key": "minioadmin", "secret_key": "minioadmin"}"#,
+/// )
+/// .unwrap();
+/// let client = MinioClient::from_config(config).unwrap();
+/// assert!(!client.is_anonymous());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClientConfig {
+    /// Host and optional port of the service, e.g. `play.min.io` or `localhost:9000`.
+    pub endpoint: String,
+    /// Access key; the client is anonymous when neither key is set.
+    pub access_key: Option<String>,
+    pub secret_key: Option<Redacted>,
+    pub session_token: Option<Redacted>,
+    pub region: Option<String>,
+    /// Use HTTPS instead of HTTP.
+    pub secure: bool,
+    /// File with PEM encoded CA certificates to trust in addition to the system trust store.
+    pub ssl_cert_file: Option<PathBuf>,
+    /// Skip TLS certificate verification. This is insecure and should only be used for testing.
+    pub ignore_cert_check: bool,
+    /// See [`MinioClientBuilder::skip_region_lookup`].
+    pub skip_region_lookup: bool,
+}
+
+impl MinioClient {
+    /// Returns a S3 client configured from a [`ClientConfig`].
+    ///
+    /// Fails with [`ValidationErr::InvalidBaseUrl`] if the endpoint is invalid or
+    /// includes a scheme (use [`ClientConfig::secure`] instead), and with
+    /// [`ValidationErr::InvalidConfig`] if only one of the access and secret key is set.
+    pub fn from_config(config: ClientConfig) -> Result<Self, Error> {
+        if config.endpoint.contains("://") {
+            return Err(ValidationErr::InvalidBaseUrl(format!(
+                "endpoint {} must not include a scheme",
+                config.endpoint
+            ))
+            .into());
+        }
+        let scheme = if config.secure { "https" } else { "http" };
+        let mut base_url: BaseUrl = format!("{scheme}://{}", config.endpoint).parse()?;
+        if let Some(region) = &config.region {
+            base_url.region = Region::new(region)?;
+        }
+
+        let provider = match (&config.access_key, &config.secret_key) {
+            (Some(access_key), Some(secret_key)) => Some(StaticProvider::new(
+                access_key,
+                secret_key.expose(),
+                config.session_token.as_ref().map(Redacted::expose),
+            )),
+            (None, None) => None,
+            _ => {
+                return Err(ValidationErr::InvalidConfig {
+                    message: "access key and secret key must be set together".into(),
+                }
+                .into());
+            }
+        };
+
+        MinioClientBuilder::new(base_url)
+            .provider(provider)
+            .ssl_cert_file(config.ssl_cert_file.as_deref())
+            .ignore_cert_check(config.ignore_cert_check.then_some(true))
+            .skip_region_lookup(config.skip_region_lookup)
+            .build()
+    }
+
+    /// Returns the connection settings of this client. The secret key and session
+    /// token are wrapped in [`Redacted`], so they do not show up in `Debug` output.
+    ///
+    /// Credentials are taken from the current value of the credential provider.
+    pub fn to_config(&self) -> ClientConfig {
+        let base_url = &self.shared.base_url;
+        let endpoint = match base_url.port() {
+            0 => base_url.host().to_string(),
+            port => format!("{}:{port}", base_url.host()),
+        };
+        let creds = self.shared.provider.as_ref().map(|p| p.fetch());
+
+        ClientConfig {
+            endpoint,
+            access_key: creds.as_ref().map(|c| c.access_key.clone()),
+            secret_key: creds.as_ref().map(|c| Redacted::new(&c.secret_key)),
+            session_token: creds
+                .as_ref()
+                .and_then(|c| c.session_token.as_deref())
+                .map(Redacted::from),
+            region: Some(base_url.region.as_str())
+                .filter(|r| !r.is_empty())
+                .map(String::from),
+            secure: base_url.https,
+            ssl_cert_file: self.shared.ssl_cert_file.clone(),
+            ignore_cert_check: self.shared.ignore_cert_check,
+            skip_region_lookup: self.shared.skip_region_lookup,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_config() -> ClientConfig {
+        ClientConfig {
+            endpoint: "localhost:9000".to_string(),
+            access_key: Some("minioadmin".to_string()),
+            secret_key: Some(Redacted::new("very-secret")),
+            session_token: None,
+            region: Some("eu-west-1".to_string()),
+            secure: false,
+            ssl_cert_file: None,
+            ignore_cert_check: false,
+            skip_region_lookup: true,
+        }
+    }
+
+    #[test]
+    fn config_round_trips_through_json() {
+        let config = sample_config();
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains("very-secret"));
+        let parsed: ClientConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, config);
+    }
+
+    #[test]
+    fn config_round_trips_through_client() {
+        let config = sample_config();
+        let client = MinioClient::from_config(config.clone()).unwrap();
+        assert!(!client.is_secure());
+        assert_eq!(client.to_config(), config);
+
+        let anonymous = ClientConfig {
+            endpoint: "play.min.io".to_string(),
+            secure: true,
+            ..Default::default()
+        };
+        let client = MinioClient::from_config(anonymous.clone()).unwrap();
+        assert!(client.is_anonymous());
+        assert_eq!(client.to_config(), anonymous);
+    }
+
+    #[test]
+    fn debug_hides_secret() {
+        let config = sample_config();
+        let client = MinioClient::from_config(config.clone()).unwrap();
+        for output in [
+            format!("{config:?}"),
+            format!("{:?}", client.to_config()),
+            format!("{}", Redacted::new("very-secret")),
+        ] {
+            assert!(!output.contains("very-secret"), "{output}");
+        }
+    }
+
+    #[test]
+    fn from_config_rejects_invalid_settings() {
+        let with_scheme = ClientConfig {
+            endpoint: "http://localhost:9000".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            MinioClient::from_config(with_scheme),
+            Err(Error::Validation(ValidationErr::InvalidBaseUrl(_)))
+        ));
+
+        let missing_secret = ClientConfig {
+            secret_key: None,
+            ..sample_config()
+        };
+        assert!(matches!(
+            MinioClient::from_config(missing_secret),
+            Err(Error::Validation(ValidationErr::InvalidConfig { .. }))
+        ));
+    }
+}
@@ -15,8 +15,10 @@
 
 use crate::s3::builders::{CreateBucket, CreateBucketBldr};
 use crate::s3::client::MinioClient;
-use crate::s3::error::ValidationErr;
-use crate::s3::types::BucketName;
+use crate::s3::error::S3ServerError::S3Error;
+use crate::s3::error::{Error, ValidationErr};
+use crate::s3::minio_error_response::MinioErrorCode;
+use crate::s3::types::{BucketName, S3Api};
 
 impl MinioClient {
     /// Creates a [`CreateBucket`] request builder.
@@ -54,4 +56,95 @@ impl MinioClient {
             .client(self.clone())
             .bucket(bucket.try_into().map_err(Into::into)?))
     }
+
+    /// Creates the bucket if it does not exist yet.
+    ///
+    /// Returns `true` if the bucket was created and `false` if it already
+    /// existed and is owned by the caller. A `BucketAlreadyOwnedByYou` error is
+    /// treated as success. Some servers answer `BucketAlreadyExists` for buckets
+    /// of the caller as well; in that case the bucket is probed with
+    /// [`bucket_exists`](Self::bucket_exists) and the error is returned unless it
+    /// is accessible. A bucket name owned by someone else therefore still fails.
+    pub async fn ensure_bucket<B>(&self, bucket: B) -> Result<bool, Error>
+    where
+        B: TryInto<BucketName>,
+        B::Error: Into<ValidationErr>,
+    {
+        let bucket: BucketName = bucket.try_into().map_err(Into::into)?;
+        let err = match self.create_bucket(&bucket)?.build().send().await {
+            Ok(_) => return Ok(true),
+            Err(e) => e,
+        };
+        let code = match &err {
+            Error::S3Server(S3Error(e)) => e.code(),
+            _ => return Err(err),
+        };
+        match code {
+            MinioErrorCode::BucketAlreadyOwnedByYou => Ok(false),
+            MinioErrorCode::BucketAlreadyExists => {
+                match self.bucket_exists(&bucket)?.build().send().await {
+                    Ok(resp) if resp.exists() => Ok(false),
+                    _ => Err(err),
+                }
+            }
+            _ => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{MockServer, raw_response};
+
+    fn xml_error(status: &str, code: &str) -> String {
+        raw_response(
+            status,
+            "Content-Type: application/xml\r\n",
+            &format!("<Error><Code>{code}</Code><Message>{code}</Message></Error>"),
+        )
+    }
+
+    fn empty(status: &str) -> String {
+        raw_response(status, "", "")
+    }
+
+    /// Serves the given raw responses in order.
+    async fn serve(responses: Vec<String>) -> MinioClient {
+        MockServer::scripted(responses).await.client()
+    }
+
+    #[tokio::test]
+    async fn test_ensure_bucket_created() {
+        let client = serve(vec![empty("200 OK")]).await;
+        assert!(client.ensure_bucket("bucket").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_ensure_bucket_already_owned() {
+        let client = serve(vec![xml_error("409 Conflict", "BucketAlreadyOwnedByYou")]).await;
+        assert!(!client.ensure_bucket("bucket").await.unwrap());
+
+        let client = serve(vec![
+            xml_error("409 Conflict", "BucketAlreadyExists"),
+            empty("200 OK"),
+        ])
+        .await;
+        assert!(!client.ensure_bucket("bucket").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_ensure_bucket_owned_by_other() {
+        let client = serve(vec![
+            xml_error("409 Conflict", "BucketAlreadyExists"),
+            empty("403 Forbidden"),
+        ])
+        .await;
+        match client.ensure_bucket("bucket").await {
+            Err(Error::S3Server(S3Error(e))) => {
+                assert_eq!(e.code(), MinioErrorCode::BucketAlreadyExists)
+            }
+            v => panic!("expected BucketAlreadyExists; got {v:?}"),
+        }
+    }
 }
@@ -0,0 +1,69 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::builders::{DownloadObjects, DownloadObjectsBldr};
+use crate::s3::client::MinioClient;
+use crate::s3::error::ValidationErr;
+use crate::s3::types::BucketName;
+use std::path::PathBuf;
+
+impl MinioClient {
+    /// Creates a [`DownloadObjects`] request builder that downloads all objects under
+    /// `prefix` into `local_dir`, mirroring the key structure as nested directories.
+    ///
+    /// To execute the request, call [`DownloadObjects::send()`](DownloadObjects::send),
+    /// which returns a [`Result`] containing a [`DownloadObjectsResponse`](crate::s3::response::DownloadObjectsResponse).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::MinioClient;
+    /// use minio::s3::creds::StaticProvider;
+    /// use minio::s3::http::BaseUrl;
+    /// use minio::s3::response::DownloadObjectsResponse;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let base_url = "http://localhost:9000/".parse::<BaseUrl>().unwrap();
+    ///     let static_provider = StaticProvider::new("minioadmin", "minioadmin", None);
+    ///     let client = MinioClient::new(base_url, Some(static_provider), None, None).unwrap();
+    ///     let resp: DownloadObjectsResponse = client
+    ///         .download_objects_by_prefix("bucket-name", "photos/", "/tmp/photos")
+    ///         .unwrap()
+    ///         .build()
+    ///         .send()
+    ///         .await
+    ///         .unwrap();
+    ///     let result = resp.result();
+    ///     println!("downloaded {} objects, {} failed", result.succeeded().count(), result.failed().count());
+    /// }
+    /// ```
+    pub fn download_objects_by_prefix<B>(
+        &self,
+        bucket: B,
+        prefix: impl Into<String>,
+        local_dir: impl Into<PathBuf>,
+    ) -> Result<DownloadObjectsBldr, ValidationErr>
+    where
+        B: TryInto<BucketName>,
+        B::Error: Into<ValidationErr>,
+    {
+        Ok(DownloadObjects::builder()
+            .client(self.clone())
+            .bucket(bucket.try_into().map_err(Into::into)?)
+            .prefix(prefix)
+            .local_dir(local_dir))
+    }
+}
@@ -0,0 +1,64 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::builders::{EmptyBucket, EmptyBucketBldr};
+use crate::s3::client::MinioClient;
+use crate::s3::error::ValidationErr;
+use crate::s3::types::BucketName;
+
+impl MinioClient {
+    /// Creates an [`EmptyBucket`] request builder that removes all objects from a bucket,
+    /// including all versions and delete markers.
+    ///
+    /// To execute the request, call [`EmptyBucket::send()`](EmptyBucket::send),
+    /// which returns a [`Result`] containing an [`EmptyBucketResponse`](crate::s3::response::EmptyBucketResponse).
+    /// Objects locked under COMPLIANCE retention or a legal hold cannot be removed and
+    /// result in a [`ValidationErr::BucketNotEmptied`] error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::MinioClient;
+    /// use minio::s3::creds::StaticProvider;
+    /// use minio::s3::http::BaseUrl;
+    /// use minio::s3::response::EmptyBucketResponse;
+    /// use minio::s3::types::S3Api;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let base_url = "http://localhost:9000/".parse::<BaseUrl>().unwrap();
+    ///     let static_provider = StaticProvider::new("minioadmin", "minioadmin", None);
+    ///     let client = MinioClient::new(base_url, Some(static_provider), None, None).unwrap();
+    ///     let resp: EmptyBucketResponse = client
+    ///         .empty_bucket("bucket-name")
+    ///         .unwrap()
+    ///         .bypass_governance(true)
+    ///         .build()
+    ///         .send()
+    ///         .await
+    ///         .unwrap();
+    ///     println!("removed {} entries from bucket '{}'", resp.deleted(), resp.bucket());
+    /// }
+    /// ```
+    pub fn empty_bucket<B>(&self, bucket: B) -> Result<EmptyBucketBldr, ValidationErr>
+    where
+        B: TryInto<BucketName>,
+        B::Error: Into<ValidationErr>,
+    {
+        Ok(EmptyBucket::builder()
+            .client(self.clone())
+            .bucket(bucket.try_into().map_err(Into::into)?))
+    }
+}
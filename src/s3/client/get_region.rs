@@ -15,8 +15,10 @@
 
 use super::{DEFAULT_REGION, MinioClient};
 use crate::s3::builders::{GetRegion, GetRegionBldr};
-use crate::s3::error::{Error, ValidationErr};
+use crate::s3::error::{Error, S3ServerError, ValidationErr};
+use crate::s3::minio_error_response::{MinioErrorCode, MinioErrorResponse};
 use crate::s3::types::{BucketName, Region, S3Api};
+use std::time::Instant;
 
 impl MinioClient {
     /// Creates a [`GetRegion`] request builder.
@@ -105,15 +107,22 @@ impl MinioClient {
         if let Some(v) = self.shared.region_map.get(bucket.as_str()) {
             return Ok(v.value().clone());
         }
+        if let Some(e) = self.cached_missing_bucket(&bucket) {
+            return Err(Error::S3Server(S3ServerError::S3Error(Box::new(e))));
+        }
 
         // Otherwise, fetch the region from the server and cache it
         let resolved_region: String = {
-            let region = self
-                .get_region(bucket2)?
-                .build()
-                .send()
-                .await?
-                .region_response()?;
+            let resp = self.get_region(bucket2)?.build().send().await;
+            if let (Some(_), Err(Error::S3Server(S3ServerError::S3Error(e)))) =
+                (self.shared.missing_bucket_cache_ttl, &resp)
+                && matches!(e.code(), MinioErrorCode::NoSuchBucket)
+            {
+                self.shared
+                    .missing_buckets
+                    .insert(bucket.to_string(), (Instant::now(), e.as_ref().clone()));
+            }
+            let region = resp?.region_response()?;
             if !region.is_empty() {
                 region
             } else {
@@ -127,15 +136,32 @@ impl MinioClient {
 
         Ok(resolved_region)
     }
+
+    /// Returns the `NoSuchBucket` error of a region lookup of `bucket` that
+    /// failed within the missing bucket cache TTL.
+    fn cached_missing_bucket(&self, bucket: &BucketName) -> Option<MinioErrorResponse> {
+        let ttl = self.shared.missing_bucket_cache_ttl?;
+        let cached = {
+            let entry = self.shared.missing_buckets.get(bucket.as_str())?;
+            (entry.0.elapsed() < ttl).then(|| entry.1.clone())
+        };
+        if cached.is_none() {
+            self.shared.missing_buckets.remove(bucket.as_str());
+        }
+        cached
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::s3::client::MinioClientBuilder;
+    use crate::s3::client::test_support::MockExecutor;
     use crate::s3::creds::StaticProvider;
     use crate::s3::http::BaseUrl;
     use crate::s3::types::BucketName;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
 
     fn create_test_client(skip_region_lookup: bool) -> MinioClient {
         let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
@@ -242,4 +268,86 @@ mod tests {
 
         assert!(!client.shared.skip_region_lookup);
     }
+
+    /// Returns a client whose server answers location requests with
+    /// `NoSuchBucket` until the bucket is created.
+    fn missing_bucket_client(ttl: Option<Duration>) -> (MinioClient, MockExecutor) {
+        let created = AtomicBool::new(false);
+        let executor = MockExecutor::new(move |req| {
+            if req.method == http::Method::PUT {
+                created.store(true, Ordering::SeqCst);
+                http::Response::builder().status(200).body(String::new())
+            } else if created.load(Ordering::SeqCst) {
+                http::Response::builder()
+                    .status(200)
+                    .body("<LocationConstraint>us-east-1</LocationConstraint>".to_string())
+            } else {
+                http::Response::builder()
+                    .status(404)
+                    .header(http::header::CONTENT_TYPE, "application/xml")
+                    .body(
+                        "<Error><Code>NoSuchBucket</Code><Message>missing</Message></Error>".into(),
+                    )
+            }
+            .unwrap()
+        });
+        let client = MinioClientBuilder::new("http://localhost:9000".parse().unwrap())
+            .provider(Some(StaticProvider::new("test", "test", None)))
+            .missing_bucket_cache_ttl(ttl)
+            .http_executor(executor.clone())
+            .build()
+            .unwrap();
+        (client, executor)
+    }
+
+    fn location_requests(executor: &MockExecutor) -> usize {
+        executor
+            .requests()
+            .iter()
+            .filter(|r| r.url.query() == Some("location="))
+            .count()
+    }
+
+    fn assert_no_such_bucket(result: Result<String, Error>) {
+        match result {
+            Err(Error::S3Server(S3ServerError::S3Error(e))) => {
+                assert_eq!(e.code(), MinioErrorCode::NoSuchBucket)
+            }
+            v => panic!("expected NoSuchBucket, got {v:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_missing_bucket_served_from_cache() {
+        let (client, executor) = missing_bucket_client(Some(Duration::from_secs(60)));
+
+        for _ in 0..2 {
+            assert_no_such_bucket(client.get_region_cached("missing", &None).await);
+        }
+        assert_eq!(location_requests(&executor), 1);
+
+        client
+            .create_bucket("missing")
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+        assert!(client.shared.missing_buckets.is_empty());
+        assert_eq!(
+            client.get_region_cached("missing", &None).await.unwrap(),
+            DEFAULT_REGION.as_str()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_bucket_cache_disabled_by_default() {
+        let (client, executor) = missing_bucket_client(None);
+
+        for _ in 0..2 {
+            assert_no_such_bucket(client.get_region_cached("missing", &None).await);
+        }
+        assert_eq!(location_requests(&executor), 2);
+        assert!(client.shared.missing_buckets.is_empty());
+    }
 }
@@ -0,0 +1,118 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pluggable transport for sending the HTTP requests of a [`MinioClient`](crate::s3::client::MinioClient).
+//!
+//! The client builds, signs and encodes each request, then hands it to an
+//! [`HttpExecutor`]. By default this is [`ReqwestExecutor`]; another executor can
+//! be set with [`MinioClientBuilder::http_executor`](crate::s3::client::MinioClientBuilder::http_executor),
+//! e.g. to serve responses from memory in tests or to route requests through a
+//! different HTTP stack.
+
+use async_trait::async_trait;
+use reqwest::{Request, Response};
+use std::fmt::Debug;
+
+/// Sends a fully prepared HTTP request and returns the response.
+///
+/// Implementations must not alter signed headers, the URL or the body, since
+/// this invalidates the request signature. A response can be built from any
+/// [`http::Response`] with `Response::from`.
+///
+/// # Example
+///
+/// ```
+/// use async_trait::async_trait;
+/// use minio::s3::client::{HttpExecutor, MinioClientBuilder};
+/// use reqwest::{Request, Response};
+///
+/// #[derive(Debug)]
+/// struct AlwaysOk;
+///
+/// #[async_trait]
+/// impl HttpExecutor for AlwaysOk {
+///     async fn execute(&self, _request: Request) -> Result<Response, reqwest::Error> {
+///         Ok(http::Response::new("").into())
+///     }
+/// }
+///
+/// let client = MinioClientBuilder::new("http://localhost:9000".parse().unwrap())
+///     .http_executor(AlwaysOk)
+///     .build()
+///     .unwrap();
+/// ```
+#[async_trait]
+pub trait HttpExecutor: Debug + Send + Sync + 'static {
+    async fn execute(&self, request: Request) -> Result<Response, reqwest::Error>;
+}
+
+/// Default [`HttpExecutor`] sending requests with a [`reqwest::Client`].
+#[derive(Clone, Debug)]
+pub struct ReqwestExecutor {
+    client: reqwest::Client,
+}
+
+impl ReqwestExecutor {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl HttpExecutor for ReqwestExecutor {
+    async fn execute(&self, request: Request) -> Result<Response, reqwest::Error> {
+        self.client.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::s3::client::test_support::{MockExecutor, test_client};
+    use crate::s3::types::S3Api;
+    use http::Method;
+
+    #[tokio::test]
+    async fn requests_go_through_custom_executor() {
+        let executor = MockExecutor::default();
+        let client = test_client(executor.clone());
+
+        let resp = client
+            .bucket_exists("bucket")
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+        assert!(resp.exists());
+
+        client.ping().await.unwrap();
+
+        let requests: Vec<(Method, String)> = executor
+            .requests()
+            .into_iter()
+            .map(|r| (r.method, r.url.to_string()))
+            .collect();
+        assert_eq!(
+            requests,
+            [
+                (Method::HEAD, "http://localhost:9000/bucket".to_string()),
+                (
+                    Method::GET,
+                    "http://localhost:9000/minio/health/live".to_string()
+                ),
+            ]
+        );
+    }
+}
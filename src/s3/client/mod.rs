@@ -36,6 +36,7 @@ use http::HeaderMap;
 pub use hyper::http::Method;
 use reqwest::Body;
 pub use reqwest::Response;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fs::File;
 use std::io::prelude::*;
@@ -48,10 +49,10 @@ use uuid::Uuid;
 use crate::s3::builders::{
     BucketExists, ComposeSource, MAX_MULTIPART_COUNT, MAX_OBJECT_SIZE, MAX_PART_SIZE, MIN_PART_SIZE,
 };
+pub use crate::s3::client::clock::{Clock, FixedClock, SystemClock};
 pub use crate::s3::client::hooks::RequestHooks;
-use crate::s3::creds::Provider;
-#[cfg(feature = "localhost")]
-use crate::s3::creds::StaticProvider;
+pub use crate::s3::client::http_executor::{HttpExecutor, ReqwestExecutor};
+use crate::s3::creds::{Credentials, Provider, StaticProvider};
 use crate::s3::error::{Error, IoError, NetworkError, S3ServerError, ValidationErr};
 use crate::s3::header_constants::*;
 use crate::s3::http::{BaseUrl, Url};
@@ -60,16 +61,23 @@ use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use crate::s3::response::*;
 use crate::s3::response_traits::{HasEtagFromHeaders, HasS3Fields};
 use crate::s3::segmented_bytes::SegmentedBytes;
-use crate::s3::signer::{SigningKeyCache, sign_v4_s3, sign_v4_s3_with_context};
+use crate::s3::signer::{
+    SignatureVersion, SigningKeyCache, sign_v2, sign_v4_s3, sign_v4_s3_with_context, to_v2_date,
+};
 use crate::s3::types::{BucketName, ObjectKey};
 use crate::s3::utils::{
-    ChecksumAlgorithm, EMPTY_SHA256, check_ssec_with_log, sha256_hash_sb, to_amz_date, utc_now,
+    ChecksumAlgorithm, EMPTY_SHA256, check_bucket_name, check_ssec_with_log, sha256_hash_sb,
+    to_amz_date,
 };
 
 mod append_object;
+pub(crate) mod bucket_config_cache;
 mod bucket_exists;
+mod client_config;
+pub mod clock;
 mod copy_object;
 mod create_bucket;
+mod delete_all_versions;
 mod delete_bucket;
 mod delete_bucket_encryption;
 mod delete_bucket_lifecycle;
@@ -80,6 +88,8 @@ mod delete_bucket_tagging;
 mod delete_object_lock_config;
 mod delete_object_tagging;
 mod delete_objects;
+mod download_objects;
+mod empty_bucket;
 mod get_bucket_encryption;
 mod get_bucket_lifecycle;
 mod get_bucket_notification;
@@ -97,9 +107,14 @@ mod get_presigned_object_url;
 mod get_presigned_post_form_data;
 mod get_region;
 pub mod hooks;
+pub mod http_executor;
 mod list_buckets;
 mod list_objects;
 mod listen_bucket_notification;
+mod move_object;
+mod object_exists;
+mod object_url;
+mod ping;
 mod put_bucket_encryption;
 mod put_bucket_lifecycle;
 mod put_bucket_notification;
@@ -108,12 +123,20 @@ mod put_bucket_replication;
 mod put_bucket_tagging;
 mod put_bucket_versioning;
 mod put_object;
+mod put_object_fan_out;
 mod put_object_legal_hold;
 mod put_object_lock_config;
 mod put_object_retention;
 mod put_object_tagging;
+mod reencrypt_object;
 mod select_object_content;
 mod stat_object;
+#[cfg(test)]
+pub(crate) mod test_support;
+
+use bucket_config_cache::BucketConfigCache;
+pub use client_config::{ClientConfig, Redacted};
+pub use ping::DEFAULT_PING_TIMEOUT;
 
 use super::types::{Region, S3Api};
 use std::sync::LazyLock;
@@ -237,6 +260,118 @@ impl ConnectionPoolConfig {
     }
 }
 
+/// Retry policy for requests that fail with a transient error.
+///
+/// A request is replayed when the server answers with HTTP 500, 502, 503 or
+/// 504 (including `SlowDown`, `ServiceUnavailable`, `InternalError` and
+/// `RequestTimeout` error responses) or when the connection cannot be
+/// established or times out. Request bodies are kept in memory, so a replay
+/// sends the same bytes with the payload digest computed for the first
+/// attempt; only the date and signature are recomputed.
+///
+/// Retries are disabled by default.
+///
+/// A replayed PUT or POST may be applied twice if the first attempt reached the
+/// server. Gateways that deduplicate writes by a client token can be given one
+/// with [`idempotency_header`](Self::idempotency_header): every PUT and POST
+/// then carries a fresh random token in that header, and its replays carry the
+/// same token.
+///
+/// # Example
+///
+/// ```
+/// use minio::s3::client::RetryConfig;
+/// use minio::s3::header_constants::X_MINIO_IDEMPOTENCY_ID;
+/// use std::time::Duration;
+///
+/// let config = RetryConfig::default()
+///     .max_retries(3)
+///     .initial_backoff(Duration::from_millis(200))
+///     .idempotency_header(X_MINIO_IDEMPOTENCY_ID);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Maximum number of replays after the first attempt. Default: 0
+    pub max_retries: u32,
+
+    /// Delay before the first replay; doubled for each further replay.
+    /// Default: 100 milliseconds
+    pub initial_backoff: std::time::Duration,
+
+    /// Upper bound of the delay between replays. Default: 5 seconds
+    pub max_backoff: std::time::Duration,
+
+    /// Header carrying a client-generated token on PUT and POST requests,
+    /// reused by their replays. Default: `None`
+    pub idempotency_header: Option<String>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: std::time::Duration::from_millis(100),
+            max_backoff: std::time::Duration::from_secs(5),
+            idempotency_header: None,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Set the maximum number of replays after the first attempt.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the delay before the first replay.
+    pub fn initial_backoff(mut self, backoff: std::time::Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the upper bound of the delay between replays.
+    pub fn max_backoff(mut self, backoff: std::time::Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Set the header carrying the idempotency token of PUT and POST requests,
+    /// e.g. [`X_MINIO_IDEMPOTENCY_ID`]. A token already set on the request by the
+    /// caller is kept.
+    pub fn idempotency_header(mut self, name: impl Into<String>) -> Self {
+        self.idempotency_header = Some(name.into());
+        self
+    }
+
+    /// Returns the delay before the given replay (1-based).
+    fn backoff(&self, retry: u32) -> std::time::Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(retry.saturating_sub(1)))
+            .min(self.max_backoff)
+    }
+}
+
+fn is_signature_mismatch(e: &Error) -> bool {
+    matches!(
+        e,
+        Error::S3Server(S3ServerError::S3Error(er))
+            if matches!(er.code(), MinioErrorCode::SignatureDoesNotMatch)
+    )
+}
+
+/// Returns whether a failed request may be replayed under a [`RetryConfig`].
+fn is_transient_error(e: &Error) -> bool {
+    match e {
+        Error::S3Server(S3ServerError::S3Error(er)) => er.code().is_transient(),
+        Error::Network(NetworkError::ServerError(status)) => {
+            matches!(status, 500 | 502 | 503 | 504)
+        }
+        Error::Validation(ValidationErr::HttpError(er)) => er.is_connect() || er.is_timeout(),
+        _ => false,
+    }
+}
+
 /// Client Builder manufactures a Client using given parameters.
 /// Creates a builder given a base URL for the MinIO service or other AWS S3
 /// compatible object storage service.
@@ -246,6 +381,10 @@ pub struct MinioClientBuilder {
     /// Set the credential provider. If not, set anonymous access is used.
     provider: Option<Arc<dyn Provider + Send + Sync + 'static>>,
     client_hooks: Vec<Arc<dyn RequestHooks + Send + Sync + 'static>>,
+    /// Transport sending the requests; a [`ReqwestExecutor`] if not set.
+    http_executor: Option<Arc<dyn HttpExecutor>>,
+    /// Source of the request time; a [`SystemClock`] if not set.
+    clock: Option<Arc<dyn Clock>>,
     /// Set file for loading CAs certs to trust. This is in addition to the system trust store. The file must contain PEM encoded certificates.
     ssl_cert_file: Option<PathBuf>,
     /// Set flag to ignore certificate check. This is insecure and should only be used for testing.
@@ -256,6 +395,26 @@ pub struct MinioClientBuilder {
     skip_region_lookup: bool,
     /// HTTP connection pool configuration.
     connection_pool_config: ConnectionPoolConfig,
+    /// Maximum size of a buffered response body; unlimited if not set.
+    max_response_body_size: Option<u64>,
+    /// Skip SHA256 hashing of upload bodies and checksum verification of downloads.
+    skip_payload_hashing: bool,
+    /// Retry policy for transient errors.
+    retry_config: RetryConfig,
+    /// Time to live of cached bucket configurations; `None` disables the cache.
+    bucket_config_cache_ttl: Option<std::time::Duration>,
+    /// Time to live of failed region lookups of missing buckets; `None` disables the cache.
+    missing_bucket_cache_ttl: Option<std::time::Duration>,
+    /// Validate bucket names against the strict S3 naming rules.
+    bucket_name_strict: bool,
+    /// Overrides the addressing style detected from the base URL host.
+    force_path_style: Option<bool>,
+    /// Signature version used to sign requests and presigned URLs.
+    signature_version: SignatureVersion,
+    /// Fall back to Signature V2 for endpoints that reject Signature V4.
+    auto_signature_version: bool,
+    /// Endpoints serving the buckets of a region, keyed by region.
+    region_endpoints: HashMap<String, BaseUrl>,
 }
 
 impl MinioClientBuilder {
@@ -266,11 +425,23 @@ impl MinioClientBuilder {
             base_url,
             provider: None,
             client_hooks: Vec::new(),
+            http_executor: None,
+            clock: None,
             ssl_cert_file: None,
             ignore_cert_check: None,
             app_info: None,
             skip_region_lookup: false,
             connection_pool_config: ConnectionPoolConfig::default(),
+            max_response_body_size: None,
+            skip_payload_hashing: false,
+            retry_config: RetryConfig::default(),
+            bucket_config_cache_ttl: None,
+            missing_bucket_cache_ttl: None,
+            bucket_name_strict: true,
+            force_path_style: None,
+            signature_version: SignatureVersion::V4,
+            auto_signature_version: false,
+            region_endpoints: HashMap::new(),
         }
     }
 
@@ -281,6 +452,24 @@ impl MinioClientBuilder {
         self
     }
 
+    /// Set the transport that sends the requests, replacing the default
+    /// [`ReqwestExecutor`].
+    ///
+    /// TLS, connection pool and user-agent settings of this builder only apply
+    /// to the default executor. The executor also sends the requests to the
+    /// endpoints set with [`region_endpoint`](Self::region_endpoint).
+    pub fn http_executor(mut self, executor: impl HttpExecutor) -> Self {
+        self.http_executor = Some(Arc::new(executor));
+        self
+    }
+
+    /// Set the clock providing the time at which requests are dated and signed,
+    /// replacing the default [`SystemClock`].
+    pub fn clock(mut self, clock: impl Clock) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
     /// Set the credential provider. If not, set anonymous access is used.
     pub fn provider<P: Provider + Send + Sync + 'static>(mut self, provider: Option<P>) -> Self {
         self.provider = provider.map(|p| Arc::new(p) as Arc<dyn Provider + Send + Sync + 'static>);
@@ -288,7 +477,11 @@ impl MinioClientBuilder {
     }
 
     /// Set the app info as an Option of (app_name, app_version) pair. This will
-    /// show up in the client's user-agent.
+    /// show up in the client's user-agent as
+    /// `MinIO (<os>; <arch>) minio-rs/<version> <app_name>/<app_version>`.
+    ///
+    /// Name and version must be non-empty and may not contain whitespace, `/`,
+    /// or control characters; otherwise [`build`](Self::build) fails.
     pub fn app_info(mut self, app_info: Option<(String, String)>) -> Self {
         self.app_info = app_info;
         self
@@ -367,60 +560,232 @@ impl MinioClientBuilder {
         self
     }
 
-    /// Build the Client.
-    pub fn build(self) -> Result<MinioClient, Error> {
-        let pool_config = &self.connection_pool_config;
-        let mut builder = reqwest::Client::builder()
-            .no_gzip()
-            .tcp_nodelay(pool_config.tcp_nodelay)
-            .tcp_keepalive(pool_config.tcp_keepalive)
-            .pool_max_idle_per_host(pool_config.max_idle_per_host)
-            .pool_idle_timeout(pool_config.idle_timeout);
-
-        // HTTP/2 adaptive window improves throughput when server supports HTTP/2.
-        // Has no effect with HTTP/1.1-only servers (graceful fallback).
-        #[cfg(feature = "http2")]
-        {
-            builder = builder.http2_adaptive_window(true);
-        }
+    /// Limit the size of response bodies that are read into memory, such as XML
+    /// responses and error bodies, to `max_size` bytes. Unlimited by default.
+    ///
+    /// A larger body fails the request with [`Error::ResponseTooLarge`] before it
+    /// is buffered. Object data streamed by `get_object` is not affected.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::client::MinioClientBuilder;
+    /// use minio::s3::http::BaseUrl;
+    ///
+    /// let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+    /// let client = MinioClientBuilder::new(base_url)
+    ///     .max_response_body_size(Some(16 * 1024 * 1024))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn max_response_body_size(mut self, max_size: Option<u64>) -> Self {
+        self.max_response_body_size = max_size;
+        self
+    }
 
-        let os = std::env::consts::OS;
-        let arch = std::env::consts::ARCH;
-        let version = env!("CARGO_PKG_VERSION");
-        let mut user_agent = format!("MinIO ({os}; {arch}) minio-rs/{version}");
+    /// Sign requests and presigned URLs with the given signature version.
+    /// Defaults to [`SignatureVersion::V4`].
+    ///
+    /// [`SignatureVersion::V2`] is only meant for legacy services that reject
+    /// Version 4. Uploads then do not use trailing checksums, and MinIO admin
+    /// APIs as well as presigned POST policies are still signed with Version 4.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::client::MinioClientBuilder;
+    /// use minio::s3::creds::StaticProvider;
+    /// use minio::s3::http::BaseUrl;
+    /// use minio::s3::signer::SignatureVersion;
+    ///
+    /// let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+    /// let client = MinioClientBuilder::new(base_url)
+    ///     .provider(Some(StaticProvider::new("minioadmin", "minioadmin", None)))
+    ///     .signature_version(SignatureVersion::V2)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn signature_version(mut self, version: SignatureVersion) -> Self {
+        self.signature_version = version;
+        self
+    }
 
-        if let Some((app_name, app_version)) = self.app_info {
-            user_agent.push_str(format!(" {app_name}/{app_version}").as_str());
-        }
-        builder = builder.user_agent(user_agent);
-
-        #[cfg(any(
-            feature = "default-tls",
-            feature = "native-tls",
-            feature = "rustls-tls"
-        ))]
-        if let Some(v) = self.ignore_cert_check {
-            builder = builder.danger_accept_invalid_certs(v);
-        }
+    /// Probe whether an endpoint only accepts Signature V2. Disabled by default.
+    ///
+    /// When enabled, the first request to an endpoint that fails with
+    /// [`MinioErrorCode::SignatureDoesNotMatch`] under Signature V4 starts a probe:
+    /// a ListBuckets request is sent with V4 and then with
+    /// [`SignatureVersion::V2`]. Only if the server rejects the V4 signature again
+    /// and accepts the V2 one is the endpoint signed with V2 from then on, and the
+    /// failed request replayed. Otherwise it stays on V4 and the original error is
+    /// returned, so wrong credentials never switch an endpoint to V2. Each
+    /// endpoint is probed at most once.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::client::MinioClientBuilder;
+    /// use minio::s3::creds::StaticProvider;
+    /// use minio::s3::http::BaseUrl;
+    ///
+    /// let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+    /// let client = MinioClientBuilder::new(base_url)
+    ///     .provider(Some(StaticProvider::new("minioadmin", "minioadmin", None)))
+    ///     .auto_signature_version(true)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn auto_signature_version(mut self, auto: bool) -> Self {
+        self.auto_signature_version = auto;
+        self
+    }
+
+    /// Skip hashing of request and response bodies. Disabled by default.
+    ///
+    /// When enabled, PUT and POST requests sent over HTTPS carry
+    /// `x-amz-content-sha256: UNSIGNED-PAYLOAD` instead of the SHA256 of the
+    /// body, so uploads no longer scan the full body before sending. Downloads
+    /// through [`GetObjectResponse::content`](crate::s3::response::GetObjectResponse::content)
+    /// skip checksum verification unless re-enabled with
+    /// [`with_verification(true)`](crate::s3::response::GetObjectResponse::with_verification).
+    ///
+    /// This is only safe over HTTPS, where TLS protects the body integrity; requests
+    /// over plain HTTP always sign the payload hash.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::client::MinioClientBuilder;
+    /// use minio::s3::creds::StaticProvider;
+    /// use minio::s3::http::BaseUrl;
+    ///
+    /// let base_url: BaseUrl = "https://minio.example.com".parse().unwrap();
+    /// let client = MinioClientBuilder::new(base_url)
+    ///     .provider(Some(StaticProvider::new("minioadmin", "minioadmin", None)))
+    ///     .skip_payload_hashing(true)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn skip_payload_hashing(mut self, skip: bool) -> Self {
+        self.skip_payload_hashing = skip;
+        self
+    }
+
+    /// Set the retry policy for requests failing with a transient error; see
+    /// [`RetryConfig`]. Retries are disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::client::{MinioClientBuilder, RetryConfig};
+    /// use minio::s3::http::BaseUrl;
+    ///
+    /// let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+    /// let client = MinioClientBuilder::new(base_url)
+    ///     .retry_config(RetryConfig::default().max_retries(3))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn retry_config(mut self, config: RetryConfig) -> Self {
+        self.retry_config = config;
+        self
+    }
+
+    /// Cache the responses of `get_bucket_policy`, `get_bucket_lifecycle` and
+    /// `get_bucket_encryption` per bucket for `ttl`. Disabled by default.
+    ///
+    /// A cached entry is dropped when a put or delete of that configuration, or
+    /// the deletion of the bucket, succeeds through this client. Changes made by
+    /// other clients are only seen after `ttl`. Individual requests can bypass
+    /// the cache with `no_cache()`.
+    pub fn bucket_config_cache_ttl(mut self, ttl: Option<std::time::Duration>) -> Self {
+        self.bucket_config_cache_ttl = ttl;
+        self
+    }
+
+    /// Remember for `ttl` that the region lookup of a bucket failed with
+    /// `NoSuchBucket`, and fail requests to that bucket with the same error
+    /// without contacting the server. Disabled by default.
+    ///
+    /// An entry is dropped when the bucket is created through this client.
+    /// Buckets created by other clients are only seen after `ttl`, so keep it short.
+    pub fn missing_bucket_cache_ttl(mut self, ttl: Option<std::time::Duration>) -> Self {
+        self.missing_bucket_cache_ttl = ttl;
+        self
+    }
+
+    /// Validate bucket names of requests against the strict S3 naming rules
+    /// (default `true`). Set to `false` for deployments that allow uppercase
+    /// letters, underscores or colons in bucket names.
+    ///
+    /// Names that are IP addresses or contain `..`, `.-` or `-.` are rejected
+    /// in both modes.
+    pub fn bucket_name_strict(mut self, strict: bool) -> Self {
+        self.bucket_name_strict = strict;
+        self
+    }
+
+    /// Address buckets in the URL path (`host/bucket/object`) instead of the host
+    /// name (`bucket.host/object`).
+    ///
+    /// By default, path-style is used for all endpoints except Amazon S3 and
+    /// Aliyun OSS, which use virtual-host style. MinIO deployments served behind a
+    /// single host name need path-style; with virtual-host style their buckets
+    /// are not found.
+    pub fn force_path_style(mut self, force_path_style: bool) -> Self {
+        self.force_path_style = Some(force_path_style);
+        self
+    }
+
+    /// Send requests for buckets in `region` to `base_url` instead of the base URL
+    /// of the client.
+    ///
+    /// For deployments where each region is served by its own host name. The region
+    /// of a bucket is looked up through the base URL as usual; requests for the
+    /// bucket then go to the endpoint of that region. With the default executor,
+    /// each regional endpoint gets its own connection pool, created on first use.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::client::MinioClientBuilder;
+    /// use minio::s3::http::BaseUrl;
+    ///
+    /// let base_url: BaseUrl = "https://eu.storage.example.com".parse().unwrap();
+    /// let client = MinioClientBuilder::new(base_url)
+    ///     .region_endpoint("us-west-1", "https://us.storage.example.com".parse().unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn region_endpoint(mut self, region: impl Into<String>, base_url: BaseUrl) -> Self {
+        self.region_endpoints.insert(region.into(), base_url);
+        self
+    }
 
-        #[cfg(any(
-            feature = "default-tls",
-            feature = "native-tls",
-            feature = "rustls-tls"
-        ))]
-        if let Some(v) = self.ssl_cert_file {
-            let mut buf = Vec::new();
-            let mut file = File::open(v).map_err(IoError::IOError)?;
-            file.read_to_end(&mut buf).map_err(IoError::IOError)?;
-
-            let certs = reqwest::Certificate::from_pem_bundle(&buf).map_err(ValidationErr::from)?;
-            for cert in certs {
-                builder = builder.add_root_certificate(cert);
+    /// Build the Client.
+    pub fn build(mut self) -> Result<MinioClient, Error> {
+        if let Some(force_path_style) = self.force_path_style {
+            self.base_url.virtual_style = !force_path_style;
+            for base_url in self.region_endpoints.values_mut() {
+                base_url.virtual_style = !force_path_style;
             }
         }
+        let user_agent = user_agent(self.app_info.as_ref())?;
+        let http_client = build_http_client(
+            &self.connection_pool_config,
+            &user_agent,
+            self.ignore_cert_check,
+            self.ssl_cert_file.as_deref(),
+        )?;
+        let custom_http_executor = self.http_executor.is_some();
+        let http_executor = match self.http_executor {
+            Some(v) => v,
+            None => Arc::new(ReqwestExecutor::new(http_client.clone())),
+        };
 
         Ok(MinioClient {
-            http_client: builder.build().map_err(ValidationErr::from)?,
+            http_client,
+            http_executor,
             shared: Arc::new(SharedClientItems {
                 base_url: self.base_url,
                 provider: self.provider,
@@ -428,19 +793,114 @@ impl MinioClientBuilder {
                 region_map: Default::default(),
                 express: Default::default(),
                 skip_region_lookup: self.skip_region_lookup,
+                ssl_cert_file: self.ssl_cert_file,
+                ignore_cert_check: self.ignore_cert_check.unwrap_or(false),
+                max_response_body_size: self.max_response_body_size,
+                signature_version: self.signature_version,
+                auto_signature_version: self.auto_signature_version,
+                probed_signature_versions: Default::default(),
+                region_endpoints: self.region_endpoints,
+                endpoint_executors: Default::default(),
+                custom_http_executor,
+                connection_pool_config: self.connection_pool_config,
+                skip_payload_hashing: self.skip_payload_hashing,
+                retry_config: self.retry_config,
                 signing_key_cache: RwLock::new(SigningKeyCache::new()),
+                user_agent,
+                bucket_config_cache: BucketConfigCache::new(self.bucket_config_cache_ttl),
+                missing_bucket_cache_ttl: self.missing_bucket_cache_ttl,
+                bucket_name_strict: self.bucket_name_strict,
+                missing_buckets: Default::default(),
+                clock: self.clock.unwrap_or_else(|| Arc::new(SystemClock)),
             }),
         })
     }
 }
 
+/// Builds the HTTP client of the default executor.
+fn build_http_client(
+    pool_config: &ConnectionPoolConfig,
+    user_agent: &str,
+    ignore_cert_check: Option<bool>,
+    ssl_cert_file: Option<&Path>,
+) -> Result<reqwest::Client, Error> {
+    let mut builder = reqwest::Client::builder()
+        .no_gzip()
+        .tcp_nodelay(pool_config.tcp_nodelay)
+        .tcp_keepalive(pool_config.tcp_keepalive)
+        .pool_max_idle_per_host(pool_config.max_idle_per_host)
+        .pool_idle_timeout(pool_config.idle_timeout);
+
+    // HTTP/2 adaptive window improves throughput when server supports HTTP/2.
+    // Has no effect with HTTP/1.1-only servers (graceful fallback).
+    #[cfg(feature = "http2")]
+    {
+        builder = builder.http2_adaptive_window(true);
+    }
+
+    builder = builder.user_agent(user_agent);
+
+    #[cfg(any(
+        feature = "default-tls",
+        feature = "native-tls",
+        feature = "rustls-tls"
+    ))]
+    if let Some(v) = ignore_cert_check {
+        builder = builder.danger_accept_invalid_certs(v);
+    }
+
+    #[cfg(any(
+        feature = "default-tls",
+        feature = "native-tls",
+        feature = "rustls-tls"
+    ))]
+    if let Some(v) = ssl_cert_file {
+        let mut buf = Vec::new();
+        let mut file = File::open(v).map_err(IoError::IOError)?;
+        file.read_to_end(&mut buf).map_err(IoError::IOError)?;
+
+        let certs = reqwest::Certificate::from_pem_bundle(&buf).map_err(ValidationErr::from)?;
+        for cert in certs {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+
+    Ok(builder.build().map_err(ValidationErr::from)?)
+}
+
+/// Returns the `User-Agent` header value identifying this SDK and, optionally,
+/// the application using it.
+fn user_agent(app_info: Option<&(String, String)>) -> Result<String, ValidationErr> {
+    let os = std::env::consts::OS;
+    let arch = std::env::consts::ARCH;
+    let version = env!("CARGO_PKG_VERSION");
+    let mut user_agent = format!("MinIO ({os}; {arch}) minio-rs/{version}");
+
+    if let Some((app_name, app_version)) = app_info {
+        let is_token = |s: &str| {
+            !s.is_empty()
+                && s.chars()
+                    .all(|c| c.is_ascii_graphic() && !matches!(c, '/' | '(' | ')'))
+        };
+        if !is_token(app_name) || !is_token(app_version) {
+            return Err(ValidationErr::InvalidConfig {
+                message: format!("invalid app info '{app_name}/{app_version}'"),
+            });
+        }
+        user_agent.push_str(&format!(" {app_name}/{app_version}"));
+    }
+    Ok(user_agent)
+}
+
 /// Simple Storage Service (aka S3) client to perform bucket and object operations.
 ///
 /// If credential provider is passed, all S3 operation requests are signed using
 /// AWS Signature Version 4; else they are performed anonymously.
 #[derive(Clone, Debug)]
 pub struct MinioClient {
+    /// Builds the requests; they are sent with `http_executor`.
     http_client: reqwest::Client,
+    http_executor: Arc<dyn HttpExecutor>,
     pub(crate) shared: Arc<SharedClientItems>,
 }
 
@@ -471,6 +931,111 @@ impl MinioClient {
             .build()
     }
 
+    /// Returns a S3 client that sends anonymous (unsigned) requests.
+    ///
+    /// No `Authorization` header is sent, which suits reading public buckets
+    /// and objects without credentials. Requests still carry `Host`,
+    /// `x-amz-date` and `x-amz-content-sha256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minio::s3::client::MinioClient;
+    /// use minio::s3::http::BaseUrl;
+    ///
+    /// let base_url: BaseUrl = "https://play.min.io".parse().unwrap();
+    /// let client = MinioClient::anonymous(base_url).unwrap();
+    /// assert!(client.is_anonymous());
+    /// ```
+    pub fn anonymous(base_url: BaseUrl) -> Result<Self, Error> {
+        MinioClientBuilder::new(base_url).build()
+    }
+
+    /// Returns whether this client has no credential provider and so sends
+    /// unsigned requests.
+    pub fn is_anonymous(&self) -> bool {
+        self.shared.provider.is_none()
+    }
+
+    /// Returns a S3 client configured from environment variables.
+    ///
+    /// | Setting       | Variables (first one set wins)                        |
+    /// |---------------|-------------------------------------------------------|
+    /// | endpoint      | `MINIO_ENDPOINT`, `AWS_ENDPOINT_URL`                  |
+    /// | access key    | `MINIO_ACCESS_KEY`, `AWS_ACCESS_KEY_ID`               |
+    /// | secret key    | `MINIO_SECRET_KEY`, `AWS_SECRET_ACCESS_KEY`           |
+    /// | session token | `MINIO_SESSION_TOKEN`, `AWS_SESSION_TOKEN`            |
+    /// | region        | `MINIO_REGION`, `AWS_REGION`, `AWS_DEFAULT_REGION`    |
+    ///
+    /// The endpoint is required and fails with [`ValidationErr::InvalidBaseUrl`]
+    /// when missing. Without an access and secret key the client is anonymous;
+    /// setting only one of them is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use minio::s3::client::MinioClient;
+    ///
+    /// let client = MinioClient::from_env().unwrap();
+    /// ```
+    pub fn from_env() -> Result<Self, Error> {
+        Self::from_env_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_env_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self, Error> {
+        let first = |names: &[&str]| {
+            names
+                .iter()
+                .filter_map(|name| var(name))
+                .find(|v| !v.is_empty())
+        };
+
+        let endpoint = first(&["MINIO_ENDPOINT", "AWS_ENDPOINT_URL"]).ok_or_else(|| {
+            ValidationErr::InvalidBaseUrl(
+                "no endpoint set in MINIO_ENDPOINT or AWS_ENDPOINT_URL".into(),
+            )
+        })?;
+        let mut base_url: BaseUrl = endpoint.parse()?;
+        if let Some(v) = first(&["MINIO_REGION", "AWS_REGION", "AWS_DEFAULT_REGION"]) {
+            base_url.region = Region::try_from(v.as_str())?;
+        }
+
+        let access_key = first(&["MINIO_ACCESS_KEY", "AWS_ACCESS_KEY_ID"]);
+        let secret_key = first(&["MINIO_SECRET_KEY", "AWS_SECRET_ACCESS_KEY"]);
+        let provider = match (access_key, secret_key) {
+            (Some(access_key), Some(secret_key)) => {
+                let session_token = first(&["MINIO_SESSION_TOKEN", "AWS_SESSION_TOKEN"]);
+                Some(StaticProvider::new(
+                    &access_key,
+                    &secret_key,
+                    session_token.as_deref(),
+                ))
+            }
+            (None, None) => None,
+            _ => {
+                return Err(ValidationErr::InvalidConfig {
+                    message: "access key and secret key must be set together".into(),
+                }
+                .into());
+            }
+        };
+
+        MinioClientBuilder::new(base_url).provider(provider).build()
+    }
+
+    /// Returns whether uploads over HTTPS are sent with `UNSIGNED-PAYLOAD` and
+    /// downloads skip checksum verification; see
+    /// [`MinioClientBuilder::skip_payload_hashing`].
+    pub fn skips_payload_hashing(&self) -> bool {
+        self.shared.skip_payload_hashing
+    }
+
+    /// Returns the `User-Agent` header value sent with every request; see
+    /// [`MinioClientBuilder::app_info`].
+    pub fn user_agent(&self) -> &str {
+        &self.shared.user_agent
+    }
+
     /// Returns whether this client uses an AWS host.
     pub fn is_aws_host(&self) -> bool {
         self.shared.base_url.is_aws_host()
@@ -514,14 +1079,29 @@ impl MinioClient {
         }
     }
 
+    /// Validates a bucket name with the strictness configured by
+    /// [`MinioClientBuilder::bucket_name_strict`].
+    pub(crate) fn check_bucket_name(&self, bucket: impl AsRef<str>) -> Result<(), ValidationErr> {
+        check_bucket_name(bucket, self.shared.bucket_name_strict)
+    }
+
     /// Add a bucket-region pair to the region cache if it does not exist.
     pub(crate) fn add_bucket_region(&mut self, bucket: &BucketName, region: Region) {
+        self.shared.missing_buckets.remove(bucket.as_str());
         self.shared
             .region_map
             .entry(bucket.as_str().to_owned())
             .or_insert_with(|| region.into_inner());
     }
 
+    /// Set the region of a bucket in the region cache, replacing any cached value.
+    pub(crate) fn set_bucket_region(&self, bucket: &BucketName, region: &Region) {
+        self.shared.missing_buckets.remove(bucket.as_str());
+        self.shared
+            .region_map
+            .insert(bucket.as_str().to_owned(), region.as_str().to_owned());
+    }
+
     /// Remove a bucket-region pair from the region cache if it exists.
     pub(crate) fn remove_bucket_region(&mut self, bucket: &BucketName) {
         self.shared.region_map.remove(bucket.as_str());
@@ -642,25 +1222,35 @@ impl MinioClient {
         body: Option<Arc<SegmentedBytes>>,
         trailing_checksum: Option<ChecksumAlgorithm>,
         use_signed_streaming: bool,
+        endpoint: Option<&BaseUrl>,
+        signature_version: SignatureVersion,
         retry: bool,
+        payload_sha256: &mut Option<String>,
     ) -> Result<reqwest::Response, Error> {
         use crate::s3::aws_chunked::{
             AwsChunkedEncoder, RechunkingStream, SignedAwsChunkedEncoder,
         };
 
-        let mut url =
-            self.shared
-                .base_url
-                .build_url(method, region, query_params, bucket, object)?;
+        let base_url = endpoint.unwrap_or(&self.shared.base_url);
+        let mut url = base_url.build_url(method, region, query_params, bucket, object)?;
         let mut extensions = http::Extensions::default();
+        let sign_v2_bucket = match signature_version {
+            SignatureVersion::V2 => {
+                bucket.filter(|b| base_url.is_virtual_host_style(method, query_params, b, object))
+            }
+            SignatureVersion::V4 => None,
+        };
 
         headers.add(HOST, url.host_header_value());
 
         // Determine if we're using trailing checksums (signed or unsigned)
         let use_trailing = trailing_checksum.is_some()
             && matches!(*method, Method::PUT | Method::POST)
-            && body.is_some();
-        let use_signed_trailing = use_trailing && use_signed_streaming;
+            && body.is_some()
+            && signature_version == SignatureVersion::V4;
+        // Anonymous requests have no key to sign chunks with.
+        let use_signed_trailing =
+            use_trailing && use_signed_streaming && self.shared.provider.is_some();
 
         let sha256: String = match *method {
             Method::PUT | Method::POST => {
@@ -669,7 +1259,6 @@ impl MinioClient {
                     headers.add(CONTENT_TYPE, "application/octet-stream");
                 }
                 let raw_len: usize = body.as_ref().map_or(0, |b| b.len());
-
                 if use_trailing {
                     // For trailing checksums, use aws-chunked encoding
                     let algorithm = trailing_checksum.unwrap();
@@ -706,10 +1295,21 @@ impl MinioClient {
                     headers.add(CONTENT_LENGTH, raw_len.to_string());
                     match body {
                         None => EMPTY_SHA256.into(),
-                        Some(ref v) => {
-                            let clone = v.clone();
-                            async_std::task::spawn_blocking(move || sha256_hash_sb(clone)).await
+                        Some(_) if self.shared.skip_payload_hashing && url.https => {
+                            UNSIGNED_PAYLOAD.into()
                         }
+                        // A replay sends the same body, so it is only hashed once.
+                        Some(ref v) => match payload_sha256 {
+                            Some(sha256) => sha256.clone(),
+                            None => {
+                                let clone = v.clone();
+                                let sha256 =
+                                    async_std::task::spawn_blocking(move || sha256_hash_sb(clone))
+                                        .await;
+                                *payload_sha256 = Some(sha256.clone());
+                                sha256
+                            }
+                        },
                     }
                 }
             }
@@ -717,8 +1317,11 @@ impl MinioClient {
         };
         headers.add(X_AMZ_CONTENT_SHA256, sha256.clone());
 
-        let date = utc_now();
-        headers.add(X_AMZ_DATE, to_amz_date(date));
+        let date = self.shared.clock.now();
+        match signature_version {
+            SignatureVersion::V2 => headers.add(X_AMZ_DATE, to_v2_date(date)),
+            SignatureVersion::V4 => headers.add(X_AMZ_DATE, to_amz_date(date)),
+        }
 
         // Allow hooks to modify the request before signing (e.g., for client-side load balancing)
         let url_before_hook = url.to_string();
@@ -746,13 +1349,23 @@ impl MinioClient {
         }
 
         // For signed streaming, we need the signing context for chunk signatures
-        let chunk_signing_context = if let Some(p) = &self.shared.provider {
-            let creds = p.fetch();
+        let chunk_signing_context = if let Some(creds) = self.credentials().await? {
             if creds.session_token.is_some() {
                 headers.add(X_AMZ_SECURITY_TOKEN, creds.session_token.unwrap());
             }
 
-            if use_signed_trailing {
+            if signature_version == SignatureVersion::V2 {
+                sign_v2(
+                    method,
+                    &url.path,
+                    sign_v2_bucket.map(BucketName::as_str),
+                    headers,
+                    query_params,
+                    &creds.access_key,
+                    &creds.secret_key,
+                );
+                None
+            } else if use_signed_trailing {
                 // Use the version that returns chunk signing context
                 Some(sign_v4_s3_with_context(
                     &self.shared.signing_key_cache,
@@ -833,7 +1446,10 @@ impl MinioClient {
             }
         }
 
-        let resp = req.send().await;
+        let resp = match req.build() {
+            Ok(v) => self.executor_for(base_url)?.execute(v).await,
+            Err(e) => Err(e),
+        };
 
         self.run_after_execute_hooks(
             method,
@@ -856,7 +1472,7 @@ impl MinioClient {
         let mut resp = resp;
         let status_code = resp.status().as_u16();
         let headers: HeaderMap = mem::take(resp.headers_mut());
-        let body: Bytes = resp.bytes().await.map_err(ValidationErr::HttpError)?;
+        let body: Bytes = self.read_body(resp).await?;
 
         let e: MinioErrorResponse = self.shared.create_minio_error_response(
             body,
@@ -880,7 +1496,171 @@ impl MinioClient {
         Err(Error::S3Server(S3ServerError::S3Error(Box::new(e))))
     }
 
-    pub(crate) async fn execute(
+    /// Returns the endpoint a request for `region` is sent to: `endpoint` if
+    /// given, else the endpoint configured for `region`, else the base URL.
+    pub(crate) fn endpoint_for<'a>(
+        &'a self,
+        endpoint: Option<&'a BaseUrl>,
+        region: &Region,
+    ) -> &'a BaseUrl {
+        endpoint
+            .or_else(|| self.shared.region_endpoints.get(region.as_str()))
+            .unwrap_or(&self.shared.base_url)
+    }
+
+    /// Returns the executor sending requests to `base_url`. Regional endpoints
+    /// get their own connection pool unless a custom executor is set.
+    fn executor_for(&self, base_url: &BaseUrl) -> Result<Arc<dyn HttpExecutor>, Error> {
+        let key = base_url.to_url_string();
+        let regional = !self.shared.custom_http_executor
+            && key != self.shared.base_url.to_url_string()
+            && self
+                .shared
+                .region_endpoints
+                .values()
+                .any(|v| v.to_url_string() == key);
+        if !regional {
+            return Ok(self.http_executor.clone());
+        }
+        if let Some(v) = self.shared.endpoint_executors.get(&key) {
+            return Ok(v.clone());
+        }
+        let http_client = build_http_client(
+            &self.shared.connection_pool_config,
+            &self.shared.user_agent,
+            Some(self.shared.ignore_cert_check),
+            self.shared.ssl_cert_file.as_deref(),
+        )?;
+        Ok(self
+            .shared
+            .endpoint_executors
+            .entry(key)
+            .or_insert_with(|| Arc::new(ReqwestExecutor::new(http_client)))
+            .clone())
+    }
+
+    /// Returns the credentials to sign requests with, or `None` for an anonymous
+    /// client. The provider renews them through the client's executor if needed.
+    pub(crate) async fn credentials(&self) -> Result<Option<Credentials>, Error> {
+        match &self.shared.provider {
+            Some(p) => p.credentials(self.http_executor.as_ref()).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the signature version to sign requests to `base_url` with.
+    pub(crate) fn signature_version_for(&self, base_url: &BaseUrl) -> SignatureVersion {
+        if self.shared.auto_signature_version
+            && let Some(v) = self
+                .shared
+                .probed_signature_versions
+                .get(&base_url.to_url_string())
+                .and_then(|v| *v)
+        {
+            return v;
+        }
+        self.shared.signature_version
+    }
+
+    /// Marks the probe of `base_url` as in progress if automatic selection is
+    /// enabled and the endpoint was not probed yet. Returns whether it should be
+    /// probed.
+    fn start_signature_v2_probe(&self, base_url: &BaseUrl) -> bool {
+        if !self.shared.auto_signature_version
+            || self.shared.signature_version == SignatureVersion::V2
+        {
+            return false;
+        }
+        match self
+            .shared
+            .probed_signature_versions
+            .entry(base_url.to_url_string())
+        {
+            dashmap::Entry::Occupied(_) => false,
+            dashmap::Entry::Vacant(e) => {
+                e.insert(None);
+                true
+            }
+        }
+    }
+
+    /// Probes whether `base_url` accepts Signature V2 but not V4, and remembers
+    /// the version to use for it. Returns whether it is V2.
+    ///
+    /// The probe is a ListBuckets request, which is idempotent and never served
+    /// anonymously, so a bad secret key cannot pass it. V2 is only chosen when the
+    /// probe consistently fails with V4 and succeeds with V2.
+    async fn probe_signature_v2(&self, region: &Region, base_url: &BaseUrl) -> bool {
+        let v4 = self
+            .send_probe(region, base_url, SignatureVersion::V4)
+            .await;
+        let v2_only = match v4 {
+            Err(ref e) if is_signature_mismatch(e) => {
+                let v2 = self
+                    .send_probe(region, base_url, SignatureVersion::V2)
+                    .await;
+                if let Err(e) = &v2 {
+                    log::debug!("endpoint rejected Signature V2 probe: {e}");
+                }
+                v2.is_ok()
+            }
+            _ => false,
+        };
+        let version = if v2_only {
+            SignatureVersion::V2
+        } else {
+            SignatureVersion::V4
+        };
+        self.shared
+            .probed_signature_versions
+            .insert(base_url.to_url_string(), Some(version));
+        v2_only
+    }
+
+    async fn send_probe(
+        &self,
+        region: &Region,
+        base_url: &BaseUrl,
+        signature_version: SignatureVersion,
+    ) -> Result<reqwest::Response, Error> {
+        self.execute_internal(
+            &Method::GET,
+            region,
+            &mut Multimap::new(),
+            &Multimap::new(),
+            None,
+            None,
+            None,
+            None,
+            false,
+            Some(base_url),
+            signature_version,
+            false,
+            &mut None,
+        )
+        .await
+    }
+
+    /// Reads the whole response body, failing with [`Error::ResponseTooLarge`]
+    /// as soon as it exceeds the configured maximum response body size.
+    pub(crate) async fn read_body(&self, mut resp: reqwest::Response) -> Result<Bytes, Error> {
+        let Some(limit) = self.shared.max_response_body_size else {
+            return Ok(resp.bytes().await.map_err(ValidationErr::HttpError)?);
+        };
+        if resp.content_length().is_some_and(|len| len > limit) {
+            return Err(Error::ResponseTooLarge { limit });
+        }
+        let mut body = Vec::new();
+        while let Some(chunk) = resp.chunk().await.map_err(ValidationErr::HttpError)? {
+            if (body.len() + chunk.len()) as u64 > limit {
+                return Err(Error::ResponseTooLarge { limit });
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(Bytes::from(body))
+    }
+
+    pub(crate) async fn execute(
         &self,
         method: Method,
         region: &Region,
@@ -891,47 +1671,86 @@ impl MinioClient {
         data: Option<Arc<SegmentedBytes>>,
         trailing_checksum: Option<ChecksumAlgorithm>,
         use_signed_streaming: bool,
+        endpoint: Option<&BaseUrl>,
+        payload_sha256: Option<String>,
     ) -> Result<reqwest::Response, Error> {
-        let resp: Result<reqwest::Response, Error> = self
-            .execute_internal(
-                &method,
-                region,
-                headers,
-                query_params,
-                bucket,
-                object,
-                data.as_ref().map(Arc::clone),
-                trailing_checksum,
-                use_signed_streaming,
-                true,
-            )
-            .await;
-        match resp {
-            Ok(r) => return Ok(r),
-            Err(e) => match e {
-                Error::S3Server(S3ServerError::S3Error(ref er)) => {
-                    if !matches!(er.code(), MinioErrorCode::RetryHead) {
+        let base_url = self.endpoint_for(endpoint, region);
+        let endpoint = Some(base_url);
+        let retry_config = &self.shared.retry_config;
+        if let Some(name) = &retry_config.idempotency_header
+            && matches!(method, Method::PUT | Method::POST)
+            && !headers.keys().any(|k| k.eq_ignore_ascii_case(name))
+        {
+            headers.add(name, Uuid::new_v4().to_string());
+        }
+        // Signing adds headers, so each replay starts from the original ones.
+        let original_headers = headers.clone();
+        let mut retry_head = true;
+        let mut retries: u32 = 0;
+        // Digest of the body, computed by the first attempt that hashes it
+        // unless the caller provided it.
+        let mut payload_sha256 = payload_sha256;
+        loop {
+            let signature_version = self.signature_version_for(base_url);
+            let e = match self
+                .execute_internal(
+                    &method,
+                    region,
+                    headers,
+                    query_params,
+                    bucket,
+                    object,
+                    data.as_ref().map(Arc::clone),
+                    trailing_checksum,
+                    use_signed_streaming,
+                    endpoint,
+                    signature_version,
+                    retry_head,
+                    &mut payload_sha256,
+                )
+                .await
+            {
+                Ok(r) => {
+                    self.shared.bucket_config_cache.invalidate_after(
+                        &method,
+                        bucket,
+                        object,
+                        query_params,
+                    );
+                    return Ok(r);
+                }
+                Err(e) => e,
+            };
+
+            let backoff = match &e {
+                _ if is_signature_mismatch(&e)
+                    && signature_version == SignatureVersion::V4
+                    && self.start_signature_v2_probe(base_url) =>
+                {
+                    if !self.probe_signature_v2(region, base_url).await {
                         return Err(e);
                     }
+                    None
+                }
+                // Retry only once on RetryHead error.
+                Error::S3Server(S3ServerError::S3Error(er))
+                    if retry_head && matches!(er.code(), MinioErrorCode::RetryHead) =>
+                {
+                    retry_head = false;
+                    None
+                }
+                e if retries < retry_config.max_retries && is_transient_error(e) => {
+                    retries += 1;
+                    Some(retry_config.backoff(retries))
                 }
                 _ => return Err(e),
-            },
-        };
-
-        // Retry only once on RetryHead error.
-        self.execute_internal(
-            &method,
-            region,
-            headers,
-            query_params,
-            bucket,
-            object,
-            data,
-            trailing_checksum,
-            use_signed_streaming,
-            false,
-        )
-        .await
+            };
+            log::debug!("replaying {method} request after error: {e}");
+            *headers = original_headers.clone();
+            if let Some(v) = backoff {
+                async_std::task::sleep(v).await;
+            }
+        }
     }
 
     /// Execute request with custom path (for admin APIs)
@@ -973,10 +1792,9 @@ impl MinioClient {
             };
             headers.add(X_AMZ_CONTENT_SHA256, sha256.clone());
 
-            let date = utc_now();
+            let date = self.shared.clock.now();
             headers.add(X_AMZ_DATE, to_amz_date(date));
-            if let Some(p) = &self.shared.provider {
-                let creds = p.fetch();
+            if let Some(creds) = self.credentials().await? {
                 if let Some(token) = &creds.session_token {
                     headers.add(X_AMZ_SECURITY_TOKEN, token);
                 }
@@ -1014,7 +1832,12 @@ impl MinioClient {
             req = req.body(Body::wrap_stream(stream));
         }
 
-        let resp: reqwest::Response = req.send().await.map_err(ValidationErr::from)?;
+        let req = req.build().map_err(ValidationErr::from)?;
+        let resp: reqwest::Response = self
+            .http_executor
+            .execute(req)
+            .await
+            .map_err(ValidationErr::from)?;
         if resp.status().is_success() {
             return Ok(resp);
         }
@@ -1022,7 +1845,7 @@ impl MinioClient {
         let mut resp = resp;
         let status_code = resp.status().as_u16();
         let headers: HeaderMap = mem::take(resp.headers_mut());
-        let body: Bytes = resp.bytes().await.map_err(ValidationErr::from)?;
+        let body: Bytes = self.read_body(resp).await?;
 
         let e: MinioErrorResponse = self.shared.create_minio_error_response(
             body,
@@ -1038,6 +1861,56 @@ impl MinioClient {
         Err(Error::S3Server(S3ServerError::S3Error(Box::new(e))))
     }
 
+    /// Sends an unsigned POST with a `multipart/form-data` body to `url` on
+    /// `base_url`; the request is authorized by the signed POST policy in the form.
+    pub(crate) async fn execute_post_form(
+        &self,
+        base_url: &BaseUrl,
+        url: &Url,
+        bucket: &BucketName,
+        boundary: &str,
+        form: SegmentedBytes,
+    ) -> Result<reqwest::Response, Error> {
+        let len = form.len();
+        let stream =
+            futures_util::stream::iter(form.into_iter().map(|b| -> Result<_, Error> { Ok(b) }));
+        let req = self
+            .http_client
+            .post(url.to_string())
+            .header(
+                CONTENT_TYPE,
+                format!("multipart/form-data; boundary={boundary}"),
+            )
+            .header(CONTENT_LENGTH, len)
+            .body(Body::wrap_stream(stream))
+            .build()
+            .map_err(ValidationErr::from)?;
+        let resp: reqwest::Response = self
+            .executor_for(base_url)?
+            .execute(req)
+            .await
+            .map_err(ValidationErr::from)?;
+        if resp.status().is_success() {
+            return Ok(resp);
+        }
+
+        let mut resp = resp;
+        let status_code = resp.status().as_u16();
+        let headers: HeaderMap = mem::take(resp.headers_mut());
+        let body: Bytes = self.read_body(resp).await?;
+        let e: MinioErrorResponse = self.shared.create_minio_error_response(
+            body,
+            status_code,
+            headers,
+            &Method::POST,
+            &url.path,
+            Some(bucket),
+            None,
+            false,
+        )?;
+        Err(Error::S3Server(S3ServerError::S3Error(Box::new(e))))
+    }
+
     async fn run_after_execute_hooks(
         &self,
         method: &Method,
@@ -1149,10 +2022,10 @@ impl MinioClient {
         object: &str,
         range: Option<(u64, Option<u64>)>,
     ) -> Result<reqwest::Response, Error> {
-        use crate::s3::utils::{check_bucket_name, check_object_name};
+        use crate::s3::utils::check_object_name;
 
         // Validate inputs (same as standard API)
-        check_bucket_name(bucket, true)?;
+        self.check_bucket_name(bucket)?;
         check_object_name(object)?;
 
         // Create typed wrappers after validation
@@ -1172,10 +2045,14 @@ impl MinioClient {
         )?;
 
         // Build headers in Multimap (single source of truth)
-        let date = utc_now();
+        let date = self.shared.clock.now();
+        let signature_version = self.signature_version_for(&self.shared.base_url);
         let mut headers = Multimap::new();
         headers.add(HOST, url.host_header_value());
-        headers.add(X_AMZ_DATE, to_amz_date(date));
+        match signature_version {
+            SignatureVersion::V2 => headers.add(X_AMZ_DATE, to_v2_date(date)),
+            SignatureVersion::V4 => headers.add(X_AMZ_DATE, to_amz_date(date)),
+        }
         headers.add(X_AMZ_CONTENT_SHA256, EMPTY_SHA256);
 
         // Add range header if specified
@@ -1188,24 +2065,42 @@ impl MinioClient {
         }
 
         // Sign the request if we have credentials
-        if let Some(provider) = &self.shared.provider {
-            let creds = provider.fetch();
+        if let Some(creds) = self.credentials().await? {
             if let Some(token) = &creds.session_token {
                 headers.add(X_AMZ_SECURITY_TOKEN, token);
             }
 
-            sign_v4_s3(
-                &self.shared.signing_key_cache,
-                &Method::GET,
-                &url.path,
-                region,
-                &mut headers,
-                &Multimap::new(),
-                &creds.access_key,
-                &creds.secret_key,
-                EMPTY_SHA256,
-                date,
-            );
+            match signature_version {
+                SignatureVersion::V2 => {
+                    let virtual_host = self.shared.base_url.is_virtual_host_style(
+                        &Method::GET,
+                        &Multimap::new(),
+                        &bucket_typed,
+                        Some(&object_typed),
+                    );
+                    sign_v2(
+                        &Method::GET,
+                        &url.path,
+                        virtual_host.then_some(bucket),
+                        &mut headers,
+                        &Multimap::new(),
+                        &creds.access_key,
+                        &creds.secret_key,
+                    );
+                }
+                SignatureVersion::V4 => sign_v4_s3(
+                    &self.shared.signing_key_cache,
+                    &Method::GET,
+                    &url.path,
+                    region,
+                    &mut headers,
+                    &Multimap::new(),
+                    &creds.access_key,
+                    &creds.secret_key,
+                    EMPTY_SHA256,
+                    date,
+                ),
+            }
         }
 
         // Build reqwest request and transfer all headers
@@ -1217,7 +2112,12 @@ impl MinioClient {
         }
 
         // Send request
-        let resp = req.send().await.map_err(ValidationErr::from)?;
+        let req = req.build().map_err(ValidationErr::from)?;
+        let resp = self
+            .http_executor
+            .execute(req)
+            .await
+            .map_err(ValidationErr::from)?;
 
         if resp.status().is_success() {
             return Ok(resp);
@@ -1263,10 +2163,33 @@ pub(crate) struct SharedClientItems {
     region_map: DashMap<String, String>,
     express: OnceLock<bool>,
     pub(crate) skip_region_lookup: bool,
+    ssl_cert_file: Option<PathBuf>,
+    ignore_cert_check: bool,
+    pub(crate) max_response_body_size: Option<u64>,
+    signature_version: SignatureVersion,
+    auto_signature_version: bool,
+    /// Signature version found to work per endpoint by the V2 fallback probe,
+    /// or `None` while the probe is in progress.
+    probed_signature_versions: DashMap<String, Option<SignatureVersion>>,
+    region_endpoints: HashMap<String, BaseUrl>,
+    /// Executors of the regional endpoints, created on first use.
+    endpoint_executors: DashMap<String, Arc<dyn HttpExecutor>>,
+    /// Whether the executor was set by the user; it then serves all endpoints.
+    custom_http_executor: bool,
+    connection_pool_config: ConnectionPoolConfig,
+    pub(crate) skip_payload_hashing: bool,
+    pub(crate) retry_config: RetryConfig,
     /// Cached precomputation of AWS Signature V4 signing keys.
     /// Stored per-client to support multiple clients with different credentials
     /// in the same process.
     pub(crate) signing_key_cache: RwLock<SigningKeyCache>,
+    user_agent: String,
+    pub(crate) bucket_config_cache: BucketConfigCache,
+    missing_bucket_cache_ttl: Option<std::time::Duration>,
+    /// Failed region lookups of missing buckets, with the time they failed.
+    missing_buckets: DashMap<String, (std::time::Instant, MinioErrorResponse)>,
+    bucket_name_strict: bool,
+    pub(crate) clock: Arc<dyn Clock>,
 }
 
 impl SharedClientItems {
@@ -1355,6 +2278,7 @@ impl SharedClientItems {
             301 | 307 | 400 => {
                 self.handle_redirect_response(http_status_code, method, &headers, bucket, retry)?
             }
+            304 => (MinioErrorCode::NotModified, "Not modified".into()),
             403 => (MinioErrorCode::AccessDenied, "Access denied".into()),
             404 => match object {
                 Some(_) => (MinioErrorCode::NoSuchKey, "Object does not exist".into()),
@@ -1366,13 +2290,21 @@ impl SharedClientItems {
                     ),
                 },
             },
+            412 => (
+                MinioErrorCode::PreconditionFailed,
+                "At least one of the pre-conditions you specified did not hold".into(),
+            ),
             405 | 501 => (
                 MinioErrorCode::MethodNotAllowed,
                 "The specified method is not allowed against this resource".into(),
             ),
-            409 => match bucket {
-                Some(_) => (MinioErrorCode::NoSuchBucket, "Bucket does not exist".into()),
-                None => (
+            409 => match (object, bucket) {
+                (Some(_), _) => (
+                    MinioErrorCode::ConditionalRequestConflict,
+                    "A conflicting conditional write to the object is in progress".into(),
+                ),
+                (None, Some(_)) => (MinioErrorCode::NoSuchBucket, "Bucket does not exist".into()),
+                (None, None) => (
                     MinioErrorCode::ResourceConflict,
                     "Request resource conflicts".into(),
                 ),
@@ -1412,3 +2344,848 @@ impl SharedClientItems {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{
+        MockExecutor, MockServer, RawRequest, raw_response, test_client_builder,
+    };
+    use crate::s3::object_content::ObjectContent;
+    use crate::s3::types::ToS3Request;
+    use crate::s3::utils::sha256_hash;
+    use std::collections::HashMap;
+
+    /// Returns the host and path of a GET of `bucket/object` sent by a client
+    /// for `base_url`.
+    fn object_url(base_url: &str, force_path_style: Option<bool>) -> (String, String) {
+        let mut builder = MinioClientBuilder::new(base_url.parse().unwrap());
+        if let Some(v) = force_path_style {
+            builder = builder.force_path_style(v);
+        }
+        let client = builder.build().unwrap();
+        let url = client
+            .shared
+            .base_url
+            .build_url(
+                &Method::GET,
+                &Region::new("us-east-1").unwrap(),
+                &Multimap::new(),
+                Some(&BucketName::new("bucket").unwrap()),
+                Some(&ObjectKey::new("object").unwrap()),
+            )
+            .unwrap();
+        (url.host, url.path)
+    }
+
+    #[test]
+    fn test_addressing_style_defaults_by_host() {
+        assert_eq!(
+            object_url("http://minio.example.com:9000", None),
+            ("minio.example.com".into(), "/bucket/object".into())
+        );
+        assert_eq!(
+            object_url("https://s3.us-east-1.amazonaws.com", None),
+            ("bucket.s3.us-east-1.amazonaws.com".into(), "/object".into())
+        );
+    }
+
+    #[test]
+    fn test_force_path_style_overrides_host_default() {
+        assert_eq!(
+            object_url("https://s3.us-east-1.amazonaws.com", Some(true)),
+            ("s3.us-east-1.amazonaws.com".into(), "/bucket/object".into())
+        );
+        assert_eq!(
+            object_url("http://minio.example.com:9000", Some(false)),
+            ("bucket.minio.example.com".into(), "/object".into())
+        );
+    }
+
+    #[test]
+    fn test_bucket_name_strict() {
+        let client = |strict: bool| {
+            MinioClientBuilder::new("http://localhost:9000".parse().unwrap())
+                .bucket_name_strict(strict)
+                .build()
+                .unwrap()
+        };
+        let (strict, lenient) = (client(true), client(false));
+
+        for name in ["My_Bucket", "my_bucket", "Logs:2024"] {
+            assert!(strict.check_bucket_name(name).is_err());
+            assert!(lenient.check_bucket_name(name).is_ok());
+        }
+        for name in ["192.168.1.1", "my..bucket", "my.-bucket"] {
+            assert!(lenient.check_bucket_name(name).is_err());
+        }
+
+        let delete = |client: &MinioClient| {
+            client
+                .delete_bucket("My_Bucket")
+                .unwrap()
+                .build()
+                .to_s3request()
+        };
+        assert!(matches!(
+            delete(&strict),
+            Err(ValidationErr::InvalidBucketName { .. })
+        ));
+        assert!(delete(&lenient).is_ok());
+    }
+
+    fn client_from_env(vars: &[(&str, &str)]) -> Result<MinioClient, Error> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        MinioClient::from_env_vars(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_from_env() {
+        let client = client_from_env(&[
+            ("MINIO_ENDPOINT", "https://play.min.io:9443"),
+            ("MINIO_ACCESS_KEY", "minio-access"),
+            ("MINIO_SECRET_KEY", "minio-secret"),
+            ("AWS_SESSION_TOKEN", "token"),
+            ("AWS_REGION", "eu-west-1"),
+        ])
+        .unwrap();
+        let base_url = &client.shared.base_url;
+        assert_eq!(base_url.host(), "play.min.io");
+        assert_eq!(base_url.port(), 9443);
+        assert!(base_url.https);
+        assert_eq!(base_url.region.as_str(), "eu-west-1");
+
+        let creds = client.shared.provider.as_ref().unwrap().fetch();
+        assert_eq!(creds.access_key, "minio-access");
+        assert_eq!(creds.secret_key, "minio-secret");
+        assert_eq!(creds.session_token.as_deref(), Some("token"));
+    }
+
+    #[test]
+    fn test_from_env_aws_fallbacks() {
+        let client = client_from_env(&[
+            ("MINIO_ENDPOINT", ""),
+            ("AWS_ENDPOINT_URL", "http://localhost:9000"),
+            ("AWS_ACCESS_KEY_ID", "aws-access"),
+            ("AWS_SECRET_ACCESS_KEY", "aws-secret"),
+        ])
+        .unwrap();
+        assert_eq!(client.shared.base_url.host(), "localhost");
+        assert!(!client.shared.base_url.https);
+        let creds = client.shared.provider.as_ref().unwrap().fetch();
+        assert_eq!(creds.access_key, "aws-access");
+        assert_eq!(creds.session_token, None);
+
+        let client = client_from_env(&[("MINIO_ENDPOINT", "http://localhost:9000")]).unwrap();
+        assert!(client.shared.provider.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_get_object_is_unsigned() {
+        let server = MockServer::scripted(vec![raw_response("200 OK", "", "hello")]).await;
+        let addr = server.addr();
+        let client = MinioClientBuilder::new(server.base_url())
+            .skip_region_lookup(true)
+            .build()
+            .unwrap();
+        assert!(client.is_anonymous());
+
+        let resp = client
+            .get_object("public", "dataset.csv")
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+        let content = resp.content().unwrap().to_segmented_bytes().await.unwrap();
+        assert_eq!(content.to_bytes().as_ref(), b"hello");
+
+        let req = &server.requests()[0].head;
+        assert!(req.starts_with("get /public/dataset.csv "), "{req}");
+        assert!(!req.contains("\r\nauthorization:"), "{req}");
+        assert!(req.contains(&format!("\r\nhost: {addr}\r\n")), "{req}");
+        assert!(req.contains("\r\nx-amz-date: "), "{req}");
+    }
+
+    #[tokio::test]
+    async fn test_put_object_replayed_after_503() {
+        let server = MockServer::scripted(vec![
+            raw_response(
+                "503 Service Unavailable",
+                "Content-Type: application/xml\r\n",
+                "<Error><Code>SlowDown</Code><Message>slow</Message></Error>",
+            ),
+            raw_response("200 OK", "ETag: \"abc\"\r\n", ""),
+        ])
+        .await;
+
+        let client = test_client_builder(server.base_url())
+            .retry_config(
+                RetryConfig::default()
+                    .max_retries(2)
+                    .initial_backoff(std::time::Duration::from_millis(1)),
+            )
+            .build()
+            .unwrap();
+
+        let data = "replayed body ".repeat(1000);
+        client
+            .put_object("bucket", "object", SegmentedBytes::from(data.clone()))
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        let sha256_header = |head: &str| {
+            head.lines()
+                .find(|l| l.starts_with("x-amz-content-sha256:"))
+                .map(str::to_string)
+        };
+        for RawRequest { head, body, .. } in &requests {
+            assert_eq!(body, data.as_bytes());
+            assert_eq!(
+                head.matches("\r\nx-amz-content-sha256:").count(),
+                1,
+                "{head}"
+            );
+        }
+        assert_eq!(
+            sha256_header(&requests[0].head),
+            sha256_header(&requests[1].head)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_put_object_content_stream_replayed_after_503() {
+        let server = MockServer::scripted(vec![
+            raw_response(
+                "503 Service Unavailable",
+                "Content-Type: application/xml\r\n",
+                "<Error><Code>SlowDown</Code><Message>slow</Message></Error>",
+            ),
+            raw_response("200 OK", "ETag: \"abc\"\r\n", ""),
+        ])
+        .await;
+        let client = test_client_builder(server.base_url())
+            .retry_config(
+                RetryConfig::default()
+                    .max_retries(1)
+                    .initial_backoff(std::time::Duration::from_millis(1)),
+            )
+            .build()
+            .unwrap();
+
+        // A one-shot stream is read into memory before the PUT, so the replay
+        // sends the same bytes rather than the rest of the stream.
+        let data = "streamed body ".repeat(1000);
+        let chunks: Vec<std::io::Result<Bytes>> = data
+            .as_bytes()
+            .chunks(100)
+            .map(|c| Ok(Bytes::copy_from_slice(c)))
+            .collect();
+        let content = ObjectContent::new_from_stream(
+            futures_util::stream::iter(chunks),
+            Some(data.len() as u64),
+        );
+        client
+            .put_object_content("bucket", "object", content)
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        for RawRequest { body, .. } in &requests {
+            assert_eq!(body, data.as_bytes());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_put_object_ignores_caller_content_sha256() {
+        let executor = MockExecutor::new(|_| http::Response::new(String::new()));
+        let client = test_client_builder("http://localhost:9000".parse().unwrap())
+            .http_executor(executor.clone())
+            .build()
+            .unwrap();
+        let mut extra_headers = Multimap::new();
+        extra_headers.add(X_AMZ_CONTENT_SHA256, EMPTY_SHA256);
+        client
+            .put_object_content("bucket", "object", "hello")
+            .unwrap()
+            .extra_headers(extra_headers)
+            .build()
+            .send()
+            .await
+            .unwrap();
+
+        let requests = executor.requests();
+        let digests: Vec<_> = requests[0]
+            .headers
+            .get_all(X_AMZ_CONTENT_SHA256)
+            .iter()
+            .map(|v| v.to_str().unwrap())
+            .collect();
+        assert!(
+            digests.contains(&sha256_hash(b"hello").as_str()),
+            "{digests:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_requests_carry_user_agent_with_app_info() {
+        let server = MockServer::scripted(vec![raw_response("200 OK", "", "")]).await;
+        let client = MinioClientBuilder::new(server.base_url())
+            .app_info(Some(("my-service".into(), "1.2.3".into())))
+            .skip_region_lookup(true)
+            .build()
+            .unwrap();
+        let expected = format!(
+            "MinIO ({}; {}) minio-rs/{} my-service/1.2.3",
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            env!("CARGO_PKG_VERSION")
+        );
+        assert_eq!(client.user_agent(), expected);
+
+        client
+            .bucket_exists("bucket")
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+
+        let head = &server.requests()[0].head;
+        let header = head
+            .lines()
+            .find_map(|l| l.strip_prefix("user-agent: "))
+            .unwrap_or_else(|| panic!("no user-agent in {head}"));
+        assert_eq!(header, expected.to_lowercase());
+    }
+
+    #[test]
+    fn test_user_agent_rejects_invalid_app_info() {
+        assert!(user_agent(None).unwrap().contains(" minio-rs/"));
+        for (name, version) in [
+            ("", "1.0"),
+            ("my app", "1.0"),
+            ("app", "1/0"),
+            ("app", "1.0\n"),
+        ] {
+            let app_info = (name.to_string(), version.to_string());
+            assert!(
+                matches!(
+                    user_agent(Some(&app_info)),
+                    Err(ValidationErr::InvalidConfig { .. })
+                ),
+                "{name:?}/{version:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_retry_config_backoff() {
+        let config = RetryConfig::default()
+            .initial_backoff(std::time::Duration::from_millis(100))
+            .max_backoff(std::time::Duration::from_millis(350));
+        assert_eq!(config.backoff(1).as_millis(), 100);
+        assert_eq!(config.backoff(2).as_millis(), 200);
+        assert_eq!(config.backoff(3).as_millis(), 350);
+        assert_eq!(config.backoff(40).as_millis(), 350);
+
+        assert!(is_transient_error(&Error::Network(
+            NetworkError::ServerError(503)
+        )));
+        assert!(!is_transient_error(&Error::Network(
+            NetworkError::ServerError(501)
+        )));
+    }
+
+    #[test]
+    fn test_from_env_errors() {
+        assert!(matches!(
+            client_from_env(&[
+                ("MINIO_ACCESS_KEY", "access"),
+                ("MINIO_SECRET_KEY", "secret")
+            ]),
+            Err(Error::Validation(ValidationErr::InvalidBaseUrl(_)))
+        ));
+        assert!(matches!(
+            client_from_env(&[
+                ("MINIO_ENDPOINT", "http://localhost:9000"),
+                ("MINIO_ACCESS_KEY", "access"),
+            ]),
+            Err(Error::Validation(ValidationErr::InvalidConfig { .. }))
+        ));
+    }
+
+    async fn get_bucket_encryption_with_limit(
+        chunked: bool,
+        limit: Option<u64>,
+    ) -> Result<GetBucketEncryptionResponse, Error> {
+        let mut body = String::from(
+            "<ServerSideEncryptionConfiguration><Rule><ApplyServerSideEncryptionByDefault>\
+             <SSEAlgorithm>AES256</SSEAlgorithm></ApplyServerSideEncryptionByDefault></Rule>",
+        );
+        body.push_str(&"<!-- padding -->".repeat(4096));
+        body.push_str("</ServerSideEncryptionConfiguration>");
+        let body = Bytes::from(body);
+        // Serves `body` with a `Content-Length` header unless `chunked` is set.
+        let executor = MockExecutor::new(move |_| {
+            let resp = http::Response::builder().status(200);
+            if chunked {
+                let chunks: Vec<Result<Bytes, std::io::Error>> = body
+                    .chunks(1024)
+                    .map(|c| Ok(Bytes::copy_from_slice(c)))
+                    .collect();
+                resp.body(Body::wrap_stream(futures_util::stream::iter(chunks)))
+            } else {
+                resp.header(CONTENT_LENGTH, body.len())
+                    .body(Body::from(body.clone()))
+            }
+            .unwrap()
+        });
+        let client = test_client_builder("http://localhost:9000".parse().unwrap())
+            .http_executor(executor)
+            .max_response_body_size(limit)
+            .build()
+            .unwrap();
+        client
+            .get_bucket_encryption("bucket")
+            .unwrap()
+            .build()
+            .send()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_max_response_body_size() {
+        for chunked in [false, true] {
+            let result = get_bucket_encryption_with_limit(chunked, Some(16 * 1024)).await;
+            assert!(
+                matches!(result, Err(Error::ResponseTooLarge { limit: 16384 })),
+                "chunked={chunked}: {result:?}"
+            );
+
+            let resp = get_bucket_encryption_with_limit(chunked, Some(1024 * 1024))
+                .await
+                .unwrap();
+            assert_eq!(resp.config().unwrap().sse_algorithm, "AES256");
+            get_bucket_encryption_with_limit(chunked, None)
+                .await
+                .unwrap();
+        }
+    }
+
+    /// Returns the signature version of every request sent so far.
+    fn signature_versions(executor: &MockExecutor) -> Vec<SignatureVersion> {
+        executor
+            .requests()
+            .iter()
+            .map(|r| match r.header(AUTHORIZATION) {
+                Some(v) if v.starts_with("AWS4-") => SignatureVersion::V4,
+                _ => SignatureVersion::V2,
+            })
+            .collect()
+    }
+
+    /// Answers with `SignatureDoesNotMatch` unless the request is signed with
+    /// Signature V4 and `accept_v4` is set, or with Signature V2 and
+    /// `reject_v2` is not set.
+    fn legacy_signature_executor(accept_v4: bool, reject_v2: bool) -> MockExecutor {
+        MockExecutor::new(move |req| {
+            let authorization = req.header(AUTHORIZATION).unwrap();
+            let accepted = if authorization.starts_with("AWS4-") {
+                accept_v4
+            } else {
+                !reject_v2
+            };
+            if accepted {
+                http::Response::builder()
+                    .status(200)
+                    .header(CONTENT_LENGTH, 5)
+                    .body("hello")
+            } else {
+                http::Response::builder()
+                    .status(403)
+                    .header(CONTENT_TYPE, "application/xml")
+                    .body(
+                        "<Error><Code>SignatureDoesNotMatch</Code>\
+                         <Message>signature mismatch</Message></Error>",
+                    )
+            }
+            .unwrap()
+        })
+    }
+
+    fn legacy_client(executor: &MockExecutor, auto: bool) -> MinioClient {
+        test_client_builder("http://localhost:9000".parse().unwrap())
+            .http_executor(executor.clone())
+            .auto_signature_version(auto)
+            .build()
+            .unwrap()
+    }
+
+    async fn get_legacy_object(client: &MinioClient) -> Result<GetObjectResponse, Error> {
+        client
+            .get_object("bucket", "object")
+            .unwrap()
+            .build()
+            .send()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_auto_signature_version_falls_back_to_v2() {
+        let executor = legacy_signature_executor(false, false);
+        let client = legacy_client(&executor, true);
+
+        get_legacy_object(&client).await.unwrap();
+        get_legacy_object(&client).await.unwrap();
+        assert_eq!(
+            signature_versions(&executor),
+            [
+                SignatureVersion::V4,
+                SignatureVersion::V4,
+                SignatureVersion::V2,
+                SignatureVersion::V2,
+                SignatureVersion::V2
+            ]
+        );
+        // The probe is a ListBuckets request.
+        let requests = executor.requests();
+        assert!(requests[1..3].iter().all(|r| r.url.path() == "/"));
+    }
+
+    #[tokio::test]
+    async fn test_auto_signature_version_keeps_v4_if_v2_fails() {
+        let executor = legacy_signature_executor(false, true);
+        let client = legacy_client(&executor, true);
+
+        for _ in 0..2 {
+            match get_legacy_object(&client).await {
+                Err(Error::S3Server(S3ServerError::S3Error(e))) => {
+                    assert_eq!(e.code(), MinioErrorCode::SignatureDoesNotMatch)
+                }
+                Err(e) => panic!("expected SignatureDoesNotMatch; got {e:?}"),
+                Ok(_) => panic!("expected SignatureDoesNotMatch"),
+            }
+        }
+        assert_eq!(
+            signature_versions(&executor),
+            [
+                SignatureVersion::V4,
+                SignatureVersion::V4,
+                SignatureVersion::V2,
+                SignatureVersion::V4
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_auto_signature_version_keeps_v4_if_v2_probe_errors() {
+        let executor = MockExecutor::new(|req| {
+            let code = if req.header(AUTHORIZATION).unwrap().starts_with("AWS4-") {
+                "SignatureDoesNotMatch"
+            } else {
+                "AccessDenied"
+            };
+            http::Response::builder()
+                .status(403)
+                .header(CONTENT_TYPE, "application/xml")
+                .body(format!(
+                    "<Error><Code>{code}</Code><Message>{code}</Message></Error>"
+                ))
+                .unwrap()
+        });
+        let client = legacy_client(&executor, true);
+
+        for _ in 0..2 {
+            match get_legacy_object(&client).await {
+                Err(Error::S3Server(S3ServerError::S3Error(e))) => {
+                    assert_eq!(e.code(), MinioErrorCode::SignatureDoesNotMatch)
+                }
+                Err(e) => panic!("expected SignatureDoesNotMatch; got {e:?}"),
+                Ok(_) => panic!("expected SignatureDoesNotMatch"),
+            }
+        }
+        assert_eq!(
+            signature_versions(&executor),
+            [
+                SignatureVersion::V4,
+                SignatureVersion::V4,
+                SignatureVersion::V2,
+                SignatureVersion::V4
+            ]
+        );
+    }
+
+    fn assert_signature_mismatch(result: Result<GetObjectResponse, Error>) {
+        match result {
+            Err(Error::S3Server(S3ServerError::S3Error(e))) => {
+                assert_eq!(e.code(), MinioErrorCode::SignatureDoesNotMatch)
+            }
+            Err(e) => panic!("expected SignatureDoesNotMatch; got {e:?}"),
+            Ok(_) => panic!("expected SignatureDoesNotMatch"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_auto_signature_version_bad_secret_keeps_v4() {
+        // With a wrong secret key every V4 request is rejected, while a server
+        // that ignores V2 signatures on public objects serves them anyway.
+        let executor = MockExecutor::new(|req| {
+            let v4 = req.header(AUTHORIZATION).unwrap().starts_with("AWS4-");
+            if !v4 && req.url.path() != "/" {
+                return http::Response::builder()
+                    .status(200)
+                    .header(CONTENT_LENGTH, 5)
+                    .body("hello".to_string())
+                    .unwrap();
+            }
+            http::Response::builder()
+                .status(403)
+                .header(CONTENT_TYPE, "application/xml")
+                .body(
+                    "<Error><Code>SignatureDoesNotMatch</Code>\
+                     <Message>signature mismatch</Message></Error>"
+                        .to_string(),
+                )
+                .unwrap()
+        });
+        let client = legacy_client(&executor, true);
+
+        assert_signature_mismatch(get_legacy_object(&client).await);
+        assert_signature_mismatch(get_legacy_object(&client).await);
+        assert_eq!(
+            signature_versions(&executor),
+            [
+                SignatureVersion::V4,
+                SignatureVersion::V4,
+                SignatureVersion::V2,
+                SignatureVersion::V4
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_auto_signature_version_not_used_if_v4_probe_succeeds() {
+        // V4 is rejected for the object only, so the mismatch is not caused by
+        // the signature version.
+        let executor = MockExecutor::new(|req| {
+            let v4 = req.header(AUTHORIZATION).unwrap().starts_with("AWS4-");
+            if v4 && req.url.path() == "/" {
+                return http::Response::builder()
+                    .status(200)
+                    .body("<ListAllMyBucketsResult/>".to_string())
+                    .unwrap();
+            }
+            http::Response::builder()
+                .status(403)
+                .header(CONTENT_TYPE, "application/xml")
+                .body(
+                    "<Error><Code>SignatureDoesNotMatch</Code>\
+                     <Message>signature mismatch</Message></Error>"
+                        .to_string(),
+                )
+                .unwrap()
+        });
+        let client = legacy_client(&executor, true);
+
+        assert_signature_mismatch(get_legacy_object(&client).await);
+        assert_eq!(
+            signature_versions(&executor),
+            [SignatureVersion::V4, SignatureVersion::V4]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_auto_signature_version_keeps_working_v4() {
+        let executor = legacy_signature_executor(true, false);
+        let client = legacy_client(&executor, true);
+
+        get_legacy_object(&client).await.unwrap();
+        assert_eq!(signature_versions(&executor), [SignatureVersion::V4]);
+    }
+
+    #[tokio::test]
+    async fn test_signature_mismatch_not_probed_by_default() {
+        let executor = legacy_signature_executor(false, false);
+        let client = legacy_client(&executor, false);
+
+        assert!(get_legacy_object(&client).await.is_err());
+        assert_eq!(signature_versions(&executor), [SignatureVersion::V4]);
+    }
+
+    /// Answers location lookups with `region-<suffix>` for bucket `bucket-<suffix>`.
+    fn regional_executor() -> MockExecutor {
+        MockExecutor::new(|req| {
+            if req.url.query() == Some("location=") {
+                let suffix = req.url.path().trim_start_matches("/bucket-");
+                http::Response::builder().status(200).body(format!(
+                    "<LocationConstraint>region-{suffix}</LocationConstraint>"
+                ))
+            } else {
+                http::Response::builder()
+                    .status(200)
+                    .header(CONTENT_LENGTH, 5)
+                    .body("hello".to_string())
+            }
+            .unwrap()
+        })
+    }
+
+    fn regional_client(http_executor: Option<MockExecutor>) -> MinioClient {
+        let mut builder = MinioClientBuilder::new("http://localhost:9000".parse().unwrap())
+            .provider(Some(StaticProvider::new("minioadmin", "minioadmin", None)))
+            .region_endpoint("region-a", "http://a.example.com:9000".parse().unwrap())
+            .region_endpoint("region-b", "http://b.example.com:9000".parse().unwrap());
+        if let Some(v) = http_executor {
+            builder = builder.http_executor(v);
+        }
+        builder.build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_region_endpoints_route_buckets() {
+        let executor = regional_executor();
+        let client = regional_client(Some(executor.clone()));
+
+        for bucket in ["bucket-a", "bucket-b", "bucket-c"] {
+            client
+                .get_object(bucket, "object")
+                .unwrap()
+                .build()
+                .send()
+                .await
+                .unwrap();
+        }
+        let urls: Vec<String> = executor
+            .requests()
+            .into_iter()
+            .filter(|r| r.url.query() != Some("location="))
+            .map(|r| r.url.to_string())
+            .collect();
+        assert_eq!(
+            urls,
+            [
+                "http://a.example.com:9000/bucket-a/object",
+                "http://b.example.com:9000/bucket-b/object",
+                "http://localhost:9000/bucket-c/object",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_region_endpoints_get_own_executor() {
+        let client = regional_client(None);
+        let executor = |region: &str| {
+            let region = Region::new(region).unwrap();
+            client
+                .executor_for(client.endpoint_for(None, &region))
+                .unwrap()
+        };
+
+        let a = executor("region-a");
+        assert!(Arc::ptr_eq(&a, &executor("region-a")));
+        assert!(!Arc::ptr_eq(&a, &executor("region-b")));
+        assert!(!Arc::ptr_eq(&a, &client.http_executor));
+        assert!(Arc::ptr_eq(&executor("region-c"), &client.http_executor));
+        assert_eq!(client.shared.endpoint_executors.len(), 2);
+    }
+
+    async fn put_with_idempotency(retry_config: RetryConfig) -> Vec<Option<String>> {
+        // Fails the first request with `SlowDown`.
+        let executor = MockExecutor::scripted(vec![
+            http::Response::builder()
+                .status(503)
+                .header(CONTENT_TYPE, "application/xml")
+                .body("<Error><Code>SlowDown</Code><Message>slow</Message></Error>")
+                .unwrap(),
+            http::Response::new(""),
+            http::Response::new(""),
+        ]);
+        let client = test_client_builder("http://localhost:9000".parse().unwrap())
+            .http_executor(executor.clone())
+            .retry_config(
+                retry_config
+                    .max_retries(1)
+                    .initial_backoff(std::time::Duration::from_millis(1)),
+            )
+            .build()
+            .unwrap();
+        for _ in 0..2 {
+            client
+                .put_object("bucket", "object", SegmentedBytes::from("data".to_string()))
+                .unwrap()
+                .build()
+                .send()
+                .await
+                .unwrap();
+        }
+        executor
+            .requests()
+            .iter()
+            .map(|r| r.header(X_MINIO_IDEMPOTENCY_ID).map(str::to_string))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_token_reused_by_replay() {
+        let tokens =
+            put_with_idempotency(RetryConfig::default().idempotency_header(X_MINIO_IDEMPOTENCY_ID))
+                .await;
+        assert_eq!(tokens.len(), 3);
+        let token = tokens[0].as_deref().unwrap();
+        assert_eq!(Uuid::parse_str(token).unwrap().get_version_num(), 4);
+        assert_eq!(tokens[1].as_deref(), Some(token));
+        assert_ne!(tokens[2].as_deref(), Some(token), "new request, new token");
+
+        let tokens = put_with_idempotency(RetryConfig::default()).await;
+        assert_eq!(tokens, [None, None, None]);
+    }
+
+    #[derive(Debug)]
+    struct FailingProvider;
+
+    #[async_trait::async_trait]
+    impl Provider for FailingProvider {
+        fn fetch(&self) -> Credentials {
+            panic!("fetch is not used to sign requests")
+        }
+
+        async fn credentials(&self, _http: &dyn HttpExecutor) -> Result<Credentials, Error> {
+            Err(ValidationErr::StsError("unavailable".into()).into())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_provider_credentials_error_fails_request() {
+        let executor = MockExecutor::new(|_| http::Response::new(String::new()));
+        let client = test_client_builder("http://localhost:9000".parse().unwrap())
+            .provider(Some(FailingProvider))
+            .http_executor(executor.clone())
+            .build()
+            .unwrap();
+
+        let result = client
+            .get_object("bucket", "object")
+            .unwrap()
+            .build()
+            .send()
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::Validation(ValidationErr::StsError(ref m))) if m == "unavailable"
+        ));
+        assert!(executor.requests().is_empty());
+    }
+}
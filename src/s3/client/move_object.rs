@@ -0,0 +1,71 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::builders::{MoveObject, MoveObjectBldr};
+use crate::s3::client::MinioClient;
+use crate::s3::error::ValidationErr;
+use crate::s3::types::{BucketName, ObjectKey};
+
+impl MinioClient {
+    /// Creates a [`MoveObject`] request builder to move an object to another key or bucket.
+    ///
+    /// The object is copied server-side and the source is deleted afterwards;
+    /// see [`MoveObject`] for the failure semantics. To execute the request, call
+    /// [`MoveObject::send()`], which returns a [`Result`] containing a
+    /// [`CopyObjectResponse`](crate::s3::response::CopyObjectResponse).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::MinioClient;
+    /// use minio::s3::creds::StaticProvider;
+    /// use minio::s3::http::BaseUrl;
+    /// use minio::s3::response::CopyObjectResponse;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let base_url = "http://localhost:9000/".parse::<BaseUrl>().unwrap();
+    ///     let static_provider = StaticProvider::new("minioadmin", "minioadmin", None);
+    ///     let client = MinioClient::new(base_url, Some(static_provider), None, None).unwrap();
+    ///     let resp: CopyObjectResponse = client
+    ///         .move_object("bucket-name", "old-name", "bucket-name", "new-name")
+    ///         .unwrap().build().send().await.unwrap();
+    /// }
+    /// ```
+    pub fn move_object<B1, O1, B2, O2>(
+        &self,
+        source_bucket: B1,
+        source_object: O1,
+        bucket: B2,
+        object: O2,
+    ) -> Result<MoveObjectBldr, ValidationErr>
+    where
+        B1: TryInto<BucketName>,
+        B1::Error: Into<ValidationErr>,
+        O1: TryInto<ObjectKey>,
+        O1::Error: Into<ValidationErr>,
+        B2: TryInto<BucketName>,
+        B2::Error: Into<ValidationErr>,
+        O2: TryInto<ObjectKey>,
+        O2::Error: Into<ValidationErr>,
+    {
+        Ok(MoveObject::builder()
+            .client(self.clone())
+            .source_bucket(source_bucket.try_into().map_err(Into::into)?)
+            .source_object(source_object.try_into().map_err(Into::into)?)
+            .bucket(bucket.try_into().map_err(Into::into)?)
+            .object(object.try_into().map_err(Into::into)?))
+    }
+}
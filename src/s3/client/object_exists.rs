@@ -0,0 +1,65 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::builders::{ObjectExists, ObjectExistsBldr};
+use crate::s3::client::MinioClient;
+use crate::s3::error::ValidationErr;
+use crate::s3::types::{BucketName, ObjectKey};
+
+impl MinioClient {
+    /// Creates a [`ObjectExists`] request builder to check if an object exists in S3.
+    ///
+    /// To execute the request, call [`ObjectExists::send()`](crate::s3::types::S3Api::send),
+    /// which returns a [`Result`] containing a [`ObjectExistsResponse`](crate::s3::response::ObjectExistsResponse).
+    /// A missing object yields `exists() == false` instead of a `NoSuchKey` error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::MinioClient;
+    /// use minio::s3::creds::StaticProvider;
+    /// use minio::s3::http::BaseUrl;
+    /// use minio::s3::response::ObjectExistsResponse;
+    /// use minio::s3::types::S3Api;
+    /// use minio::s3::response_traits::HasObject;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let base_url = "http://localhost:9000/".parse::<BaseUrl>().unwrap();
+    ///     let static_provider = StaticProvider::new("minioadmin", "minioadmin", None);
+    ///     let client = MinioClient::new(base_url, Some(static_provider), None, None).unwrap();
+    ///     let resp: ObjectExistsResponse = client
+    ///         .object_exists("bucket-name", "object-name")
+    ///         .unwrap().build().send().await.unwrap();
+    ///     println!("object '{}' exists: {}", resp.object().unwrap(), resp.exists());
+    /// }
+    /// ```
+    pub fn object_exists<B, O>(
+        &self,
+        bucket: B,
+        object: O,
+    ) -> Result<ObjectExistsBldr, ValidationErr>
+    where
+        B: TryInto<BucketName>,
+        B::Error: Into<ValidationErr>,
+        O: TryInto<ObjectKey>,
+        O::Error: Into<ValidationErr>,
+    {
+        Ok(ObjectExists::builder()
+            .client(self.clone())
+            .bucket(bucket.try_into().map_err(Into::into)?)
+            .object(object.try_into().map_err(Into::into)?))
+    }
+}
@@ -0,0 +1,104 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::client::{DEFAULT_REGION, MinioClient};
+use crate::s3::error::ValidationErr;
+use crate::s3::multimap_ext::Multimap;
+use crate::s3::types::{BucketName, ObjectKey, Region};
+use http::Method;
+
+impl MinioClient {
+    /// Returns the plain, unsigned URL of an object, e.g. to link to objects
+    /// in a public-read bucket.
+    ///
+    /// The URL is path- or virtual-host-style following the client's addressing
+    /// style, and the object key is encoded the same way as in signed requests.
+    /// The region is taken from the base URL or the region cache, falling back
+    /// to [`DEFAULT_REGION`]; no request is sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minio::s3::MinioClient;
+    /// use minio::s3::http::BaseUrl;
+    ///
+    /// let base_url = "http://localhost:9000/".parse::<BaseUrl>().unwrap();
+    /// let client = MinioClient::anonymous(base_url).unwrap();
+    /// let url = client.object_url("assets", "img/logo 1.png").unwrap();
+    /// assert_eq!(url, "http://localhost:9000/assets/img/logo%201.png");
+    /// ```
+    pub fn object_url<B, O>(&self, bucket: B, object: O) -> Result<String, ValidationErr>
+    where
+        B: TryInto<BucketName>,
+        B::Error: Into<ValidationErr>,
+        O: TryInto<ObjectKey>,
+        O::Error: Into<ValidationErr>,
+    {
+        let bucket: BucketName = bucket.try_into().map_err(Into::into)?;
+        let object: ObjectKey = object.try_into().map_err(Into::into)?;
+
+        let region = match self.get_region_from_url() {
+            Some(v) => Region::new(v)?,
+            None => match self.shared.region_map.get(bucket.as_str()) {
+                Some(v) => Region::new(v.value())?,
+                None => DEFAULT_REGION.clone(),
+            },
+        };
+
+        let url = self.shared.base_url.build_url(
+            &Method::GET,
+            &region,
+            &Multimap::new(),
+            Some(&bucket),
+            Some(&object),
+        )?;
+        Ok(url.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::s3::client::{MinioClient, MinioClientBuilder};
+
+    #[test]
+    fn object_url_path_style() {
+        let client = MinioClient::anonymous("http://localhost:9000".parse().unwrap()).unwrap();
+        assert_eq!(
+            client.object_url("bucket", "dir/a b+c&d.txt").unwrap(),
+            "http://localhost:9000/bucket/dir/a%20b%2Bc%26d.txt"
+        );
+    }
+
+    #[test]
+    fn object_url_virtual_host_style() {
+        let client = MinioClientBuilder::new("https://s3.amazonaws.com".parse().unwrap())
+            .build()
+            .unwrap();
+        client.set_bucket_region(
+            &"bucket".try_into().unwrap(),
+            &"eu-west-1".try_into().unwrap(),
+        );
+        assert_eq!(
+            client.object_url("bucket", "dir/café.txt").unwrap(),
+            "https://bucket.s3.eu-west-1.amazonaws.com/dir/caf%C3%A9.txt"
+        );
+    }
+
+    #[test]
+    fn object_url_rejects_invalid_bucket() {
+        let client = MinioClient::anonymous("http://localhost:9000".parse().unwrap()).unwrap();
+        assert!(client.object_url("B", "object").is_err());
+    }
+}
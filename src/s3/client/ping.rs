@@ -0,0 +1,118 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::client::MinioClient;
+use crate::s3::error::{Error, NetworkError, ValidationErr};
+use std::time::Duration;
+
+/// Default timeout of [`MinioClient::ping`].
+pub const DEFAULT_PING_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl MinioClient {
+    /// Checks that the server is alive by requesting MinIO's liveness endpoint
+    /// `/minio/health/live`, with a timeout of [`DEFAULT_PING_TIMEOUT`].
+    ///
+    /// Returns `Ok(())` if the server answers with HTTP 200 and
+    /// [`NetworkError::ServerError`] with the status code otherwise. A connection
+    /// failure or timeout is returned as [`ValidationErr::HttpError`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::MinioClient;
+    /// use minio::s3::http::BaseUrl;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let base_url = "http://localhost:9000/".parse::<BaseUrl>().unwrap();
+    ///     let client = MinioClient::anonymous(base_url).unwrap();
+    ///     client.ping().await.unwrap();
+    /// }
+    /// ```
+    pub async fn ping(&self) -> Result<(), Error> {
+        self.ping_with_timeout(DEFAULT_PING_TIMEOUT).await
+    }
+
+    /// Same as [`ping`](Self::ping), with the given timeout instead of the default.
+    pub async fn ping_with_timeout(&self, timeout: Duration) -> Result<(), Error> {
+        let url = format!("{}/minio/health/live", self.shared.base_url.to_url_string());
+        let req = self
+            .http_client
+            .get(url)
+            .timeout(timeout)
+            .build()
+            .map_err(ValidationErr::from)?;
+        let resp = self
+            .http_executor
+            .execute(req)
+            .await
+            .map_err(ValidationErr::from)?;
+
+        match resp.status().as_u16() {
+            200 => Ok(()),
+            status => Err(NetworkError::ServerError(status).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::{MockServer, raw_response};
+
+    #[tokio::test]
+    async fn ping_succeeds_on_200() {
+        let server = MockServer::scripted(vec![raw_response("200 OK", "", "")]).await;
+        let client = MinioClient::anonymous(server.base_url()).unwrap();
+
+        client.ping().await.unwrap();
+        assert_eq!(server.request_lines(), ["GET /minio/health/live HTTP/1.1"]);
+    }
+
+    #[tokio::test]
+    async fn ping_fails_on_503() {
+        let server =
+            MockServer::scripted(vec![raw_response("503 Service Unavailable", "", "")]).await;
+        let client = MinioClient::anonymous(server.base_url()).unwrap();
+
+        let err = client
+            .ping_with_timeout(Duration::from_secs(1))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, Error::Network(NetworkError::ServerError(503))),
+            "{err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn ping_times_out() {
+        let server = MockServer::start(|_| async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            String::new()
+        })
+        .await;
+        let client = MinioClient::anonymous(server.base_url()).unwrap();
+
+        let err = client
+            .ping_with_timeout(Duration::from_millis(100))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::Validation(ValidationErr::HttpError(e)) if e.is_timeout()),
+            "{err:?}"
+        );
+    }
+}
@@ -42,7 +42,7 @@ impl MinioClient {
     ///     let base_url = "http://localhost:9000/".parse::<BaseUrl>().unwrap();
     ///     let static_provider = StaticProvider::new("minioadmin", "minioadmin", None);
     ///     let client = MinioClient::new(base_url, Some(static_provider), None, None).unwrap();
-    ///     let config = SseConfig::default();
+    ///     let config = SseConfig::s3();
     ///     let resp: PutBucketEncryptionResponse = client
     ///         .put_bucket_encryption("bucket-name").unwrap()
     ///         .sse_config(config)
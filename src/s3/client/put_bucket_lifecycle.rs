@@ -42,7 +42,7 @@ impl MinioClient {
     ///     let client = MinioClient::new(base_url, Some(static_provider), None, None).unwrap();
     ///     let rules: Vec<LifecycleRule> = vec![LifecycleRule {
     ///         id: String::from("rule1"),
-    ///         filter: Filter {and_operator: None, prefix: Some(String::from("logs/")), tag: None},
+    ///         filter: Filter {prefix: Some(String::from("logs/")), ..Default::default()},
     ///         expiration_days: Some(365),
     ///         status: true,
     ///         ..Default::default()
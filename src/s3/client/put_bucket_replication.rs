@@ -64,9 +64,9 @@ impl MinioClient {
     ///             and_operator: Some(AndOperator {
     ///                 prefix: Some(String::from("TaxDocs")),
     ///                 tags: Some(tags),
+    ///                 ..Default::default()
     ///             }),
-    ///             prefix: None,
-    ///             tag: None,
+    ///             ..Default::default()
     ///         }),
     ///         id: Some(String::from("rule1")),
     ///         prefix: None,
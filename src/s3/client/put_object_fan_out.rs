@@ -0,0 +1,77 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::builders::{FanOutEntry, ObjectContent, PutObjectFanOut, PutObjectFanOutBldr};
+use crate::s3::client::MinioClient;
+use crate::s3::error::ValidationErr;
+use crate::s3::types::BucketName;
+
+impl MinioClient {
+    /// Creates a [`PutObjectFanOut`] request builder to store one upload as many objects.
+    ///
+    /// The content is sent once and written under the key of every entry, each
+    /// with its own metadata and tags. This is a MinIO extension; see
+    /// [`PutObjectFanOut`]. To execute the request, call [`PutObjectFanOut::send()`],
+    /// which returns a [`Result`] containing a
+    /// [`PutObjectFanOutResponse`](crate::s3::response::PutObjectFanOutResponse).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::MinioClient;
+    /// use minio::s3::builders::FanOutEntry;
+    /// use minio::s3::creds::StaticProvider;
+    /// use minio::s3::http::BaseUrl;
+    /// use minio::s3::types::ObjectKey;
+    /// use std::collections::HashMap;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let base_url = "http://localhost:9000/".parse::<BaseUrl>().unwrap();
+    ///     let static_provider = StaticProvider::new("minioadmin", "minioadmin", None);
+    ///     let client = MinioClient::new(base_url, Some(static_provider), None, None).unwrap();
+    ///     let entries = vec![
+    ///         FanOutEntry::builder()
+    ///             .key(ObjectKey::new("photo-small.jpg").unwrap())
+    ///             .user_metadata(HashMap::from([("variant".into(), "small".into())]))
+    ///             .build(),
+    ///         FanOutEntry::from(ObjectKey::new("photo-large.jpg").unwrap()),
+    ///     ];
+    ///     let resp = client
+    ///         .put_object_fan_out("bucket-name", entries, "image data")
+    ///         .unwrap().build().send().await.unwrap();
+    ///     for result in resp.results() {
+    ///         println!("{}: {:?}", result.key, result.error);
+    ///     }
+    /// }
+    /// ```
+    pub fn put_object_fan_out<B, C>(
+        &self,
+        bucket: B,
+        entries: Vec<FanOutEntry>,
+        content: C,
+    ) -> Result<PutObjectFanOutBldr, ValidationErr>
+    where
+        B: TryInto<BucketName>,
+        B::Error: Into<ValidationErr>,
+        C: Into<ObjectContent>,
+    {
+        Ok(PutObjectFanOut::builder()
+            .client(self.clone())
+            .bucket(bucket.try_into().map_err(Into::into)?)
+            .entries(entries)
+            .content(content))
+    }
+}
@@ -0,0 +1,70 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::builders::{ReencryptObject, ReencryptObjectBldr};
+use crate::s3::client::MinioClient;
+use crate::s3::error::ValidationErr;
+use crate::s3::sse::Sse;
+use crate::s3::types::{BucketName, ObjectKey};
+use std::sync::Arc;
+
+impl MinioClient {
+    /// Creates a [`ReencryptObject`] request builder to change the server-side encryption of an object.
+    ///
+    /// The object is copied onto itself with the new encryption settings; see
+    /// [`ReencryptObject`] for SSE-C sources and versioned buckets. To execute the
+    /// request, call [`ReencryptObject::send()`], which returns a [`Result`] containing a
+    /// [`CopyObjectResponse`](crate::s3::response::CopyObjectResponse).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::MinioClient;
+    /// use minio::s3::creds::StaticProvider;
+    /// use minio::s3::http::BaseUrl;
+    /// use minio::s3::response::CopyObjectResponse;
+    /// use minio::s3::sse::SseKms;
+    /// use std::sync::Arc;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let base_url = "https://play.min.io/".parse::<BaseUrl>().unwrap();
+    ///     let static_provider = StaticProvider::new("minioadmin", "minioadmin", None);
+    ///     let client = MinioClient::new(base_url, Some(static_provider), None, None).unwrap();
+    ///     let sse = Arc::new(SseKms::new("my-minio-key", None));
+    ///     let resp: CopyObjectResponse = client
+    ///         .reencrypt_object("bucket-name", "object-name", sse)
+    ///         .unwrap().build().send().await.unwrap();
+    /// }
+    /// ```
+    pub fn reencrypt_object<B, O>(
+        &self,
+        bucket: B,
+        object: O,
+        sse: Arc<dyn Sse>,
+    ) -> Result<ReencryptObjectBldr, ValidationErr>
+    where
+        B: TryInto<BucketName>,
+        B::Error: Into<ValidationErr>,
+        O: TryInto<ObjectKey>,
+        O::Error: Into<ValidationErr>,
+    {
+        Ok(ReencryptObject::builder()
+            .client(self.clone())
+            .bucket(bucket.try_into().map_err(Into::into)?)
+            .object(object.try_into().map_err(Into::into)?)
+            .sse(sse))
+    }
+}
@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::s3::builders::{StatObject, StatObjectBldr};
+use crate::s3::builders::{KeysStream, StatObject, StatObjectBldr, StatObjects};
 use crate::s3::client::MinioClient;
 use crate::s3::error::ValidationErr;
 use crate::s3::types::{BucketName, ObjectKey};
@@ -61,4 +61,50 @@ impl MinioClient {
             .bucket(bucket.try_into().map_err(Into::into)?)
             .object(object.try_into().map_err(Into::into)?))
     }
+
+    /// Creates a [`StatObjects`] request to retrieve metadata of many objects.
+    ///
+    /// The HEAD requests are issued with bounded concurrency, see
+    /// [`StatObjects::concurrency`]. The resulting stream yields each key
+    /// together with its own result, so a missing object does not abort the
+    /// remaining requests.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use minio::s3::MinioClient;
+    /// use minio::s3::creds::StaticProvider;
+    /// use minio::s3::http::BaseUrl;
+    /// use minio::s3::types::ObjectKey;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let base_url = "http://localhost:9000/".parse::<BaseUrl>().unwrap();
+    ///     let static_provider = StaticProvider::new("minioadmin", "minioadmin", None);
+    ///     let client = MinioClient::new(base_url, Some(static_provider), None, None).unwrap();
+    ///     let keys = ["a.txt", "b.txt"].map(|k| ObjectKey::new(k).unwrap());
+    ///     let mut stream = client
+    ///         .stat_objects("bucket-name", keys.into_iter())
+    ///         .unwrap()
+    ///         .concurrency(16)
+    ///         .to_stream()
+    ///         .await;
+    ///     while let Some((key, result)) = stream.next().await {
+    ///         println!("{key}: {:?}", result.map(|r| r.size()));
+    ///     }
+    /// }
+    /// ```
+    pub fn stat_objects<B, K>(&self, bucket: B, keys: K) -> Result<StatObjects, ValidationErr>
+    where
+        B: TryInto<BucketName>,
+        B::Error: Into<ValidationErr>,
+        K: Into<KeysStream>,
+    {
+        Ok(StatObjects::new(
+            self.clone(),
+            bucket.try_into().map_err(Into::into)?,
+            keys,
+        ))
+    }
 }
@@ -0,0 +1,266 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test doubles for the HTTP layer of a [`MinioClient`].
+
+use crate::s3::client::{HttpExecutor, MinioClient, MinioClientBuilder};
+use crate::s3::creds::StaticProvider;
+use crate::s3::http::BaseUrl;
+use async_trait::async_trait;
+use futures_util::future::BoxFuture;
+use http::{HeaderMap, Method};
+use reqwest::{Body, Request, Response, Url};
+use std::collections::VecDeque;
+use std::fmt::{self, Debug};
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Returns a client builder for `base_url` with static credentials and
+/// without region lookups.
+pub(crate) fn test_client_builder(base_url: BaseUrl) -> MinioClientBuilder {
+    MinioClientBuilder::new(base_url)
+        .provider(Some(StaticProvider::new("minioadmin", "minioadmin", None)))
+        .skip_region_lookup(true)
+}
+
+/// A request received by a [`MockServer`].
+#[derive(Clone, Debug)]
+pub(crate) struct RawRequest {
+    /// The request line, e.g. `GET /bucket?location= HTTP/1.1`.
+    pub(crate) line: String,
+    /// The request line and headers, lowercased.
+    pub(crate) head: String,
+    pub(crate) body: Vec<u8>,
+}
+
+/// Reads one HTTP/1.1 request, including its `Content-Length` body. Returns
+/// `None` if the connection is closed before the end of the headers.
+pub(crate) async fn read_request(sock: &mut TcpStream) -> Option<RawRequest> {
+    let mut req: Vec<u8> = Vec::new();
+    let mut buf = [0_u8; 4096];
+    let head_end = loop {
+        if let Some(i) = req.windows(4).position(|w| w == b"\r\n\r\n") {
+            break i + 4;
+        }
+        let n = sock.read(&mut buf).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        req.extend_from_slice(&buf[..n]);
+    };
+    let head = String::from_utf8_lossy(&req[..head_end]);
+    let line = head.lines().next().unwrap_or_default().to_string();
+    let head = head.to_lowercase();
+    let content_length: usize = head
+        .lines()
+        .find_map(|l| l.strip_prefix("content-length: "))
+        .map_or(0, |v| v.trim().parse().unwrap());
+    while req.len() < head_end + content_length {
+        let n = sock.read(&mut buf).await.unwrap();
+        assert_ne!(n, 0, "connection closed before end of body");
+        req.extend_from_slice(&buf[..n]);
+    }
+    Some(RawRequest {
+        line,
+        head,
+        body: req[head_end..].to_vec(),
+    })
+}
+
+/// Formats a raw HTTP/1.1 response; `headers` holds `Name: value\r\n` lines.
+pub(crate) fn raw_response(status: &str, headers: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+type Handler = Arc<dyn Fn(RawRequest) -> BoxFuture<'static, String> + Send + Sync>;
+
+/// Local HTTP/1.1 server answering one request per connection, for tests that
+/// go through the real transport. Every request is recorded before it is answered.
+pub(crate) struct MockServer {
+    addr: SocketAddr,
+    requests: Arc<Mutex<Vec<RawRequest>>>,
+}
+
+impl MockServer {
+    /// Answers the requests with the given raw responses, in order.
+    pub(crate) async fn scripted(responses: Vec<String>) -> Self {
+        let responses = Mutex::new(VecDeque::from(responses));
+        Self::start(move |req| {
+            let resp = responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or_else(|| panic!("unexpected request: {}", req.line));
+            async move { resp }
+        })
+        .await
+    }
+
+    /// Answers every request with the raw response returned by `handler`.
+    /// Connections are served concurrently.
+    pub(crate) async fn start<F, Fut>(handler: F) -> Self
+    where
+        F: Fn(RawRequest) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = String> + Send + 'static,
+    {
+        let handler: Handler = Arc::new(move |req| Box::pin(handler(req)));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut sock, _) = listener.accept().await.unwrap();
+                let (handler, recorded) = (handler.clone(), recorded.clone());
+                tokio::spawn(async move {
+                    let Some(req) = read_request(&mut sock).await else {
+                        return;
+                    };
+                    recorded.lock().unwrap().push(req.clone());
+                    let resp = handler(req).await;
+                    sock.write_all(resp.as_bytes()).await.ok();
+                    sock.shutdown().await.ok();
+                });
+            }
+        });
+        Self { addr, requests }
+    }
+
+    pub(crate) fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    pub(crate) fn base_url(&self) -> BaseUrl {
+        format!("http://{}", self.addr).parse().unwrap()
+    }
+
+    /// Returns a client for this server built by [`test_client_builder`].
+    pub(crate) fn client(&self) -> MinioClient {
+        test_client_builder(self.base_url()).build().unwrap()
+    }
+
+    /// Returns the requests received so far, in arrival order.
+    pub(crate) fn requests(&self) -> Vec<RawRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Returns the request lines received so far, in arrival order.
+    pub(crate) fn request_lines(&self) -> Vec<String> {
+        self.requests().into_iter().map(|r| r.line).collect()
+    }
+}
+
+/// Returns a client for `http://localhost:9000` that sends its requests
+/// through `executor`.
+pub(crate) fn test_client(executor: impl HttpExecutor) -> MinioClient {
+    test_client_builder("http://localhost:9000".parse().unwrap())
+        .http_executor(executor)
+        .build()
+        .unwrap()
+}
+
+/// A request sent through a [`MockExecutor`].
+#[derive(Clone, Debug)]
+pub(crate) struct RecordedRequest {
+    pub(crate) method: Method,
+    pub(crate) url: Url,
+    pub(crate) headers: HeaderMap,
+}
+
+impl RecordedRequest {
+    /// Returns the value of header `name`, if present.
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(|v| v.to_str().unwrap())
+    }
+}
+
+type Responder = dyn Fn(&RecordedRequest) -> http::Response<Body> + Send + Sync;
+
+/// In-memory [`HttpExecutor`] that records every request and answers it
+/// with a responder. Clones share the recorded requests.
+#[derive(Clone)]
+pub(crate) struct MockExecutor {
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    responder: Arc<Responder>,
+}
+
+impl MockExecutor {
+    /// Answers every request with the response built by `responder`.
+    pub(crate) fn new<F, B>(responder: F) -> Self
+    where
+        F: Fn(&RecordedRequest) -> http::Response<B> + Send + Sync + 'static,
+        B: Into<Body>,
+    {
+        Self {
+            requests: Arc::default(),
+            responder: Arc::new(move |req| responder(req).map(Into::into)),
+        }
+    }
+
+    /// Answers the requests with the given responses, in order.
+    pub(crate) fn scripted<B>(responses: Vec<http::Response<B>>) -> Self
+    where
+        B: Into<Body> + Send + 'static,
+    {
+        let responses = Mutex::new(VecDeque::from(responses));
+        Self::new(move |req| {
+            responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or_else(|| panic!("unexpected request: {} {}", req.method, req.url))
+        })
+    }
+
+    /// Returns the requests sent so far, in order.
+    pub(crate) fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Default for MockExecutor {
+    /// Answers every request with an empty 200.
+    fn default() -> Self {
+        Self::new(|_| http::Response::new(""))
+    }
+}
+
+impl Debug for MockExecutor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockExecutor")
+            .field("requests", &self.requests)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl HttpExecutor for MockExecutor {
+    async fn execute(&self, request: Request) -> Result<Response, reqwest::Error> {
+        let request = RecordedRequest {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+        };
+        let resp = (self.responder)(&request);
+        self.requests.lock().unwrap().push(request);
+        Ok(resp.into())
+    }
+}
@@ -15,6 +15,19 @@
 
 //! Credential providers
 
+mod profile;
+mod sts;
+
+use crate::s3::client::HttpExecutor;
+use crate::s3::error::Error;
+use async_trait::async_trait;
+
+pub use profile::ProfileProvider;
+pub use sts::{
+    AssumeRoleProvider, DEFAULT_DURATION_SECONDS, DEFAULT_EXPIRY_MARGIN, StsCredentials,
+    WebIdentityProvider, parse_sts_response,
+};
+
 /// Credentials containing access key, secret key, and optional session token.
 #[derive(Clone, Debug)]
 pub struct Credentials {
@@ -24,8 +37,18 @@ pub struct Credentials {
 }
 
 /// Provider trait to fetch credentials.
+#[async_trait]
 pub trait Provider: std::fmt::Debug {
+    /// Returns the current credentials without performing any I/O.
     fn fetch(&self) -> Credentials;
+
+    /// Returns the credentials to sign a request with, renewing them first if
+    /// needed. The client calls this for every signed request and fails the
+    /// request if it returns an error. Renewal requests are sent with the given
+    /// executor of the client, so they use its TLS settings.
+    async fn credentials(&self, _http: &dyn HttpExecutor) -> Result<Credentials, Error> {
+        Ok(self.fetch())
+    }
 }
 
 /// Static credential provider.
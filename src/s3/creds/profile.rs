@@ -0,0 +1,414 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Credential provider reading the shared AWS credentials and config files
+
+use super::{AssumeRoleProvider, Credentials, Provider};
+use crate::s3::client::HttpExecutor;
+use crate::s3::error::{Error, ValidationErr};
+use crate::s3::types::Region;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+const DEFAULT_PROFILE: &str = "default";
+
+type Profiles = HashMap<String, HashMap<String, String>>;
+
+/// Parses an INI formatted credentials or config file into profiles.
+///
+/// Section names of the form `[profile name]` (used by the config file) are
+/// normalized to `name`.
+fn parse_profiles(content: &str) -> Profiles {
+    let mut profiles = Profiles::new();
+    let mut current: Option<String> = None;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(section) = line.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+            let section = section.trim();
+            let name = section
+                .strip_prefix("profile ")
+                .map(str::trim)
+                .unwrap_or(section);
+            profiles.entry(name.to_string()).or_default();
+            current = Some(name.to_string());
+            continue;
+        }
+        if let (Some(profile), Some((key, value))) = (&current, line.split_once('=')) {
+            profiles
+                .entry(profile.clone())
+                .or_default()
+                .insert(key.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+    profiles
+}
+
+/// Returns the value of the first of `names` that is set and not empty.
+fn first_var(var: &impl Fn(&str) -> Option<OsString>, names: &[&str]) -> Option<OsString> {
+    names
+        .iter()
+        .filter_map(|name| var(name))
+        .find(|v| !v.is_empty())
+}
+
+fn read_profiles(path: &Path) -> Result<Profiles, ValidationErr> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(parse_profiles(&content)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Profiles::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[derive(Debug)]
+enum ProfileCredentials {
+    Static(Credentials),
+    AssumeRole(Box<AssumeRoleProvider>),
+}
+
+/// Credential provider using a named profile from the shared credentials files.
+///
+/// Settings are read from a credentials file and a config file; settings in the
+/// credentials file take precedence. The files and the profile are chosen by
+/// environment variables:
+///
+/// | Setting          | Variables (first one set wins)                                  | Default              |
+/// |------------------|-----------------------------------------------------------------|----------------------|
+/// | credentials file | `MINIO_SHARED_CREDENTIALS_FILE`, `AWS_SHARED_CREDENTIALS_FILE`  | `~/.aws/credentials` |
+/// | config file      | `MINIO_CONFIG_FILE`, `AWS_CONFIG_FILE`                          | `~/.aws/config`      |
+/// | profile          | `MINIO_PROFILE`, `AWS_PROFILE`                                  | `default`            |
+///
+/// Variables set to an empty value are ignored.
+///
+/// A profile with `aws_access_key_id`, `aws_secret_access_key` and optionally
+/// `aws_session_token` yields static credentials. A profile with `role_arn`
+/// instead assumes that role through [`AssumeRoleProvider`], using the keys of
+/// its `source_profile` (or its own keys) and the STS endpoint given by
+/// `endpoint_url`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use minio::s3::creds::ProfileProvider;
+///
+/// let provider = ProfileProvider::from_env().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ProfileProvider {
+    profile: String,
+    creds: ProfileCredentials,
+}
+
+impl ProfileProvider {
+    /// Returns a provider for the profile named by `MINIO_PROFILE` or
+    /// `AWS_PROFILE` (or `default`), reading the files named by the environment
+    /// variables.
+    pub fn from_env() -> Result<Self, ValidationErr> {
+        Self::from_env_vars(|name| std::env::var_os(name))
+    }
+
+    /// Returns a provider for the given profile, reading the files named by the
+    /// environment variables.
+    pub fn new(profile: &str) -> Result<Self, ValidationErr> {
+        Self::from_env_vars_with_profile(profile, |name| std::env::var_os(name))
+    }
+
+    fn from_env_vars(var: impl Fn(&str) -> Option<OsString>) -> Result<Self, ValidationErr> {
+        let profile = match first_var(&var, &["MINIO_PROFILE", "AWS_PROFILE"]) {
+            Some(v) => v.into_string().map_err(|v| {
+                ValidationErr::InvalidCredentialsProfile(format!(
+                    "profile name {v:?} is not valid UTF-8"
+                ))
+            })?,
+            None => DEFAULT_PROFILE.into(),
+        };
+        Self::from_env_vars_with_profile(&profile, var)
+    }
+
+    fn from_env_vars_with_profile(
+        profile: &str,
+        var: impl Fn(&str) -> Option<OsString>,
+    ) -> Result<Self, ValidationErr> {
+        let aws_dir =
+            first_var(&var, &["HOME", "USERPROFILE"]).map(|v| PathBuf::from(v).join(".aws"));
+        let credentials_file = first_var(
+            &var,
+            &[
+                "MINIO_SHARED_CREDENTIALS_FILE",
+                "AWS_SHARED_CREDENTIALS_FILE",
+            ],
+        )
+        .map(PathBuf::from)
+        .or_else(|| aws_dir.as_ref().map(|v| v.join("credentials")));
+        let config_file = first_var(&var, &["MINIO_CONFIG_FILE", "AWS_CONFIG_FILE"])
+            .map(PathBuf::from)
+            .or_else(|| aws_dir.as_ref().map(|v| v.join("config")));
+        Self::from_files(credentials_file.as_deref(), config_file.as_deref(), profile)
+    }
+
+    /// Returns a provider for the given profile read from explicit file paths.
+    pub fn from_files(
+        credentials_file: Option<&Path>,
+        config_file: Option<&Path>,
+        profile: &str,
+    ) -> Result<Self, ValidationErr> {
+        let mut profiles = match config_file {
+            Some(path) => read_profiles(path)?,
+            None => Profiles::new(),
+        };
+        if let Some(path) = credentials_file {
+            for (name, settings) in read_profiles(path)? {
+                profiles.entry(name).or_default().extend(settings);
+            }
+        }
+        Self::from_profiles(&profiles, profile)
+    }
+
+    fn from_profiles(profiles: &Profiles, profile: &str) -> Result<Self, ValidationErr> {
+        let settings = profiles.get(profile).ok_or_else(|| {
+            ValidationErr::InvalidCredentialsProfile(format!("profile '{profile}' not found"))
+        })?;
+
+        let creds = match settings.get("role_arn") {
+            Some(role_arn) => {
+                let source = match settings.get("source_profile") {
+                    Some(name) => profiles.get(name).ok_or_else(|| {
+                        ValidationErr::InvalidCredentialsProfile(format!(
+                            "source profile '{name}' of profile '{profile}' not found"
+                        ))
+                    })?,
+                    None => settings,
+                };
+                let source_creds = static_credentials(source, profile)?;
+                let endpoint = settings
+                    .get("endpoint_url")
+                    .or_else(|| source.get("endpoint_url"))
+                    .ok_or_else(|| {
+                        ValidationErr::InvalidCredentialsProfile(format!(
+                            "profile '{profile}' sets role_arn but no endpoint_url"
+                        ))
+                    })?;
+
+                let mut provider = AssumeRoleProvider::new(
+                    endpoint,
+                    &source_creds.access_key,
+                    &source_creds.secret_key,
+                )?
+                .role_arn(role_arn);
+                if let Some(v) = settings.get("role_session_name") {
+                    provider = provider.role_session_name(v);
+                }
+                if let Some(v) = settings.get("external_id") {
+                    provider = provider.external_id(v);
+                }
+                if let Some(v) = settings.get("duration_seconds") {
+                    provider = provider.duration_seconds(v.parse()?);
+                }
+                if let Some(v) = settings.get("region") {
+                    provider = provider.region(Region::new(v)?);
+                }
+                ProfileCredentials::AssumeRole(Box::new(provider))
+            }
+            None => ProfileCredentials::Static(static_credentials(settings, profile)?),
+        };
+
+        Ok(Self {
+            profile: profile.to_string(),
+            creds,
+        })
+    }
+
+    /// Returns the name of the profile this provider was created from.
+    pub fn profile(&self) -> &str {
+        &self.profile
+    }
+}
+
+fn static_credentials(
+    settings: &HashMap<String, String>,
+    profile: &str,
+) -> Result<Credentials, ValidationErr> {
+    let get = |key: &str| {
+        settings.get(key).cloned().ok_or_else(|| {
+            ValidationErr::InvalidCredentialsProfile(format!("profile '{profile}' has no {key}"))
+        })
+    };
+    Ok(Credentials {
+        access_key: get("aws_access_key_id")?,
+        secret_key: get("aws_secret_access_key")?,
+        session_token: settings.get("aws_session_token").cloned(),
+    })
+}
+
+#[async_trait]
+impl Provider for ProfileProvider {
+    fn fetch(&self) -> Credentials {
+        match &self.creds {
+            ProfileCredentials::Static(creds) => creds.clone(),
+            ProfileCredentials::AssumeRole(provider) => provider.fetch(),
+        }
+    }
+
+    async fn credentials(&self, http: &dyn HttpExecutor) -> Result<Credentials, Error> {
+        match &self.creds {
+            ProfileCredentials::Static(creds) => Ok(creds.clone()),
+            ProfileCredentials::AssumeRole(provider) => provider.credentials(http).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CREDENTIALS: &str = "\
+[default]
+aws_access_key_id = AKIADEFAULT
+aws_secret_access_key = default-secret
+
+# temporary credentials
+[dev]
+aws_access_key_id=AKIADEV
+aws_secret_access_key=dev-secret
+aws_session_token=dev-token
+";
+
+    const CONFIG: &str = "\
+[profile admin]
+role_arn = arn:minio:iam:::role/admin
+source_profile = default
+endpoint_url = https://minio.example.com:9000
+duration_seconds = 900
+";
+
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(content: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("minio-creds-{}", uuid::Uuid::new_v4()));
+            std::fs::write(&path, content).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_profile_provider_reads_two_profiles() {
+        let file = TempFile::new(CREDENTIALS);
+
+        let provider = ProfileProvider::from_files(Some(&file.0), None, "default").unwrap();
+        let creds = provider.fetch();
+        assert_eq!(creds.access_key, "AKIADEFAULT");
+        assert_eq!(creds.secret_key, "default-secret");
+        assert_eq!(creds.session_token, None);
+
+        let provider = ProfileProvider::from_files(Some(&file.0), None, "dev").unwrap();
+        let creds = provider.fetch();
+        assert_eq!(provider.profile(), "dev");
+        assert_eq!(creds.access_key, "AKIADEV");
+        assert_eq!(creds.secret_key, "dev-secret");
+        assert_eq!(creds.session_token.as_deref(), Some("dev-token"));
+    }
+
+    #[test]
+    fn test_profile_provider_missing_profile() {
+        let file = TempFile::new(CREDENTIALS);
+        let err = ProfileProvider::from_files(Some(&file.0), None, "prod").unwrap_err();
+        assert!(matches!(err, ValidationErr::InvalidCredentialsProfile(_)));
+    }
+
+    #[test]
+    fn test_profile_provider_role_arn_chains_into_assume_role() {
+        let credentials = TempFile::new(CREDENTIALS);
+        let config = TempFile::new(CONFIG);
+
+        let provider =
+            ProfileProvider::from_files(Some(&credentials.0), Some(&config.0), "admin").unwrap();
+        assert!(matches!(provider.creds, ProfileCredentials::AssumeRole(_)));
+    }
+
+    const OTHER_CREDENTIALS: &str = "\
+[default]
+aws_access_key_id = AKIAOTHER
+aws_secret_access_key = other-secret
+";
+
+    fn from_vars(vars: &[(&str, &Path)]) -> ProfileProvider {
+        ProfileProvider::from_env_vars(|name| {
+            vars.iter()
+                .find(|(k, _)| *k == name)
+                .map(|(_, v)| v.as_os_str().to_owned())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_profile_provider_minio_env_takes_precedence() {
+        let minio = TempFile::new(CREDENTIALS);
+        let aws = TempFile::new(OTHER_CREDENTIALS);
+        let empty = Path::new("");
+
+        let provider = from_vars(&[
+            ("MINIO_SHARED_CREDENTIALS_FILE", &minio.0),
+            ("AWS_SHARED_CREDENTIALS_FILE", &aws.0),
+            ("MINIO_PROFILE", Path::new("dev")),
+            ("AWS_PROFILE", Path::new("default")),
+        ]);
+        assert_eq!(provider.profile(), "dev");
+        assert_eq!(provider.fetch().access_key, "AKIADEV");
+
+        // Empty MINIO_* variables fall back to the AWS_* ones.
+        let provider = from_vars(&[
+            ("MINIO_SHARED_CREDENTIALS_FILE", empty),
+            ("AWS_SHARED_CREDENTIALS_FILE", &aws.0),
+            ("MINIO_PROFILE", empty),
+        ]);
+        assert_eq!(provider.profile(), "default");
+        assert_eq!(provider.fetch().access_key, "AKIAOTHER");
+    }
+
+    #[test]
+    fn test_profile_provider_minio_config_file() {
+        let credentials = TempFile::new(CREDENTIALS);
+        let config = TempFile::new(CONFIG);
+        let other = TempFile::new("");
+
+        let provider = from_vars(&[
+            ("MINIO_SHARED_CREDENTIALS_FILE", &credentials.0),
+            ("MINIO_CONFIG_FILE", &config.0),
+            ("AWS_CONFIG_FILE", &other.0),
+            ("MINIO_PROFILE", Path::new("admin")),
+        ]);
+        assert!(matches!(provider.creds, ProfileCredentials::AssumeRole(_)));
+    }
+
+    #[test]
+    fn test_parse_profiles_normalizes_config_sections() {
+        let profiles = parse_profiles(CONFIG);
+        let admin = profiles.get("admin").unwrap();
+        assert_eq!(admin.get("source_profile").unwrap(), "default");
+        assert_eq!(admin.get("duration_seconds").unwrap(), "900");
+    }
+}
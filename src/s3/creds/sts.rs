@@ -0,0 +1,770 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Credential providers backed by the Security Token Service (STS)
+//!
+//! [`AssumeRoleProvider`] and [`WebIdentityProvider`] exchange long-lived
+//! credentials or an identity token for temporary credentials, cache them and
+//! transparently request new ones shortly before they expire.
+
+use super::{Credentials, Provider};
+use crate::s3::client::HttpExecutor;
+use crate::s3::error::{Error, NetworkError, ValidationErr};
+use crate::s3::header_constants::{CONTENT_TYPE, HOST, X_AMZ_CONTENT_SHA256, X_AMZ_DATE};
+use crate::s3::multimap_ext::{Multimap, MultimapExt};
+use crate::s3::signer::{SigningKeyCache, sign_v4_sts};
+use crate::s3::types::Region;
+use crate::s3::utils::{
+    UtcTime, from_iso8601utc, get_text_option, get_text_result, sha256_hash, to_amz_date, utc_now,
+};
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use chrono::TimeDelta;
+use hyper::http::Method;
+use hyper::http::header::{HeaderName, HeaderValue};
+use std::path::PathBuf;
+use std::sync::RwLock;
+use url::Url;
+use xmltree::Element;
+
+const STS_API_VERSION: &str = "2011-06-15";
+
+/// Default lifetime requested for temporary credentials, in seconds.
+pub const DEFAULT_DURATION_SECONDS: u32 = 3600;
+
+/// Temporary credentials are refreshed this long before they expire.
+pub const DEFAULT_EXPIRY_MARGIN: TimeDelta = TimeDelta::seconds(60);
+
+/// Temporary credentials returned by STS along with their expiration time.
+#[derive(Clone, Debug)]
+pub struct StsCredentials {
+    pub credentials: Credentials,
+    pub expiration: UtcTime,
+}
+
+impl StsCredentials {
+    /// Returns true if these credentials expire within `margin` of `now`.
+    pub fn expires_within(&self, now: UtcTime, margin: TimeDelta) -> bool {
+        now + margin >= self.expiration
+    }
+}
+
+/// Parses the XML body of an `AssumeRole*` STS response.
+///
+/// Both `<AssumeRoleResponse>` and `<AssumeRoleWithWebIdentityResponse>` are
+/// accepted. An `<ErrorResponse>` body is returned as [`ValidationErr::StsError`].
+pub fn parse_sts_response(body: &str) -> Result<StsCredentials, ValidationErr> {
+    let root = Element::parse(body.as_bytes())?;
+
+    if root.name == "ErrorResponse" {
+        let error = root
+            .get_child("Error")
+            .ok_or(ValidationErr::xml_error("<Error> tag not found"))?;
+        let code = get_text_option(error, "Code").unwrap_or_default();
+        let message = get_text_option(error, "Message").unwrap_or_default();
+        return Err(ValidationErr::StsError(format!("{code}: {message}")));
+    }
+
+    let result = root
+        .children
+        .iter()
+        .filter_map(|v| v.as_element())
+        .find(|v| v.name.ends_with("Result"))
+        .ok_or_else(|| ValidationErr::xml_error(format!("<{}Result> tag not found", root.name)))?;
+    let creds = result
+        .get_child("Credentials")
+        .ok_or(ValidationErr::xml_error("<Credentials> tag not found"))?;
+
+    Ok(StsCredentials {
+        credentials: Credentials {
+            access_key: get_text_result(creds, "AccessKeyId")?,
+            secret_key: get_text_result(creds, "SecretAccessKey")?,
+            session_token: get_text_option(creds, "SessionToken"),
+        },
+        expiration: from_iso8601utc(&get_text_result(creds, "Expiration")?)?,
+    })
+}
+
+/// Cache of temporary credentials shared by the STS providers.
+#[derive(Debug)]
+struct CredentialsCache {
+    creds: RwLock<Option<StsCredentials>>,
+    refresh_lock: Mutex<()>,
+    expiry_margin: TimeDelta,
+}
+
+impl CredentialsCache {
+    fn new() -> Self {
+        Self {
+            creds: RwLock::new(None),
+            refresh_lock: Mutex::new(()),
+            expiry_margin: DEFAULT_EXPIRY_MARGIN,
+        }
+    }
+
+    /// Returns the cached credentials, if any.
+    fn get(&self) -> Option<Credentials> {
+        self.creds
+            .read()
+            .unwrap()
+            .as_ref()
+            .map(|c| c.credentials.clone())
+    }
+
+    fn fresh(&self, now: UtcTime) -> Option<Credentials> {
+        self.creds
+            .read()
+            .unwrap()
+            .as_ref()
+            .filter(|c| !c.expires_within(now, self.expiry_margin))
+            .map(|c| c.credentials.clone())
+    }
+
+    /// Returns the cached credentials, calling `refresh` first if they are
+    /// missing or expire within the expiry margin of `now`.
+    ///
+    /// If `refresh` fails while the cached credentials have not expired yet,
+    /// the error is logged and they are returned; otherwise it is returned.
+    async fn get_or_refresh<F, Fut>(&self, now: UtcTime, refresh: F) -> Result<Credentials, Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<StsCredentials, Error>>,
+    {
+        if let Some(c) = self.fresh(now) {
+            return Ok(c);
+        }
+
+        let _guard = self.refresh_lock.lock().await;
+        // Another task may have refreshed while we waited for the lock.
+        if let Some(c) = self.fresh(now) {
+            return Ok(c);
+        }
+
+        match refresh().await {
+            Ok(c) => {
+                let credentials = c.credentials.clone();
+                *self.creds.write().unwrap() = Some(c);
+                Ok(credentials)
+            }
+            Err(e) => {
+                let cached = self
+                    .creds
+                    .read()
+                    .unwrap()
+                    .as_ref()
+                    .filter(|c| now < c.expiration)
+                    .map(|c| c.credentials.clone());
+                match cached {
+                    Some(c) => {
+                        log::warn!("failed to refresh STS credentials, using cached ones: {e}");
+                        Ok(c)
+                    }
+                    None => Err(e),
+                }
+            }
+        }
+    }
+
+    /// Returns the cached credentials for [`Provider::fetch`], which cannot
+    /// request new ones. Before the first [`Provider::credentials`] call these
+    /// are empty, and a warning is logged.
+    fn fetch(&self) -> Credentials {
+        self.get().unwrap_or_else(|| {
+            log::warn!(
+                "no STS credentials requested yet; use Provider::credentials to request them"
+            );
+            Credentials {
+                access_key: String::new(),
+                secret_key: String::new(),
+                session_token: None,
+            }
+        })
+    }
+}
+
+fn parse_sts_endpoint(sts_endpoint: &str) -> Result<Url, ValidationErr> {
+    let url = Url::parse(sts_endpoint)
+        .map_err(|e| ValidationErr::StsError(format!("invalid STS endpoint: {e}")))?;
+    if url.host_str().is_none() {
+        return Err(ValidationErr::StsError(format!(
+            "invalid STS endpoint: {sts_endpoint}"
+        )));
+    }
+    Ok(url)
+}
+
+/// Sends a form-encoded POST to the STS endpoint with `http` and returns the
+/// response body.
+async fn post_form(
+    http: &dyn HttpExecutor,
+    url: Url,
+    headers: Multimap,
+    body: String,
+) -> Result<String, Error> {
+    let mut req = reqwest::Request::new(Method::POST, url);
+    for (key, values) in headers.iter_all() {
+        let name = HeaderName::try_from(key)
+            .map_err(|e| ValidationErr::StsError(format!("invalid header {key}: {e}")))?;
+        for value in values {
+            let value = HeaderValue::try_from(value)
+                .map_err(|e| ValidationErr::StsError(format!("invalid header {key}: {e}")))?;
+            req.headers_mut().append(name.clone(), value);
+        }
+    }
+    *req.body_mut() = Some(body.into());
+
+    let resp = http.execute(req).await.map_err(NetworkError::from)?;
+    let status = resp.status();
+    let text = resp.text().await.map_err(NetworkError::from)?;
+    if !status.is_success() && !text.contains("<ErrorResponse") {
+        return Err(NetworkError::ServerError(status.as_u16()).into());
+    }
+    Ok(text)
+}
+
+fn host_header(url: &Url) -> String {
+    let host = url.host_str().unwrap_or_default();
+    match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    }
+}
+
+fn encode_form(params: &[(&str, String)]) -> String {
+    let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+    for (key, value) in params {
+        serializer.append_pair(key, value);
+    }
+    serializer.finish()
+}
+
+/// Credential provider using the STS `AssumeRole` API.
+///
+/// Temporary credentials are requested with the given long-lived access and
+/// secret keys, cached, and refreshed shortly before they expire.
+///
+/// # Examples
+///
+/// ```no_run
+/// use minio::s3::creds::AssumeRoleProvider;
+///
+/// let provider = AssumeRoleProvider::new("https://play.min.io", "minioadmin", "minioadmin")
+///     .unwrap()
+///     .duration_seconds(900);
+/// ```
+#[derive(Debug)]
+pub struct AssumeRoleProvider {
+    sts_endpoint: Url,
+    access_key: String,
+    secret_key: String,
+    region: Region,
+    role_arn: Option<String>,
+    role_session_name: Option<String>,
+    policy: Option<String>,
+    external_id: Option<String>,
+    duration_seconds: u32,
+    cache: CredentialsCache,
+    signing_key_cache: RwLock<SigningKeyCache>,
+}
+
+impl AssumeRoleProvider {
+    /// Returns an `AssumeRole` provider for the given STS endpoint and credentials.
+    pub fn new(
+        sts_endpoint: &str,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<Self, ValidationErr> {
+        Ok(Self {
+            sts_endpoint: parse_sts_endpoint(sts_endpoint)?,
+            access_key: access_key.to_string(),
+            secret_key: secret_key.to_string(),
+            region: Region::default(),
+            role_arn: None,
+            role_session_name: None,
+            policy: None,
+            external_id: None,
+            duration_seconds: DEFAULT_DURATION_SECONDS,
+            cache: CredentialsCache::new(),
+            signing_key_cache: RwLock::new(SigningKeyCache::new()),
+        })
+    }
+
+    /// Sets the region used to sign STS requests.
+    pub fn region(mut self, region: Region) -> Self {
+        self.region = region;
+        self
+    }
+
+    /// Sets the ARN of the role to assume.
+    pub fn role_arn(mut self, role_arn: impl Into<String>) -> Self {
+        self.role_arn = Some(role_arn.into());
+        self
+    }
+
+    /// Sets the session name of the assumed role.
+    pub fn role_session_name(mut self, name: impl Into<String>) -> Self {
+        self.role_session_name = Some(name.into());
+        self
+    }
+
+    /// Sets a JSON session policy further restricting the temporary credentials.
+    pub fn policy(mut self, policy: impl Into<String>) -> Self {
+        self.policy = Some(policy.into());
+        self
+    }
+
+    /// Sets the external ID passed to the `AssumeRole` call.
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Sets the requested lifetime of the temporary credentials, in seconds.
+    pub fn duration_seconds(mut self, duration_seconds: u32) -> Self {
+        self.duration_seconds = duration_seconds;
+        self
+    }
+
+    /// Sets how long before expiry the temporary credentials are refreshed.
+    pub fn expiry_margin(mut self, margin: TimeDelta) -> Self {
+        self.cache.expiry_margin = margin;
+        self
+    }
+
+    async fn assume_role(&self, http: &dyn HttpExecutor) -> Result<StsCredentials, Error> {
+        let mut params = vec![
+            ("Action", "AssumeRole".to_string()),
+            ("Version", STS_API_VERSION.to_string()),
+            ("DurationSeconds", self.duration_seconds.to_string()),
+        ];
+        if let Some(v) = &self.role_arn {
+            params.push(("RoleArn", v.clone()));
+        }
+        if let Some(v) = &self.role_session_name {
+            params.push(("RoleSessionName", v.clone()));
+        }
+        if let Some(v) = &self.policy {
+            params.push(("Policy", v.clone()));
+        }
+        if let Some(v) = &self.external_id {
+            params.push(("ExternalId", v.clone()));
+        }
+        let body = encode_form(&params);
+        let content_sha256 = sha256_hash(body.as_bytes());
+        let date = utc_now();
+
+        let mut headers = Multimap::new();
+        headers.add(HOST, host_header(&self.sts_endpoint));
+        headers.add(CONTENT_TYPE, "application/x-www-form-urlencoded");
+        headers.add(X_AMZ_CONTENT_SHA256, &content_sha256);
+        headers.add(X_AMZ_DATE, to_amz_date(date));
+
+        sign_v4_sts(
+            &self.signing_key_cache,
+            &Method::POST,
+            self.sts_endpoint.path(),
+            &self.region,
+            &mut headers,
+            &Multimap::new(),
+            &self.access_key,
+            &self.secret_key,
+            &content_sha256,
+            date,
+        );
+
+        let text = post_form(http, self.sts_endpoint.clone(), headers, body).await?;
+        Ok(parse_sts_response(&text)?)
+    }
+}
+
+#[async_trait]
+impl Provider for AssumeRoleProvider {
+    fn fetch(&self) -> Credentials {
+        self.cache.fetch()
+    }
+
+    async fn credentials(&self, http: &dyn HttpExecutor) -> Result<Credentials, Error> {
+        self.cache
+            .get_or_refresh(utc_now(), || self.assume_role(http))
+            .await
+    }
+}
+
+/// Credential provider using the STS `AssumeRoleWithWebIdentity` API.
+///
+/// The identity token (for example a Kubernetes projected service account
+/// token) is re-read from its file on every refresh, so rotated tokens are
+/// picked up automatically.
+///
+/// # Examples
+///
+/// ```no_run
+/// use minio::s3::creds::WebIdentityProvider;
+///
+/// let provider = WebIdentityProvider::new(
+///     "https://minio.example.com",
+///     "/var/run/secrets/kubernetes.io/serviceaccount/token",
+/// )
+/// .unwrap()
+/// .role_arn("arn:minio:iam:::role/example");
+/// ```
+#[derive(Debug)]
+pub struct WebIdentityProvider {
+    sts_endpoint: Url,
+    token_file: PathBuf,
+    role_arn: Option<String>,
+    role_session_name: Option<String>,
+    policy: Option<String>,
+    duration_seconds: u32,
+    cache: CredentialsCache,
+}
+
+impl WebIdentityProvider {
+    /// Returns an `AssumeRoleWithWebIdentity` provider reading its token from `token_file`.
+    pub fn new(sts_endpoint: &str, token_file: impl Into<PathBuf>) -> Result<Self, ValidationErr> {
+        Ok(Self {
+            sts_endpoint: parse_sts_endpoint(sts_endpoint)?,
+            token_file: token_file.into(),
+            role_arn: None,
+            role_session_name: None,
+            policy: None,
+            duration_seconds: DEFAULT_DURATION_SECONDS,
+            cache: CredentialsCache::new(),
+        })
+    }
+
+    /// Returns a provider configured from the `AWS_WEB_IDENTITY_TOKEN_FILE`,
+    /// `AWS_ROLE_ARN` and `AWS_ROLE_SESSION_NAME` environment variables, as set
+    /// up for pods using projected service account tokens.
+    pub fn from_env(sts_endpoint: &str) -> Result<Self, ValidationErr> {
+        let token_file = std::env::var("AWS_WEB_IDENTITY_TOKEN_FILE").map_err(|_| {
+            ValidationErr::StsError("AWS_WEB_IDENTITY_TOKEN_FILE is not set".into())
+        })?;
+        let mut provider = Self::new(sts_endpoint, token_file)?;
+        provider.role_arn = std::env::var("AWS_ROLE_ARN").ok();
+        provider.role_session_name = std::env::var("AWS_ROLE_SESSION_NAME").ok();
+        Ok(provider)
+    }
+
+    /// Sets the ARN of the role to assume.
+    pub fn role_arn(mut self, role_arn: impl Into<String>) -> Self {
+        self.role_arn = Some(role_arn.into());
+        self
+    }
+
+    /// Sets the session name of the assumed role.
+    pub fn role_session_name(mut self, name: impl Into<String>) -> Self {
+        self.role_session_name = Some(name.into());
+        self
+    }
+
+    /// Sets a JSON session policy further restricting the temporary credentials.
+    pub fn policy(mut self, policy: impl Into<String>) -> Self {
+        self.policy = Some(policy.into());
+        self
+    }
+
+    /// Sets the requested lifetime of the temporary credentials, in seconds.
+    pub fn duration_seconds(mut self, duration_seconds: u32) -> Self {
+        self.duration_seconds = duration_seconds;
+        self
+    }
+
+    /// Sets how long before expiry the temporary credentials are refreshed.
+    pub fn expiry_margin(mut self, margin: TimeDelta) -> Self {
+        self.cache.expiry_margin = margin;
+        self
+    }
+
+    async fn assume_role_with_web_identity(
+        &self,
+        http: &dyn HttpExecutor,
+    ) -> Result<StsCredentials, Error> {
+        let token = async_std::fs::read_to_string(&self.token_file)
+            .await
+            .map_err(ValidationErr::from)?;
+
+        let mut params = vec![
+            ("Action", "AssumeRoleWithWebIdentity".to_string()),
+            ("Version", STS_API_VERSION.to_string()),
+            ("DurationSeconds", self.duration_seconds.to_string()),
+            ("WebIdentityToken", token.trim().to_string()),
+        ];
+        if let Some(v) = &self.role_arn {
+            params.push(("RoleArn", v.clone()));
+        }
+        if let Some(v) = &self.role_session_name {
+            params.push(("RoleSessionName", v.clone()));
+        }
+        if let Some(v) = &self.policy {
+            params.push(("Policy", v.clone()));
+        }
+
+        let mut headers = Multimap::new();
+        headers.add(HOST, host_header(&self.sts_endpoint));
+        headers.add(CONTENT_TYPE, "application/x-www-form-urlencoded");
+
+        let text = post_form(
+            http,
+            self.sts_endpoint.clone(),
+            headers,
+            encode_form(&params),
+        )
+        .await?;
+        Ok(parse_sts_response(&text)?)
+    }
+}
+
+#[async_trait]
+impl Provider for WebIdentityProvider {
+    fn fetch(&self) -> Credentials {
+        self.cache.fetch()
+    }
+
+    async fn credentials(&self, http: &dyn HttpExecutor) -> Result<Credentials, Error> {
+        self.cache
+            .get_or_refresh(utc_now(), || self.assume_role_with_web_identity(http))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::test_support::MockExecutor;
+    use chrono::{TimeZone, Utc};
+    use std::cell::Cell;
+
+    const ASSUME_ROLE_RESPONSE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<AssumeRoleResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleResult>
+    <AssumedRoleUser>
+      <Arn></Arn>
+      <AssumeRoleId></AssumeRoleId>
+    </AssumedRoleUser>
+    <Credentials>
+      <AccessKeyId>Y4RJU1RNFGK48LGO9I2S</AccessKeyId>
+      <SecretAccessKey>sYLRKS1Z7hSjluf6gEbb9066hnx315wHTiACPAjg</SecretAccessKey>
+      <Expiration>2019-08-08T20:26:12Z</Expiration>
+      <SessionToken>eyJhbGciOiJIUzUxMiIsInR5cCI6IkpXVCJ9</SessionToken>
+    </Credentials>
+  </AssumeRoleResult>
+  <ResponseMetadata>
+    <RequestId>c6104cbe-af31-11e0-8154-cbc7ccf896c7</RequestId>
+  </ResponseMetadata>
+</AssumeRoleResponse>"#;
+
+    const WEB_IDENTITY_RESPONSE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<AssumeRoleWithWebIdentityResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleWithWebIdentityResult>
+    <Credentials>
+      <AccessKeyId>Y4RJU1RNFGK48LGO9I2S</AccessKeyId>
+      <SecretAccessKey>sYLRKS1Z7hSjluf6gEbb9066hnx315wHTiACPAjg</SecretAccessKey>
+      <Expiration>2019-08-08T20:26:12.000Z</Expiration>
+      <SessionToken>token</SessionToken>
+    </Credentials>
+  </AssumeRoleWithWebIdentityResult>
+</AssumeRoleWithWebIdentityResponse>"#;
+
+    const ERROR_RESPONSE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ErrorResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <Error>
+    <Type></Type>
+    <Code>AccessDenied</Code>
+    <Message>Access denied: Invalid access key</Message>
+  </Error>
+</ErrorResponse>"#;
+
+    fn sts_credentials(access_key: &str, expiration: UtcTime) -> StsCredentials {
+        StsCredentials {
+            credentials: Credentials {
+                access_key: access_key.to_string(),
+                secret_key: "secret".to_string(),
+                session_token: Some("token".to_string()),
+            },
+            expiration,
+        }
+    }
+
+    #[test]
+    fn test_parse_assume_role_response() {
+        let c = parse_sts_response(ASSUME_ROLE_RESPONSE).unwrap();
+        assert_eq!(c.credentials.access_key, "Y4RJU1RNFGK48LGO9I2S");
+        assert_eq!(
+            c.credentials.secret_key,
+            "sYLRKS1Z7hSjluf6gEbb9066hnx315wHTiACPAjg"
+        );
+        assert_eq!(
+            c.credentials.session_token.as_deref(),
+            Some("eyJhbGciOiJIUzUxMiIsInR5cCI6IkpXVCJ9")
+        );
+        assert_eq!(
+            c.expiration,
+            Utc.with_ymd_and_hms(2019, 8, 8, 20, 26, 12).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_web_identity_response() {
+        let c = parse_sts_response(WEB_IDENTITY_RESPONSE).unwrap();
+        assert_eq!(c.credentials.access_key, "Y4RJU1RNFGK48LGO9I2S");
+        assert_eq!(c.credentials.session_token.as_deref(), Some("token"));
+        assert_eq!(
+            c.expiration,
+            Utc.with_ymd_and_hms(2019, 8, 8, 20, 26, 12).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_error_response() {
+        let err = parse_sts_response(ERROR_RESPONSE).unwrap_err();
+        assert!(matches!(err, ValidationErr::StsError(ref m) if m.starts_with("AccessDenied")));
+    }
+
+    #[tokio::test]
+    async fn test_cache_refreshes_before_expiry() {
+        let cache = CredentialsCache::new();
+        let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let calls = Cell::new(0);
+
+        let c = cache
+            .get_or_refresh(now, || async {
+                calls.set(calls.get() + 1);
+                Ok(sts_credentials("first", now + TimeDelta::minutes(15)))
+            })
+            .await
+            .unwrap();
+        assert_eq!(c.access_key, "first");
+        assert_eq!(calls.get(), 1);
+
+        // Still well within validity: served from cache.
+        let later = now + TimeDelta::minutes(10);
+        let c = cache
+            .get_or_refresh(later, || async {
+                calls.set(calls.get() + 1);
+                Ok(sts_credentials("second", later + TimeDelta::minutes(15)))
+            })
+            .await
+            .unwrap();
+        assert_eq!(c.access_key, "first");
+        assert_eq!(calls.get(), 1);
+
+        // Inside the expiry margin: refreshed.
+        let near_expiry = now + TimeDelta::minutes(15) - TimeDelta::seconds(30);
+        let c = cache
+            .get_or_refresh(near_expiry, || async {
+                calls.set(calls.get() + 1);
+                Ok(sts_credentials(
+                    "second",
+                    near_expiry + TimeDelta::minutes(15),
+                ))
+            })
+            .await
+            .unwrap();
+        assert_eq!(c.access_key, "second");
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cache_keeps_unexpired_credentials_on_refresh_failure() {
+        let cache = CredentialsCache::new();
+        let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+
+        cache
+            .get_or_refresh(now, || async {
+                Ok(sts_credentials("first", now + TimeDelta::seconds(30)))
+            })
+            .await
+            .unwrap();
+        let c = cache
+            .get_or_refresh(now, || async {
+                Err(ValidationErr::StsError("unavailable".into()).into())
+            })
+            .await
+            .unwrap();
+        assert_eq!(c.access_key, "first");
+    }
+
+    #[tokio::test]
+    async fn test_cache_returns_refresh_failure() {
+        let cache = CredentialsCache::new();
+        let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+
+        let err = cache
+            .get_or_refresh(now, || async {
+                Err(ValidationErr::StsError("unavailable".into()).into())
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Validation(ValidationErr::StsError(ref m)) if m == "unavailable"
+        ));
+
+        cache
+            .get_or_refresh(now, || async { Ok(sts_credentials("first", now)) })
+            .await
+            .unwrap();
+        let err = cache
+            .get_or_refresh(now, || async {
+                Err(ValidationErr::StsError("unavailable".into()).into())
+            })
+            .await;
+        assert!(err.is_err(), "expired credentials must not be kept");
+    }
+
+    #[tokio::test]
+    async fn test_assume_role_uses_client_executor() {
+        let executor = MockExecutor::new(|_| http::Response::new(ASSUME_ROLE_RESPONSE));
+        let provider = AssumeRoleProvider::new("https://sts.example.com:9000", "access", "secret")
+            .unwrap()
+            // The sample response has long expired.
+            .expiry_margin(TimeDelta::days(-365 * 100));
+
+        let c = provider.credentials(&executor).await.unwrap();
+        assert_eq!(c.access_key, "Y4RJU1RNFGK48LGO9I2S");
+        assert_eq!(provider.fetch().access_key, "Y4RJU1RNFGK48LGO9I2S");
+
+        let requests = executor.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::POST);
+        assert_eq!(requests[0].url.as_str(), "https://sts.example.com:9000/");
+        assert_eq!(requests[0].header("host"), Some("sts.example.com:9000"));
+        assert!(
+            requests[0]
+                .header("authorization")
+                .unwrap()
+                .starts_with("AWS4-HMAC-SHA256 Credential=access/")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_assume_role_returns_sts_error() {
+        let executor = MockExecutor::new(|_| {
+            http::Response::builder()
+                .status(403)
+                .body(ERROR_RESPONSE)
+                .unwrap()
+        });
+        let provider =
+            AssumeRoleProvider::new("https://sts.example.com", "access", "secret").unwrap();
+
+        let err = provider.credentials(&executor).await.unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Validation(ValidationErr::StsError(ref m)) if m.starts_with("AccessDenied")
+        ));
+    }
+}
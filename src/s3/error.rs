@@ -1,5 +1,6 @@
 use crate::s3::minio_error_response::MinioErrorResponse;
-use crate::s3::types::Region;
+use crate::s3::types::{Region, RetentionMode};
+use crate::s3::utils::UtcTime;
 use thiserror::Error;
 
 // Client side validation issues like invalid url or bucket name
@@ -120,8 +121,8 @@ pub enum ValidationErr {
     )]
     TooManyDeleteObjects(usize),
 
-    #[error("{}", sse_tls_required_message(.0))]
-    SseTlsRequired(Option<String>),
+    #[error("{operation}: {sse} must be performed over a secure connection")]
+    SseTlsRequired { operation: String, sse: String },
 
     #[error("Too much data in the stream - exceeds {0} bytes")]
     TooMuchData(u64),
@@ -129,6 +130,57 @@ pub enum ValidationErr {
     #[error("Not enough data in the stream; expected: {expected}, got: {got} bytes")]
     InsufficientData { expected: u64, got: u64 },
 
+    /// The stream supplied for an upload did not match its declared size. When the
+    /// stream is too long, reading stops shortly past the declared size, so `actual`
+    /// is a lower bound.
+    #[error("Content length mismatch; declared: {declared}, actual: {actual} bytes")]
+    ContentLengthMismatch { declared: u64, actual: u64 },
+
+    /// Some objects could not be removed while emptying a bucket, typically because
+    /// they are under COMPLIANCE retention, a legal hold, or GOVERNANCE retention
+    /// without bypass.
+    #[error(
+        "Bucket '{bucket}' could not be emptied: {failed} object(s) were not deleted; first failure: {first}"
+    )]
+    BucketNotEmptied {
+        bucket: String,
+        failed: usize,
+        first: String,
+    },
+
+    /// Some versions of an object could not be removed, typically because they are
+    /// under retention or a legal hold.
+    #[error(
+        "Not all versions of '{object}' in bucket '{bucket}' could be deleted: {failed} version(s) were not deleted; first failure: {first}"
+    )]
+    VersionsNotDeleted {
+        bucket: String,
+        object: String,
+        failed: usize,
+        first: String,
+    },
+
+    /// The object is protected by retention or a legal hold and cannot be deleted.
+    #[error("{}", object_locked_message(.object, .mode, .retain_until, *.legal_hold))]
+    ObjectLocked {
+        object: String,
+        mode: Option<RetentionMode>,
+        retain_until: Option<UtcTime>,
+        legal_hold: bool,
+    },
+
+    #[error("Invalid lifecycle rule '{id}': {reason}")]
+    InvalidLifecycleRule { id: String, reason: String },
+
+    #[error("Invalid range: {0}")]
+    InvalidRange(String),
+
+    #[error("Invalid server-side encryption configuration: {0}")]
+    InvalidSseConfig(String),
+
+    #[error("Invalid SSE-C key length: expected 32 bytes, got {0}")]
+    InvalidSseCustomerKeyLength(usize),
+
     #[error("Invalid legal hold: {0}")]
     InvalidLegalHold(String),
 
@@ -144,6 +196,12 @@ pub enum ValidationErr {
     #[error("URL build error: {0}")]
     UrlBuildError(String),
 
+    #[error("Invalid presigned URL: {0}")]
+    InvalidPresignedUrl(String),
+
+    #[error("Header '{0}' is set by the client and cannot be overridden")]
+    ReservedHeader(String),
+
     #[error("Region must be {bucket_region}, but passed {region}")]
     RegionMismatch {
         bucket_region: Region,
@@ -160,6 +218,19 @@ pub enum ValidationErr {
     #[error("Checksum mismatch; expected: {expected}, computed: {computed}")]
     ChecksumMismatch { expected: String, computed: String },
 
+    #[error("Invalid checksum: {0}")]
+    InvalidChecksum(String),
+
+    #[error(
+        "Verification of uploaded object '{object}' failed: {field} is '{actual}', expected '{expected}'"
+    )]
+    UploadVerificationFailed {
+        object: String,
+        field: String,
+        expected: String,
+        actual: String,
+    },
+
     #[error("Unknown event type: {0}")]
     UnknownEventType(String),
 
@@ -207,6 +278,12 @@ pub enum ValidationErr {
     #[error("Invalid copy directive: {0}")]
     InvalidCopyDirective(String),
 
+    #[error("Invalid move destination: {0}")]
+    InvalidMoveDestination(String),
+
+    #[error("Invalid fan-out upload: {0}")]
+    InvalidFanOut(String),
+
     #[error("{}", format_s3_object_error(.bucket, .object, .version.as_deref(), "InvalidComposeSourcePartSize", &format!("compose size {size} must be greater than {expected_size}")))]
     InvalidComposeSourcePartSize {
         bucket: String,
@@ -249,6 +326,15 @@ pub enum ValidationErr {
         error_message: String,
     },
 
+    #[error("STS error: {0}")]
+    StsError(String),
+
+    #[error("Invalid credentials profile: {0}")]
+    InvalidCredentialsProfile(String),
+
+    #[error("Duplicate tag key: {0}")]
+    DuplicateTagKey(String),
+
     #[error("Content length is unknown")]
     ContentLengthUnknown,
 
@@ -374,6 +460,13 @@ impl From<std::convert::Infallible> for ValidationErr {
 
 // Some convenience methods for creating ValidationErr instances
 impl ValidationErr {
+    pub fn invalid_lifecycle_rule(id: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidLifecycleRule {
+            id: id.into(),
+            reason: reason.into(),
+        }
+    }
+
     pub fn xml_error(message: impl Into<String>) -> Self {
         Self::XmlError {
             message: message.into(),
@@ -444,16 +537,34 @@ pub enum Error {
 
     #[error("Validation error occurred")]
     Validation(#[from] ValidationErr),
+
+    #[error("Response body exceeds the maximum size of {limit} bytes")]
+    ResponseTooLarge { limit: u64 },
+
+    #[error("Bucket '{bucket}' must have versioning enabled; versioning is {status}")]
+    VersioningRequired { bucket: String, status: String },
 }
 
 // region message helpers
 
 // Helper functions for formatting error messages with Option<String>
-fn sse_tls_required_message(prefix: &Option<String>) -> String {
-    match prefix {
-        Some(p) => format!("{p} SSE operation must be performed over a secure connection",),
-        None => "SSE operation must be performed over a secure connection".to_string(),
+fn object_locked_message(
+    object: &str,
+    mode: &Option<RetentionMode>,
+    retain_until: &Option<UtcTime>,
+    legal_hold: bool,
+) -> String {
+    let mut reasons: Vec<String> = Vec::new();
+    if let Some(mode) = mode {
+        match retain_until {
+            Some(until) => reasons.push(format!("{mode} retention until {}", until.to_rfc3339())),
+            None => reasons.push(format!("{mode} retention")),
+        }
+    }
+    if legal_hold {
+        reasons.push("legal hold".to_string());
     }
+    format!("Object '{object}' is locked by {}", reasons.join(" and "))
 }
 
 fn format_s3_object_error(
@@ -589,22 +700,17 @@ mod tests {
     }
 
     #[test]
-    fn test_validation_err_sse_tls_required_no_prefix() {
-        let err = ValidationErr::SseTlsRequired(None);
+    fn test_validation_err_sse_tls_required() {
+        let err = ValidationErr::SseTlsRequired {
+            operation: "GetObject".to_string(),
+            sse: "SSE-C".to_string(),
+        };
         assert_eq!(
             err.to_string(),
-            "SSE operation must be performed over a secure connection"
+            "GetObject: SSE-C must be performed over a secure connection"
         );
     }
 
-    #[test]
-    fn test_validation_err_sse_tls_required_with_prefix() {
-        let err = ValidationErr::SseTlsRequired(Some("Server-side encryption".to_string()));
-        let msg = err.to_string();
-        assert!(msg.contains("Server-side encryption"));
-        assert!(msg.contains("SSE operation"));
-    }
-
     #[test]
     fn test_validation_err_too_much_data() {
         let err = ValidationErr::TooMuchData(5_000_000_000);
@@ -626,6 +732,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validation_err_content_length_mismatch() {
+        let err = ValidationErr::ContentLengthMismatch {
+            declared: 1000,
+            actual: 1001,
+        };
+        assert_eq!(
+            err.to_string(),
+            "Content length mismatch; declared: 1000, actual: 1001 bytes"
+        );
+    }
+
+    #[test]
+    fn test_validation_err_versions_not_deleted() {
+        let err = ValidationErr::VersionsNotDeleted {
+            bucket: "my-bucket".to_string(),
+            object: "obj".to_string(),
+            failed: 1,
+            first: "obj?versionId=v1: AccessDenied Object is WORM protected".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Not all versions of 'obj' in bucket 'my-bucket' could be deleted: 1 version(s) were not deleted; first failure: obj?versionId=v1: AccessDenied Object is WORM protected"
+        );
+    }
+
+    #[test]
+    fn test_validation_err_bucket_not_emptied() {
+        let err = ValidationErr::BucketNotEmptied {
+            bucket: "my-bucket".to_string(),
+            failed: 2,
+            first: "obj?versionId=v1: AccessDenied Object is WORM protected".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Bucket 'my-bucket' could not be emptied: 2 object(s) were not deleted; first failure: obj?versionId=v1: AccessDenied Object is WORM protected"
+        );
+    }
+
+    #[test]
+    fn test_validation_err_invalid_lifecycle_rule() {
+        let err = ValidationErr::invalid_lifecycle_rule("rule1", "duplicate rule ID");
+        assert_eq!(
+            err.to_string(),
+            "Invalid lifecycle rule 'rule1': duplicate rule ID"
+        );
+    }
+
+    #[test]
+    fn test_validation_err_object_locked() {
+        let until = chrono::DateTime::parse_from_rfc3339("2030-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let err = ValidationErr::ObjectLocked {
+            object: "obj".to_string(),
+            mode: Some(RetentionMode::COMPLIANCE),
+            retain_until: Some(until),
+            legal_hold: true,
+        };
+        assert_eq!(
+            err.to_string(),
+            "Object 'obj' is locked by COMPLIANCE retention until 2030-01-01T00:00:00+00:00 and legal hold"
+        );
+    }
+
     #[test]
     fn test_validation_err_invalid_legal_hold() {
         let err = ValidationErr::InvalidLegalHold("MAYBE".to_string());
@@ -653,6 +824,24 @@ mod tests {
         assert_eq!(err.to_string(), "Invalid base URL: not a url");
     }
 
+    #[test]
+    fn test_validation_err_reserved_header() {
+        let err = ValidationErr::ReservedHeader("Authorization".to_string());
+        assert_eq!(
+            err.to_string(),
+            "Header 'Authorization' is set by the client and cannot be overridden"
+        );
+    }
+
+    #[test]
+    fn test_validation_err_invalid_presigned_url() {
+        let err = ValidationErr::InvalidPresignedUrl("missing X-Amz-Signature".to_string());
+        assert_eq!(
+            err.to_string(),
+            "Invalid presigned URL: missing X-Amz-Signature"
+        );
+    }
+
     #[test]
     fn test_validation_err_url_build_error() {
         let err = ValidationErr::UrlBuildError("missing scheme".to_string());
@@ -443,6 +443,25 @@ impl BaseUrl {
         url.host = format!("{s3_prefix}{region}.{domain_suffix}");
     }
 
+    /// Returns whether a request for `bucket` puts the bucket name in the host
+    /// (virtual-hosted style) rather than in the path.
+    pub(crate) fn is_virtual_host_style(
+        &self,
+        method: &Method,
+        query: &Multimap,
+        bucket: &BucketName,
+        object: Option<&ObjectKey>,
+    ) -> bool {
+        !(!self.virtual_style ||
+	// CreateBucket API requires path style in Amazon AWS S3.
+	    (method == Method::PUT && object.is_none() && query.is_empty()) ||
+	// GetBucketLocation API requires path style in Amazon AWS S3.
+	    query.contains_key("location") ||
+	// Use path style for bucket name containing '.' which causes
+	// SSL certificate validation error.
+	    (bucket.as_str().contains('.') && self.https))
+    }
+
     /// Builds URL from base URL for given parameters for S3 operation
     pub fn build_url(
         &self,
@@ -465,15 +484,7 @@ impl BaseUrl {
             return Ok(url);
         };
 
-        #[allow(clippy::nonminimal_bool)]
-        let enforce_path_style = true &&
-	// CreateBucket API requires path style in Amazon AWS S3.
-	    (method == Method::PUT && object.is_none() && query.is_empty()) ||
-	// GetBucketLocation API requires path style in Amazon AWS S3.
-	    query.contains_key("location") ||
-	// Use path style for bucket name containing '.' which causes
-	// SSL certificate validation error.
-	    (bucket.as_str().contains('.') && self.https);
+        let enforce_path_style = !self.is_virtual_host_style(method, query, bucket, object);
 
         if !self.aws_domain_suffix.is_empty() {
             self.build_aws_url(&mut url, bucket.as_str(), enforce_path_style, region)?;
@@ -482,7 +493,7 @@ impl BaseUrl {
         let mut host = String::from(&url.host);
         let mut path = String::new();
 
-        if enforce_path_style || !self.virtual_style {
+        if enforce_path_style {
             path.push('/');
             path.push_str(bucket.as_str());
         } else {
@@ -490,6 +501,13 @@ impl BaseUrl {
         }
 
         if let Some(object) = object {
+            // The HTTP client resolves `.` and `..` segments (also when percent-encoded)
+            // before sending, so the sent path would differ from the signed path.
+            if object.as_str().split('/').any(|v| v == "." || v == "..") {
+                return Err(ValidationErr::InvalidObjectName(format!(
+                    "'{object}' contains a '.' or '..' path segment"
+                )));
+            }
             if !object.as_str().starts_with('/') {
                 path.push('/');
             }
@@ -1133,6 +1151,68 @@ mod tests {
         assert!(url.path.contains("mybucket"));
     }
 
+    #[test]
+    fn test_baseurl_build_url_signed_path_matches_sent_path() {
+        let base: BaseUrl = "localhost:9000".parse().unwrap();
+        let bucket = BucketName::new("mybucket").unwrap();
+        let cases = [
+            ("my file (1).txt", "/mybucket/my%20file%20%281%29.txt"),
+            ("a+b", "/mybucket/a%2Bb"),
+            ("emoji-\u{1f642}.bin", "/mybucket/emoji-%F0%9F%99%82.bin"),
+            ("dir/sub dir/obj", "/mybucket/dir/sub%20dir/obj"),
+        ];
+
+        for (key, expected) in cases {
+            let object = ObjectKey::new(key).unwrap();
+            let url = base
+                .build_url(
+                    &Method::PUT,
+                    &Region::default(),
+                    &Multimap::default(),
+                    Some(&bucket),
+                    Some(&object),
+                )
+                .unwrap();
+            assert_eq!(url.path, expected, "key {key:?}");
+
+            let sent = reqwest::Url::parse(&url.to_string()).unwrap();
+            assert_eq!(sent.path(), url.path, "key {key:?}");
+        }
+    }
+
+    #[test]
+    fn test_baseurl_build_url_rejects_dot_segments() {
+        let base: BaseUrl = "localhost:9000".parse().unwrap();
+        let bucket = BucketName::new("mybucket").unwrap();
+
+        for key in ["a/./b", "a/../b", "./a", "a/.."] {
+            let object = ObjectKey::new(key).unwrap();
+            let result = base.build_url(
+                &Method::GET,
+                &Region::default(),
+                &Multimap::default(),
+                Some(&bucket),
+                Some(&object),
+            );
+            assert!(
+                matches!(result, Err(ValidationErr::InvalidObjectName(_))),
+                "key {key:?}"
+            );
+        }
+
+        let object = ObjectKey::new("a/.b/..c").unwrap();
+        assert!(
+            base.build_url(
+                &Method::GET,
+                &Region::default(),
+                &Multimap::default(),
+                Some(&bucket),
+                Some(&object),
+            )
+            .is_ok()
+        );
+    }
+
     #[test]
     fn test_baseurl_build_url_bucket_only() {
         let base: BaseUrl = "localhost:9000".parse().unwrap();
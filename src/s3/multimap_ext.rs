@@ -14,7 +14,7 @@
 // limitations under the License.
 
 use crate::s3::types::VersionId;
-use crate::s3::utils::url_encode;
+use crate::s3::utils::{canonical_query_string, url_encode};
 use std::borrow::Cow;
 use std::collections::BTreeMap;
 
@@ -102,37 +102,7 @@ impl MultimapExt for Multimap {
     }
 
     fn get_canonical_query_string(&self) -> String {
-        // Use BTreeMap for automatic sorting (avoids explicit sort)
-        let mut sorted: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
-        let mut total_len = 0usize;
-
-        for (key, values) in self.iter_all() {
-            for value in values {
-                // Pre-calculate total length to avoid reallocations.
-                // Most S3 query params are alphanumeric (uploadId, partNumber, versionId)
-                // so we use actual length + 20% buffer for occasional URL encoding.
-                total_len += key.len() + 1 + value.len() + 2; // key=value&
-            }
-            sorted
-                .entry(key.as_str())
-                .or_default()
-                .extend(values.iter().map(|s| s.as_str()));
-        }
-
-        // Add 20% buffer for URL encoding overhead
-        let mut query = String::with_capacity(total_len + total_len / 5);
-        for (key, values) in sorted {
-            for value in values {
-                if !query.is_empty() {
-                    query.push('&');
-                }
-                query.push_str(&url_encode(key));
-                query.push('=');
-                query.push_str(&url_encode(value));
-            }
-        }
-
-        query
+        canonical_query_string(self)
     }
 
     fn get_canonical_headers(&self) -> (String, String) {
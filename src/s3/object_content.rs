@@ -90,9 +90,32 @@ pub struct ObjectContent(ObjectContentInner);
 enum ObjectContentInner {
     Stream(Pin<Box<dyn Stream<Item = IoResult<Bytes>> + Send>>, Size),
     FilePath(PathBuf),
+    TempFile(TempFile),
     Bytes(SegmentedBytes),
 }
 
+/// Temporary file holding spilled content; removed when dropped.
+struct TempFile(PathBuf);
+
+impl TempFile {
+    /// Creates a new file in [`std::env::temp_dir`] that only the current user can access.
+    fn create() -> IoResult<(Self, fs::File)> {
+        let path = std::env::temp_dir().join(format!("minio-spill-{}", Uuid::new_v4()));
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let file = options.open(&path)?;
+        Ok((TempFile(path), file))
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
 impl From<Bytes> for ObjectContent {
     fn from(value: Bytes) -> Self {
         ObjectContent(ObjectContentInner::Bytes(SegmentedBytes::from(value)))
@@ -153,30 +176,81 @@ impl ObjectContent {
         ObjectContent(ObjectContentInner::Stream(r, size.into()))
     }
 
+    /// Create a new `ObjectContent` from an async reader.
+    ///
+    /// The reader is consumed sequentially and never seeked, so non-seekable
+    /// sources such as pipes and sockets are supported. When `size` is unknown,
+    /// a part size must be set on the upload.
+    pub fn new_from_reader(
+        mut r: impl async_std::io::Read + Unpin + Send + 'static,
+        size: impl Into<Size>,
+    ) -> Self {
+        let stream = async_stream::try_stream! {
+            let mut buf = vec![0u8; 64 * 1024];
+            loop {
+                let n = r.read(&mut buf).await?;
+                if n == 0 {
+                    break;
+                }
+                yield Bytes::copy_from_slice(&buf[..n]);
+            }
+        };
+        Self::new_from_stream(stream, size)
+    }
+
+    /// Buffers the content so that it can be consumed independently of its source.
+    ///
+    /// Content of up to `max_in_memory` bytes is held in memory. Larger content is
+    /// spilled to a temporary file in [`std::env::temp_dir`], which is removed once
+    /// the returned `ObjectContent` (or a stream created from it) is dropped.
+    pub async fn buffered(self, max_in_memory: u64) -> IoResult<Self> {
+        let (mut r, _) = self.to_stream().await?;
+        let mut segmented_bytes = SegmentedBytes::new();
+        while let Some(bytes) = r.next().await {
+            let bytes = bytes?;
+            if segmented_bytes.len() as u64 + bytes.len() as u64 <= max_in_memory {
+                segmented_bytes.append(bytes);
+                continue;
+            }
+
+            let (tmp, fp) = TempFile::create()?;
+            let mut fp = async_std::fs::File::from(fp);
+            for chunk in segmented_bytes.into_iter() {
+                fp.write_all(&chunk).await?;
+            }
+            fp.write_all(&bytes).await?;
+            while let Some(bytes) = r.next().await {
+                fp.write_all(&bytes?).await?;
+            }
+            fp.flush().await?;
+            return Ok(ObjectContent(ObjectContentInner::TempFile(tmp)));
+        }
+        Ok(ObjectContent(ObjectContentInner::Bytes(segmented_bytes)))
+    }
+
+    /// Returns the path of the file backing this content, if any. This is the case
+    /// for content created from a path and for content spilled to disk by
+    /// [`buffered`](Self::buffered).
+    pub fn file_path(&self) -> Option<&Path> {
+        match &self.0 {
+            ObjectContentInner::FilePath(path) | ObjectContentInner::TempFile(TempFile(path)) => {
+                Some(path)
+            }
+            _ => None,
+        }
+    }
+
     pub async fn to_stream(
         self,
     ) -> IoResult<(Pin<Box<dyn Stream<Item = IoResult<Bytes>> + Send>>, Size)> {
         match self.0 {
             ObjectContentInner::Stream(r, size) => Ok((r, size)),
 
-            ObjectContentInner::FilePath(path) => {
-                let mut file = async_std::fs::File::open(&path).await?;
-                let metadata = file.metadata().await?;
-                let size = metadata.len();
-
-                // Define a stream that reads the file in chunks
-                let stream = async_stream::try_stream! {
-                    let mut buf = vec![0u8; 8192];
-                    loop {
-                        let n = file.read(&mut buf).await?;
-                        if n == 0 {
-                            break;
-                        }
-                        yield Bytes::copy_from_slice(&buf[..n]);
-                    }
-                };
-
-                Ok((Box::pin(stream), Some(size).into()))
+            ObjectContentInner::FilePath(path) => Self::file_stream(&path, None).await,
+
+            ObjectContentInner::TempFile(tmp) => {
+                let path = tmp.0.clone();
+                Self::file_stream(&path, Some(tmp)).await
             }
 
             ObjectContentInner::Bytes(sb) => {
@@ -187,6 +261,32 @@ impl ObjectContent {
         }
     }
 
+    /// Streams the file at `path` in chunks. `guard` is kept alive until the
+    /// stream is dropped.
+    async fn file_stream(
+        path: &Path,
+        guard: Option<TempFile>,
+    ) -> IoResult<(Pin<Box<dyn Stream<Item = IoResult<Bytes>> + Send>>, Size)> {
+        let mut file = async_std::fs::File::open(path).await?;
+        let metadata = file.metadata().await?;
+        let size = metadata.len();
+
+        // Define a stream that reads the file in chunks
+        let stream = async_stream::try_stream! {
+            let _guard = guard;
+            let mut buf = vec![0u8; 8192];
+            loop {
+                let n = file.read(&mut buf).await?;
+                if n == 0 {
+                    break;
+                }
+                yield Bytes::copy_from_slice(&buf[..n]);
+            }
+        };
+
+        Ok((Box::pin(stream), Some(size).into()))
+    }
+
     #[allow(clippy::wrong_self_convention)]
     pub(crate) async fn to_content_stream(self) -> IoResult<ContentStream> {
         let (r, size) = self.to_stream().await?;
@@ -216,13 +316,24 @@ impl ObjectContent {
     /// If the file already exists, it will be replaced. If the parent directory
     /// does not exist, an attempt to create it will be made.
     pub async fn to_file(self, file_path: &Path) -> IoResult<u64> {
-        if file_path.is_dir() {
+        self.to_file_with_sync(file_path, false).await
+    }
+
+    /// Same as [`to_file`](Self::to_file), but if `sync_on_close` is true the
+    /// file is flushed to disk with `sync_all` before it is renamed, so the
+    /// content survives a crash once this returns. Syncing can be slow for
+    /// large files.
+    ///
+    /// All file operations run on a blocking thread pool and do not stall the
+    /// async runtime.
+    pub async fn to_file_with_sync(self, file_path: &Path, sync_on_close: bool) -> IoResult<u64> {
+        if is_dir(file_path).await {
             return Err(std::io::Error::other("path is a directory"));
         }
         let parent_dir = file_path.parent().ok_or(std::io::Error::other(format!(
             "path {file_path:?} does not have a parent directory"
         )))?;
-        if !parent_dir.is_dir() {
+        if !is_dir(parent_dir).await {
             async_std::fs::create_dir_all(parent_dir).await?;
         }
         let file_name = file_path.file_name().ok_or(std::io::Error::other(
@@ -249,11 +360,21 @@ impl ObjectContent {
             fp.write_all(&bytes).await?;
         }
         fp.flush().await?;
-        fs::rename(&tmp_file_path, file_path)?;
+        if sync_on_close {
+            fp.sync_all().await?;
+        }
+        drop(fp);
+        async_std::fs::rename(&tmp_file_path, file_path).await?;
         Ok(total_bytes_written)
     }
 }
 
+async fn is_dir(path: &Path) -> bool {
+    async_std::fs::metadata(path)
+        .await
+        .is_ok_and(|v| v.is_dir())
+}
+
 pub struct ContentStream {
     r: Pin<Box<dyn Stream<Item = IoResult<Bytes>> + Send>>,
     extra: Option<Bytes>,
@@ -329,3 +450,49 @@ impl ContentStream {
         Ok(segmented_bytes)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content_of(len: usize) -> (Vec<u8>, ObjectContent) {
+        let data: Vec<u8> = (0..len).map(|i| (i % 251) as u8).collect();
+        let chunks: Vec<IoResult<Bytes>> = data
+            .chunks(300)
+            .map(|c| Ok(Bytes::copy_from_slice(c)))
+            .collect();
+        let content = ObjectContent::new_from_stream(stream::iter(chunks), Some(len as u64));
+        (data, content)
+    }
+
+    #[tokio::test]
+    async fn test_buffered_spills_large_content_to_temp_file() {
+        let (data, content) = content_of(10 * 1024);
+
+        let content = content.buffered(1024).await.unwrap();
+        let path = content.file_path().unwrap().to_path_buf();
+        assert!(path.starts_with(std::env::temp_dir()));
+        let metadata = fs::metadata(&path).unwrap();
+        assert_eq!(metadata.len(), data.len() as u64);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+        }
+
+        let got = content.to_segmented_bytes().await.unwrap().to_bytes();
+        assert_eq!(got.as_ref(), data.as_slice());
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_buffered_keeps_small_content_in_memory() {
+        let (data, content) = content_of(1024);
+
+        let content = content.buffered(1024).await.unwrap();
+        assert!(content.file_path().is_none());
+
+        let got = content.to_segmented_bytes().await.unwrap().to_bytes();
+        assert_eq!(got.as_ref(), data.as_slice());
+    }
+}
@@ -0,0 +1,152 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Per-item outcome of a batch operation, such as downloading, deleting or
+/// statting many objects, in input order.
+///
+/// It can be collected from any iterator of items convertible into
+/// `Result<T, E>`, e.g. the [`DeleteResult`](crate::s3::response::DeleteResult)s
+/// returned by [`DeleteObjectsResponse::result`](crate::s3::response::DeleteObjectsResponse::result):
+///
+/// ```no_run
+/// # use minio::s3::error::Error;
+/// # use minio::s3::response::{BatchResult, DeleteError, DeleteObjectsResponse, DeletedObject};
+/// # fn f(resp: DeleteObjectsResponse) -> Result<(), Error> {
+/// let result: BatchResult<DeletedObject, DeleteError> = resp.result()?.into_iter().collect();
+/// for e in result.failed() {
+///     eprintln!("{}: {}", e.object_name, e.message);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BatchResult<T, E> {
+    results: Vec<Result<T, E>>,
+}
+
+impl<T, E> Default for BatchResult<T, E> {
+    fn default() -> Self {
+        Self {
+            results: Vec::new(),
+        }
+    }
+}
+
+impl<T, E> BatchResult<T, E> {
+    pub fn new(results: Vec<Result<T, E>>) -> Self {
+        Self { results }
+    }
+
+    /// The result of every item, in input order.
+    pub fn results(&self) -> &[Result<T, E>] {
+        &self.results
+    }
+
+    /// The items that succeeded, in input order.
+    pub fn succeeded(&self) -> impl Iterator<Item = &T> {
+        self.results.iter().filter_map(|r| r.as_ref().ok())
+    }
+
+    /// The errors of the items that failed, in input order.
+    pub fn failed(&self) -> impl Iterator<Item = &E> {
+        self.results.iter().filter_map(|r| r.as_ref().err())
+    }
+
+    /// Returns `true` if no item failed; also for an empty batch.
+    pub fn is_all_ok(&self) -> bool {
+        self.results.iter().all(Result::is_ok)
+    }
+
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Returns the succeeded items if none failed, and the errors otherwise.
+    pub fn into_result(self) -> Result<Vec<T>, Vec<E>> {
+        if self.is_all_ok() {
+            Ok(self.results.into_iter().flatten().collect())
+        } else {
+            Err(self.results.into_iter().filter_map(Result::err).collect())
+        }
+    }
+}
+
+impl<T, E, R: Into<Result<T, E>>> FromIterator<R> for BatchResult<T, E> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        Self::new(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T, E, R: Into<Result<T, E>>> Extend<R> for BatchResult<T, E> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        self.results.extend(iter.into_iter().map(Into::into));
+    }
+}
+
+impl<T, E> IntoIterator for BatchResult<T, E> {
+    type Item = Result<T, E>;
+    type IntoIter = std::vec::IntoIter<Result<T, E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(results: Vec<Result<u32, String>>) -> BatchResult<u32, String> {
+        results.into_iter().collect()
+    }
+
+    #[test]
+    fn all_ok() {
+        let b = batch(vec![Ok(1), Ok(2)]);
+        assert!(b.is_all_ok());
+        assert_eq!(b.succeeded().collect::<Vec<_>>(), [&1, &2]);
+        assert_eq!(b.failed().count(), 0);
+        assert_eq!(b.into_result(), Ok(vec![1, 2]));
+
+        let empty = batch(vec![]);
+        assert!(empty.is_all_ok());
+        assert_eq!(empty.into_result(), Ok(vec![]));
+    }
+
+    #[test]
+    fn all_failed() {
+        let b = batch(vec![Err("a".into()), Err("b".into())]);
+        assert!(!b.is_all_ok());
+        assert_eq!(b.succeeded().count(), 0);
+        assert_eq!(b.failed().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(b.into_result(), Err(vec!["a".into(), "b".into()]));
+    }
+
+    #[test]
+    fn mixed() {
+        let mut b = batch(vec![Ok(1), Err("a".into())]);
+        b.extend([Ok(3)]);
+        assert!(!b.is_all_ok());
+        assert_eq!(b.len(), 3);
+        assert_eq!(b.succeeded().collect::<Vec<_>>(), [&1, &3]);
+        assert_eq!(b.failed().collect::<Vec<_>>(), ["a"]);
+        assert_eq!(b.clone().into_iter().count(), 3);
+        assert_eq!(b.into_result(), Err(vec!["a".into()]));
+    }
+}
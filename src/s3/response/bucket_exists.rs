@@ -14,8 +14,8 @@
 // limitations under the License.
 
 use crate::impl_has_s3fields;
+use crate::s3::error::Error;
 use crate::s3::error::S3ServerError::S3Error;
-use crate::s3::error::{Error, ValidationErr};
 use crate::s3::minio_error_response::MinioErrorCode;
 use crate::s3::response_traits::{HasBucket, HasRegion};
 use crate::s3::types::{FromS3Response, S3Request};
@@ -26,7 +26,10 @@ use std::mem;
 
 /// Response from the [`bucket_exists()`](crate::s3::client::MinioClient::bucket_exists) API.
 ///
-/// Contains information about the existence of a bucket.
+/// Contains information about the existence of a bucket. A bucket that lives in
+/// another region is answered with a redirect carrying `x-amz-bucket-region`; it
+/// is reported as existing, and its region is stored in the client's region
+/// cache so that subsequent requests are signed for that region.
 #[derive(Clone, Debug)]
 pub struct BucketExistsResponse {
     request: S3Request,
@@ -48,11 +51,25 @@ impl FromS3Response for BucketExistsResponse {
     ) -> Result<Self, Error> {
         match response {
             Ok(mut resp) => Ok(Self {
-                request,
                 headers: mem::take(resp.headers_mut()),
-                body: resp.bytes().await.map_err(ValidationErr::HttpError)?,
+                body: request.client.read_body(resp).await?,
+                request,
                 exists: true,
             }),
+            Err(Error::S3Server(S3Error(mut e)))
+                if matches!(e.code(), MinioErrorCode::PermanentRedirect)
+                    && e.bucket_region().is_some() =>
+            {
+                if let (Some(bucket), Some(region)) = (&request.bucket, e.bucket_region()) {
+                    request.client.set_bucket_region(bucket, &region);
+                }
+                Ok(Self {
+                    request,
+                    headers: e.take_headers(),
+                    body: Bytes::new(),
+                    exists: true,
+                })
+            }
             Err(Error::S3Server(S3Error(mut e)))
                 if matches!(e.code(), MinioErrorCode::NoSuchBucket) =>
             {
@@ -74,3 +91,88 @@ impl BucketExistsResponse {
         self.exists
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::MinioClient;
+    use crate::s3::creds::StaticProvider;
+    use crate::s3::header_constants::X_AMZ_BUCKET_REGION;
+    use crate::s3::http::BaseUrl;
+    use crate::s3::minio_error_response::MinioErrorResponse;
+    use crate::s3::types::{BucketName, ToS3Request};
+
+    fn dummy_client() -> MinioClient {
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let provider = StaticProvider::new("minioadmin", "minioadmin", None);
+        MinioClient::new(base_url, Some(provider), None, None).unwrap()
+    }
+
+    fn request(client: &MinioClient) -> S3Request {
+        client
+            .bucket_exists("bucket")
+            .unwrap()
+            .build()
+            .to_s3request()
+            .unwrap()
+    }
+
+    fn server_error(code: MinioErrorCode, headers: HeaderMap) -> Error {
+        let e = MinioErrorResponse::new(
+            headers,
+            code,
+            None,
+            "/bucket".into(),
+            String::new(),
+            String::new(),
+            BucketName::new("bucket").ok(),
+            None,
+        );
+        Error::S3Server(S3Error(Box::new(e)))
+    }
+
+    #[tokio::test]
+    async fn test_bucket_exists_ok() {
+        let client = dummy_client();
+        let http_resp = http::Response::builder().status(200).body("").unwrap();
+        let resp = BucketExistsResponse::from_s3response(request(&client), Ok(http_resp.into()))
+            .await
+            .unwrap();
+        assert!(resp.exists());
+    }
+
+    #[tokio::test]
+    async fn test_bucket_exists_not_found() {
+        let client = dummy_client();
+        let err = server_error(MinioErrorCode::NoSuchBucket, HeaderMap::new());
+        let resp = BucketExistsResponse::from_s3response(request(&client), Err(err))
+            .await
+            .unwrap();
+        assert!(!resp.exists());
+    }
+
+    #[tokio::test]
+    async fn test_bucket_exists_redirect_updates_region_cache() {
+        let client = dummy_client();
+        let mut headers = HeaderMap::new();
+        headers.insert(X_AMZ_BUCKET_REGION, "eu-west-1".parse().unwrap());
+        let err = server_error(MinioErrorCode::PermanentRedirect, headers);
+
+        let resp = BucketExistsResponse::from_s3response(request(&client), Err(err))
+            .await
+            .unwrap();
+        assert!(resp.exists());
+        assert_eq!(
+            client.get_region_cached("bucket", &None).await.unwrap(),
+            "eu-west-1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bucket_exists_redirect_without_region_is_error() {
+        let client = dummy_client();
+        let err = server_error(MinioErrorCode::PermanentRedirect, HeaderMap::new());
+        let result = BucketExistsResponse::from_s3response(request(&client), Err(err)).await;
+        assert!(result.is_err());
+    }
+}
@@ -13,13 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::impl_has_s3fields;
+use crate::s3::error::{Error, S3ServerError, ValidationErr};
+use crate::s3::minio_error_response::MinioErrorResponse;
 use crate::s3::response_traits::{
-    HasBucket, HasChecksumHeaders, HasEtagFromBody, HasObject, HasRegion, HasVersion,
+    HasBucket, HasBucketKeyEnabled, HasChecksumHeaders, HasEtagFromBody, HasObject, HasRegion,
+    HasS3Fields, HasVersion,
 };
-use crate::s3::types::S3Request;
-use crate::{impl_from_s3response, impl_has_s3fields};
-use bytes::Bytes;
+use crate::s3::types::{FromS3Response, S3Request};
+use crate::s3::utils::{UtcTime, from_iso8601utc, get_text_result};
+use bytes::{Buf, Bytes};
 use http::HeaderMap;
+use xmltree::Element;
 
 /// Base response struct that contains common functionality for S3 operations
 #[derive(Clone, Debug)]
@@ -29,7 +34,27 @@ pub struct S3Response2 {
     pub(crate) body: Bytes,
 }
 
-impl_from_s3response!(S3Response2);
+#[async_trait::async_trait]
+impl FromS3Response for S3Response2 {
+    async fn from_s3response(
+        request: S3Request,
+        response: Result<reqwest::Response, Error>,
+    ) -> Result<Self, Error> {
+        let mut resp: reqwest::Response = response?;
+        let headers: HeaderMap = std::mem::take(resp.headers_mut());
+        let body: Bytes = request.client.read_body(resp).await?;
+        // A copy can fail after the server has sent a 200 status.
+        if let Some(e) = MinioErrorResponse::from_ok_body(&body, &headers) {
+            return Err(Error::S3Server(S3ServerError::S3Error(Box::new(e))));
+        }
+        Ok(Self {
+            request,
+            headers,
+            body,
+        })
+    }
+}
+
 impl_has_s3fields!(S3Response2);
 
 impl HasBucket for S3Response2 {}
@@ -38,6 +63,16 @@ impl HasRegion for S3Response2 {}
 impl HasVersion for S3Response2 {}
 impl HasEtagFromBody for S3Response2 {}
 impl HasChecksumHeaders for S3Response2 {}
+impl HasBucketKeyEnabled for S3Response2 {}
+
+impl S3Response2 {
+    /// Returns the last modified time of the copied object, as reported in the
+    /// `<LastModified>` element of the response body.
+    pub fn last_modified(&self) -> Result<UtcTime, ValidationErr> {
+        let root = Element::parse(self.body().clone().reader())?;
+        from_iso8601utc(&get_text_result(&root, "LastModified")?)
+    }
+}
 
 /// Represents the response of the `upload_part_copy` API call.
 /// This struct contains metadata and information about the part being copied during a multipart upload.
@@ -53,3 +88,67 @@ pub type CopyObjectResponse = S3Response2;
 /// Represents the response of the [compose_object()](crate::s3::client::MinioClient::compose_object) API call.
 /// This struct contains metadata and information about the composed object.
 pub type ComposeObjectResponse = S3Response2;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::MinioClient;
+    use crate::s3::http::BaseUrl;
+    use crate::s3::minio_error_response::MinioErrorCode;
+    use crate::s3::types::BucketName;
+    use chrono::{TimeZone, Utc};
+
+    async fn copy_response(
+        headers: &[(&str, &str)],
+        body: &'static str,
+    ) -> Result<CopyObjectResponse, Error> {
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let request = S3Request::builder()
+            .client(MinioClient::anonymous(base_url).unwrap())
+            .method(http::Method::PUT)
+            .bucket(BucketName::new("bucket").unwrap())
+            .build();
+        let mut http_resp = http::Response::builder().status(200);
+        for (k, v) in headers {
+            http_resp = http_resp.header(*k, *v);
+        }
+        CopyObjectResponse::from_s3response(request, Ok(http_resp.body(body).unwrap().into())).await
+    }
+
+    #[tokio::test]
+    async fn test_copy_object_result() {
+        let resp = copy_response(
+            &[("x-amz-version-id", "v2")],
+            "<CopyObjectResult><ETag>\"9b2cf535f27731c974343645a3985328\"</ETag>\
+             <LastModified>2025-01-15T10:20:30.000Z</LastModified></CopyObjectResult>",
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            resp.etag().unwrap().as_str(),
+            "9b2cf535f27731c974343645a3985328"
+        );
+        assert_eq!(
+            resp.last_modified().unwrap(),
+            Utc.with_ymd_and_hms(2025, 1, 15, 10, 20, 30).unwrap()
+        );
+        assert_eq!(resp.version_id().unwrap().as_str(), "v2");
+    }
+
+    #[tokio::test]
+    async fn test_copy_object_error_in_ok_body() {
+        let err = copy_response(
+            &[],
+            "\n  <?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+             <Error><Code>InternalError</Code><Message>We encountered an internal error.</Message>\
+             <Resource>/bucket/object</Resource><RequestId>req-1</RequestId></Error>",
+        )
+        .await
+        .unwrap_err();
+        let Error::S3Server(S3ServerError::S3Error(e)) = err else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(e.code(), MinioErrorCode::InternalError);
+        assert_eq!(e.request_id(), "req-1");
+    }
+}
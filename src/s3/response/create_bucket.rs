@@ -53,9 +53,9 @@ impl FromS3Response for CreateBucketResponse {
             .add_bucket_region(bucket, request.inner_region.clone());
 
         Ok(Self {
-            request,
             headers: mem::take(resp.headers_mut()),
-            body: resp.bytes().await.map_err(ValidationErr::HttpError)?,
+            body: request.client.read_body(resp).await?,
+            request,
         })
     }
 }
@@ -0,0 +1,63 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::builders::ObjectToDelete;
+use crate::s3::types::{BucketName, ObjectKey};
+
+/// Response of [`DeleteAllVersions::send()`](crate::s3::builders::DeleteAllVersions::send).
+#[derive(Clone, Debug)]
+pub struct DeleteAllVersionsResponse {
+    bucket: BucketName,
+    object: ObjectKey,
+    deleted: u64,
+    would_delete: Vec<ObjectToDelete>,
+}
+
+impl DeleteAllVersionsResponse {
+    pub(crate) fn new(
+        bucket: BucketName,
+        object: ObjectKey,
+        deleted: u64,
+        would_delete: Vec<ObjectToDelete>,
+    ) -> Self {
+        Self {
+            bucket,
+            object,
+            deleted,
+            would_delete,
+        }
+    }
+
+    /// The bucket containing the object.
+    pub fn bucket(&self) -> &BucketName {
+        &self.bucket
+    }
+
+    /// The object whose versions were removed.
+    pub fn object(&self) -> &ObjectKey {
+        &self.object
+    }
+
+    /// Number of removed object versions and delete markers.
+    pub fn deleted(&self) -> u64 {
+        self.deleted
+    }
+
+    /// Object versions and delete markers that would have been removed.
+    /// Only populated in dry-run mode.
+    pub fn would_delete(&self) -> &[ObjectToDelete] {
+        &self.would_delete
+    }
+}
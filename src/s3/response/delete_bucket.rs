@@ -50,9 +50,9 @@ impl FromS3Response for DeleteBucketResponse {
 
         request.client.remove_bucket_region(bucket);
         Ok(Self {
-            request,
             headers: mem::take(resp.headers_mut()),
-            body: resp.bytes().await.map_err(ValidationErr::HttpError)?,
+            body: request.client.read_body(resp).await?,
+            request,
         })
     }
 }
@@ -14,7 +14,7 @@
 // limitations under the License.
 
 use crate::impl_has_s3fields;
-use crate::s3::error::{Error, S3ServerError, ValidationErr};
+use crate::s3::error::{Error, S3ServerError};
 use crate::s3::minio_error_response::MinioErrorCode;
 use crate::s3::response_traits::{HasBucket, HasRegion};
 use crate::s3::types::{FromS3Response, S3Request};
@@ -45,9 +45,9 @@ impl FromS3Response for DeleteBucketPolicyResponse {
     ) -> Result<Self, Error> {
         match response {
             Ok(mut resp) => Ok(Self {
-                request,
                 headers: mem::take(resp.headers_mut()),
-                body: resp.bytes().await.map_err(ValidationErr::HttpError)?,
+                body: request.client.read_body(resp).await?,
+                request,
             }),
             Err(Error::S3Server(S3ServerError::S3Error(mut e)))
                 if matches!(e.code(), MinioErrorCode::NoSuchBucketPolicy) =>
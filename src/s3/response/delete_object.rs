@@ -129,3 +129,64 @@ impl DeleteObjectsResponse {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::MinioClient;
+    use crate::s3::creds::StaticProvider;
+    use crate::s3::http::BaseUrl;
+    use crate::s3::types::{FromS3Response, ToS3Request};
+
+    async fn delete_response(headers: &[(&str, &str)]) -> DeleteObjectResponse {
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let provider = StaticProvider::new("minioadmin", "minioadmin", None);
+        let client = MinioClient::new(base_url, Some(provider), None, None).unwrap();
+        let request = client
+            .delete_object("bucket", "object")
+            .unwrap()
+            .build()
+            .to_s3request()
+            .unwrap();
+
+        let mut http_resp = http::Response::builder().status(204);
+        for (k, v) in headers {
+            http_resp = http_resp.header(*k, *v);
+        }
+        DeleteObjectResponse::from_s3response(request, Ok(http_resp.body("").unwrap().into()))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_delete_object_delete_marker() {
+        // Deleting in a versioned bucket creates a delete marker.
+        let resp = delete_response(&[
+            ("x-amz-delete-marker", "true"),
+            ("x-amz-version-id", "3HL4kqtJlcpXroDTDmjVBH40Nrjfkd"),
+        ])
+        .await;
+        assert!(resp.is_delete_marker().unwrap());
+        assert_eq!(
+            resp.version_id().as_ref().map(|v| v.as_str()),
+            Some("3HL4kqtJlcpXroDTDmjVBH40Nrjfkd")
+        );
+
+        // Deleting a specific version removes it without creating a marker.
+        let resp = delete_response(&[
+            ("x-amz-delete-marker", "false"),
+            (
+                "x-amz-version-id",
+                "UIORUnfndfiufdisojhr398493jfdkjFJjkndnqUifhnw89493jJFJ",
+            ),
+        ])
+        .await;
+        assert!(!resp.is_delete_marker().unwrap());
+        assert!(resp.version_id().is_some());
+
+        // Unversioned buckets send neither header.
+        let resp = delete_response(&[]).await;
+        assert!(!resp.is_delete_marker().unwrap());
+        assert_eq!(resp.version_id(), None);
+    }
+}
@@ -0,0 +1,89 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::response::BatchResult;
+use crate::s3::types::BucketName;
+use std::path::PathBuf;
+
+/// Information about an object that was downloaded.
+#[derive(Clone, Debug)]
+pub struct DownloadedObject {
+    pub name: String,
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+/// Information about an object that could not be downloaded.
+#[derive(Clone, Debug)]
+pub struct DownloadError {
+    pub object_name: String,
+    pub message: String,
+}
+
+/// Result of downloading an object.
+#[derive(Clone, Debug)]
+pub enum DownloadResult {
+    Downloaded(DownloadedObject),
+    Error(DownloadError),
+}
+
+impl From<DownloadResult> for Result<DownloadedObject, DownloadError> {
+    fn from(result: DownloadResult) -> Self {
+        match result {
+            DownloadResult::Downloaded(obj) => Ok(obj),
+            DownloadResult::Error(err) => Err(err),
+        }
+    }
+}
+
+impl DownloadResult {
+    pub fn is_downloaded(&self) -> bool {
+        matches!(self, DownloadResult::Downloaded(_))
+    }
+    pub fn is_error(&self) -> bool {
+        matches!(self, DownloadResult::Error(_))
+    }
+}
+
+/// Response of [`DownloadObjects::send()`](crate::s3::builders::DownloadObjects::send).
+#[derive(Clone, Debug)]
+pub struct DownloadObjectsResponse {
+    bucket: BucketName,
+    result: BatchResult<DownloadedObject, DownloadError>,
+}
+
+impl DownloadObjectsResponse {
+    pub(crate) fn new(bucket: BucketName, results: Vec<DownloadResult>) -> Self {
+        Self {
+            bucket,
+            result: results.into_iter().collect(),
+        }
+    }
+
+    /// The bucket the objects were downloaded from.
+    pub fn bucket(&self) -> &BucketName {
+        &self.bucket
+    }
+
+    /// The result of each listed object, in listing order.
+    pub fn result(&self) -> &BatchResult<DownloadedObject, DownloadError> {
+        &self.result
+    }
+
+    /// Consumes the response and returns the result of each listed object.
+    pub fn into_result(self) -> BatchResult<DownloadedObject, DownloadError> {
+        self.result
+    }
+}
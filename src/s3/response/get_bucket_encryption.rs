@@ -18,7 +18,6 @@ use crate::s3::error::{Error, S3ServerError, ValidationErr};
 use crate::s3::minio_error_response::MinioErrorCode;
 use crate::s3::response_traits::{HasBucket, HasRegion};
 use crate::s3::types::{FromS3Response, S3Request, SseConfig};
-use crate::s3::utils::{get_text_option, get_text_result};
 use async_trait::async_trait;
 use bytes::{Buf, Bytes};
 use http::HeaderMap;
@@ -54,22 +53,8 @@ impl GetBucketEncryptionResponse {
         if self.body.is_empty() {
             return Ok(SseConfig::default());
         }
-        let mut root = Element::parse(self.body.clone().reader()).map_err(ValidationErr::from)?; // clone of Bytes is inexpensive
-
-        let rule = root
-            .get_mut_child("Rule")
-            .ok_or(ValidationErr::xml_error("<Rule> tag not found"))?;
-
-        let sse_by_default = rule
-            .get_mut_child("ApplyServerSideEncryptionByDefault")
-            .ok_or(ValidationErr::xml_error(
-                "<ApplyServerSideEncryptionByDefault> tag not found",
-            ))?;
-
-        Ok(SseConfig {
-            sse_algorithm: get_text_result(sse_by_default, "SSEAlgorithm")?,
-            kms_master_key_id: get_text_option(sse_by_default, "KMSMasterKeyID"),
-        })
+        let root = Element::parse(self.body.clone().reader()).map_err(ValidationErr::from)?; // clone of Bytes is inexpensive
+        SseConfig::from_xml(&root)
     }
 }
 
@@ -81,9 +66,9 @@ impl FromS3Response for GetBucketEncryptionResponse {
     ) -> Result<Self, Error> {
         match response {
             Ok(mut resp) => Ok(Self {
-                request,
                 headers: mem::take(resp.headers_mut()),
-                body: resp.bytes().await.map_err(ValidationErr::HttpError)?,
+                body: request.client.read_body(resp).await?,
+                request,
             }),
             Err(Error::S3Server(S3ServerError::S3Error(mut e)))
                 if matches!(
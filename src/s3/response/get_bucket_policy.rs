@@ -14,6 +14,7 @@
 // limitations under the License.
 
 use crate::impl_has_s3fields;
+use crate::s3::bucket_policy_config::BucketPolicy;
 use crate::s3::error::{Error, S3ServerError, ValidationErr};
 use crate::s3::minio_error_response::MinioErrorCode;
 use crate::s3::response_traits::{HasBucket, HasRegion};
@@ -35,6 +36,8 @@ pub struct GetBucketPolicyResponse {
     request: S3Request,
     headers: HeaderMap,
     body: Bytes,
+    /// False when the server reported `NoSuchBucketPolicy`.
+    has_policy: bool,
 }
 
 impl_has_s3fields!(GetBucketPolicyResponse);
@@ -50,6 +53,25 @@ impl GetBucketPolicyResponse {
     pub fn config(&self) -> Result<&str, ValidationErr> {
         Ok(std::str::from_utf8(&self.body)?)
     }
+
+    /// Returns the bucket policy as the raw JSON string sent by the server.
+    ///
+    /// Yields `{}` when the bucket has no policy.
+    pub fn as_json_string(&self) -> Result<&str, ValidationErr> {
+        self.config()
+    }
+
+    /// Returns the parsed bucket policy, or `None` if the bucket has no policy.
+    ///
+    /// The policy is parsed with [`BucketPolicy::parse_from_json`], which also checks
+    /// that every statement's resources belong to this bucket.
+    pub fn policy(&self) -> Result<Option<BucketPolicy>, Error> {
+        if !self.has_policy {
+            return Ok(None);
+        }
+        let bucket = self.bucket().map(|b| b.as_str()).unwrap_or_default();
+        BucketPolicy::parse_from_json(self.body.as_ref(), bucket).map(Some)
+    }
 }
 
 #[async_trait]
@@ -60,9 +82,10 @@ impl FromS3Response for GetBucketPolicyResponse {
     ) -> Result<Self, Error> {
         match response {
             Ok(mut resp) => Ok(Self {
-                request,
                 headers: mem::take(resp.headers_mut()),
-                body: resp.bytes().await.map_err(ValidationErr::HttpError)?,
+                body: request.client.read_body(resp).await?,
+                request,
+                has_policy: true,
             }),
             Err(Error::S3Server(S3ServerError::S3Error(mut e)))
                 if matches!(e.code(), MinioErrorCode::NoSuchBucketPolicy) =>
@@ -71,9 +94,81 @@ impl FromS3Response for GetBucketPolicyResponse {
                     request,
                     headers: e.take_headers(),
                     body: Bytes::from_static("{}".as_ref()),
+                    has_policy: false,
                 })
             }
             Err(e) => Err(e),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::MinioClient;
+    use crate::s3::bucket_policy_config::{Action, Effect, Principal};
+    use crate::s3::creds::StaticProvider;
+    use crate::s3::http::BaseUrl;
+    use crate::s3::minio_error_response::MinioErrorResponse;
+    use crate::s3::types::{BucketName, ToS3Request};
+
+    fn request() -> S3Request {
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let provider = StaticProvider::new("minioadmin", "minioadmin", None);
+        let client = MinioClient::new(base_url, Some(provider), None, None).unwrap();
+        client
+            .get_bucket_policy("bucket")
+            .unwrap()
+            .build()
+            .to_s3request()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_bucket_policy_typed() {
+        let json = r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Principal":{"AWS":["*"]},"Action":["s3:GetObject"],"Resource":["arn:aws:s3:::bucket/*"]}]}"#;
+        let http_resp = http::Response::builder().status(200).body(json).unwrap();
+        let resp = GetBucketPolicyResponse::from_s3response(request(), Ok(http_resp.into()))
+            .await
+            .unwrap();
+
+        assert_eq!(resp.as_json_string().unwrap(), json);
+        let policy = resp.policy().unwrap().unwrap();
+        assert_eq!(policy.version, BucketPolicy::DEFAULT_VERSION);
+        assert_eq!(policy.statements.len(), 1);
+        let statement = &policy.statements[0];
+        assert_eq!(statement.effect, Effect::Allow);
+        assert_eq!(
+            statement.principal,
+            Principal::Aws {
+                aws: vec!["*".to_string()]
+            }
+        );
+        assert_eq!(statement.actions, vec![Action::GetObject]);
+        assert_eq!(
+            statement.resources,
+            vec!["arn:aws:s3:::bucket/*".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_bucket_policy_no_such_bucket_policy() {
+        let e = MinioErrorResponse::new(
+            HeaderMap::new(),
+            MinioErrorCode::NoSuchBucketPolicy,
+            None,
+            "/bucket".into(),
+            String::new(),
+            String::new(),
+            BucketName::new("bucket").ok(),
+            None,
+        );
+        let err = Error::S3Server(S3ServerError::S3Error(Box::new(e)));
+        let resp = GetBucketPolicyResponse::from_s3response(request(), Err(err))
+            .await
+            .unwrap();
+
+        assert_eq!(resp.as_json_string().unwrap(), "{}");
+        assert!(resp.policy().unwrap().is_none());
+    }
+}
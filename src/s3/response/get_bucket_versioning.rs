@@ -68,4 +68,55 @@ impl GetBucketVersioningResponse {
         let root = Element::parse(self.body.clone().reader())?;
         Ok(get_text_option(&root, "MFADelete").map(|v| v.eq_ignore_ascii_case("Enabled")))
     }
+
+    /// Returns the prefixes of objects excluded from versioning (MinIO extension).
+    pub fn excluded_prefixes(&self) -> Result<Vec<String>, ValidationErr> {
+        let root = Element::parse(self.body.clone().reader())?;
+        Ok(root
+            .children
+            .iter()
+            .filter_map(|n| n.as_element())
+            .filter(|e| e.name == "ExcludedPrefixes")
+            .filter_map(|e| get_text_option(e, "Prefix"))
+            .collect())
+    }
+
+    /// Returns whether folder objects are excluded from versioning (MinIO extension).
+    pub fn exclude_folders(&self) -> Result<bool, ValidationErr> {
+        let root = Element::parse(self.body.clone().reader())?;
+        Ok(get_text_option(&root, "ExcludeFolders").is_some_and(|v| v == "true"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::MinioClient;
+    use crate::s3::http::BaseUrl;
+    use crate::s3::types::{FromS3Response, ToS3Request};
+
+    #[tokio::test]
+    async fn parses_minio_versioning_extensions() {
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let request = MinioClient::anonymous(base_url)
+            .unwrap()
+            .get_bucket_versioning("bucket")
+            .unwrap()
+            .build()
+            .to_s3request()
+            .unwrap();
+        let body = "<VersioningConfiguration><Status>Enabled</Status>\
+             <ExcludeFolders>true</ExcludeFolders>\
+             <ExcludedPrefixes><Prefix>scratch/</Prefix></ExcludedPrefixes>\
+             <ExcludedPrefixes><Prefix>tmp/</Prefix></ExcludedPrefixes>\
+             </VersioningConfiguration>";
+        let response = http::Response::builder().status(200).body(body).unwrap();
+        let resp = GetBucketVersioningResponse::from_s3response(request, Ok(response.into()))
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status().unwrap(), Some(VersioningStatus::Enabled));
+        assert!(resp.exclude_folders().unwrap());
+        assert_eq!(resp.excluded_prefixes().unwrap(), ["scratch/", "tmp/"]);
+    }
 }
@@ -15,12 +15,16 @@
 
 use crate::impl_has_s3fields;
 use crate::s3::builders::ObjectContent;
-use crate::s3::error::{Error, ValidationErr};
+use crate::s3::error::{Error, IoError, ValidationErr};
+use crate::s3::header_constants::{CONTENT_ENCODING, CONTENT_RANGE};
 use crate::s3::response_traits::{
-    HasBucket, HasChecksumHeaders, HasEtagFromHeaders, HasObject, HasRegion, HasVersion,
+    HasBucket, HasBucketKeyEnabled, HasChecksumHeaders, HasEtagFromHeaders, HasExpires,
+    HasIsDeleteMarker, HasLastModified, HasObject, HasObjectExpiration, HasRegion,
+    HasRequestCharged, HasTaggingCount, HasVersion,
 };
-use crate::s3::types::{FromS3Response, S3Request};
+use crate::s3::types::{ContentRange, FromS3Response, S3Request};
 use crate::s3::utils::{ChecksumAlgorithm, b64_encode, compute_checksum};
+use async_compression::futures::bufread::{GzipDecoder, ZlibDecoder};
 use async_trait::async_trait;
 use bytes::Bytes;
 use crc_fast::{CrcAlgorithm, Digest as CrcFastDigest};
@@ -35,10 +39,11 @@ use std::io;
 use std::mem;
 use std::pin::Pin;
 
-/// Type alias for a boxed byte stream with size, used by [`GetObjectResponse::into_boxed_stream`].
+/// Type alias for a boxed byte stream with its size, if known, used by
+/// [`GetObjectResponse::into_boxed_stream`].
 pub type BoxedByteStream = (
     Pin<Box<dyn futures_util::Stream<Item = std::io::Result<Bytes>> + Send>>,
-    u64,
+    Option<u64>,
 );
 use std::task::{Context as TaskContext, Poll};
 
@@ -202,12 +207,20 @@ where
     }
 }
 
+/// Content codings that [`GetObjectResponse::content`] can decompress.
+enum ContentCoding {
+    Gzip,
+    /// The zlib format, as specified for HTTP `deflate` by RFC 9110.
+    Deflate,
+}
+
 pub struct GetObjectResponse {
     request: S3Request,
     headers: HeaderMap,
     body: Bytes, // Note: not used
     resp: reqwest::Response,
     verify_checksum: bool,
+    auto_decompress: bool,
 }
 
 impl_has_s3fields!(GetObjectResponse);
@@ -218,6 +231,13 @@ impl HasObject for GetObjectResponse {}
 impl HasVersion for GetObjectResponse {}
 impl HasEtagFromHeaders for GetObjectResponse {}
 impl HasChecksumHeaders for GetObjectResponse {}
+impl HasRequestCharged for GetObjectResponse {}
+impl HasLastModified for GetObjectResponse {}
+impl HasExpires for GetObjectResponse {}
+impl HasBucketKeyEnabled for GetObjectResponse {}
+impl HasIsDeleteMarker for GetObjectResponse {}
+impl HasTaggingCount for GetObjectResponse {}
+impl HasObjectExpiration for GetObjectResponse {}
 
 impl GetObjectResponse {
     /// Checks if the checksum is a composite (multipart) checksum.
@@ -239,6 +259,17 @@ impl GetObjectResponse {
         false
     }
 
+    /// Returns the byte range served by a ranged request, parsed from the
+    /// `Content-Range` header of a `206` response.
+    ///
+    /// Returns `None` if the header is absent, as for a full-object response, or malformed.
+    pub fn content_range(&self) -> Option<ContentRange> {
+        self.headers
+            .get(CONTENT_RANGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(ContentRange::parse)
+    }
+
     /// Returns the content of the object as a (streaming) byte buffer. Note: consumes the response.
     ///
     /// If `verify_checksum` is enabled and the server provided checksums, the stream will
@@ -247,9 +278,34 @@ impl GetObjectResponse {
     /// **Note on multipart objects**: Objects uploaded via multipart upload have COMPOSITE checksums
     /// (checksum-of-checksums) which cannot be verified by computing a checksum over the downloaded
     /// data. For these objects, checksum verification is automatically skipped.
+    ///
+    /// If [decompression](Self::with_decompression) is enabled and the object is stored
+    /// with `Content-Encoding: gzip` or `deflate`, the content is decompressed and its
+    /// size is unknown. Content that cannot be decoded yields an I/O error of kind
+    /// [`InvalidData`](io::ErrorKind::InvalidData) while streaming.
     pub fn content(self) -> Result<ObjectContent, Error> {
-        let content_length: u64 = self.object_size()?;
+        let content_length: Option<u64> = self.object_size();
+        let coding = match self.auto_decompress {
+            true => self.content_coding(),
+            false => None,
+        };
+        let body = self.body_stream();
+
+        Ok(match coding {
+            Some(ContentCoding::Gzip) => {
+                let mut decoder = GzipDecoder::new(body.into_async_read());
+                decoder.multiple_members(true);
+                ObjectContent::new_from_reader(decoder, None)
+            }
+            Some(ContentCoding::Deflate) => {
+                ObjectContent::new_from_reader(ZlibDecoder::new(body.into_async_read()), None)
+            }
+            None => ObjectContent::new_from_stream(body, content_length),
+        })
+    }
 
+    /// Returns the body as stored, verifying its checksum if enabled.
+    fn body_stream(self) -> Pin<Box<dyn Stream<Item = io::Result<Bytes>> + Send>> {
         // Skip verification for composite checksums (multipart uploads)
         // Composite checksums are checksum-of-checksums and cannot be verified
         // by computing a checksum over the full object data
@@ -258,23 +314,39 @@ impl GetObjectResponse {
                 "Skipping checksum verification for composite checksum (multipart upload). \
                  Composite checksums cannot be verified without part boundaries."
             );
-            let body = self.resp.bytes_stream().map_err(std::io::Error::other);
-            return Ok(ObjectContent::new_from_stream(body, Some(content_length)));
+            return Box::pin(self.resp.bytes_stream().map_err(io::Error::other));
         }
 
         if let (true, Some(algorithm)) = (self.verify_checksum, self.detect_checksum_algorithm())
             && let Some(expected) = self.get_checksum(algorithm)
         {
             let stream = self.resp.bytes_stream();
-            let verifying_stream = ChecksumVerifyingStream::new(stream, algorithm, expected);
-            return Ok(ObjectContent::new_from_stream(
-                verifying_stream,
-                Some(content_length),
-            ));
+            return Box::pin(ChecksumVerifyingStream::new(stream, algorithm, expected));
         }
 
-        let body = self.resp.bytes_stream().map_err(std::io::Error::other);
-        Ok(ObjectContent::new_from_stream(body, Some(content_length)))
+        Box::pin(self.resp.bytes_stream().map_err(io::Error::other))
+    }
+
+    /// Returns the `Content-Encoding` of the object if it can be decompressed.
+    fn content_coding(&self) -> Option<ContentCoding> {
+        let value = self.headers.get(CONTENT_ENCODING)?.to_str().ok()?.trim();
+        if value.eq_ignore_ascii_case("gzip") || value.eq_ignore_ascii_case("x-gzip") {
+            Some(ContentCoding::Gzip)
+        } else if value.eq_ignore_ascii_case("deflate") {
+            Some(ContentCoding::Deflate)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the content of the object fully downloaded. Content of up to
+    /// `max_in_memory` bytes is held in memory; larger content is spilled to a
+    /// temporary file. See [`ObjectContent::buffered`].
+    pub async fn buffered_content(self, max_in_memory: u64) -> Result<ObjectContent, Error> {
+        self.content()?
+            .buffered(max_in_memory)
+            .await
+            .map_err(|e| IoError::from(e).into())
     }
 
     /// Returns the content as a boxed stream for direct streaming access.
@@ -283,7 +355,7 @@ impl GetObjectResponse {
     /// requiring minimal overhead, as it bypasses the async wrapper entirely.
     /// Use this for high-throughput scenarios like DataFusion queries.
     pub fn into_boxed_stream(self) -> Result<BoxedByteStream, Error> {
-        let content_length = self.object_size()?;
+        let content_length = self.object_size();
         let stream = Box::pin(self.resp.bytes_stream().map_err(std::io::Error::other));
         Ok((stream, content_length))
     }
@@ -302,18 +374,28 @@ impl GetObjectResponse {
     }
 
     /// Sets whether to automatically verify checksums when calling `content()`.
-    /// Default is `true`. Verification is performed incrementally during streaming with minimal overhead.
+    /// Default is `true`, or `false` if the client [skips payload hashing](crate::s3::client::MinioClient::skips_payload_hashing).
+    /// Verification is performed incrementally during streaming with minimal overhead.
     /// Set to `false` to disable checksum verification entirely.
     pub fn with_verification(mut self, verify: bool) -> Self {
         self.verify_checksum = verify;
         self
     }
 
-    /// Returns the content size (in Bytes) of the object.
-    pub fn object_size(&self) -> Result<u64, ValidationErr> {
-        self.resp
-            .content_length()
-            .ok_or(ValidationErr::ContentLengthUnknown)
+    /// Sets whether `content()` decompresses objects stored with `Content-Encoding: gzip`
+    /// or `deflate`. Default is `false`, in which case the stored bytes are returned as-is.
+    /// Other encodings are never decoded, and [`into_boxed_stream`](Self::into_boxed_stream)
+    /// and [`into_bytes`](Self::into_bytes) always return the stored bytes.
+    pub fn with_decompression(mut self, decompress: bool) -> Self {
+        self.auto_decompress = decompress;
+        self
+    }
+
+    /// Returns the content size (in Bytes) of the object, or `None` if the response
+    /// has no `Content-Length`, as with `Transfer-Encoding: chunked` responses from
+    /// some proxies. The content can be streamed either way.
+    pub fn object_size(&self) -> Option<u64> {
+        self.resp.content_length()
     }
 
     /// Returns the content with automatic checksum verification.
@@ -372,12 +454,14 @@ impl FromS3Response for GetObjectResponse {
         response: Result<reqwest::Response, Error>,
     ) -> Result<Self, Error> {
         let mut resp = response?;
+        let verify_checksum = !request.client.shared.skip_payload_hashing;
         Ok(Self {
             request,
             headers: mem::take(resp.headers_mut()),
             body: Bytes::new(),
             resp,
-            verify_checksum: true, // Default to auto-verify
+            verify_checksum,
+            auto_decompress: false,
         })
     }
 }
@@ -15,8 +15,8 @@
 
 use crate::s3::error::ValidationErr;
 use crate::s3::response_traits::HasS3Fields;
-use crate::s3::types::{Bucket, BucketName, S3Request};
-use crate::s3::utils::{from_iso8601utc, get_text_result};
+use crate::s3::types::{Bucket, BucketName, Region, S3Request};
+use crate::s3::utils::{from_iso8601utc, get_text_option, get_text_result};
 use crate::{impl_from_s3response, impl_has_s3fields};
 use bytes::{Buf, Bytes};
 use http::HeaderMap;
@@ -47,8 +47,73 @@ impl ListBucketsResponse {
             buckets.push(Bucket {
                 name: BucketName::new_unchecked(get_text_result(&bucket, "Name")?),
                 creation_date: from_iso8601utc(&get_text_result(&bucket, "CreationDate")?)?,
+                // An unknown region must not fail the whole listing.
+                region: get_text_option(&bucket, "BucketRegion").and_then(|v| Region::new(v).ok()),
             })
         }
         Ok(buckets)
     }
+
+    /// Returns the token to pass as `continuation_token` to
+    /// [list_buckets()](crate::s3::client::MinioClient::list_buckets) to fetch the next
+    /// page, or `None` if this is the last page.
+    pub fn continuation_token(&self) -> Result<Option<String>, ValidationErr> {
+        let root = Element::parse(self.body().clone().reader())?;
+        Ok(get_text_option(&root, "ContinuationToken"))
+    }
+
+    /// Returns the prefix the listing was filtered by, if any.
+    pub fn prefix(&self) -> Result<Option<String>, ValidationErr> {
+        let root = Element::parse(self.body().clone().reader())?;
+        Ok(get_text_option(&root, "Prefix"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::client::MinioClient;
+    use crate::s3::http::BaseUrl;
+    use crate::s3::types::{FromS3Response, ToS3Request};
+    use chrono::{TimeZone, Utc};
+
+    #[tokio::test]
+    async fn parses_creation_date_and_region() {
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let request = MinioClient::anonymous(base_url)
+            .unwrap()
+            .list_buckets()
+            .build()
+            .to_s3request()
+            .unwrap();
+        let body = "<ListAllMyBucketsResult><Owner><ID>minio</ID></Owner><Buckets>\
+             <Bucket><Name>old</Name><CreationDate>2021-03-04T05:06:07.000Z</CreationDate></Bucket>\
+             <Bucket><Name>new</Name><CreationDate>2024-11-12T13:14:15.123Z</CreationDate>\
+             <BucketRegion>eu-west-1</BucketRegion></Bucket>\
+             <Bucket><Name>odd</Name><CreationDate>2024-11-12T13:14:15.123Z</CreationDate>\
+             <BucketRegion/></Bucket>\
+             </Buckets></ListAllMyBucketsResult>";
+        let response = http::Response::builder().status(200).body(body).unwrap();
+        let resp = ListBucketsResponse::from_s3response(request, Ok(response.into()))
+            .await
+            .unwrap();
+
+        let buckets = resp.buckets().unwrap();
+        assert_eq!(buckets.len(), 3);
+        assert_eq!(buckets[0].name().as_str(), "old");
+        assert_eq!(
+            buckets[0].creation_date(),
+            Utc.with_ymd_and_hms(2021, 3, 4, 5, 6, 7).unwrap()
+        );
+        assert_eq!(buckets[0].region(), None);
+        assert_eq!(buckets[1].name().as_str(), "new");
+        assert_eq!(
+            buckets[1].creation_date(),
+            Utc.with_ymd_and_hms(2024, 11, 12, 13, 14, 15).unwrap()
+                + chrono::Duration::milliseconds(123)
+        );
+        assert_eq!(buckets[1].region().map(Region::as_str), Some("eu-west-1"));
+        assert_eq!(buckets[2].name().as_str(), "odd");
+        assert_eq!(buckets[2].region(), None);
+    }
 }
@@ -12,7 +12,7 @@
 
 use crate::impl_has_s3fields;
 use crate::s3::error::{Error, ValidationErr};
-
+use crate::s3::response_traits::HasRequestCharged;
 use crate::s3::types::{FromS3Response, ListEntry, S3Request};
 use crate::s3::utils::xml::{Element, MergeXmlElements};
 use crate::s3::utils::{from_iso8601utc, parse_tags, url_decode};
@@ -201,6 +201,8 @@ pub struct ListObjectsV1Response {
 
 impl_has_s3fields!(ListObjectsV1Response);
 
+impl HasRequestCharged for ListObjectsV1Response {}
+
 #[async_trait]
 impl FromS3Response for ListObjectsV1Response {
     async fn from_s3response(
@@ -209,7 +211,7 @@ impl FromS3Response for ListObjectsV1Response {
     ) -> Result<Self, Error> {
         let mut resp = response?;
         let headers: HeaderMap = mem::take(resp.headers_mut());
-        let body = resp.bytes().await.map_err(ValidationErr::HttpError)?;
+        let body = request.client.read_body(resp).await?;
 
         let xmltree_root =
             xmltree::Element::parse(body.clone().reader()).map_err(ValidationErr::from)?;
@@ -220,10 +222,12 @@ impl FromS3Response for ListObjectsV1Response {
         let mut next_marker = url_decode_w_enc(&encoding_type, root.get_child_text("NextMarker"))?;
         let mut contents: Vec<ListEntry> = Vec::new();
         parse_list_objects_contents(&mut contents, &root, "Contents", &encoding_type, false)?;
+        parse_list_objects_common_prefixes(&mut contents, &root, &encoding_type)?;
+        // NextMarker is only returned when a delimiter is given; otherwise the
+        // next page starts after the greatest key or common prefix of this page.
         if is_truncated && next_marker.is_none() {
-            next_marker = contents.last().map(|v| v.name.clone())
+            next_marker = contents.iter().map(|v| &v.name).max().cloned();
         }
-        parse_list_objects_common_prefixes(&mut contents, &root, &encoding_type)?;
 
         Ok(Self {
             request,
@@ -265,6 +269,8 @@ pub struct ListObjectsV2Response {
 
 impl_has_s3fields!(ListObjectsV2Response);
 
+impl HasRequestCharged for ListObjectsV2Response {}
+
 #[async_trait]
 impl FromS3Response for ListObjectsV2Response {
     async fn from_s3response(
@@ -273,7 +279,7 @@ impl FromS3Response for ListObjectsV2Response {
     ) -> Result<Self, Error> {
         let mut resp = response?;
         let headers: HeaderMap = mem::take(resp.headers_mut());
-        let body = resp.bytes().await.map_err(ValidationErr::HttpError)?;
+        let body = request.client.read_body(resp).await?;
 
         let xmltree_root =
             xmltree::Element::parse(body.clone().reader()).map_err(ValidationErr::from)?;
@@ -334,6 +340,8 @@ pub struct ListObjectVersionsResponse {
 
 impl_has_s3fields!(ListObjectVersionsResponse);
 
+impl HasRequestCharged for ListObjectVersionsResponse {}
+
 #[async_trait]
 impl FromS3Response for ListObjectVersionsResponse {
     async fn from_s3response(
@@ -342,7 +350,7 @@ impl FromS3Response for ListObjectVersionsResponse {
     ) -> Result<Self, Error> {
         let mut resp = response?;
         let headers: HeaderMap = mem::take(resp.headers_mut());
-        let body = resp.bytes().await.map_err(ValidationErr::HttpError)?;
+        let body = request.client.read_body(resp).await?;
 
         let xmltree_root =
             xmltree::Element::parse(body.clone().reader()).map_err(ValidationErr::from)?;
@@ -412,6 +420,8 @@ pub struct ListObjectsResponse {
 
 impl_has_s3fields!(ListObjectsResponse);
 
+impl HasRequestCharged for ListObjectsResponse {}
+
 impl From<ListObjectVersionsResponse> for ListObjectsResponse {
     fn from(value: ListObjectVersionsResponse) -> Self {
         Self {
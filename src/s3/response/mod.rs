@@ -14,9 +14,11 @@
 // limitations under the License.
 
 mod append_object;
+mod batch_result;
 mod bucket_exists;
 mod copy_object;
 mod create_bucket;
+mod delete_all_versions;
 mod delete_bucket;
 mod delete_bucket_encryption;
 mod delete_bucket_lifecycle;
@@ -27,6 +29,8 @@ mod delete_bucket_tagging;
 mod delete_object;
 mod delete_object_lock_config;
 mod delete_object_tagging;
+mod download_objects;
+mod empty_bucket;
 mod get_bucket_encryption;
 mod get_bucket_lifecycle;
 mod get_bucket_notification;
@@ -45,6 +49,7 @@ mod get_region;
 mod list_buckets;
 pub(crate) mod list_objects;
 mod listen_bucket_notification;
+mod object_exists;
 mod put_bucket_encryption;
 mod put_bucket_lifecycle;
 mod put_bucket_notification;
@@ -53,6 +58,7 @@ mod put_bucket_replication;
 mod put_bucket_tagging;
 mod put_bucket_versioning;
 mod put_object;
+mod put_object_fan_out;
 mod put_object_legal_hold;
 mod put_object_lock_config;
 mod put_object_retention;
@@ -61,9 +67,11 @@ mod select_object_content;
 mod stat_object;
 
 pub use append_object::AppendObjectResponse;
+pub use batch_result::BatchResult;
 pub use bucket_exists::BucketExistsResponse;
 pub use copy_object::*;
 pub use create_bucket::CreateBucketResponse;
+pub use delete_all_versions::DeleteAllVersionsResponse;
 pub use delete_bucket::DeleteBucketResponse;
 pub use delete_bucket_encryption::DeleteBucketEncryptionResponse;
 pub use delete_bucket_lifecycle::DeleteBucketLifecycleResponse;
@@ -76,6 +84,10 @@ pub use delete_object::{
 };
 pub use delete_object_lock_config::DeleteObjectLockConfigResponse;
 pub use delete_object_tagging::DeleteObjectTaggingResponse;
+pub use download_objects::{
+    DownloadError, DownloadObjectsResponse, DownloadResult, DownloadedObject,
+};
+pub use empty_bucket::EmptyBucketResponse;
 pub use get_bucket_encryption::GetBucketEncryptionResponse;
 pub use get_bucket_lifecycle::GetBucketLifecycleResponse;
 pub use get_bucket_notification::GetBucketNotificationResponse;
@@ -94,6 +106,7 @@ pub use get_region::GetRegionResponse;
 pub use list_buckets::ListBucketsResponse;
 pub use list_objects::ListObjectsResponse;
 pub use listen_bucket_notification::ListenBucketNotificationResponse;
+pub use object_exists::ObjectExistsResponse;
 pub use put_bucket_encryption::PutBucketEncryptionResponse;
 pub use put_bucket_lifecycle::PutBucketLifecycleResponse;
 pub use put_bucket_notification::PutBucketNotificationResponse;
@@ -105,6 +118,7 @@ pub use put_object::{
     AbortMultipartUploadResponse, CompleteMultipartUploadResponse, CreateMultipartUploadResponse,
     PutObjectContentResponse, PutObjectResponse, UploadPartResponse,
 };
+pub use put_object_fan_out::{FanOutResult, PutObjectFanOutResponse};
 pub use put_object_legal_hold::PutObjectLegalHoldResponse;
 pub use put_object_lock_config::PutObjectLockConfigResponse;
 pub use put_object_retention::PutObjectRetentionResponse;
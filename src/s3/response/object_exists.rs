@@ -0,0 +1,142 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::impl_has_s3fields;
+use crate::s3::error::Error;
+use crate::s3::error::S3ServerError::S3Error;
+use crate::s3::minio_error_response::MinioErrorCode;
+use crate::s3::response_traits::{HasBucket, HasObject, HasRegion, HasVersion};
+use crate::s3::types::{FromS3Response, S3Request};
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::HeaderMap;
+use std::mem;
+
+/// Response from the [`object_exists()`](crate::s3::client::MinioClient::object_exists) API.
+///
+/// A missing object (`NoSuchKey`, which a HEAD request also reports for a
+/// missing bucket) is reported as not existing. Any other error, such as a
+/// missing SSE-C key or denied access, is returned as an error.
+#[derive(Clone, Debug)]
+pub struct ObjectExistsResponse {
+    request: S3Request,
+    headers: HeaderMap,
+    body: Bytes,
+
+    exists: bool,
+}
+impl_has_s3fields!(ObjectExistsResponse);
+
+impl HasBucket for ObjectExistsResponse {}
+impl HasRegion for ObjectExistsResponse {}
+impl HasObject for ObjectExistsResponse {}
+impl HasVersion for ObjectExistsResponse {}
+
+#[async_trait]
+impl FromS3Response for ObjectExistsResponse {
+    async fn from_s3response(
+        request: S3Request,
+        response: Result<reqwest::Response, Error>,
+    ) -> Result<Self, Error> {
+        match response {
+            Ok(mut resp) => Ok(Self {
+                headers: mem::take(resp.headers_mut()),
+                body: request.client.read_body(resp).await?,
+                request,
+                exists: true,
+            }),
+            Err(Error::S3Server(S3Error(mut e)))
+                if matches!(e.code(), MinioErrorCode::NoSuchKey) =>
+            {
+                Ok(Self {
+                    request,
+                    headers: e.take_headers(),
+                    body: Bytes::new(),
+                    exists: false,
+                })
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl ObjectExistsResponse {
+    /// Returns `true` if the object exists, `false` otherwise.
+    pub fn exists(&self) -> bool {
+        self.exists
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::MinioClient;
+    use crate::s3::creds::StaticProvider;
+    use crate::s3::http::BaseUrl;
+    use crate::s3::minio_error_response::MinioErrorResponse;
+    use crate::s3::types::{BucketName, ObjectKey, ToS3Request};
+
+    fn request() -> S3Request {
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let provider = StaticProvider::new("minioadmin", "minioadmin", None);
+        let client = MinioClient::new(base_url, Some(provider), None, None).unwrap();
+        client
+            .object_exists("bucket", "object")
+            .unwrap()
+            .build()
+            .to_s3request()
+            .unwrap()
+    }
+
+    fn server_error(code: MinioErrorCode) -> Error {
+        let e = MinioErrorResponse::new(
+            HeaderMap::new(),
+            code,
+            None,
+            "/bucket/object".into(),
+            String::new(),
+            String::new(),
+            BucketName::new("bucket").ok(),
+            ObjectKey::new("object").ok(),
+        );
+        Error::S3Server(S3Error(Box::new(e)))
+    }
+
+    #[tokio::test]
+    async fn test_object_exists_present() {
+        let http_resp = http::Response::builder().status(200).body("").unwrap();
+        let resp = ObjectExistsResponse::from_s3response(request(), Ok(http_resp.into()))
+            .await
+            .unwrap();
+        assert!(resp.exists());
+    }
+
+    #[tokio::test]
+    async fn test_object_exists_absent() {
+        let err = server_error(MinioErrorCode::NoSuchKey);
+        let resp = ObjectExistsResponse::from_s3response(request(), Err(err))
+            .await
+            .unwrap();
+        assert!(!resp.exists());
+    }
+
+    #[tokio::test]
+    async fn test_object_exists_ssec_required_is_error() {
+        // HEAD of an SSE-C object without its key is rejected with 400 Bad Request.
+        let err = server_error(MinioErrorCode::BadRequest);
+        let result = ObjectExistsResponse::from_s3response(request(), Err(err)).await;
+        assert!(result.is_err());
+    }
+}
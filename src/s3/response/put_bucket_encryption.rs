@@ -14,9 +14,8 @@
 // limitations under the License.
 
 use crate::s3::error::ValidationErr;
-use crate::s3::response_traits::{HasBucket, HasRegion, HasS3Fields};
+use crate::s3::response_traits::{HasBucket, HasRegion};
 use crate::s3::types::{S3Request, SseConfig};
-use crate::s3::utils::{get_text_option, get_text_result};
 use crate::{impl_from_s3response, impl_has_s3fields};
 use bytes::{Buf, Bytes};
 use http::HeaderMap;
@@ -39,23 +38,14 @@ impl HasBucket for PutBucketEncryptionResponse {}
 impl HasRegion for PutBucketEncryptionResponse {}
 
 impl PutBucketEncryptionResponse {
-    /// Returns the server-side encryption configuration.
+    /// Returns the server-side encryption configuration that was set.
     pub fn config(&self) -> Result<SseConfig, ValidationErr> {
-        let mut root = Element::parse(self.body().clone().reader())?;
-
-        let rule = root
-            .get_mut_child("Rule")
-            .ok_or(ValidationErr::xml_error("<Rule> tag not found"))?;
-
-        let sse_by_default = rule
-            .get_mut_child("ApplyServerSideEncryptionByDefault")
-            .ok_or(ValidationErr::xml_error(
-                "<ApplyServerSideEncryptionByDefault> tag not found",
-            ))?;
-
-        Ok(SseConfig {
-            sse_algorithm: get_text_result(sse_by_default, "SSEAlgorithm")?,
-            kms_master_key_id: get_text_option(sse_by_default, "KMSMasterKeyID"),
-        })
+        let body: Bytes = self
+            .request
+            .body()
+            .map(|v| v.to_bytes())
+            .unwrap_or_default();
+        let root = Element::parse(body.reader())?;
+        SseConfig::from_xml(&root)
     }
 }
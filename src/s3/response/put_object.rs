@@ -13,15 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::s3::error::ValidationErr;
+use crate::s3::error::{Error, S3ServerError, ValidationErr};
+use crate::s3::minio_error_response::MinioErrorResponse;
 use crate::s3::response_traits::{
-    HasBucket, HasChecksumHeaders, HasEtagFromHeaders, HasObject, HasRegion, HasVersion,
+    HasBucket, HasBucketKeyEnabled, HasChecksumHeaders, HasEtagFromHeaders, HasObject, HasRegion,
+    HasRequestCharged, HasVersion,
 };
-use crate::s3::types::{S3Request, UploadId};
+use crate::s3::types::{FromS3Response, S3Request, UploadId};
 use crate::s3::utils::get_text_result;
 use crate::{impl_from_s3response, impl_from_s3response_with_size, impl_has_s3fields};
 use bytes::{Buf, Bytes};
 use http::HeaderMap;
+use http::header::CONTENT_TYPE;
 use xmltree::Element;
 
 // region
@@ -43,6 +46,8 @@ impl HasRegion for S3Response1 {}
 impl HasVersion for S3Response1 {}
 impl HasEtagFromHeaders for S3Response1 {}
 impl HasChecksumHeaders for S3Response1 {}
+impl HasRequestCharged for S3Response1 {}
+impl HasBucketKeyEnabled for S3Response1 {}
 
 /// Extended response struct for operations that need additional data like object size
 #[derive(Clone, Debug)]
@@ -64,6 +69,8 @@ impl HasRegion for S3Response1WithSize {}
 impl HasVersion for S3Response1WithSize {}
 impl HasEtagFromHeaders for S3Response1WithSize {}
 impl HasChecksumHeaders for S3Response1WithSize {}
+impl HasRequestCharged for S3Response1WithSize {}
+impl HasBucketKeyEnabled for S3Response1WithSize {}
 
 impl S3Response1WithSize {
     pub fn new(response: S3Response1, object_size: u64) -> Self {
@@ -98,6 +105,7 @@ impl HasRegion for S3MultipartResponse {}
 impl HasVersion for S3MultipartResponse {}
 impl HasEtagFromHeaders for S3MultipartResponse {}
 impl HasChecksumHeaders for S3MultipartResponse {}
+impl HasRequestCharged for S3MultipartResponse {}
 
 impl S3MultipartResponse {
     /// Returns the upload ID for the multipart upload, while consuming the response.
@@ -119,10 +127,172 @@ pub type CreateMultipartUploadResponse = S3MultipartResponse;
 pub type AbortMultipartUploadResponse = S3MultipartResponse;
 
 /// Response of [complete_multipart_upload()](crate::s3::client::MinioClient::complete_multipart_upload) API
-pub type CompleteMultipartUploadResponse = S3Response1;
+#[derive(Clone, Debug)]
+pub struct CompleteMultipartUploadResponse {
+    pub(crate) request: S3Request,
+    pub(crate) headers: HeaderMap,
+    pub(crate) body: Bytes,
+}
+
+#[async_trait::async_trait]
+impl FromS3Response for CompleteMultipartUploadResponse {
+    async fn from_s3response(
+        request: S3Request,
+        response: Result<reqwest::Response, Error>,
+    ) -> Result<Self, Error> {
+        let mut resp: reqwest::Response = response?;
+        let headers: HeaderMap = std::mem::take(resp.headers_mut());
+        let body: Bytes = request.client.read_body(resp).await?;
+        // The server sends the 200 status before combining the parts, so a
+        // failure to complete is reported in the body.
+        if let Some(e) = MinioErrorResponse::from_ok_body(&body, &headers) {
+            return Err(Error::S3Server(S3ServerError::S3Error(Box::new(e))));
+        }
+        let root = body
+            .iter()
+            .position(|b| !b.is_ascii_whitespace())
+            .and_then(|start| Element::parse(body.slice(start..).reader()).ok());
+        if root.is_none_or(|root| root.name != "CompleteMultipartUploadResult") {
+            return Err(Error::S3Server(S3ServerError::InvalidServerResponse {
+                message: "expected a CompleteMultipartUploadResult body".into(),
+                http_status_code: 200,
+                content_type: headers
+                    .get(CONTENT_TYPE)
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default()
+                    .into(),
+            }));
+        }
+        Ok(Self {
+            request,
+            headers,
+            body,
+        })
+    }
+}
+
+impl_has_s3fields!(CompleteMultipartUploadResponse);
+
+impl HasBucket for CompleteMultipartUploadResponse {}
+impl HasObject for CompleteMultipartUploadResponse {}
+impl HasRegion for CompleteMultipartUploadResponse {}
+impl HasVersion for CompleteMultipartUploadResponse {}
+impl HasEtagFromHeaders for CompleteMultipartUploadResponse {}
+impl HasChecksumHeaders for CompleteMultipartUploadResponse {}
+impl HasRequestCharged for CompleteMultipartUploadResponse {}
+impl HasBucketKeyEnabled for CompleteMultipartUploadResponse {}
 
 /// Response of [upload_part()](crate::s3::client::MinioClient::upload_part) API
 pub type UploadPartResponse = S3Response1;
 
 /// Response for put_object operations that include object size information
 pub type PutObjectContentResponse = S3Response1WithSize;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::MinioClient;
+    use crate::s3::client::test_support::{MockExecutor, test_client};
+    use crate::s3::creds::StaticProvider;
+    use crate::s3::http::BaseUrl;
+    use crate::s3::minio_error_response::MinioErrorCode;
+    use crate::s3::response_traits::HasObject;
+    use crate::s3::segmented_bytes::SegmentedBytes;
+    use crate::s3::types::{
+        ChecksumType, ETag, FromS3Response, ObjectChecksum, PartInfo, S3Api, ToS3Request,
+    };
+    use crate::s3::utils::ChecksumAlgorithm;
+
+    #[tokio::test]
+    async fn test_put_object_response_checksum() {
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let provider = StaticProvider::new("minioadmin", "minioadmin", None);
+        let client = MinioClient::new(base_url, Some(provider), None, None).unwrap();
+        let request = client
+            .put_object("bucket", "object", SegmentedBytes::from("data".to_string()))
+            .unwrap()
+            .build()
+            .to_s3request()
+            .unwrap();
+        let http_resp = http::Response::builder()
+            .status(200)
+            .header("x-amz-checksum-crc32c", "yZRlqg==")
+            .header("x-amz-checksum-type", "FULL_OBJECT")
+            .body("")
+            .unwrap();
+
+        let resp = PutObjectResponse::from_s3response(request, Ok(http_resp.into()))
+            .await
+            .unwrap();
+        assert_eq!(
+            resp.checksum(),
+            Some(ObjectChecksum {
+                algorithm: ChecksumAlgorithm::CRC32C,
+                value: "yZRlqg==".into(),
+                part_count: None,
+                checksum_type: Some(ChecksumType::FullObject),
+            })
+        );
+        assert_eq!(
+            resp.get_checksum(ChecksumAlgorithm::CRC32C).as_deref(),
+            Some("yZRlqg==")
+        );
+        assert_eq!(resp.get_checksum(ChecksumAlgorithm::SHA256), None);
+    }
+
+    async fn complete_with_body(
+        body: &'static str,
+    ) -> Result<CompleteMultipartUploadResponse, Error> {
+        let executor = MockExecutor::new(move |_| {
+            http::Response::builder()
+                .status(200)
+                .header("Content-Type", "application/xml")
+                .body(body)
+                .unwrap()
+        });
+        let parts = vec![PartInfo::new(1, ETag::new("abc").unwrap(), 5, None)];
+        test_client(executor)
+            .complete_multipart_upload("bucket", "object", "upload-1", parts)
+            .unwrap()
+            .build()
+            .send()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_complete_multipart_upload_success_body() {
+        let resp = complete_with_body(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+             <CompleteMultipartUploadResult><Bucket>bucket</Bucket><Key>object</Key>\
+             <ETag>\"065947336a2f2a95ba8899f3675c3be6-2\"</ETag></CompleteMultipartUploadResult>",
+        )
+        .await
+        .unwrap();
+        assert_eq!(resp.object().unwrap().as_str(), "object");
+    }
+
+    #[tokio::test]
+    async fn test_complete_multipart_upload_error_in_ok_body() {
+        let err = complete_with_body(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n  \
+             <Error><Code>InternalError</Code><Message>We encountered an internal error, please try again.</Message>\
+             <Key>object</Key><BucketName>bucket</BucketName><RequestId>req-1</RequestId></Error>",
+        )
+        .await
+        .unwrap_err();
+        let Error::S3Server(S3ServerError::S3Error(e)) = err else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(e.code(), MinioErrorCode::InternalError);
+        assert_eq!(e.request_id(), "req-1");
+
+        let err = complete_with_body("").await.unwrap_err();
+        assert!(
+            matches!(
+                err,
+                Error::S3Server(S3ServerError::InvalidServerResponse { .. })
+            ),
+            "{err:?}"
+        );
+    }
+}
@@ -0,0 +1,123 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::s3::error::{Error, ValidationErr};
+use crate::s3::types::BucketName;
+use crate::s3::utils::UtcTime;
+use http::HeaderMap;
+use serde::Deserialize;
+
+/// Outcome of writing one object of a fan-out upload.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FanOutResult {
+    pub key: String,
+    #[serde(default)]
+    pub etag: Option<String>,
+    #[serde(default)]
+    pub version_id: Option<String>,
+    #[serde(default)]
+    pub last_modified: Option<UtcTime>,
+    /// Why the object was not written; `None` on success.
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Response of [`PutObjectFanOut::send()`](crate::s3::builders::PutObjectFanOut::send).
+#[derive(Clone, Debug)]
+pub struct PutObjectFanOutResponse {
+    bucket: BucketName,
+    headers: HeaderMap,
+    results: Vec<FanOutResult>,
+}
+
+impl PutObjectFanOutResponse {
+    /// Parses the body, a sequence of JSON objects with one per entry.
+    pub(crate) fn new(bucket: BucketName, headers: HeaderMap, body: &[u8]) -> Result<Self, Error> {
+        let results = serde_json::Deserializer::from_slice(body)
+            .into_iter::<FanOutResult>()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|source| ValidationErr::InvalidJson {
+                source,
+                context: "parsing fan-out upload response".into(),
+            })?;
+        Ok(Self {
+            bucket,
+            headers,
+            results,
+        })
+    }
+
+    /// The bucket the objects were written to.
+    pub fn bucket(&self) -> &BucketName {
+        &self.bucket
+    }
+
+    /// HTTP headers of the upload response.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The outcome of each entry, in the order returned by the server.
+    pub fn results(&self) -> &[FanOutResult] {
+        &self.results
+    }
+
+    /// Consumes the response and returns the outcome of each entry.
+    pub fn into_results(self) -> Vec<FanOutResult> {
+        self.results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fan_out_results() {
+        let body = br#"{"key":"a","etag":"e1","versionId":"v1","lastModified":"2025-01-15T10:30:45.123456789Z"}
+{"key":"b","error":"Access Denied."}
+"#;
+        let resp = PutObjectFanOutResponse::new(
+            BucketName::new("bucket").unwrap(),
+            HeaderMap::new(),
+            body,
+        )
+        .unwrap();
+        let results = resp.results();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].key, "a");
+        assert_eq!(results[0].etag.as_deref(), Some("e1"));
+        assert_eq!(results[0].version_id.as_deref(), Some("v1"));
+        assert_eq!(
+            results[0].last_modified.unwrap().to_rfc3339(),
+            "2025-01-15T10:30:45.123456789+00:00"
+        );
+        assert_eq!(results[0].error, None);
+        assert_eq!(results[1].key, "b");
+        assert_eq!(results[1].error.as_deref(), Some("Access Denied."));
+
+        let err = PutObjectFanOutResponse::new(
+            BucketName::new("bucket").unwrap(),
+            HeaderMap::new(),
+            b"<Error/>",
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, Error::Validation(ValidationErr::InvalidJson { .. })),
+            "{err:?}"
+        );
+    }
+}
@@ -16,17 +16,19 @@
 use crate::s3::error::ValidationErr;
 use crate::s3::header_constants::*;
 use crate::s3::response_traits::{
-    HasBucket, HasChecksumHeaders, HasEtagFromHeaders, HasIsDeleteMarker, HasObject, HasObjectSize,
-    HasRegion, HasS3Fields, HasVersion,
+    HasBucket, HasBucketKeyEnabled, HasChecksumHeaders, HasEtagFromHeaders, HasExpires,
+    HasIsDeleteMarker, HasLastModified, HasObject, HasObjectExpiration, HasObjectSize, HasRegion,
+    HasRequestCharged, HasS3Fields, HasTaggingCount, HasVersion,
 };
 use crate::s3::types::S3Request;
 use crate::s3::types::{RetentionMode, parse_legal_hold};
-use crate::s3::utils::{UtcTime, from_http_header_value, from_iso8601utc};
+use crate::s3::utils::{UtcTime, from_http_header_value, from_iso8601utc, to_iso8601utc};
 use crate::{impl_from_s3response, impl_has_s3fields};
 use bytes::Bytes;
 use http::HeaderMap;
 use http::header::LAST_MODIFIED;
-use std::collections::HashMap;
+use serde::ser::{Error as _, Serialize, SerializeStruct, Serializer};
+use std::collections::{BTreeMap, HashMap};
 
 /// Response from the [`stat_object`](crate::s3::client::MinioClient::stat_object) API.
 ///
@@ -49,6 +51,12 @@ impl HasIsDeleteMarker for StatObjectResponse {}
 impl HasChecksumHeaders for StatObjectResponse {}
 impl HasVersion for StatObjectResponse {}
 impl HasObjectSize for StatObjectResponse {}
+impl HasLastModified for StatObjectResponse {}
+impl HasExpires for StatObjectResponse {}
+impl HasBucketKeyEnabled for StatObjectResponse {}
+impl HasRequestCharged for StatObjectResponse {}
+impl HasTaggingCount for StatObjectResponse {}
+impl HasObjectExpiration for StatObjectResponse {}
 
 impl StatObjectResponse {
     /// Returns the size of the object (header-value of `Content-Length`).
@@ -61,6 +69,9 @@ impl StatObjectResponse {
     }
 
     /// Returns the last modified time of the object (header-value of `Last-Modified`).
+    ///
+    /// Unlike [`HasLastModified::last_modified`], a malformed header value is
+    /// returned as an error rather than `None`.
     pub fn last_modified(&self) -> Result<Option<UtcTime>, ValidationErr> {
         match self.headers().get(LAST_MODIFIED) {
             Some(v) => Ok(Some(from_http_header_value(v.to_str()?)?)),
@@ -103,3 +114,159 @@ impl StatObjectResponse {
         Ok(user_metadata)
     }
 }
+
+/// Serializes the object metadata (not the raw headers), for example to emit JSON.
+///
+/// Timestamps use the same ISO 8601 format as [`aws_date_format`](crate::s3::utils::aws_date_format).
+impl Serialize for StatObjectResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let user_metadata: BTreeMap<String, String> = self
+            .user_metadata()
+            .map_err(S::Error::custom)?
+            .into_iter()
+            .collect();
+        let content_type = self
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok());
+
+        let mut s = serializer.serialize_struct("StatObjectResponse", 12)?;
+        s.serialize_field("bucket", &self.bucket())?;
+        s.serialize_field("object", &self.object())?;
+        s.serialize_field("size", &self.size().map_err(S::Error::custom)?)?;
+        s.serialize_field("etag", &self.etag().ok())?;
+        s.serialize_field(
+            "last_modified",
+            &HasLastModified::last_modified(self).map(to_iso8601utc),
+        )?;
+        s.serialize_field("version_id", &self.version_id())?;
+        s.serialize_field(
+            "is_delete_marker",
+            &self.is_delete_marker().map_err(S::Error::custom)?,
+        )?;
+        s.serialize_field("content_type", &content_type)?;
+        s.serialize_field("user_metadata", &user_metadata)?;
+        s.serialize_field(
+            "retention_mode",
+            &self
+                .retention_mode()
+                .map_err(S::Error::custom)?
+                .map(|v| v.to_string()),
+        )?;
+        s.serialize_field(
+            "retention_retain_until_date",
+            &self
+                .retention_retain_until_date()
+                .map_err(S::Error::custom)?
+                .map(to_iso8601utc),
+        )?;
+        s.serialize_field("legal_hold", &self.legal_hold().map_err(S::Error::custom)?)?;
+        s.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::MinioClient;
+    use crate::s3::creds::StaticProvider;
+    use crate::s3::http::BaseUrl;
+    use crate::s3::types::{FromS3Response, ToS3Request};
+    use chrono::{TimeZone, Utc};
+
+    async fn stat_response(last_modified: Option<&str>) -> StatObjectResponse {
+        let headers: Vec<(&str, &str)> = last_modified
+            .map(|v| vec![("last-modified", v)])
+            .unwrap_or_default();
+        stat_response_with(&headers).await
+    }
+
+    async fn stat_response_with(headers: &[(&str, &str)]) -> StatObjectResponse {
+        let base_url: BaseUrl = "http://localhost:9000".parse().unwrap();
+        let provider = StaticProvider::new("minioadmin", "minioadmin", None);
+        let client = MinioClient::new(base_url, Some(provider), None, None).unwrap();
+        let request = client
+            .stat_object("bucket", "object")
+            .unwrap()
+            .build()
+            .to_s3request()
+            .unwrap();
+
+        let mut http_resp = http::Response::builder().status(200);
+        for (k, v) in headers {
+            http_resp = http_resp.header(*k, *v);
+        }
+        StatObjectResponse::from_s3response(request, Ok(http_resp.body("").unwrap().into()))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_last_modified() {
+        let expected = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+        let resp = stat_response(Some("Wed, 21 Oct 2015 07:28:00 GMT")).await;
+        assert_eq!(HasLastModified::last_modified(&resp), Some(expected));
+        assert_eq!(resp.last_modified().unwrap(), Some(expected));
+
+        let resp = stat_response(None).await;
+        assert_eq!(HasLastModified::last_modified(&resp), None);
+        assert_eq!(resp.last_modified().unwrap(), None);
+
+        let resp = stat_response(Some("yesterday")).await;
+        assert_eq!(HasLastModified::last_modified(&resp), None);
+        assert!(resp.last_modified().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tagging_count() {
+        let resp = stat_response_with(&[("x-amz-tagging-count", "3")]).await;
+        assert_eq!(resp.tagging_count(), Some(3));
+
+        assert_eq!(stat_response(None).await.tagging_count(), None);
+    }
+
+    #[tokio::test]
+    async fn test_expiration() {
+        let resp = stat_response_with(&[(
+            "x-amz-expiration",
+            "expiry-date=\"Sun, 23 Dec 2012 00:00:00 GMT\", rule-id=\"picture-deletion-rule\"",
+        )])
+        .await;
+        let exp = resp.expiration().unwrap();
+        assert_eq!(exp.rule_id, "picture-deletion-rule");
+        assert_eq!(exp.expiry_date.to_rfc3339(), "2012-12-23T00:00:00+00:00");
+
+        assert_eq!(stat_response(None).await.expiration(), None);
+    }
+
+    #[tokio::test]
+    async fn test_stat_object_serialize_json() {
+        let resp = stat_response_with(&[
+            ("content-length", "11"),
+            ("content-type", "text/plain"),
+            ("etag", "\"d41d8cd98f00b204e9800998ecf8427e\""),
+            ("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT"),
+            ("x-amz-version-id", "v1"),
+            ("x-amz-meta-owner", "alice"),
+        ])
+        .await;
+
+        assert_eq!(
+            serde_json::to_value(&resp).unwrap(),
+            serde_json::json!({
+                "bucket": "bucket",
+                "object": "object",
+                "size": 11,
+                "etag": "d41d8cd98f00b204e9800998ecf8427e",
+                "last_modified": "2015-10-21T07:28:00.000Z",
+                "version_id": "v1",
+                "is_delete_marker": false,
+                "content_type": "text/plain",
+                "user_metadata": {"owner": "alice"},
+                "retention_mode": null,
+                "retention_retain_until_date": null,
+                "legal_hold": null,
+            })
+        );
+    }
+}
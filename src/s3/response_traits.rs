@@ -25,6 +25,9 @@
 //! - [`HasEtagFromHeaders`]: Object ETag/hash (via `ETag` header)
 //! - [`HasChecksumHeaders`]: Object checksum values (via `x-amz-checksum-*` headers)
 //! - [`HasIsDeleteMarker`]: Whether the object is a delete marker (via `x-amz-delete-marker` header)
+//! - [`HasLastModified`]: Object modification time (via `Last-Modified` header)
+//! - [`HasExpires`]: Object cache expiry time (via `Expires` header)
+//! - [`HasBucketKeyEnabled`]: Whether an S3 Bucket Key is used for SSE-KMS
 //!
 //! ## 2. GET Requests (Metadata + Body)
 //!
@@ -47,6 +50,7 @@
 //! impl HasChecksumHeaders for StatObjectResponse {}
 //! impl HasVersion for StatObjectResponse {}       // Version ID from header
 //! impl HasObjectSize for StatObjectResponse {}    // Size from header
+//! impl HasLastModified for StatObjectResponse {}  // Time from header
 //! ```
 //!
 //! This allows users to access metadata uniformly:
@@ -66,10 +70,16 @@
 
 use crate::s3::error::ValidationErr;
 use crate::s3::header_constants::*;
-use crate::s3::types::{BucketName, ETag, ObjectKey, Region, S3Request, VersionId};
-use crate::s3::utils::{ChecksumAlgorithm, get_text_result, parse_bool, trim_quotes};
+use crate::s3::types::{
+    BucketName, ETag, ObjectChecksum, ObjectExpiration, ObjectKey, Region, S3Request, Tags,
+    VersionId,
+};
+use crate::s3::utils::{
+    ChecksumAlgorithm, UtcTime, from_http_header_value, get_text_result, parse_bool, trim_quotes,
+};
 use bytes::{Buf, Bytes};
 use http::HeaderMap;
+use http::header::LAST_MODIFIED;
 use std::collections::HashMap;
 use xmltree::Element;
 
@@ -86,9 +96,9 @@ macro_rules! impl_from_s3response {
                 ) -> Result<Self, $crate::s3::error::Error> {
                     let mut resp: reqwest::Response = response?;
                     Ok(Self {
-                        request,
                         headers: std::mem::take(resp.headers_mut()),
-                        body: resp.bytes().await.map_err($crate::s3::error::ValidationErr::from)?,
+                        body: request.client.read_body(resp).await?,
+                        request,
                     })
                 }
             }
@@ -109,9 +119,9 @@ macro_rules! impl_from_s3response_with_size {
                 ) -> Result<Self, $crate::s3::error::Error> {
                     let mut resp: reqwest::Response = response?;
                     Ok(Self {
-                        request,
                         headers: std::mem::take(resp.headers_mut()),
-                        body: resp.bytes().await.map_err($crate::s3::error::ValidationErr::from)?,
+                        body: request.client.read_body(resp).await?,
+                        request,
                         object_size: 0, // Default value, can be set later
                     })
                 }
@@ -237,6 +247,92 @@ pub trait HasObjectSize: HasS3Fields {
     }
 }
 
+/// Returns the time the object was last modified, as specified by the `Last-Modified` header.
+pub trait HasLastModified: HasS3Fields {
+    /// Returns the time the object was last modified, as specified by the `Last-Modified` header.
+    ///
+    /// Returns `None` if the header is absent or not a valid HTTP date.
+    #[inline]
+    fn last_modified(&self) -> Option<UtcTime> {
+        self.headers()
+            .get(LAST_MODIFIED)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| from_http_header_value(s).ok())
+    }
+}
+
+/// Returns the cache expiry time of the object, as specified by the `Expires` header.
+///
+/// This is the standard HTTP caching header stored with the object, unrelated to the
+/// lifecycle expiration reported by [`HasObjectExpiration`].
+pub trait HasExpires: HasS3Fields {
+    /// Returns the value of the `Expires` header.
+    ///
+    /// Returns `None` if the header is absent or not a valid HTTP date.
+    #[inline]
+    fn expires(&self) -> Option<UtcTime> {
+        self.headers()
+            .get(EXPIRES)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| from_http_header_value(s).ok())
+    }
+}
+
+/// Provides access to the `x-amz-request-charged` header value.
+///
+/// Requester-pays buckets return this header when the requester, rather than the
+/// bucket owner, was charged for the request.
+pub trait HasRequestCharged: HasS3Fields {
+    /// Returns `true` if the requester was charged for the request.
+    #[inline]
+    fn request_charged(&self) -> bool {
+        self.headers()
+            .get(X_AMZ_REQUEST_CHARGED)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.eq_ignore_ascii_case("requester"))
+    }
+}
+
+/// Provides access to the `x-amz-server-side-encryption-bucket-key-enabled` header value.
+pub trait HasBucketKeyEnabled: HasS3Fields {
+    /// Returns `true` if the object is encrypted with an S3 Bucket Key for SSE-KMS.
+    #[inline]
+    fn bucket_key_enabled(&self) -> bool {
+        self.headers()
+            .get(X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"))
+    }
+}
+
+/// Provides access to the `x-amz-tagging-count` header value.
+pub trait HasTaggingCount: HasS3Fields {
+    /// Returns the number of tags on the object, as specified by the `x-amz-tagging-count` header.
+    ///
+    /// Returns `None` if the header is absent, which servers do when the object has no tags.
+    #[inline]
+    fn tagging_count(&self) -> Option<u32> {
+        self.headers()
+            .get(X_AMZ_TAGGING_COUNT)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<u32>().ok())
+    }
+}
+
+/// Provides access to the `x-amz-expiration` header value.
+pub trait HasObjectExpiration: HasS3Fields {
+    /// Returns when the object expires under a bucket lifecycle rule, and which rule applies.
+    ///
+    /// Returns `None` if no expiration rule matches the object or the header is malformed.
+    #[inline]
+    fn expiration(&self) -> Option<ObjectExpiration> {
+        self.headers()
+            .get(X_AMZ_EXPIRATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(ObjectExpiration::parse)
+    }
+}
+
 /// Provides access to the `x-amz-delete-marker` header value.
 ///
 /// Indicates whether the specified object version that was permanently deleted was (true) or
@@ -258,19 +354,12 @@ pub trait HasTagging: HasS3Fields {
     /// If the bucket has no tags, this will return an empty `HashMap`.
     #[inline]
     fn tags(&self) -> Result<HashMap<String, String>, ValidationErr> {
-        let mut tags = HashMap::new();
         if self.body().is_empty() {
             // Note: body is empty when server responses with NoSuchTagSet
-            return Ok(tags);
+            return Ok(HashMap::new());
         }
-        let mut root = Element::parse(self.body().clone().reader())?;
-        let element = root
-            .get_mut_child("TagSet")
-            .ok_or(ValidationErr::xml_error("<TagSet> tag not found"))?;
-        while let Some(v) = element.take_child("Tag") {
-            tags.insert(get_text_result(&v, "Key")?, get_text_result(&v, "Value")?);
-        }
-        Ok(tags)
+        let root = Element::parse(self.body().clone().reader())?;
+        Ok(Tags::from_xml(&root)?.into())
     }
 }
 
@@ -334,6 +423,21 @@ pub trait HasChecksumHeaders: HasS3Fields {
             .map(|s| s.to_string())
     }
 
+    /// Returns the typed checksum of the object reported by the server.
+    ///
+    /// Combines whichever `x-amz-checksum-*` value header is present with the
+    /// `x-amz-checksum-type` header. A composite checksum value of the form
+    /// `<base64>-N` is split into its value and part count.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(checksum)` if a checksum header is found
+    /// - `None` if no checksum headers are present
+    #[inline]
+    fn checksum(&self) -> Option<ObjectChecksum> {
+        ObjectChecksum::from_headers(self.headers())
+    }
+
     /// Detects which checksum algorithm was used by the server (if any).
     ///
     /// Examines response headers to determine if the server computed a checksum
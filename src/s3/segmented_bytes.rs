@@ -37,6 +37,8 @@ impl SegmentedBytes {
         }
     }
 
+    /// Returns the total number of bytes. The length is maintained on mutation,
+    /// so this does not walk the segments.
     pub fn len(&self) -> usize {
         self.total_size
     }
@@ -54,6 +56,17 @@ impl SegmentedBytes {
         }
     }
 
+    /// Appends `bytes` to the end; same as [`append`](Self::append).
+    pub fn push(&mut self, bytes: Bytes) {
+        self.append(bytes);
+    }
+
+    /// Moves all segments of `other` to the end without copying the bytes.
+    pub fn extend(&mut self, other: SegmentedBytes) {
+        self.total_size += other.total_size;
+        self.segments.extend(other.segments);
+    }
+
     pub fn iter(&self) -> SegmentedBytesIterator<'_> {
         SegmentedBytesIterator {
             sb: self,
@@ -181,3 +194,35 @@ impl From<String> for SegmentedBytes {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_has_zero_len() {
+        let sb = SegmentedBytes::new();
+        assert_eq!(sb.len(), 0);
+        assert!(sb.is_empty());
+        assert_eq!(sb.iter().count(), 0);
+    }
+
+    #[test]
+    fn push_and_extend_maintain_len() {
+        let mut sb = SegmentedBytes::new();
+        sb.push(Bytes::from_static(b"abc"));
+        sb.push(Bytes::new());
+        assert_eq!(sb.len(), 3);
+
+        let mut other = SegmentedBytes::from(Bytes::from_static(b"de"));
+        other.push(Bytes::from_static(b"fgh"));
+        sb.extend(other);
+        assert_eq!(sb.len(), 8);
+
+        sb.extend(SegmentedBytes::new());
+        sb.push(Bytes::from_static(b"i"));
+        assert_eq!(sb.len(), 9);
+        assert_eq!(sb.to_bytes(), Bytes::from_static(b"abcdefghi"));
+        assert_eq!(sb.iter().map(|b| b.len()).sum::<usize>(), sb.len());
+    }
+}
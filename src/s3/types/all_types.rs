@@ -16,6 +16,8 @@
 //! Comprehensive S3 data types for serialization, notifications, replication, and object locking
 
 use super::basic_types::RetentionMode;
+use super::s3_filter::S3Filter;
+pub use super::s3_filter::{AndOperator, Filter};
 use crate::s3::error::ValidationErr;
 use crate::s3::types::header_constants::*;
 use crate::s3::utils::{UtcTime, get_text_option, get_text_result};
@@ -637,132 +639,6 @@ pub struct Tag {
     pub value: String,
 }
 
-#[derive(PartialEq, Clone, Debug)]
-/// The 'And' operator contains prefix and tags
-pub struct AndOperator {
-    pub prefix: Option<String>,
-    pub tags: Option<HashMap<String, String>>,
-}
-
-#[derive(Clone, Debug, PartialEq, Default)]
-/// Filter information
-pub struct Filter {
-    pub and_operator: Option<AndOperator>,
-    pub prefix: Option<String>,
-    pub tag: Option<Tag>,
-}
-
-impl Filter {
-    pub fn from_xml(element: &Element) -> Result<Filter, ValidationErr> {
-        let and_operator = match element.get_child("And") {
-            Some(v) => Some(AndOperator {
-                prefix: match v.get_child("Prefix") {
-                    Some(p) => Some(
-                        p.get_text()
-                            .ok_or(ValidationErr::xml_error(
-                                "the text of <Prefix>-tag not found",
-                            ))?
-                            .to_string(),
-                    ),
-                    None => None,
-                },
-                tags: match v.get_child("Tag") {
-                    Some(tags) => {
-                        let mut map: HashMap<String, String> = HashMap::new();
-                        for xml_node in &tags.children {
-                            let tag = xml_node
-                                .as_element()
-                                .ok_or(ValidationErr::xml_error("<Tag> element not found"))?;
-                            map.insert(
-                                get_text_result(tag, "Key")?,
-                                get_text_result(tag, "Value")?,
-                            );
-                        }
-                        Some(map)
-                    }
-                    None => None,
-                },
-            }),
-            None => None,
-        };
-
-        let prefix = match element.get_child("Prefix") {
-            Some(v) => Some(
-                v.get_text()
-                    .ok_or(ValidationErr::xml_error(
-                        "the text of <Prefix>-tag not found",
-                    ))?
-                    .to_string(),
-            ),
-            None => None,
-        };
-
-        let tag = match element.get_child("Tag") {
-            Some(v) => Some(Tag {
-                key: get_text_result(v, "Key")?,
-                value: get_text_result(v, "Value")?,
-            }),
-            None => None,
-        };
-
-        Ok(Filter {
-            and_operator,
-            prefix,
-            tag,
-        })
-    }
-
-    pub fn validate(&self) -> Result<(), ValidationErr> {
-        if self.and_operator.is_some() ^ self.prefix.is_some() ^ self.tag.is_some() {
-            return Ok(());
-        }
-        Err(ValidationErr::InvalidFilter(self.to_xml()))
-    }
-
-    pub fn to_xml(&self) -> String {
-        let mut data = String::from("<Filter>");
-        if let Some(and_op) = &self.and_operator {
-            data.push_str("<And>");
-            if let Some(prefix) = &and_op.prefix {
-                data.push_str("<Prefix>");
-                data.push_str(prefix);
-                data.push_str("</Prefix>");
-            }
-            if let Some(tags) = &and_op.tags {
-                for (key, value) in tags {
-                    data.push_str("<Tag>");
-                    data.push_str("<Key>");
-                    data.push_str(key);
-                    data.push_str("</Key>");
-                    data.push_str("<Value>");
-                    data.push_str(value);
-                    data.push_str("</Value>");
-                    data.push_str("</Tag>");
-                }
-            }
-            data.push_str("</And>");
-        }
-        if let Some(prefix) = &self.prefix {
-            data.push_str("<Prefix>");
-            data.push_str(prefix);
-            data.push_str("</Prefix>");
-        }
-        if let Some(tag) = &self.tag {
-            data.push_str("<Tag>");
-            data.push_str("<Key>");
-            data.push_str(&tag.key);
-            data.push_str("</Key>");
-            data.push_str("<Value>");
-            data.push_str(&tag.value);
-            data.push_str("</Value>");
-            data.push_str("</Tag>");
-        }
-        data.push_str("</Filter>");
-
-        data
-    }
-}
-
 #[allow(clippy::type_complexity)]
 fn parse_common_notification_config(
     element: &mut Element,
@@ -1327,7 +1203,7 @@ pub struct ReplicationRule {
     pub destination: Destination,
     pub delete_marker_replication_status: Option<bool>,
     pub existing_object_replication_status: Option<bool>,
-    pub filter: Option<Filter>,
+    pub filter: Option<S3Filter>,
     pub id: Option<String>,
     pub prefix: Option<String>,
     pub priority: Option<i32>,
@@ -1354,7 +1230,7 @@ impl ReplicationRule {
                 _ => None,
             },
             filter: match element.get_child("Filter") {
-                Some(v) => Some(Filter::from_xml(v)?),
+                Some(v) => Some(S3Filter::from_xml(v)?),
                 _ => None,
             },
             id: get_text_option(element, "ID"),
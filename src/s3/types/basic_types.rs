@@ -16,15 +16,18 @@
 //! Basic S3 data types: ListEntry, Bucket, Part, Retention, etc.
 
 use crate::s3::error::ValidationErr;
-use crate::s3::types::{BucketName, ETag};
-use crate::s3::utils::{ChecksumAlgorithm, UtcTime};
+use crate::s3::multimap_ext::{Multimap, MultimapExt};
+use crate::s3::types::{BucketName, ETag, Region};
+use crate::s3::utils::{ChecksumAlgorithm, UtcTime, from_http_header_value, to_http_header_value};
+use serde::Serialize;
 use std::collections::HashMap;
 use std::fmt;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 /// Contains information of an item of [list_objects()](crate::s3::client::MinioClient::list_objects) API
 pub struct ListEntry {
     pub name: String,
+    #[serde(with = "crate::s3::utils::aws_date_format_opt")]
     pub last_modified: Option<UtcTime>,
     pub etag: Option<String>, // except DeleteMarker
     pub owner_id: Option<String>,
@@ -40,11 +43,39 @@ pub struct ListEntry {
     pub encoding_type: Option<String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[non_exhaustive]
 /// Contains the bucket name and creation date
 pub struct Bucket {
     pub name: BucketName,
+    #[serde(with = "crate::s3::utils::aws_date_format")]
     pub creation_date: UtcTime,
+    /// Region of the bucket, as returned in `BucketRegion` by newer servers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+}
+
+impl Bucket {
+    /// Creates a bucket entry without a region.
+    pub fn new(name: BucketName, creation_date: UtcTime) -> Self {
+        Self {
+            name,
+            creation_date,
+            region: None,
+        }
+    }
+
+    pub fn name(&self) -> &BucketName {
+        &self.name
+    }
+
+    pub fn creation_date(&self) -> UtcTime {
+        self.creation_date
+    }
+
+    pub fn region(&self) -> Option<&Region> {
+        self.region.as_ref()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -119,6 +150,110 @@ pub struct Retention {
     pub retain_until_date: UtcTime,
 }
 
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Response headers of a GET replaced by the server, sent as `response-*` query
+/// parameters
+pub struct ResponseHeaderOverrides {
+    pub content_type: Option<String>,
+    pub content_language: Option<String>,
+    pub content_disposition: Option<String>,
+    pub content_encoding: Option<String>,
+    pub cache_control: Option<String>,
+    pub expires: Option<UtcTime>,
+}
+
+impl ResponseHeaderOverrides {
+    /// Adds the set overrides to the query parameters of a request.
+    pub fn add_to(&self, query_params: &mut Multimap) {
+        let values = [
+            ("response-content-type", &self.content_type),
+            ("response-content-language", &self.content_language),
+            ("response-content-disposition", &self.content_disposition),
+            ("response-content-encoding", &self.content_encoding),
+            ("response-cache-control", &self.cache_control),
+        ];
+        for (key, value) in values {
+            if let Some(v) = value {
+                query_params.add(key, v);
+            }
+        }
+        if let Some(v) = self.expires {
+            query_params.add("response-expires", to_http_header_value(v));
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Lifecycle expiry of an object, from the `x-amz-expiration` response header
+pub struct ObjectExpiration {
+    pub expiry_date: UtcTime,
+    pub rule_id: String,
+}
+
+impl ObjectExpiration {
+    /// Parses a header value such as
+    /// `expiry-date="Sun, 23 Dec 2012 00:00:00 GMT", rule-id="picture-deletion-rule"`.
+    ///
+    /// Returns `None` if the expiry date or rule ID is missing or malformed.
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut expiry_date = None;
+        let mut rule_id = None;
+        let mut rest = value.trim();
+        while !rest.is_empty() {
+            let (key, after) = rest.split_once('=')?;
+            let after = after.trim_start();
+            let (val, after) = match after.strip_prefix('"') {
+                Some(quoted) => {
+                    let (val, after) = quoted.split_once('"')?;
+                    (val, after.trim_start())
+                }
+                None => {
+                    let end = after.find(',').unwrap_or(after.len());
+                    (after[..end].trim_end(), &after[end..])
+                }
+            };
+            match key.trim() {
+                "expiry-date" => expiry_date = Some(from_http_header_value(val).ok()?),
+                "rule-id" => rule_id = Some(val.to_string()),
+                _ => {}
+            }
+            rest = after.strip_prefix(',').unwrap_or(after).trim_start();
+        }
+        Some(Self {
+            expiry_date: expiry_date?,
+            rule_id: rule_id?,
+        })
+    }
+}
+
+/// Byte range served by a partial response, from the `Content-Range` header
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContentRange {
+    /// Offset of the first byte served.
+    pub start: u64,
+    /// Offset of the last byte served, inclusive.
+    pub end: u64,
+    /// Size of the whole object, or `None` if the server sent `*`.
+    pub total: Option<u64>,
+}
+
+impl ContentRange {
+    /// Parses a header value such as `bytes 0-99/12345` or `bytes 0-99/*`.
+    ///
+    /// Returns `None` if the value is malformed or has the `bytes */12345` form
+    /// of an unsatisfiable range.
+    pub fn parse(value: &str) -> Option<Self> {
+        let (range, total) = value.trim().strip_prefix("bytes ")?.split_once('/')?;
+        let (start, end) = range.split_once('-')?;
+        let (start, end): (u64, u64) = (start.parse().ok()?, end.parse().ok()?);
+        let total = match total {
+            "*" => None,
+            v => Some(v.parse().ok()?),
+        };
+        (start <= end).then_some(Self { start, end, total })
+    }
+}
+
 /// Parses 'legal hold' string value
 pub fn parse_legal_hold(s: &str) -> Result<bool, ValidationErr> {
     if s.eq_ignore_ascii_case("ON") {
@@ -129,3 +264,74 @@ pub fn parse_legal_hold(s: &str) -> Result<bool, ValidationErr> {
         Err(ValidationErr::InvalidLegalHold(s.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_content_range_parse() {
+        assert_eq!(
+            ContentRange::parse("bytes 0-99/12345"),
+            Some(ContentRange {
+                start: 0,
+                end: 99,
+                total: Some(12345),
+            })
+        );
+        assert_eq!(
+            ContentRange::parse("bytes 0-99/*"),
+            Some(ContentRange {
+                start: 0,
+                end: 99,
+                total: None,
+            })
+        );
+        assert_eq!(
+            ContentRange::parse("bytes 0-0/1").map(|v| (v.start, v.end)),
+            Some((0, 0))
+        );
+        assert_eq!(ContentRange::parse("bytes */100"), None);
+        assert_eq!(ContentRange::parse("bytes 10-9/100"), None);
+        assert_eq!(ContentRange::parse("bytes 0-99/many"), None);
+        assert_eq!(ContentRange::parse(""), None);
+    }
+
+    #[test]
+    fn test_object_expiration_parse() {
+        let exp = ObjectExpiration::parse(
+            "expiry-date=\"Sun, 23 Dec 2012 00:00:00 GMT\", rule-id=\"picture-deletion-rule\"",
+        )
+        .unwrap();
+        assert_eq!(
+            exp,
+            ObjectExpiration {
+                expiry_date: Utc.with_ymd_and_hms(2012, 12, 23, 0, 0, 0).unwrap(),
+                rule_id: "picture-deletion-rule".to_string(),
+            }
+        );
+
+        // Key order is not fixed and rule IDs may contain commas.
+        let exp = ObjectExpiration::parse(
+            "rule-id=\"a, b\",expiry-date=\"Sun, 05 Jan 2025 00:00:00 GMT\"",
+        )
+        .unwrap();
+        assert_eq!(exp.rule_id, "a, b");
+        assert_eq!(
+            exp.expiry_date,
+            Utc.with_ymd_and_hms(2025, 1, 5, 0, 0, 0).unwrap()
+        );
+
+        assert_eq!(ObjectExpiration::parse(""), None);
+        assert_eq!(ObjectExpiration::parse("rule-id=\"r\""), None);
+        assert_eq!(
+            ObjectExpiration::parse("expiry-date=\"yesterday\", rule-id=\"r\""),
+            None
+        );
+        assert_eq!(
+            ObjectExpiration::parse("expiry-date=\"Sun, 23 Dec 2012 00:00:00 GMT"),
+            None
+        );
+    }
+}
@@ -0,0 +1,171 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed object checksum parsed from `x-amz-checksum-*` response headers
+
+use crate::s3::header_constants::X_AMZ_CHECKSUM_TYPE;
+use crate::s3::utils::ChecksumAlgorithm;
+use http::HeaderMap;
+use std::fmt;
+
+/// Checksum algorithms in the order their headers are looked up.
+const ALGORITHMS: [ChecksumAlgorithm; 5] = [
+    ChecksumAlgorithm::CRC32,
+    ChecksumAlgorithm::CRC32C,
+    ChecksumAlgorithm::CRC64NVME,
+    ChecksumAlgorithm::SHA1,
+    ChecksumAlgorithm::SHA256,
+];
+
+/// Whether a checksum covers the full object or is a checksum of part checksums.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumType {
+    /// Checksum computed over the entire object content
+    FullObject,
+    /// Checksum of the part checksums of a multipart upload
+    Composite,
+}
+
+impl ChecksumType {
+    /// Returns the value used in the `x-amz-checksum-type` header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChecksumType::FullObject => "FULL_OBJECT",
+            ChecksumType::Composite => "COMPOSITE",
+        }
+    }
+}
+
+impl std::str::FromStr for ChecksumType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "FULL_OBJECT" => Ok(ChecksumType::FullObject),
+            "COMPOSITE" => Ok(ChecksumType::Composite),
+            _ => Err(format!("Unknown checksum type: {s}")),
+        }
+    }
+}
+
+impl fmt::Display for ChecksumType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Checksum of an object as reported by the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObjectChecksum {
+    /// Algorithm of the checksum
+    pub algorithm: ChecksumAlgorithm,
+    /// Base64-encoded checksum value, without any `-N` part count suffix
+    pub value: String,
+    /// Number of parts for a composite checksum reported as `<value>-N`
+    pub part_count: Option<u32>,
+    /// Checksum type from the `x-amz-checksum-type` header, if present
+    pub checksum_type: Option<ChecksumType>,
+}
+
+impl ObjectChecksum {
+    /// Parses the checksum from response headers.
+    ///
+    /// Returns `None` if no `x-amz-checksum-*` value header is present.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let (algorithm, raw) = ALGORITHMS.iter().find_map(|algorithm| {
+            headers
+                .get(algorithm.header_name())
+                .and_then(|v| v.to_str().ok())
+                .map(|v| (*algorithm, v.trim()))
+        })?;
+
+        // Standard base64 never contains '-', so a trailing `-<digits>` is a part count.
+        let (value, part_count) = match raw.rsplit_once('-') {
+            Some((value, count))
+                if !count.is_empty() && count.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                (value, count.parse().ok())
+            }
+            _ => (raw, None),
+        };
+
+        let checksum_type = headers
+            .get(X_AMZ_CHECKSUM_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok());
+
+        Some(Self {
+            algorithm,
+            value: value.to_string(),
+            part_count,
+            checksum_type,
+        })
+    }
+
+    /// Returns true if this is a composite (multipart) checksum.
+    pub fn is_composite(&self) -> bool {
+        self.checksum_type == Some(ChecksumType::Composite)
+            || (self.checksum_type.is_none() && self.part_count.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    #[test]
+    fn test_object_checksum_crc32c_full_object() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-amz-checksum-crc32c",
+            HeaderValue::from_static("yZRlqg=="),
+        );
+        headers.insert(
+            "x-amz-checksum-type",
+            HeaderValue::from_static("FULL_OBJECT"),
+        );
+
+        let checksum = ObjectChecksum::from_headers(&headers).unwrap();
+        assert_eq!(checksum.algorithm, ChecksumAlgorithm::CRC32C);
+        assert_eq!(checksum.value, "yZRlqg==");
+        assert_eq!(checksum.part_count, None);
+        assert_eq!(checksum.checksum_type, Some(ChecksumType::FullObject));
+        assert!(!checksum.is_composite());
+    }
+
+    #[test]
+    fn test_object_checksum_composite_suffix() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-amz-checksum-crc32c",
+            HeaderValue::from_static("yZRlqg==-3"),
+        );
+        headers.insert("x-amz-checksum-type", HeaderValue::from_static("COMPOSITE"));
+
+        let checksum = ObjectChecksum::from_headers(&headers).unwrap();
+        assert_eq!(checksum.value, "yZRlqg==");
+        assert_eq!(checksum.part_count, Some(3));
+        assert_eq!(checksum.checksum_type, Some(ChecksumType::Composite));
+        assert!(checksum.is_composite());
+    }
+
+    #[test]
+    fn test_object_checksum_absent() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-amz-checksum-type", HeaderValue::from_static("COMPOSITE"));
+        assert!(ObjectChecksum::from_headers(&headers).is_none());
+    }
+}
@@ -20,13 +20,17 @@ pub const IF_UNMODIFIED_SINCE: &str = "If-Unmodified-Since";
 pub const CONTENT_MD5: &str = "Content-MD5";
 pub const CONTENT_TYPE: &str = "Content-Type";
 pub const AUTHORIZATION: &str = "Authorization";
+pub const DATE: &str = "Date";
 pub const RANGE: &str = "Range";
+pub const CONTENT_RANGE: &str = "Content-Range";
 pub const HOST: &str = "Host";
 pub const CONTENT_LENGTH: &str = "Content-Length";
+pub const EXPIRES: &str = "Expires";
 
 pub const POLICY: &str = "policy";
 
 pub const X_MINIO_DEPLOYMENT_ID: &str = "X-Minio-Deployment-Id";
+pub const X_MINIO_IDEMPOTENCY_ID: &str = "X-Minio-Idempotency-Id";
 
 pub const X_AMZ_VERSION_ID: &str = "X-Amz-Version-Id";
 pub const X_AMZ_ID_2: &str = "X-Amz-Id-2";
@@ -34,6 +38,8 @@ pub const X_AMZ_WRITE_OFFSET_BYTES: &str = "X-Amz-Write-Offset-Bytes";
 
 pub const X_AMZ_OBJECT_SIZE: &str = "X-Amz-Object-Size";
 pub const X_AMZ_TAGGING: &str = "X-Amz-Tagging";
+pub const X_AMZ_TAGGING_COUNT: &str = "X-Amz-Tagging-Count";
+pub const X_AMZ_EXPIRATION: &str = "X-Amz-Expiration";
 
 pub const X_AMZ_BUCKET_REGION: &str = "X-Amz-Bucket-Region";
 
@@ -74,6 +80,10 @@ pub const X_AMZ_SIGNATURE: &str = "X-Amz-Signature";
 
 pub const X_AMZ_REQUEST_ID: &str = "X-Amz-Request-Id";
 
+pub const X_AMZ_REQUEST_PAYER: &str = "X-Amz-Request-Payer";
+
+pub const X_AMZ_REQUEST_CHARGED: &str = "X-Amz-Request-Charged";
+
 pub const X_AMZ_EXPIRES: &str = "X-Amz-Expires";
 
 pub const X_AMZ_SIGNED_HEADERS: &str = "X-Amz-SignedHeaders";
@@ -86,6 +96,9 @@ pub const X_AMZ_SERVER_SIDE_ENCRYPTION: &str = "X-Amz-Server-Side-Encryption";
 
 pub const X_AMZ_SERVER_SIDE_ENCRYPTION_CONTEXT: &str = "X-Amz-Server-Side-Encryption-Context";
 
+pub const X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED: &str =
+    "X-Amz-Server-Side-Encryption-Bucket-Key-Enabled";
+
 pub const X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID: &str =
     "X-Amz-Server-Side-Encryption-Aws-Kms-Key-Id";
 
@@ -120,12 +133,17 @@ pub const X_AMZ_CHECKSUM_CRC64NVME: &str = "X-Amz-Checksum-CRC64NVME";
 
 pub const X_AMZ_CHECKSUM_TYPE: &str = "X-Amz-Checksum-Type";
 
+pub const X_AMZ_CHECKSUM_MODE: &str = "X-Amz-Checksum-Mode";
+
 pub const X_AMZ_TRAILER: &str = "X-Amz-Trailer";
 
 pub const X_AMZ_DECODED_CONTENT_LENGTH: &str = "X-Amz-Decoded-Content-Length";
 
 pub const CONTENT_ENCODING: &str = "Content-Encoding";
 
+/// Content-SHA256 value for requests whose payload is not included in the signature
+pub const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+
 /// Content-SHA256 value for streaming uploads with unsigned payload and trailing checksum
 pub const STREAMING_UNSIGNED_PAYLOAD_TRAILER: &str = "STREAMING-UNSIGNED-PAYLOAD-TRAILER";
 
@@ -14,8 +14,9 @@
 // limitations under the License.
 
 use crate::s3::error::ValidationErr;
-use crate::s3::types::Filter;
+use crate::s3::types::S3Filter;
 use crate::s3::utils::to_iso8601utc;
+use std::collections::HashSet;
 use xmltree::Element;
 
 #[derive(PartialEq, Clone, Debug, Default)]
@@ -38,16 +39,28 @@ impl LifecycleConfig {
         Ok(config)
     }
 
-    pub fn validate(&self) -> Result<(), ValidationErr> {
-        // Skip validation if empty
-        if self.rules.is_empty() {
-            return Ok(());
-        }
+    /// Returns an iterator over the rules of this configuration.
+    pub fn iter_rules(&self) -> impl Iterator<Item = &LifecycleRule> {
+        self.rules.iter()
+    }
+
+    /// Returns the rule with the given ID, if any.
+    pub fn rule(&self, id: &str) -> Option<&LifecycleRule> {
+        self.rules.iter().find(|r| r.id == id)
+    }
 
+    /// Validates every rule and checks that rule IDs are unique.
+    pub fn validate(&self) -> Result<(), ValidationErr> {
+        let mut ids: HashSet<&str> = HashSet::new();
         for rule in &self.rules {
             rule.validate()?;
+            if !ids.insert(&rule.id) {
+                return Err(ValidationErr::invalid_lifecycle_rule(
+                    &rule.id,
+                    "duplicate rule ID",
+                ));
+            }
         }
-
         Ok(())
     }
 
@@ -141,10 +154,18 @@ impl LifecycleConfig {
             }
 
             // NoncurrentVersionExpiration
-            if let Some(days) = rule.noncurrent_version_expiration_noncurrent_days {
-                data.push_str("<NoncurrentVersionExpiration><NoncurrentDays>");
-                data.push_str(&days.to_string());
-                data.push_str("</NoncurrentDays>");
+            let has_noncurrent_expiration =
+                rule.noncurrent_version_expiration_noncurrent_days.is_some()
+                    || rule.noncurrent_version_expiration_newer_versions.is_some();
+
+            if has_noncurrent_expiration {
+                data.push_str("<NoncurrentVersionExpiration>");
+
+                if let Some(days) = rule.noncurrent_version_expiration_noncurrent_days {
+                    data.push_str("<NoncurrentDays>");
+                    data.push_str(&days.to_string());
+                    data.push_str("</NoncurrentDays>");
+                }
 
                 if let Some(versions) = rule.noncurrent_version_expiration_newer_versions {
                     data.push_str("<NewerNoncurrentVersions>");
@@ -227,7 +248,7 @@ pub struct LifecycleRule {
     // Common
     pub id: String,
     pub status: bool,
-    pub filter: Filter,
+    pub filter: S3Filter,
 
     // Expiration
     pub expiration_days: Option<u32>,
@@ -249,6 +270,8 @@ pub struct LifecycleRule {
 
     // NoncurrentVersionExpiration
     pub noncurrent_version_expiration_noncurrent_days: Option<u32>,
+    /// Number of most recent noncurrent versions to retain; older noncurrent
+    /// versions expire once they are `noncurrent_days` old.
     pub noncurrent_version_expiration_newer_versions: Option<u32>,
 
     // NoncurrentVersionTransition
@@ -282,7 +305,7 @@ impl LifecycleRule {
 
         // Parse Filter
         if let Some(filter_elem) = rule_elem.get_child("Filter") {
-            rule.filter = Filter::from_xml(filter_elem)?;
+            rule.filter = S3Filter::from_xml(filter_elem)?;
         }
 
         // Parse AbortIncompleteMultipartUpload
@@ -449,43 +472,106 @@ impl LifecycleRule {
         Ok(rule)
     }
 
+    /// Returns true if the rule specifies at least one expiration, transition or
+    /// abort action.
+    pub fn has_action(&self) -> bool {
+        self.expiration_days.is_some()
+            || self.expiration_date.is_some()
+            || self.expiration_expired_object_delete_marker == Some(true)
+            || self.expiration_expired_object_all_versions == Some(true)
+            || self.del_marker_expiration_days.is_some()
+            || self.all_versions_expiration_days.is_some()
+            || self.transition_days.is_some()
+            || self.transition_date.is_some()
+            || self.noncurrent_version_expiration_noncurrent_days.is_some()
+            || self.noncurrent_version_expiration_newer_versions.is_some()
+            || self.noncurrent_version_transition_noncurrent_days.is_some()
+            || self
+                .abort_incomplete_multipart_upload_days_after_initiation
+                .is_some()
+    }
+
+    /// Checks the rule the way the server does and returns
+    /// [`ValidationErr::InvalidLifecycleRule`] on the first conflict found.
     pub fn validate(&self) -> Result<(), ValidationErr> {
-        // Basic validation requirements
+        let err = |reason: &str| Err(ValidationErr::invalid_lifecycle_rule(&self.id, reason));
 
-        // Ensure ID is present
         if self.id.is_empty() {
-            return Err(ValidationErr::xml_error("Rule ID cannot be empty"));
+            return err("rule ID cannot be empty");
         }
 
-        // Validate storage classes in transitions
-        if let Some(storage_class) = &self.transition_storage_class
-            && storage_class.is_empty()
-        {
-            return Err(ValidationErr::xml_error(
-                "Transition StorageClass cannot be empty",
-            ));
+        if !self.has_action() {
+            return err("rule must specify at least one action");
         }
 
-        if let Some(storage_class) = &self.noncurrent_version_transition_storage_class
-            && storage_class.is_empty()
+        // A lifecycle filter may be empty (the rule applies to all objects), but
+        // at most one of And, Prefix and Tag may be set.
+        let filter = &self.filter;
+        let filter_parts = [
+            filter.and_operator.is_some(),
+            filter.prefix.is_some(),
+            filter.tag.is_some(),
+        ];
+        if filter_parts.iter().filter(|v| **v).count() > 1 {
+            return err("filter can specify only one of And, Prefix and Tag");
+        }
+        let has_size =
+            filter.object_size_greater_than.is_some() || filter.object_size_less_than.is_some();
+        if has_size
+            && (filter_parts.contains(&true)
+                || (filter.object_size_greater_than.is_some()
+                    && filter.object_size_less_than.is_some()))
         {
-            return Err(ValidationErr::xml_error(
-                "NoncurrentVersionTransition StorageClass cannot be empty",
-            ));
+            return err("filter can specify only one condition outside of And");
+        }
+        if let Some(and) = &filter.and_operator
+            && and.prefix.is_none()
+            && and.tags.as_ref().is_none_or(|t| t.is_empty())
+            && and.object_size_greater_than.is_none()
+            && and.object_size_less_than.is_none()
+        {
+            return err("filter And must specify a prefix, a tag or an object size");
+        }
+        let (greater_than, less_than) = match &filter.and_operator {
+            Some(and) => (and.object_size_greater_than, and.object_size_less_than),
+            None => (
+                filter.object_size_greater_than,
+                filter.object_size_less_than,
+            ),
+        };
+        if let (Some(greater_than), Some(less_than)) = (greater_than, less_than)
+            && greater_than >= less_than
+        {
+            return err("filter ObjectSizeGreaterThan must be less than ObjectSizeLessThan");
         }
 
-        // Check that expiration has either days or date, not both
         if self.expiration_days.is_some() && self.expiration_date.is_some() {
-            return Err(ValidationErr::xml_error(
-                "Expiration cannot specify both Days and Date",
-            ));
+            return err("Expiration cannot specify both Days and Date");
+        }
+        if self.expiration_expired_object_delete_marker == Some(true)
+            && (self.expiration_days.is_some() || self.expiration_date.is_some())
+        {
+            return err("Expiration cannot specify ExpiredObjectDeleteMarker with Days or Date");
         }
 
-        // Check that transition has either days or date, not both
         if self.transition_days.is_some() && self.transition_date.is_some() {
-            return Err(ValidationErr::xml_error(
-                "Transition cannot specify both Days and Date",
-            ));
+            return err("Transition cannot specify both Days and Date");
+        }
+        if (self.transition_days.is_some() || self.transition_date.is_some())
+            && self
+                .transition_storage_class
+                .as_ref()
+                .is_none_or(|v| v.is_empty())
+        {
+            return err("Transition StorageClass cannot be empty");
+        }
+        if self.noncurrent_version_transition_noncurrent_days.is_some()
+            && self
+                .noncurrent_version_transition_storage_class
+                .as_ref()
+                .is_none_or(|v| v.is_empty())
+        {
+            return err("NoncurrentVersionTransition StorageClass cannot be empty");
         }
 
         Ok(())
@@ -500,3 +586,252 @@ fn parse_iso8601(date_str: &str) -> Result<chrono::DateTime<chrono::Utc>, Valida
             ValidationErr::xml_error_with_source(format!("Invalid date format: {date_str}"), e)
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::types::{AndOperator, Tag};
+    use std::collections::HashMap;
+
+    fn expiring_rule(id: &str) -> LifecycleRule {
+        LifecycleRule {
+            id: id.to_string(),
+            status: true,
+            expiration_days: Some(30),
+            ..Default::default()
+        }
+    }
+
+    fn assert_invalid(rule: LifecycleRule, expected_reason: &str) {
+        match rule.validate() {
+            Err(ValidationErr::InvalidLifecycleRule { reason, .. }) => {
+                assert_eq!(reason, expected_reason)
+            }
+            other => panic!("expected InvalidLifecycleRule, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_valid_config_and_accessors() {
+        let config = LifecycleConfig {
+            rules: vec![
+                LifecycleRule {
+                    filter: S3Filter {
+                        prefix: Some("logs/".to_string()),
+                        ..Default::default()
+                    },
+                    ..expiring_rule("expire-logs")
+                },
+                LifecycleRule {
+                    id: "archive".to_string(),
+                    status: true,
+                    filter: S3Filter {
+                        tag: Some(Tag {
+                            key: "tier".to_string(),
+                            value: "cold".to_string(),
+                        }),
+                        ..Default::default()
+                    },
+                    transition_days: Some(90),
+                    transition_storage_class: Some("GLACIER".to_string()),
+                    ..Default::default()
+                },
+            ],
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(config.iter_rules().count(), 2);
+        assert_eq!(config.rule("archive").unwrap().transition_days, Some(90));
+        assert!(config.rule("missing").is_none());
+    }
+
+    #[test]
+    fn test_rule_without_action() {
+        let rule = LifecycleRule {
+            id: "noop".to_string(),
+            status: true,
+            ..Default::default()
+        };
+        assert_invalid(rule, "rule must specify at least one action");
+    }
+
+    #[test]
+    fn test_expiration_days_and_date() {
+        let rule = LifecycleRule {
+            expiration_date: Some(chrono::Utc::now()),
+            ..expiring_rule("both")
+        };
+        assert_invalid(rule, "Expiration cannot specify both Days and Date");
+    }
+
+    #[test]
+    fn test_expired_delete_marker_with_days() {
+        let rule = LifecycleRule {
+            expiration_expired_object_delete_marker: Some(true),
+            ..expiring_rule("marker")
+        };
+        assert_invalid(
+            rule,
+            "Expiration cannot specify ExpiredObjectDeleteMarker with Days or Date",
+        );
+    }
+
+    #[test]
+    fn test_invalid_filters() {
+        let rule = LifecycleRule {
+            filter: S3Filter {
+                prefix: Some("logs/".to_string()),
+                tag: Some(Tag {
+                    key: "k".to_string(),
+                    value: "v".to_string(),
+                }),
+                ..Default::default()
+            },
+            ..expiring_rule("prefix-and-tag")
+        };
+        assert_invalid(rule, "filter can specify only one of And, Prefix and Tag");
+
+        let rule = LifecycleRule {
+            filter: S3Filter {
+                and_operator: Some(AndOperator::default()),
+                ..Default::default()
+            },
+            ..expiring_rule("empty-and")
+        };
+        assert_invalid(
+            rule,
+            "filter And must specify a prefix, a tag or an object size",
+        );
+    }
+
+    #[test]
+    fn test_transition_without_storage_class() {
+        let rule = LifecycleRule {
+            id: "transition".to_string(),
+            status: true,
+            transition_days: Some(10),
+            ..Default::default()
+        };
+        assert_invalid(rule, "Transition StorageClass cannot be empty");
+    }
+
+    #[test]
+    fn test_duplicate_rule_ids() {
+        let config = LifecycleConfig {
+            rules: vec![expiring_rule("dup"), expiring_rule("dup")],
+        };
+        match config.validate() {
+            Err(ValidationErr::InvalidLifecycleRule { id, reason }) => {
+                assert_eq!(id, "dup");
+                assert_eq!(reason, "duplicate rule ID");
+            }
+            other => panic!("expected InvalidLifecycleRule, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_newer_noncurrent_versions_roundtrip() {
+        let roundtrip = |rule: LifecycleRule| {
+            let config = LifecycleConfig { rules: vec![rule] };
+            let xml = config.to_xml();
+            let parsed = LifecycleConfig::from_xml(&Element::parse(xml.as_bytes()).unwrap());
+            assert_eq!(parsed.unwrap(), config, "{xml}");
+            xml
+        };
+
+        let xml = roundtrip(LifecycleRule {
+            id: "keep-3".to_string(),
+            status: true,
+            noncurrent_version_expiration_noncurrent_days: Some(30),
+            noncurrent_version_expiration_newer_versions: Some(3),
+            ..Default::default()
+        });
+        assert!(xml.contains(
+            "<NoncurrentVersionExpiration><NoncurrentDays>30</NoncurrentDays>\
+             <NewerNoncurrentVersions>3</NewerNoncurrentVersions></NoncurrentVersionExpiration>"
+        ));
+
+        let rule = LifecycleRule {
+            id: "keep-5".to_string(),
+            status: true,
+            noncurrent_version_expiration_newer_versions: Some(5),
+            ..Default::default()
+        };
+        assert!(rule.validate().is_ok());
+        roundtrip(rule);
+    }
+
+    #[test]
+    fn test_object_size_filters() {
+        let roundtrip = |filter: S3Filter| {
+            let rule = LifecycleRule {
+                id: "large".to_string(),
+                status: true,
+                filter,
+                transition_days: Some(30),
+                transition_storage_class: Some("WARM".to_string()),
+                ..Default::default()
+            };
+            assert!(rule.validate().is_ok());
+            let config = LifecycleConfig { rules: vec![rule] };
+            let xml = config.to_xml();
+            let parsed = LifecycleConfig::from_xml(&Element::parse(xml.as_bytes()).unwrap());
+            assert_eq!(parsed.unwrap(), config, "{xml}");
+            xml
+        };
+
+        let xml = roundtrip(S3Filter {
+            object_size_greater_than: Some(1024 * 1024),
+            ..Default::default()
+        });
+        assert!(
+            xml.contains("<Filter><ObjectSizeGreaterThan>1048576</ObjectSizeGreaterThan></Filter>")
+        );
+
+        let xml = roundtrip(S3Filter {
+            and_operator: Some(AndOperator {
+                prefix: Some("logs/".to_string()),
+                tags: Some(HashMap::from([
+                    ("tier".to_string(), "cold".to_string()),
+                    ("team".to_string(), "ops".to_string()),
+                ])),
+                object_size_greater_than: Some(1024),
+                object_size_less_than: Some(1024 * 1024),
+            }),
+            ..Default::default()
+        });
+        assert!(xml.contains("<Filter><And><Prefix>logs/</Prefix><Tag>"));
+        assert!(xml.contains(
+            "<ObjectSizeGreaterThan>1024</ObjectSizeGreaterThan>\
+             <ObjectSizeLessThan>1048576</ObjectSizeLessThan></And></Filter>"
+        ));
+    }
+
+    #[test]
+    fn test_invalid_object_size_filters() {
+        let rule = LifecycleRule {
+            filter: S3Filter {
+                prefix: Some("logs/".to_string()),
+                object_size_greater_than: Some(1024),
+                ..Default::default()
+            },
+            ..expiring_rule("prefix-and-size")
+        };
+        assert_invalid(rule, "filter can specify only one condition outside of And");
+
+        let rule = LifecycleRule {
+            filter: S3Filter {
+                and_operator: Some(AndOperator {
+                    object_size_greater_than: Some(1024),
+                    object_size_less_than: Some(1024),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..expiring_rule("empty-range")
+        };
+        assert_invalid(
+            rule,
+            "filter ObjectSizeGreaterThan must be less than ObjectSizeLessThan",
+        );
+    }
+}
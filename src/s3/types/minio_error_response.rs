@@ -16,7 +16,8 @@
 extern crate alloc;
 
 use crate::s3::error::{Error, ValidationErr};
-use crate::s3::types::{BucketName, ObjectKey};
+use crate::s3::header_constants::X_AMZ_BUCKET_REGION;
+use crate::s3::types::{BucketName, ObjectKey, Region};
 use crate::s3::utils::{get_text_default, get_text_option};
 use bytes::{Buf, Bytes};
 use http::HeaderMap;
@@ -51,15 +52,18 @@ pub enum MinioErrorCode {
     NoSuchKey,
     //NoSuchUpload:                      "The specified multipart upload does not exist. The upload ID may be invalid, or the upload may have been aborted or completed.",
     //NotImplemented:                    "A header you provided implies functionality that is not implemented.",
-    //PreconditionFailed:                "At least one of the pre-conditions you specified did not hold.",
+    /// At least one of the pre-conditions you specified did not hold
+    PreconditionFailed,
+    /// The object was not modified; answer to a conditional GET or HEAD
+    NotModified,
     //RequestTimeTooSkewed:              "The difference between the request time and the server's time is too large.",
-    //SignatureDoesNotMatch:             "The request signature we calculated does not match the signature you provided. Check your key and signing method.",
+    /// The request signature we calculated does not match the signature you provided
+    SignatureDoesNotMatch,
     /// The specified method is not allowed against this resource
     MethodNotAllowed,
     //InvalidPart:                       "One or more of the specified parts could not be found.",
     //InvalidPartOrder:                  "The list of parts was not in ascending order. The parts list must be specified in order by part number.",
     //InvalidObjectState:                "The operation is not valid for the current state of the object.",
-    //AuthorizationHeaderMalformed:      "The authorization header is malformed; the region is wrong.",
     //MalformedPOSTRequest:              "The body of your POST request is not well-formed multipart/form-data.",
     /// The bucket you tried to delete is not empty
     BucketNotEmpty,
@@ -95,6 +99,22 @@ pub enum MinioErrorCode {
     InvalidWriteOffset,
     /// Attempted to use S3 DeleteBucket API on a warehouse bucket (S3 Tables)
     WarehouseBucketOperationNotSupported,
+    /// A conflicting conditional write to the same object is in progress
+    ConditionalRequestConflict,
+    /// The request is not valid with the current state of the bucket
+    InvalidBucketState,
+    /// The requested bucket name is not available; it is owned by another account
+    BucketAlreadyExists,
+    /// The authorization header is malformed; the region is wrong
+    AuthorizationHeaderMalformed,
+    /// The server asks the client to reduce its request rate
+    SlowDown,
+    /// The server is temporarily unable to handle the request
+    ServiceUnavailable,
+    /// The server encountered an internal error; the request may be retried
+    InternalError,
+    /// The connection was not read from or written to within the timeout
+    RequestTimeout,
 
     OtherError(String), // This is a catch-all for any error code not explicitly defined
 }
@@ -124,6 +144,17 @@ const ALL_MINIO_ERROR_CODE: &[MinioErrorCode] = &[
     MinioErrorCode::BucketAlreadyOwnedByYou,
     MinioErrorCode::InvalidWriteOffset,
     MinioErrorCode::WarehouseBucketOperationNotSupported,
+    MinioErrorCode::PreconditionFailed,
+    MinioErrorCode::NotModified,
+    MinioErrorCode::SignatureDoesNotMatch,
+    MinioErrorCode::ConditionalRequestConflict,
+    MinioErrorCode::InvalidBucketState,
+    MinioErrorCode::BucketAlreadyExists,
+    MinioErrorCode::AuthorizationHeaderMalformed,
+    MinioErrorCode::SlowDown,
+    MinioErrorCode::ServiceUnavailable,
+    MinioErrorCode::InternalError,
+    MinioErrorCode::RequestTimeout,
     //MinioErrorCode::OtherError("".to_string()),
 ];
 
@@ -161,12 +192,48 @@ impl FromStr for MinioErrorCode {
             "warehousebucketoperationnotsupported" => {
                 Ok(MinioErrorCode::WarehouseBucketOperationNotSupported)
             }
+            "preconditionfailed" => Ok(MinioErrorCode::PreconditionFailed),
+            "notmodified" => Ok(MinioErrorCode::NotModified),
+            "signaturedoesnotmatch" => Ok(MinioErrorCode::SignatureDoesNotMatch),
+            "conditionalrequestconflict" => Ok(MinioErrorCode::ConditionalRequestConflict),
+            "invalidbucketstate" => Ok(MinioErrorCode::InvalidBucketState),
+            "bucketalreadyexists" => Ok(MinioErrorCode::BucketAlreadyExists),
+            "authorizationheadermalformed" => Ok(MinioErrorCode::AuthorizationHeaderMalformed),
+            "slowdown" => Ok(MinioErrorCode::SlowDown),
+            "serviceunavailable" => Ok(MinioErrorCode::ServiceUnavailable),
+            "internalerror" => Ok(MinioErrorCode::InternalError),
+            "requesttimeout" => Ok(MinioErrorCode::RequestTimeout),
 
             v => Ok(MinioErrorCode::OtherError(v.to_owned())),
         }
     }
 }
 
+impl MinioErrorCode {
+    /// Returns whether the error is transient, so that replaying the same
+    /// request may succeed.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            MinioErrorCode::SlowDown
+                | MinioErrorCode::ServiceUnavailable
+                | MinioErrorCode::InternalError
+                | MinioErrorCode::RequestTimeout
+        )
+    }
+
+    /// Returns whether a conditional request was rejected because its
+    /// condition did not hold ([`MinioErrorCode::PreconditionFailed`], 412) or
+    /// because a concurrent conditional write to the same object is in progress
+    /// ([`MinioErrorCode::ConditionalRequestConflict`], 409).
+    pub fn is_precondition_failed(&self) -> bool {
+        matches!(
+            self,
+            MinioErrorCode::PreconditionFailed | MinioErrorCode::ConditionalRequestConflict
+        )
+    }
+}
+
 impl std::fmt::Display for MinioErrorCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -204,6 +271,19 @@ impl std::fmt::Display for MinioErrorCode {
             MinioErrorCode::WarehouseBucketOperationNotSupported => {
                 write!(f, "WarehouseBucketOperationNotSupported")
             }
+            MinioErrorCode::PreconditionFailed => write!(f, "PreconditionFailed"),
+            MinioErrorCode::NotModified => write!(f, "NotModified"),
+            MinioErrorCode::ConditionalRequestConflict => write!(f, "ConditionalRequestConflict"),
+            MinioErrorCode::InvalidBucketState => write!(f, "InvalidBucketState"),
+            MinioErrorCode::BucketAlreadyExists => write!(f, "BucketAlreadyExists"),
+            MinioErrorCode::AuthorizationHeaderMalformed => {
+                write!(f, "AuthorizationHeaderMalformed")
+            }
+            MinioErrorCode::SlowDown => write!(f, "SlowDown"),
+            MinioErrorCode::ServiceUnavailable => write!(f, "ServiceUnavailable"),
+            MinioErrorCode::InternalError => write!(f, "InternalError"),
+            MinioErrorCode::RequestTimeout => write!(f, "RequestTimeout"),
+            MinioErrorCode::SignatureDoesNotMatch => write!(f, "SignatureDoesNotMatch"),
             MinioErrorCode::OtherError(msg) => write!(f, "{msg}"),
         }
     }
@@ -239,6 +319,7 @@ pub struct MinioErrorResponse {
     host_id: String,
     bucket: Option<BucketName>,
     object: Option<ObjectKey>,
+    region: Option<String>,
 }
 
 impl MinioErrorResponse {
@@ -261,6 +342,7 @@ impl MinioErrorResponse {
             host_id,
             bucket,
             object,
+            region: None,
         }
     }
 
@@ -274,6 +356,8 @@ impl MinioErrorResponse {
             401 => MinioErrorCode::AccessDenied,
             400 => MinioErrorCode::BadRequest,
             409 => MinioErrorCode::ResourceConflict,
+            304 => MinioErrorCode::NotModified,
+            412 => MinioErrorCode::PreconditionFailed,
             _ => MinioErrorCode::OtherError(format!("HTTP {}", status_code)),
         };
         Self {
@@ -285,6 +369,7 @@ impl MinioErrorResponse {
             host_id: String::new(),
             bucket: None,
             object: None,
+            region: None,
         }
     }
 
@@ -299,9 +384,36 @@ impl MinioErrorResponse {
             host_id: get_text_default(&root, "HostId"),
             bucket: get_text_option(&root, "BucketName").and_then(|s| BucketName::new(s).ok()),
             object: get_text_option(&root, "Key").and_then(|s| ObjectKey::new(s).ok()),
+            region: get_text_option(&root, "Region"),
         })
     }
 
+    /// Returns the error embedded in the body of an HTTP 200 response, if any.
+    ///
+    /// Operations such as `CopyObject` and `CompleteMultipartUpload` may fail after
+    /// the server has already sent the 200 status line; the error is then returned
+    /// as an `<Error>` document in the body, possibly preceded by whitespace.
+    pub(crate) fn from_ok_body(body: &Bytes, headers: &HeaderMap) -> Option<Self> {
+        let start = body.iter().position(|b| !b.is_ascii_whitespace())?;
+        let body = body.slice(start..);
+        let root = Element::parse(body.clone().reader()).ok()?;
+        if root.name != "Error" {
+            return None;
+        }
+        Self::new_from_body(body, headers.clone()).ok()
+    }
+
+    /// Returns the bucket region reported by the server, taken from the
+    /// `x-amz-bucket-region` header or the `<Region>` element of the error body.
+    pub fn bucket_region(&self) -> Option<Region> {
+        self.headers
+            .get(X_AMZ_BUCKET_REGION)
+            .and_then(|v| v.to_str().ok())
+            .or(self.region.as_deref())
+            .filter(|v| !v.is_empty())
+            .and_then(|v| Region::new(v).ok())
+    }
+
     pub fn headers(&self) -> &HeaderMap {
         &self.headers
     }
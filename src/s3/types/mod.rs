@@ -18,6 +18,7 @@
 // Core infrastructure modules
 pub mod all_types;
 pub mod basic_types;
+pub mod checksum;
 pub mod header_constants;
 pub mod lifecycle_config;
 pub mod minio_error_response;
@@ -34,6 +35,7 @@ pub mod notification;
 
 // Other types
 pub mod s3_bucket;
+pub mod s3_filter;
 pub mod s3_object;
 pub mod s3_struct;
 pub mod sse_config;
@@ -44,10 +46,12 @@ pub mod replication;
 
 // Re-export core types from submodules
 pub use basic_types::{
-    Bucket, ListEntry, Part, PartInfo, Retention, RetentionMode, parse_legal_hold,
+    Bucket, ContentRange, ListEntry, ObjectExpiration, Part, PartInfo, ResponseHeaderOverrides,
+    Retention, RetentionMode, parse_legal_hold,
 };
+pub use checksum::{ChecksumType, ObjectChecksum};
 pub use s3_request::S3Request;
-pub use traits::{FromS3Response, S3Api, ToS3Request, ToStream};
+pub use traits::{EndpointOverride, FromS3Response, RequestHeaders, S3Api, ToS3Request, ToStream};
 pub use typed_parameters::{BucketName, ContentType, ETag, ObjectKey, Region, UploadId, VersionId};
 
 // Re-export serialization types
@@ -59,22 +63,23 @@ pub use serialization::{
 
 // Re-export notification types
 pub use notification::{
-    AndOperator, CloudFuncConfig, Directive, Filter, NotificationConfig, NotificationRecord,
+    CloudFuncConfig, Directive, NotificationConfig, NotificationEvent, NotificationRecord,
     NotificationRecords, PrefixFilterRule, QueueConfig, RequestParameters, ResponseElements,
     Source, SuffixFilterRule, TopicConfig, UserIdentity,
 };
 
 // Re-export other types
 pub use s3_bucket::S3Bucket;
+pub use s3_filter::{AndOperator, Filter, S3Filter};
 pub use s3_object::S3Object;
 pub use s3_struct::S3;
-pub use sse_config::SseConfig;
-pub use tag::Tag;
+pub use sse_config::{SseAlgorithm, SseConfig};
+pub use tag::{Tag, Tags};
 
 // Re-export replication types
 pub use replication::{
     AccessControlTranslation, Destination, EncryptionConfig, Metrics, ObjectLockConfig,
-    ReplicationConfig, ReplicationRule, ReplicationTime, SourceSelectionCriteria,
+    ReplicationConfig, ReplicationRule, ReplicationTime, RetentionPeriod, SourceSelectionCriteria,
 };
 
 // Re-export all types from all_types module for backward compatibility
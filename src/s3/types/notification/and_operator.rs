@@ -1,5 +1,5 @@
 // MinIO Rust Library for Amazon S3 Compatible Cloud Storage
-// Copyright 2022 MinIO, Inc.
+// Copyright 2025 MinIO, Inc.
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,12 +13,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! And operator containing prefix and tags
+//! And operator, re-exported from [`crate::s3::types::s3_filter`]
 
-use std::collections::HashMap;
-
-#[derive(PartialEq, Clone, Debug)]
-pub struct AndOperator {
-    pub prefix: Option<String>,
-    pub tags: Option<HashMap<String, String>>,
-}
+pub use crate::s3::types::s3_filter::AndOperator;
@@ -21,6 +21,7 @@ pub mod directive;
 pub mod filter;
 pub mod notification_common;
 pub mod notification_config;
+pub mod notification_event;
 pub mod notification_record;
 pub mod notification_records;
 pub mod prefix_filter_rule;
@@ -37,6 +38,7 @@ pub use cloud_func_config::CloudFuncConfig;
 pub use directive::Directive;
 pub use filter::Filter;
 pub use notification_config::NotificationConfig;
+pub use notification_event::NotificationEvent;
 pub use notification_record::NotificationRecord;
 pub use notification_records::NotificationRecords;
 pub use prefix_filter_rule::PrefixFilterRule;
@@ -0,0 +1,155 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Event types reported in bucket notification records
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Type of a bucket notification event, as named by `eventName` in a
+/// [`NotificationRecord`](super::NotificationRecord).
+///
+/// Event names without a dedicated variant are kept verbatim in
+/// [`NotificationEvent::Other`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum NotificationEvent {
+    /// `s3:ObjectCreated:Put`
+    ObjectCreatedPut,
+    /// `s3:ObjectCreated:Post`
+    ObjectCreatedPost,
+    /// `s3:ObjectCreated:Copy`
+    ObjectCreatedCopy,
+    /// `s3:ObjectCreated:CompleteMultipartUpload`
+    ObjectCreatedCompleteMultipartUpload,
+    /// `s3:ObjectCreated:PutRetention`
+    ObjectCreatedPutRetention,
+    /// `s3:ObjectCreated:PutLegalHold`
+    ObjectCreatedPutLegalHold,
+    /// `s3:ObjectCreated:PutTagging`
+    ObjectCreatedPutTagging,
+    /// `s3:ObjectCreated:DeleteTagging`
+    ObjectCreatedDeleteTagging,
+    /// `s3:ObjectAccessed:Get`
+    ObjectAccessedGet,
+    /// `s3:ObjectAccessed:Head`
+    ObjectAccessedHead,
+    /// `s3:ObjectAccessed:GetRetention`
+    ObjectAccessedGetRetention,
+    /// `s3:ObjectAccessed:GetLegalHold`
+    ObjectAccessedGetLegalHold,
+    /// `s3:ObjectRemoved:Delete`
+    ObjectRemovedDelete,
+    /// `s3:ObjectRemoved:DeleteMarkerCreated`
+    ObjectRemovedDeleteMarkerCreated,
+    /// `s3:BucketCreated`
+    BucketCreated,
+    /// `s3:BucketRemoved`
+    BucketRemoved,
+    /// Any other event name.
+    Other(String),
+}
+
+impl NotificationEvent {
+    /// Returns the event name as sent by the server.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ObjectCreatedPut => "s3:ObjectCreated:Put",
+            Self::ObjectCreatedPost => "s3:ObjectCreated:Post",
+            Self::ObjectCreatedCopy => "s3:ObjectCreated:Copy",
+            Self::ObjectCreatedCompleteMultipartUpload => {
+                "s3:ObjectCreated:CompleteMultipartUpload"
+            }
+            Self::ObjectCreatedPutRetention => "s3:ObjectCreated:PutRetention",
+            Self::ObjectCreatedPutLegalHold => "s3:ObjectCreated:PutLegalHold",
+            Self::ObjectCreatedPutTagging => "s3:ObjectCreated:PutTagging",
+            Self::ObjectCreatedDeleteTagging => "s3:ObjectCreated:DeleteTagging",
+            Self::ObjectAccessedGet => "s3:ObjectAccessed:Get",
+            Self::ObjectAccessedHead => "s3:ObjectAccessed:Head",
+            Self::ObjectAccessedGetRetention => "s3:ObjectAccessed:GetRetention",
+            Self::ObjectAccessedGetLegalHold => "s3:ObjectAccessed:GetLegalHold",
+            Self::ObjectRemovedDelete => "s3:ObjectRemoved:Delete",
+            Self::ObjectRemovedDeleteMarkerCreated => "s3:ObjectRemoved:DeleteMarkerCreated",
+            Self::BucketCreated => "s3:BucketCreated",
+            Self::BucketRemoved => "s3:BucketRemoved",
+            Self::Other(name) => name,
+        }
+    }
+
+    /// Returns true for `s3:ObjectCreated:*` events.
+    pub fn is_object_created(&self) -> bool {
+        self.as_str().starts_with("s3:ObjectCreated:")
+    }
+
+    /// Returns true for `s3:ObjectAccessed:*` events.
+    pub fn is_object_accessed(&self) -> bool {
+        self.as_str().starts_with("s3:ObjectAccessed:")
+    }
+
+    /// Returns true for `s3:ObjectRemoved:*` events.
+    pub fn is_object_removed(&self) -> bool {
+        self.as_str().starts_with("s3:ObjectRemoved:")
+    }
+}
+
+impl From<&str> for NotificationEvent {
+    fn from(name: &str) -> Self {
+        match name {
+            "s3:ObjectCreated:Put" => Self::ObjectCreatedPut,
+            "s3:ObjectCreated:Post" => Self::ObjectCreatedPost,
+            "s3:ObjectCreated:Copy" => Self::ObjectCreatedCopy,
+            "s3:ObjectCreated:CompleteMultipartUpload" => {
+                Self::ObjectCreatedCompleteMultipartUpload
+            }
+            "s3:ObjectCreated:PutRetention" => Self::ObjectCreatedPutRetention,
+            "s3:ObjectCreated:PutLegalHold" => Self::ObjectCreatedPutLegalHold,
+            "s3:ObjectCreated:PutTagging" => Self::ObjectCreatedPutTagging,
+            "s3:ObjectCreated:DeleteTagging" => Self::ObjectCreatedDeleteTagging,
+            "s3:ObjectAccessed:Get" => Self::ObjectAccessedGet,
+            "s3:ObjectAccessed:Head" => Self::ObjectAccessedHead,
+            "s3:ObjectAccessed:GetRetention" => Self::ObjectAccessedGetRetention,
+            "s3:ObjectAccessed:GetLegalHold" => Self::ObjectAccessedGetLegalHold,
+            "s3:ObjectRemoved:Delete" => Self::ObjectRemovedDelete,
+            "s3:ObjectRemoved:DeleteMarkerCreated" => Self::ObjectRemovedDeleteMarkerCreated,
+            "s3:BucketCreated" => Self::BucketCreated,
+            "s3:BucketRemoved" => Self::BucketRemoved,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for NotificationEvent {
+    fn from(name: String) -> Self {
+        match Self::from(name.as_str()) {
+            Self::Other(_) => Self::Other(name),
+            event => event,
+        }
+    }
+}
+
+impl From<NotificationEvent> for String {
+    fn from(event: NotificationEvent) -> Self {
+        match event {
+            NotificationEvent::Other(name) => name,
+            event => event.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for NotificationEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
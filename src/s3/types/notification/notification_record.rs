@@ -16,6 +16,7 @@
 //! Notification record information
 
 use super::super::s3_struct::S3;
+use super::notification_event::NotificationEvent;
 use super::request_parameters::RequestParameters;
 use super::response_elements::ResponseElements;
 use super::source::Source;
@@ -50,3 +51,76 @@ pub struct NotificationRecord {
     #[serde(alias = "source")]
     pub source: Source,
 }
+
+impl NotificationRecord {
+    /// Returns the typed event of this record.
+    pub fn event(&self) -> NotificationEvent {
+        NotificationEvent::from(self.event_name.as_str())
+    }
+
+    /// Returns the name of the bucket the event occurred in.
+    pub fn bucket_name(&self) -> &str {
+        &self.s3.bucket.name
+    }
+
+    /// Returns the (decoded) key of the object the event refers to.
+    pub fn object_key(&self) -> &str {
+        &self.s3.object.key
+    }
+
+    /// Returns the IP address of the client that caused the event, if reported.
+    pub fn source_ip(&self) -> Option<&str> {
+        self.request_parameters
+            .as_ref()
+            .and_then(RequestParameters::source_ip_address)
+            .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::types::NotificationRecords;
+
+    const PUT_EVENT: &str = r#"{"EventName":"s3:ObjectCreated:Put","Key":"bucket/dir/my%20file%2B1.txt","Records":[{"eventVersion":"2.0","eventSource":"minio:s3","awsRegion":"","eventTime":"2024-05-01T10:20:30.123Z","eventName":"s3:ObjectCreated:Put","userIdentity":{"principalId":"minioadmin"},"requestParameters":{"principalId":"minioadmin","region":"","sourceIPAddress":"192.168.1.10"},"responseElements":{"x-amz-id-2":"dd9025bab4ad464b049177c95eb6ebf374d3b3fd1af9251148b658df7ac2e3e8","x-amz-request-id":"17CB4D3A8E1E7A41","x-minio-deployment-id":"3b1e2c9e-6f3e-4d7a-9b1a-1c2d3e4f5a6b","x-minio-origin-endpoint":"http://127.0.0.1:9000"},"s3":{"s3SchemaVersion":"1.0","configurationId":"Config","bucket":{"name":"bucket","ownerIdentity":{"principalId":"minioadmin"},"arn":"arn:aws:s3:::bucket"},"object":{"key":"dir%2Fmy+file%2B1.txt","size":11,"eTag":"5eb63bbbe01eeed093cb22bb8f5acdc3","contentType":"text/plain","userMetadata":{"content-type":"text/plain"},"sequencer":"17CB4D3A8E4F0C18"}},"source":{"host":"192.168.1.10","port":"","userAgent":"MinIO (linux; amd64) minio-go/v7.0.70"}}]}"#;
+
+    #[test]
+    fn test_deserialize_object_created_put() {
+        let records: NotificationRecords = serde_json::from_str(PUT_EVENT).unwrap();
+        assert_eq!(records.records.len(), 1);
+        let record = &records.records[0];
+
+        assert_eq!(record.event(), NotificationEvent::ObjectCreatedPut);
+        assert!(record.event().is_object_created());
+        assert_eq!(record.bucket_name(), "bucket");
+        assert_eq!(record.object_key(), "dir/my file+1.txt");
+        assert_eq!(record.s3.object.size, Some(11));
+        assert_eq!(
+            record.s3.object.etag.as_deref(),
+            Some("5eb63bbbe01eeed093cb22bb8f5acdc3")
+        );
+        assert_eq!(record.s3.object.sequencer, "17CB4D3A8E4F0C18");
+        assert_eq!(record.source_ip(), Some("192.168.1.10"));
+    }
+
+    #[test]
+    fn test_notification_event_round_trip() {
+        for name in [
+            "s3:ObjectRemoved:Delete",
+            "s3:Replication:OperationFailedReplication",
+        ] {
+            let event = NotificationEvent::from(name);
+            assert_eq!(event.to_string(), name);
+            let json = serde_json::to_string(&event).unwrap();
+            assert_eq!(json, format!("\"{name}\""));
+            assert_eq!(
+                serde_json::from_str::<NotificationEvent>(&json).unwrap(),
+                event
+            );
+        }
+        assert_eq!(
+            NotificationEvent::from("s3:Replication:OperationFailedReplication"),
+            NotificationEvent::Other("s3:Replication:OperationFailedReplication".to_string())
+        );
+    }
+}
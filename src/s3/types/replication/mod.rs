@@ -29,7 +29,7 @@ pub use access_control_translation::AccessControlTranslation;
 pub use destination::Destination;
 pub use encryption_config::EncryptionConfig;
 pub use metrics::Metrics;
-pub use object_lock_config::ObjectLockConfig;
+pub use object_lock_config::{ObjectLockConfig, RetentionPeriod};
 pub use replication_config::ReplicationConfig;
 pub use replication_rule::ReplicationRule;
 pub use replication_time::ReplicationTime;
@@ -17,16 +17,41 @@
 
 use super::super::basic_types::RetentionMode;
 use crate::s3::error::ValidationErr;
-use crate::s3::utils::{get_text_option, get_text_result};
+use crate::s3::utils::{get_text_default, get_text_option, get_text_result};
 use xmltree::Element;
 
-#[derive(Clone, Debug, Default)]
+/// Default retention period applied to new objects in an object-lock enabled bucket.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetentionPeriod {
+    Days(i32),
+    Years(i32),
+}
+
+/// Bucket object lock configuration.
+///
+/// Object lock can only be configured on a bucket created with object lock
+/// enabled; otherwise the server rejects the request with
+/// [`MinioErrorCode::InvalidBucketState`](crate::s3::minio_error_response::MinioErrorCode::InvalidBucketState).
+#[derive(Clone, Debug, PartialEq)]
 pub struct ObjectLockConfig {
+    /// Whether object lock is enabled (`<ObjectLockEnabled>Enabled</ObjectLockEnabled>`)
+    pub enabled: bool,
     pub retention_mode: Option<RetentionMode>,
     pub retention_duration_days: Option<i32>,
     pub retention_duration_years: Option<i32>,
 }
 
+impl Default for ObjectLockConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            retention_mode: None,
+            retention_duration_days: None,
+            retention_duration_years: None,
+        }
+    }
+}
+
 impl ObjectLockConfig {
     pub fn new(
         mode: RetentionMode,
@@ -35,6 +60,7 @@ impl ObjectLockConfig {
     ) -> Result<Self, ValidationErr> {
         if days.is_some() ^ years.is_some() {
             return Ok(Self {
+                enabled: true,
                 retention_mode: Some(mode),
                 retention_duration_days: days,
                 retention_duration_years: years,
@@ -46,8 +72,37 @@ impl ObjectLockConfig {
         ))
     }
 
+    /// Returns a configuration with the given default retention mode and period.
+    pub fn with_default_retention(mode: RetentionMode, period: RetentionPeriod) -> Self {
+        let (days, years) = match period {
+            RetentionPeriod::Days(v) => (Some(v), None),
+            RetentionPeriod::Years(v) => (None, Some(v)),
+        };
+        Self {
+            enabled: true,
+            retention_mode: Some(mode),
+            retention_duration_days: days,
+            retention_duration_years: years,
+        }
+    }
+
+    /// Returns the default retention mode, if a default retention rule is set.
+    pub fn default_mode(&self) -> Option<&RetentionMode> {
+        self.retention_mode.as_ref()
+    }
+
+    /// Returns the default retention period, if a default retention rule is set.
+    pub fn default_period(&self) -> Option<RetentionPeriod> {
+        match (self.retention_duration_days, self.retention_duration_years) {
+            (Some(d), _) => Some(RetentionPeriod::Days(d)),
+            (None, Some(y)) => Some(RetentionPeriod::Years(y)),
+            (None, None) => None,
+        }
+    }
+
     pub fn from_xml(root: &Element) -> Result<ObjectLockConfig, ValidationErr> {
         let mut config = ObjectLockConfig {
+            enabled: get_text_default(root, "ObjectLockEnabled") == "Enabled",
             retention_mode: None,
             retention_duration_days: None,
             retention_duration_years: None,
@@ -76,7 +131,9 @@ impl ObjectLockConfig {
 
     pub fn to_xml(&self) -> String {
         let mut data = String::from("<ObjectLockConfiguration>");
-        data.push_str("<ObjectLockEnabled>Enabled</ObjectLockEnabled>");
+        if self.enabled {
+            data.push_str("<ObjectLockEnabled>Enabled</ObjectLockEnabled>");
+        }
         if let Some(v) = &self.retention_mode {
             data.push_str("<Rule><DefaultRetention>");
             data.push_str("<Mode>");
@@ -99,3 +156,54 @@ impl ObjectLockConfig {
         data
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(config: &ObjectLockConfig) -> ObjectLockConfig {
+        let root = Element::parse(config.to_xml().as_bytes()).unwrap();
+        ObjectLockConfig::from_xml(&root).unwrap()
+    }
+
+    #[test]
+    fn test_object_lock_config_round_trip_days() {
+        let config = ObjectLockConfig::with_default_retention(
+            RetentionMode::GOVERNANCE,
+            RetentionPeriod::Days(30),
+        );
+        let parsed = round_trip(&config);
+        assert_eq!(parsed, config);
+        assert!(parsed.enabled);
+        assert_eq!(parsed.default_mode(), Some(&RetentionMode::GOVERNANCE));
+        assert_eq!(parsed.default_period(), Some(RetentionPeriod::Days(30)));
+    }
+
+    #[test]
+    fn test_object_lock_config_round_trip_years() {
+        let config = ObjectLockConfig::new(RetentionMode::COMPLIANCE, None, Some(2)).unwrap();
+        let parsed = round_trip(&config);
+        assert_eq!(parsed, config);
+        assert_eq!(parsed.default_period(), Some(RetentionPeriod::Years(2)));
+    }
+
+    #[test]
+    fn test_object_lock_config_round_trip_without_rule() {
+        let config = ObjectLockConfig::default();
+        let parsed = round_trip(&config);
+        assert_eq!(parsed, config);
+        assert!(parsed.enabled);
+        assert_eq!(parsed.default_mode(), None);
+        assert_eq!(parsed.default_period(), None);
+    }
+
+    #[test]
+    fn test_object_lock_config_parse_server_response() {
+        let xml = r#"<ObjectLockConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/"><ObjectLockEnabled>Enabled</ObjectLockEnabled><Rule><DefaultRetention><Mode>COMPLIANCE</Mode><Days>7</Days></DefaultRetention></Rule></ObjectLockConfiguration>"#;
+        let root = Element::parse(xml.as_bytes()).unwrap();
+        let config = ObjectLockConfig::from_xml(&root).unwrap();
+        assert!(config.enabled);
+        assert_eq!(config.default_mode(), Some(&RetentionMode::COMPLIANCE));
+        assert_eq!(config.default_period(), Some(RetentionPeriod::Days(7)));
+    }
+}
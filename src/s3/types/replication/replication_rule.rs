@@ -15,7 +15,7 @@
 
 //! Replication rule information
 
-use super::super::notification::Filter;
+use super::super::s3_filter::S3Filter;
 use super::destination::Destination;
 use super::source_selection_criteria::SourceSelectionCriteria;
 use crate::s3::error::ValidationErr;
@@ -27,7 +27,7 @@ pub struct ReplicationRule {
     pub destination: Destination,
     pub delete_marker_replication_status: Option<bool>,
     pub existing_object_replication_status: Option<bool>,
-    pub filter: Option<Filter>,
+    pub filter: Option<S3Filter>,
     pub id: Option<String>,
     pub prefix: Option<String>,
     pub priority: Option<i32>,
@@ -54,7 +54,7 @@ impl ReplicationRule {
                 _ => None,
             },
             filter: match element.get_child("Filter") {
-                Some(v) => Some(Filter::from_xml(v)?),
+                Some(v) => Some(S3Filter::from_xml(v)?),
                 _ => None,
             },
             id: get_text_option(element, "ID"),
@@ -167,3 +167,39 @@ impl ReplicationRule {
         data
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::types::AndOperator;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_filter_roundtrip() {
+        let rule = ReplicationRule {
+            destination: Destination {
+                bucket_arn: "arn:aws:s3:::dst".to_string(),
+                ..Default::default()
+            },
+            filter: Some(S3Filter {
+                and_operator: Some(AndOperator {
+                    prefix: Some("TaxDocs".to_string()),
+                    tags: Some(HashMap::from([
+                        ("key1".to_string(), "value1".to_string()),
+                        ("key2".to_string(), "value2".to_string()),
+                    ])),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            id: Some("rule1".to_string()),
+            priority: Some(1),
+            status: true,
+            ..Default::default()
+        };
+
+        let xml = format!("<Rule>{}</Rule>", rule.to_xml());
+        let parsed = ReplicationRule::from_xml(&Element::parse(xml.as_bytes()).unwrap());
+        assert_eq!(parsed.unwrap(), rule, "{xml}");
+    }
+}
@@ -0,0 +1,277 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Object filter shared by lifecycle and replication rules
+
+use super::tag::Tag;
+use crate::s3::error::ValidationErr;
+use crate::s3::utils::get_text_result;
+use std::collections::HashMap;
+use xmltree::Element;
+
+/// Conditions combined with a logical AND in an [`S3Filter`].
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct AndOperator {
+    pub prefix: Option<String>,
+    pub tags: Option<HashMap<String, String>>,
+    /// Matches objects larger than this many bytes (lifecycle rules only).
+    pub object_size_greater_than: Option<u64>,
+    /// Matches objects smaller than this many bytes (lifecycle rules only).
+    pub object_size_less_than: Option<u64>,
+}
+
+/// The `<Filter>` element selecting the objects a lifecycle or replication rule
+/// applies to.
+///
+/// At most one condition may be set outside of [`and_operator`](Self::and_operator);
+/// multiple conditions are combined by putting them in an [`AndOperator`].
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct S3Filter {
+    pub and_operator: Option<AndOperator>,
+    pub prefix: Option<String>,
+    pub tag: Option<Tag>,
+    /// Matches objects larger than this many bytes (lifecycle rules only).
+    pub object_size_greater_than: Option<u64>,
+    /// Matches objects smaller than this many bytes (lifecycle rules only).
+    pub object_size_less_than: Option<u64>,
+}
+
+impl S3Filter {
+    pub fn from_xml(element: &Element) -> Result<S3Filter, ValidationErr> {
+        let and_operator = match element.get_child("And") {
+            Some(v) => {
+                let mut tags: HashMap<String, String> = HashMap::new();
+                for tag in v
+                    .children
+                    .iter()
+                    .filter_map(|c| c.as_element())
+                    .filter(|e| e.name == "Tag")
+                {
+                    tags.insert(get_text_result(tag, "Key")?, get_text_result(tag, "Value")?);
+                }
+                Some(AndOperator {
+                    prefix: match v.get_child("Prefix") {
+                        Some(p) => Some(
+                            p.get_text()
+                                .ok_or(ValidationErr::xml_error(
+                                    "the text of <Prefix>-tag not found",
+                                ))?
+                                .to_string(),
+                        ),
+                        None => None,
+                    },
+                    tags: (!tags.is_empty()).then_some(tags),
+                    object_size_greater_than: parse_size(v, "ObjectSizeGreaterThan")?,
+                    object_size_less_than: parse_size(v, "ObjectSizeLessThan")?,
+                })
+            }
+            None => None,
+        };
+
+        let prefix = match element.get_child("Prefix") {
+            Some(v) => Some(
+                v.get_text()
+                    .ok_or(ValidationErr::xml_error(
+                        "the text of <Prefix>-tag not found",
+                    ))?
+                    .to_string(),
+            ),
+            None => None,
+        };
+
+        let tag = match element.get_child("Tag") {
+            Some(v) => Some(Tag {
+                key: get_text_result(v, "Key")?,
+                value: get_text_result(v, "Value")?,
+            }),
+            None => None,
+        };
+
+        Ok(S3Filter {
+            and_operator,
+            prefix,
+            tag,
+            object_size_greater_than: parse_size(element, "ObjectSizeGreaterThan")?,
+            object_size_less_than: parse_size(element, "ObjectSizeLessThan")?,
+        })
+    }
+
+    /// Checks that exactly one condition is set outside of `And`.
+    pub fn validate(&self) -> Result<(), ValidationErr> {
+        let conditions = [
+            self.and_operator.is_some(),
+            self.prefix.is_some(),
+            self.tag.is_some(),
+            self.object_size_greater_than.is_some(),
+            self.object_size_less_than.is_some(),
+        ];
+        if conditions.iter().filter(|v| **v).count() == 1 {
+            return Ok(());
+        }
+        Err(ValidationErr::InvalidFilter(self.to_xml()))
+    }
+
+    pub fn to_xml(&self) -> String {
+        let mut data = String::from("<Filter>");
+        if let Some(and_op) = &self.and_operator {
+            data.push_str("<And>");
+            if let Some(prefix) = &and_op.prefix {
+                data.push_str("<Prefix>");
+                data.push_str(prefix);
+                data.push_str("</Prefix>");
+            }
+            if let Some(tags) = &and_op.tags {
+                for (key, value) in tags {
+                    data.push_str("<Tag>");
+                    data.push_str("<Key>");
+                    data.push_str(key);
+                    data.push_str("</Key>");
+                    data.push_str("<Value>");
+                    data.push_str(value);
+                    data.push_str("</Value>");
+                    data.push_str("</Tag>");
+                }
+            }
+            push_sizes(
+                &mut data,
+                and_op.object_size_greater_than,
+                and_op.object_size_less_than,
+            );
+            data.push_str("</And>");
+        }
+        if let Some(prefix) = &self.prefix {
+            data.push_str("<Prefix>");
+            data.push_str(prefix);
+            data.push_str("</Prefix>");
+        }
+        if let Some(tag) = &self.tag {
+            data.push_str("<Tag>");
+            data.push_str("<Key>");
+            data.push_str(&tag.key);
+            data.push_str("</Key>");
+            data.push_str("<Value>");
+            data.push_str(&tag.value);
+            data.push_str("</Value>");
+            data.push_str("</Tag>");
+        }
+        push_sizes(
+            &mut data,
+            self.object_size_greater_than,
+            self.object_size_less_than,
+        );
+        data.push_str("</Filter>");
+
+        data
+    }
+}
+
+fn parse_size(element: &Element, name: &str) -> Result<Option<u64>, ValidationErr> {
+    match element.get_child(name).and_then(|v| v.get_text()) {
+        Some(v) => {
+            v.trim().parse().map(Some).map_err(|e| {
+                ValidationErr::xml_error_with_source(format!("Invalid {name} value"), e)
+            })
+        }
+        None => Ok(None),
+    }
+}
+
+fn push_sizes(data: &mut String, greater_than: Option<u64>, less_than: Option<u64>) {
+    if let Some(v) = greater_than {
+        data.push_str("<ObjectSizeGreaterThan>");
+        data.push_str(&v.to_string());
+        data.push_str("</ObjectSizeGreaterThan>");
+    }
+    if let Some(v) = less_than {
+        data.push_str("<ObjectSizeLessThan>");
+        data.push_str(&v.to_string());
+        data.push_str("</ObjectSizeLessThan>");
+    }
+}
+
+/// Former name of [`S3Filter`].
+pub type Filter = S3Filter;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(filter: &S3Filter) -> S3Filter {
+        S3Filter::from_xml(&Element::parse(filter.to_xml().as_bytes()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let filters = [
+            S3Filter::default(),
+            S3Filter {
+                prefix: Some("logs/".to_string()),
+                ..Default::default()
+            },
+            S3Filter {
+                tag: Some(Tag {
+                    key: "tier".to_string(),
+                    value: "cold".to_string(),
+                }),
+                ..Default::default()
+            },
+            S3Filter {
+                object_size_less_than: Some(4096),
+                ..Default::default()
+            },
+            S3Filter {
+                and_operator: Some(AndOperator {
+                    prefix: Some("logs/".to_string()),
+                    tags: Some(HashMap::from([
+                        ("tier".to_string(), "cold".to_string()),
+                        ("team".to_string(), "ops".to_string()),
+                    ])),
+                    object_size_greater_than: Some(1024),
+                    object_size_less_than: None,
+                }),
+                ..Default::default()
+            },
+        ];
+        for filter in &filters {
+            assert_eq!(&roundtrip(filter), filter, "{}", filter.to_xml());
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(
+            S3Filter {
+                object_size_greater_than: Some(1024),
+                ..Default::default()
+            }
+            .validate()
+            .is_ok()
+        );
+        assert!(S3Filter::default().validate().is_err());
+        assert!(
+            S3Filter {
+                and_operator: Some(AndOperator::default()),
+                prefix: Some("logs/".to_string()),
+                tag: Some(Tag {
+                    key: "k".to_string(),
+                    value: "v".to_string(),
+                }),
+                ..Default::default()
+            }
+            .validate()
+            .is_err()
+        );
+    }
+}
@@ -15,12 +15,14 @@
 
 //! S3 object information
 
-use serde::{Deserialize, Serialize};
+use crate::s3::utils::url_decode;
+use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct S3Object {
-    #[serde(alias = "key")]
+    /// Object key; the percent-encoded key sent by the server is decoded.
+    #[serde(alias = "key", deserialize_with = "deserialize_key")]
     pub key: String,
     #[serde(alias = "size")]
     pub size: Option<u64>,
@@ -35,3 +37,7 @@ pub struct S3Object {
     #[serde(alias = "sequencer", default)]
     pub sequencer: String,
 }
+
+fn deserialize_key<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    String::deserialize(deserializer).map(|key| url_decode(&key))
+}
@@ -16,7 +16,9 @@
 //! S3Request struct and implementation for executing HTTP requests.
 
 use super::super::client::{DEFAULT_REGION, MinioClient};
-use crate::s3::error::Error;
+use crate::s3::error::{Error, S3ServerError};
+use crate::s3::http::BaseUrl;
+use crate::s3::minio_error_response::MinioErrorCode;
 use crate::s3::multimap_ext::Multimap;
 use crate::s3::segmented_bytes::SegmentedBytes;
 use crate::s3::types::{BucketName, ObjectKey, Region};
@@ -58,6 +60,10 @@ pub struct S3Request {
     #[builder(default, setter(into))]
     body: Option<Arc<SegmentedBytes>>,
 
+    /// Hex SHA256 of the body, sent as the payload hash instead of hashing the body.
+    #[builder(default, setter(into))]
+    pub(crate) payload_sha256: Option<String>,
+
     /// Optional trailing checksum algorithm for streaming uploads.
     ///
     /// When set, the request body will be sent using aws-chunked encoding
@@ -72,6 +78,10 @@ pub struct S3Request {
     #[builder(default = false)]
     pub(crate) use_signed_streaming: bool,
 
+    /// Endpoint used instead of the client's base URL for this request only.
+    #[builder(default, setter(into))]
+    pub(crate) endpoint_override: Option<BaseUrl>,
+
     /// region computed by [`S3Request::execute`]
     #[builder(default, setter(skip))]
     pub(crate) inner_region: Region,
@@ -79,6 +89,16 @@ pub struct S3Request {
 
 impl S3Request {
     async fn compute_inner_region(&self) -> Result<Region, Error> {
+        // Bucket regions are cached per client endpoint, so they are not looked
+        // up for an overridden endpoint.
+        if let Some(endpoint) = &self.endpoint_override {
+            return Ok(match &self.region {
+                Some(r) => r.clone(),
+                None if !endpoint.region.is_empty() => endpoint.region.clone(),
+                None => DEFAULT_REGION.clone(),
+            });
+        }
+
         let region_str = match &self.bucket {
             Some(b) => {
                 self.client
@@ -90,12 +110,50 @@ impl S3Request {
         Region::new(&region_str).map_err(Into::into)
     }
 
+    #[cfg(test)]
+    pub(crate) fn method(&self) -> &Method {
+        &self.method
+    }
+
+    #[cfg(test)]
+    pub(crate) fn headers(&self) -> &Multimap {
+        &self.headers
+    }
+
+    /// Returns the request body, if any.
+    pub(crate) fn body(&self) -> Option<&SegmentedBytes> {
+        self.body.as_deref()
+    }
+
+    /// Returns the region to retry in when the server redirects a bucket
+    /// request to another region.
+    fn redirect_region(&self, resp: &Result<reqwest::Response, Error>) -> Option<Region> {
+        let Err(Error::S3Server(S3ServerError::S3Error(e))) = resp else {
+            return None;
+        };
+        if self.bucket.is_none() || self.endpoint_override.is_some() {
+            return None;
+        }
+        if !matches!(
+            e.code(),
+            MinioErrorCode::PermanentRedirect | MinioErrorCode::AuthorizationHeaderMalformed
+        ) {
+            return None;
+        }
+        e.bucket_region().filter(|v| *v != self.inner_region)
+    }
+
     /// Execute the request, returning the response. Only used in [`S3Api::send()`]
+    ///
+    /// If the server answers a bucket request with a redirect or a malformed
+    /// authorization error naming another region, the bucket region cache is
+    /// updated and the request is retried once in that region.
     pub async fn execute(&mut self) -> Result<reqwest::Response, Error> {
         self.inner_region = self.compute_inner_region().await?;
 
         if let Some(custom_path) = &self.custom_path {
-            self.client
+            return self
+                .client
                 .execute_with_custom_path(
                     self.method.clone(),
                     &self.inner_region,
@@ -104,21 +162,174 @@ impl S3Request {
                     custom_path,
                     self.body.as_ref().map(Arc::clone),
                 )
-                .await
-        } else {
-            self.client
-                .execute(
-                    self.method.clone(),
-                    &self.inner_region,
-                    &mut self.headers,
-                    &self.query_params,
-                    self.bucket.as_ref(),
-                    self.object.as_ref(),
-                    self.body.as_ref().map(Arc::clone),
-                    self.trailing_checksum,
-                    self.use_signed_streaming,
-                )
-                .await
+                .await;
+        }
+
+        let headers = self.headers.clone();
+        let resp = self.execute_in_region().await;
+        let Some(region) = self.redirect_region(&resp) else {
+            return resp;
+        };
+
+        if let Some(bucket) = &self.bucket {
+            self.client.set_bucket_region(bucket, &region);
+        }
+        self.inner_region = region;
+        self.headers = headers;
+        self.execute_in_region().await
+    }
+
+    async fn execute_in_region(&mut self) -> Result<reqwest::Response, Error> {
+        self.client
+            .execute(
+                self.method.clone(),
+                &self.inner_region,
+                &mut self.headers,
+                &self.query_params,
+                self.bucket.as_ref(),
+                self.object.as_ref(),
+                self.body.as_ref().map(Arc::clone),
+                self.trailing_checksum,
+                self.use_signed_streaming,
+                self.endpoint_override.as_ref(),
+                self.payload_sha256.clone(),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::s3::client::test_support::{MockServer, raw_response};
+    use crate::s3::client::{MinioClient, MinioClientBuilder};
+    use crate::s3::creds::StaticProvider;
+    use crate::s3::error::ValidationErr;
+    use crate::s3::types::{BucketName, Region, RequestHeaders, S3Api};
+
+    /// Serves the given raw responses in order, with region lookups enabled.
+    async fn serve(responses: Vec<String>) -> (MinioClient, MockServer) {
+        let server = MockServer::scripted(responses).await;
+        let client = MinioClientBuilder::new(server.base_url())
+            .provider(Some(StaticProvider::new("minioadmin", "minioadmin", None)))
+            .build()
+            .unwrap();
+        (client, server)
+    }
+
+    fn xml_response(status: &str, extra_headers: &str, body: &str) -> String {
+        raw_response(
+            status,
+            &format!("Content-Type: application/xml\r\n{extra_headers}"),
+            body,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_execute_retries_in_redirected_region() {
+        let (client, server) = serve(vec![
+            xml_response(
+                "301 Moved Permanently",
+                "x-amz-bucket-region: eu-west-1\r\n",
+                "<Error><Code>PermanentRedirect</Code><Message>moved</Message></Error>",
+            ),
+            xml_response("200 OK", "", "<VersioningConfiguration/>"),
+        ])
+        .await;
+        let bucket = BucketName::new("bucket").unwrap();
+        client.set_bucket_region(&bucket, &Region::new("us-east-1").unwrap());
+
+        client
+            .get_bucket_versioning(&bucket)
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+
+        let requests: Vec<String> = server.requests().into_iter().map(|r| r.head).collect();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].contains("/us-east-1/s3/aws4_request"));
+        assert!(requests[1].contains("/eu-west-1/s3/aws4_request"));
+        assert_eq!(requests[1].matches("authorization:").count(), 1);
+        assert_eq!(
+            client.get_region_cached(&bucket, &None).await.unwrap(),
+            "eu-west-1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_authorization_header_malformed_region_from_body() {
+        let (client, server) = serve(vec![
+            xml_response(
+                "400 Bad Request",
+                "",
+                "<Error><Code>AuthorizationHeaderMalformed</Code><Message>wrong region</Message><Region>ap-south-1</Region></Error>",
+            ),
+            xml_response("200 OK", "", "<VersioningConfiguration/>"),
+        ])
+        .await;
+        let bucket = BucketName::new("bucket").unwrap();
+        client.set_bucket_region(&bucket, &Region::new("us-east-1").unwrap());
+
+        client
+            .get_bucket_versioning(&bucket)
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+
+        assert!(
+            server.requests()[1]
+                .head
+                .contains("/ap-south-1/s3/aws4_request")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_sends_custom_headers() {
+        let (client, server) = serve(vec![xml_response(
+            "200 OK",
+            "",
+            "<VersioningConfiguration/>",
+        )])
+        .await;
+        let bucket = BucketName::new("bucket").unwrap();
+        client.set_bucket_region(&bucket, &Region::new("us-east-1").unwrap());
+
+        client
+            .get_bucket_versioning(&bucket)
+            .unwrap()
+            .build()
+            .header("x-amz-meta-color", "blue")
+            .unwrap()
+            .header("x-amz-write-offset-bytes", "42")
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+
+        let head = &server.requests()[0].head;
+        assert!(head.contains("\r\nx-amz-meta-color: blue\r\n"));
+        assert!(head.contains("\r\nx-amz-write-offset-bytes: 42\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_header_rejects_reserved_headers() {
+        let (client, server) = serve(vec![]).await;
+        let bucket = BucketName::new("bucket").unwrap();
+
+        for name in ["authorization", "Host", "X-AMZ-DATE"] {
+            let result = client
+                .get_bucket_versioning(&bucket)
+                .unwrap()
+                .build()
+                .header(name, "forged");
+            assert!(
+                matches!(&result, Err(ValidationErr::ReservedHeader(h)) if h == name),
+                "{name}"
+            );
         }
+        assert!(server.requests().is_empty());
     }
 }
@@ -15,8 +15,10 @@
 
 //! Server side encryption definitions
 
+use crate::s3::error::ValidationErr;
 use crate::s3::header_constants::*;
 use crate::s3::multimap_ext::{Multimap, MultimapExt};
+use crate::s3::types::SseAlgorithm;
 use crate::s3::utils::{b64_encode, md5sum_hash};
 use std::any::Any;
 
@@ -27,6 +29,10 @@ pub trait Sse: std::fmt::Debug + Send + Sync {
     /// Headers for copy operation
     fn copy_headers(&self) -> Multimap;
     fn tls_required(&self) -> bool;
+    /// Returns the name of the encryption type, e.g. `SSE-KMS`, used in messages.
+    fn name(&self) -> &str {
+        "SSE"
+    }
     fn as_any(&self) -> &dyn Any;
 }
 
@@ -38,9 +44,27 @@ pub struct SseCustomerKey {
 }
 
 impl SseCustomerKey {
-    pub fn new(key: &str) -> Self {
+    /// Length in bytes of an SSE-C key (AES-256).
+    pub const KEY_LEN: usize = 32;
+
+    /// Returns SSE-C with the given raw 256-bit key.
+    ///
+    /// The key is sent base64-encoded together with the base64 MD5 of the raw
+    /// key; [`Sse::copy_headers`] returns the same values as
+    /// `x-amz-copy-source-server-side-encryption-customer-*` headers for
+    /// decrypting the source of a server-side copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationErr::InvalidSseCustomerKeyLength`] if the key is not
+    /// exactly 32 bytes.
+    pub fn new(key: impl AsRef<[u8]>) -> Result<Self, ValidationErr> {
+        let key = key.as_ref();
+        if key.len() != Self::KEY_LEN {
+            return Err(ValidationErr::InvalidSseCustomerKeyLength(key.len()));
+        }
         let b64key: String = b64_encode(key);
-        let md5key: String = md5sum_hash(key.as_bytes());
+        let md5key: String = md5sum_hash(key);
 
         let mut headers = Multimap::with_capacity(3);
         headers.add(X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM, "AES256");
@@ -64,10 +88,19 @@ impl SseCustomerKey {
             md5key,
         );
 
-        Self {
+        Ok(Self {
             headers,
             copy_headers,
-        }
+        })
+    }
+
+    /// Returns the base64-encoded MD5 of the key, as sent in
+    /// `x-amz-server-side-encryption-customer-key-MD5`.
+    pub fn key_md5(&self) -> &str {
+        self.headers
+            .get(X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5)
+            .map(String::as_str)
+            .unwrap_or_default()
     }
 }
 
@@ -84,6 +117,10 @@ impl Sse for SseCustomerKey {
         true
     }
 
+    fn name(&self) -> &str {
+        "SSE-C"
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -97,10 +134,19 @@ pub struct SseKms {
 
 impl SseKms {
     pub fn new(key: &str, context: Option<&str>) -> SseKms {
+        Self::with_algorithm(SseAlgorithm::Kms, key, context)
+    }
+
+    /// Returns dual-layer SSE-KMS (`aws:kms:dsse`) with the given KMS key and context.
+    pub fn dsse(key: &str, context: Option<&str>) -> SseKms {
+        Self::with_algorithm(SseAlgorithm::Dsse, key, context)
+    }
+
+    fn with_algorithm(algorithm: SseAlgorithm, key: &str, context: Option<&str>) -> SseKms {
         let mut headers = Multimap::with_capacity(3);
 
         headers.add(X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID, key);
-        headers.add(X_AMZ_SERVER_SIDE_ENCRYPTION, "aws:kms");
+        headers.add(X_AMZ_SERVER_SIDE_ENCRYPTION, algorithm.as_str());
         if let Some(v) = context {
             headers.add(X_AMZ_SERVER_SIDE_ENCRYPTION_CONTEXT, b64_encode(v));
         }
@@ -122,6 +168,13 @@ impl Sse for SseKms {
         true
     }
 
+    fn name(&self) -> &str {
+        match self.headers.get(X_AMZ_SERVER_SIDE_ENCRYPTION) {
+            Some(v) if v == SseAlgorithm::Dsse.as_str() => "DSSE-KMS",
+            _ => "SSE-KMS",
+        }
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -161,7 +214,93 @@ impl Sse for SseS3 {
         false
     }
 
+    fn name(&self) -> &str {
+        "SSE-S3"
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &[u8; 32] = b"32byteslongsecretkeymustprovided";
+
+    #[test]
+    fn test_sse_customer_key_headers() {
+        let ssec = SseCustomerKey::new(KEY).unwrap();
+        let key_b64 = "MzJieXRlc2xvbmdzZWNyZXRrZXltdXN0cHJvdmlkZWQ=";
+        let key_md5 = "7PpPLAK26ONlVUGOWlusfg==";
+
+        let headers = ssec.headers();
+        assert_eq!(
+            headers
+                .get(X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM)
+                .unwrap(),
+            "AES256"
+        );
+        assert_eq!(
+            headers
+                .get(X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY)
+                .unwrap(),
+            key_b64
+        );
+        assert_eq!(
+            headers
+                .get(X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5)
+                .unwrap(),
+            key_md5
+        );
+        assert_eq!(ssec.key_md5(), key_md5);
+
+        let copy_headers = ssec.copy_headers();
+        assert_eq!(
+            copy_headers
+                .get(X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY)
+                .unwrap(),
+            key_b64
+        );
+        assert_eq!(
+            copy_headers
+                .get(X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5)
+                .unwrap(),
+            key_md5
+        );
+    }
+
+    #[test]
+    fn test_sse_customer_key_wrong_length() {
+        assert!(matches!(
+            SseCustomerKey::new(&KEY[..31]),
+            Err(ValidationErr::InvalidSseCustomerKeyLength(31))
+        ));
+        assert!(matches!(
+            SseCustomerKey::new([0_u8; 33]),
+            Err(ValidationErr::InvalidSseCustomerKeyLength(33))
+        ));
+    }
+
+    #[test]
+    fn test_sse_kms_dsse_headers() {
+        let headers = SseKms::dsse("my-key", None).headers();
+        assert_eq!(
+            headers.get_vec(X_AMZ_SERVER_SIDE_ENCRYPTION).unwrap(),
+            &vec!["aws:kms:dsse".to_string()]
+        );
+        assert_eq!(
+            headers
+                .get(X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID)
+                .unwrap(),
+            "my-key"
+        );
+
+        let headers = SseKms::new("my-key", None).headers();
+        assert_eq!(
+            headers.get(X_AMZ_SERVER_SIDE_ENCRYPTION).unwrap(),
+            "aws:kms"
+        );
+    }
+}
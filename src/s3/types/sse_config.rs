@@ -15,6 +15,71 @@
 
 //! Server-side encryption configuration
 
+use crate::s3::error::ValidationErr;
+use crate::s3::utils::{get_text_option, get_text_result};
+use std::fmt;
+use std::str::FromStr;
+use xmltree::Element;
+
+/// Server-side encryption algorithm.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum SseAlgorithm {
+    /// SSE-S3 with server-managed keys (`AES256`)
+    Aes256,
+    /// SSE-KMS (`aws:kms`)
+    Kms,
+    /// Dual-layer SSE-KMS (`aws:kms:dsse`)
+    Dsse,
+}
+
+impl SseAlgorithm {
+    /// All supported algorithms.
+    pub const ALL: [SseAlgorithm; 3] =
+        [SseAlgorithm::Aes256, SseAlgorithm::Kms, SseAlgorithm::Dsse];
+
+    /// Returns the value used in `SSEAlgorithm` and the `x-amz-server-side-encryption` header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SseAlgorithm::Aes256 => "AES256",
+            SseAlgorithm::Kms => "aws:kms",
+            SseAlgorithm::Dsse => "aws:kms:dsse",
+        }
+    }
+
+    /// Returns true if the algorithm uses KMS keys.
+    pub fn is_kms(&self) -> bool {
+        matches!(self, SseAlgorithm::Kms | SseAlgorithm::Dsse)
+    }
+}
+
+impl FromStr for SseAlgorithm {
+    type Err = ValidationErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SseAlgorithm::ALL
+            .into_iter()
+            .find(|v| v.as_str() == s)
+            .ok_or_else(|| {
+                let expected: Vec<&str> = SseAlgorithm::ALL.iter().map(|v| v.as_str()).collect();
+                ValidationErr::InvalidSseConfig(format!(
+                    "unsupported SSE algorithm '{s}'; expected one of {}",
+                    expected.join(", ")
+                ))
+            })
+    }
+}
+
+impl fmt::Display for SseAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Default server-side encryption configuration of a bucket.
+///
+/// Use [`SseConfig::s3`], [`SseConfig::kms`], [`SseConfig::kms_with_key`],
+/// [`SseConfig::kms_default`] or [`SseConfig::dsse`] to construct a valid
+/// configuration.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct SseConfig {
     pub sse_algorithm: String,
@@ -22,20 +87,82 @@ pub struct SseConfig {
 }
 
 impl SseConfig {
+    /// Returns a configuration for SSE-S3 (`AES256`) with server-managed keys.
     pub fn s3() -> SseConfig {
         SseConfig {
-            sse_algorithm: String::from("AES256"),
+            sse_algorithm: SseAlgorithm::Aes256.to_string(),
             kms_master_key_id: None,
         }
     }
 
+    /// Returns a configuration for SSE-KMS (`aws:kms`); without a key the
+    /// server's default KMS key is used.
     pub fn kms(kms_master_key_id: Option<String>) -> SseConfig {
         SseConfig {
-            sse_algorithm: String::from("aws:kms"),
+            sse_algorithm: SseAlgorithm::Kms.to_string(),
             kms_master_key_id,
         }
     }
 
+    /// Returns a configuration for SSE-KMS (`aws:kms`) with the given KMS key.
+    pub fn kms_with_key(kms_master_key_id: impl Into<String>) -> SseConfig {
+        SseConfig {
+            sse_algorithm: SseAlgorithm::Kms.to_string(),
+            kms_master_key_id: Some(kms_master_key_id.into()),
+        }
+    }
+
+    /// Returns a configuration for SSE-KMS (`aws:kms`) with the server's default KMS key.
+    pub fn kms_default() -> SseConfig {
+        SseConfig {
+            sse_algorithm: SseAlgorithm::Kms.to_string(),
+            kms_master_key_id: None,
+        }
+    }
+
+    /// Returns a configuration for dual-layer SSE-KMS (`aws:kms:dsse`); without
+    /// a key the server's default KMS key is used.
+    pub fn dsse(kms_master_key_id: Option<String>) -> SseConfig {
+        SseConfig {
+            sse_algorithm: SseAlgorithm::Dsse.to_string(),
+            kms_master_key_id,
+        }
+    }
+
+    /// Returns the typed algorithm of this configuration.
+    pub fn algorithm(&self) -> Result<SseAlgorithm, ValidationErr> {
+        self.sse_algorithm.parse()
+    }
+
+    /// Checks that the algorithm is a known [`SseAlgorithm`] and that a KMS
+    /// key is only given for a KMS algorithm.
+    pub fn validate(&self) -> Result<(), ValidationErr> {
+        let algorithm = self.algorithm()?;
+        if self.kms_master_key_id.is_some() && !algorithm.is_kms() {
+            return Err(ValidationErr::InvalidSseConfig(format!(
+                "KMS master key ID is not applicable to SSE algorithm '{}'",
+                self.sse_algorithm
+            )));
+        }
+        Ok(())
+    }
+
+    /// Parses the `ServerSideEncryptionConfiguration` element of a bucket encryption response.
+    pub fn from_xml(root: &Element) -> Result<SseConfig, ValidationErr> {
+        let rule = root
+            .get_child("Rule")
+            .ok_or(ValidationErr::xml_error("<Rule> tag not found"))?;
+
+        let sse_by_default = rule.get_child("ApplyServerSideEncryptionByDefault").ok_or(
+            ValidationErr::xml_error("<ApplyServerSideEncryptionByDefault> tag not found"),
+        )?;
+
+        Ok(SseConfig {
+            sse_algorithm: get_text_result(sse_by_default, "SSEAlgorithm")?,
+            kms_master_key_id: get_text_option(sse_by_default, "KMSMasterKeyID"),
+        })
+    }
+
     pub fn to_xml(&self) -> String {
         let mut data = String::from(
             "<ServerSideEncryptionConfiguration><Rule><ApplyServerSideEncryptionByDefault>",
@@ -55,3 +182,94 @@ impl SseConfig {
         data
     }
 }
+
+impl fmt::Display for SseConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.sse_algorithm)?;
+        if let Some(v) = &self.kms_master_key_id {
+            write!(f, " (KMS key: {v})")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sse_config_s3() {
+        let config = SseConfig::s3();
+        assert_eq!(config.sse_algorithm, "AES256");
+        assert_eq!(config.kms_master_key_id, None);
+        assert!(config.validate().is_ok());
+        assert_eq!(config.to_string(), "AES256");
+    }
+
+    #[test]
+    fn test_sse_config_kms() {
+        let config = SseConfig::kms_with_key("my-key");
+        assert_eq!(config, SseConfig::kms(Some("my-key".to_string())));
+        assert_eq!(config.sse_algorithm, "aws:kms");
+        assert_eq!(config.kms_master_key_id.as_deref(), Some("my-key"));
+        assert!(config.validate().is_ok());
+        assert_eq!(config.to_string(), "aws:kms (KMS key: my-key)");
+        assert!(
+            config
+                .to_xml()
+                .contains("<KMSMasterKeyID>my-key</KMSMasterKeyID>")
+        );
+    }
+
+    #[test]
+    fn test_sse_config_kms_default() {
+        let config = SseConfig::kms_default();
+        assert_eq!(config, SseConfig::kms(None));
+        assert_eq!(config.sse_algorithm, "aws:kms");
+        assert_eq!(config.kms_master_key_id, None);
+        assert!(config.validate().is_ok());
+        assert!(!config.to_xml().contains("KMSMasterKeyID"));
+    }
+
+    #[test]
+    fn test_sse_config_dsse_round_trip() {
+        let xml = "<ServerSideEncryptionConfiguration xmlns=\"http://s3.amazonaws.com/doc/2006-03-01/\"><Rule><ApplyServerSideEncryptionByDefault><SSEAlgorithm>aws:kms:dsse</SSEAlgorithm><KMSMasterKeyID>my-key</KMSMasterKeyID></ApplyServerSideEncryptionByDefault></Rule></ServerSideEncryptionConfiguration>";
+        let config = SseConfig::from_xml(&Element::parse(xml.as_bytes()).unwrap()).unwrap();
+        assert_eq!(config, SseConfig::dsse(Some("my-key".into())));
+        assert_eq!(config.algorithm().unwrap(), SseAlgorithm::Dsse);
+        assert!(config.validate().is_ok());
+
+        let xml = config.to_xml();
+        assert!(xml.contains("<SSEAlgorithm>aws:kms:dsse</SSEAlgorithm>"));
+        let parsed = SseConfig::from_xml(&Element::parse(xml.as_bytes()).unwrap()).unwrap();
+        assert_eq!(parsed, config);
+    }
+
+    #[test]
+    fn test_sse_algorithm_from_str() {
+        for algorithm in SseAlgorithm::ALL {
+            assert_eq!(
+                algorithm.as_str().parse::<SseAlgorithm>().unwrap(),
+                algorithm
+            );
+        }
+        assert!("aws:kms:DSSE".parse::<SseAlgorithm>().is_err());
+    }
+
+    #[test]
+    fn test_sse_config_validate_rejects_invalid() {
+        assert!(SseConfig::default().validate().is_err());
+
+        let config = SseConfig {
+            sse_algorithm: "aes256".into(),
+            kms_master_key_id: None,
+        };
+        assert!(config.validate().is_err());
+
+        let config = SseConfig {
+            sse_algorithm: "AES256".into(),
+            kms_master_key_id: Some("my-key".into()),
+        };
+        assert!(config.validate().is_err());
+    }
+}
@@ -13,10 +13,235 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Tag containing key and value
+//! Tag containing key and value, and an ordered set of tags
+
+use crate::s3::error::ValidationErr;
+use crate::s3::utils::{get_text_default, get_text_result, xml_escape};
+use std::collections::{BTreeMap, HashMap};
+use xmltree::Element;
 
 #[derive(PartialEq, Clone, Debug)]
 pub struct Tag {
     pub key: String,
     pub value: String,
 }
+
+/// Set of object or bucket tags with unique keys.
+///
+/// Tags are kept ordered by key, so serializing them (for the `x-amz-tagging`
+/// header or a `<Tagging>` XML document) always yields the same output for the
+/// same set of tags.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Tags(BTreeMap<String, String>);
+
+impl Tags {
+    /// Creates an empty set of tags.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationErr::DuplicateTagKey`] if a tag with the same key already exists.
+    pub fn insert(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<(), ValidationErr> {
+        let key = key.into();
+        if self.0.contains_key(&key) {
+            return Err(ValidationErr::DuplicateTagKey(key));
+        }
+        self.0.insert(key, value.into());
+        Ok(())
+    }
+
+    /// Returns the value of the tag with the given key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Removes the tag with the given key, returning its value.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.0.remove(key)
+    }
+
+    /// Returns `true` if a tag with the given key exists.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    /// Returns the number of tags.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no tags.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the tags in key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Consumes self and returns the underlying map.
+    pub fn into_inner(self) -> BTreeMap<String, String> {
+        self.0
+    }
+
+    /// Returns the `<Tagging>` XML document of the PutBucketTagging and
+    /// PutObjectTagging APIs, with tags in key order.
+    pub fn to_xml(&self) -> String {
+        let mut data = String::from("<Tagging>");
+        if !self.is_empty() {
+            data.push_str("<TagSet>");
+            for (key, value) in self.iter() {
+                data.push_str("<Tag><Key>");
+                data.push_str(&xml_escape(key));
+                data.push_str("</Key><Value>");
+                data.push_str(&xml_escape(value));
+                data.push_str("</Value></Tag>");
+            }
+            data.push_str("</TagSet>");
+        }
+        data.push_str("</Tagging>");
+        data
+    }
+
+    /// Parses a `<Tagging>` XML document. A document without `<TagSet>` yields
+    /// no tags.
+    pub fn from_xml(root: &Element) -> Result<Tags, ValidationErr> {
+        let mut tags = Tags::new();
+        let Some(tag_set) = root.get_child("TagSet") else {
+            return Ok(tags);
+        };
+        for tag in tag_set
+            .children
+            .iter()
+            .filter_map(|v| v.as_element())
+            .filter(|v| v.name == "Tag")
+        {
+            tags.insert(get_text_result(tag, "Key")?, get_text_default(tag, "Value"))?;
+        }
+        Ok(tags)
+    }
+}
+
+impl From<HashMap<String, String>> for Tags {
+    fn from(value: HashMap<String, String>) -> Self {
+        Self(value.into_iter().collect())
+    }
+}
+
+impl From<&HashMap<String, String>> for Tags {
+    fn from(value: &HashMap<String, String>) -> Self {
+        Self(value.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+    }
+}
+
+impl From<BTreeMap<String, String>> for Tags {
+    fn from(value: BTreeMap<String, String>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&Tags> for Tags {
+    fn from(value: &Tags) -> Self {
+        value.clone()
+    }
+}
+
+impl From<Tags> for HashMap<String, String> {
+    fn from(value: Tags) -> Self {
+        value.0.into_iter().collect()
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> TryFrom<Vec<(K, V)>> for Tags {
+    type Error = ValidationErr;
+
+    fn try_from(value: Vec<(K, V)>) -> Result<Self, Self::Error> {
+        let mut tags = Self::new();
+        for (k, v) in value {
+            tags.insert(k, v)?;
+        }
+        Ok(tags)
+    }
+}
+
+impl<K: Into<String>, V: Into<String>, const N: usize> TryFrom<[(K, V); N]> for Tags {
+    type Error = ValidationErr;
+
+    fn try_from(value: [(K, V); N]) -> Result<Self, Self::Error> {
+        let mut tags = Self::new();
+        for (k, v) in value {
+            tags.insert(k, v)?;
+        }
+        Ok(tags)
+    }
+}
+
+impl<'a> IntoIterator for &'a Tags {
+    type Item = (&'a String, &'a String);
+    type IntoIter = std::collections::btree_map::Iter<'a, String, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tags_insert_rejects_duplicate_key() {
+        let mut tags = Tags::new();
+        tags.insert("Project", "One").unwrap();
+        let err = tags.insert("Project", "Two").unwrap_err();
+        assert!(matches!(err, ValidationErr::DuplicateTagKey(ref k) if k == "Project"));
+        assert_eq!(tags.get("Project"), Some("One"));
+    }
+
+    #[test]
+    fn test_tags_try_from_rejects_duplicate_key() {
+        let res = Tags::try_from([("a", "1"), ("b", "2"), ("a", "3")]);
+        assert!(matches!(res, Err(ValidationErr::DuplicateTagKey(_))));
+    }
+
+    #[test]
+    fn test_tags_xml_round_trip() {
+        let tags = Tags::try_from([
+            ("cost center", "team a & b"),
+            ("env", "<prod>"),
+            ("empty", ""),
+        ])
+        .unwrap();
+        let xml = tags.to_xml();
+        assert_eq!(
+            xml,
+            "<Tagging><TagSet><Tag><Key>cost center</Key><Value>team a &amp; b</Value></Tag><Tag><Key>empty</Key><Value></Value></Tag><Tag><Key>env</Key><Value>&lt;prod&gt;</Value></Tag></TagSet></Tagging>"
+        );
+
+        let parsed = Tags::from_xml(&Element::parse(xml.as_bytes()).unwrap()).unwrap();
+        assert_eq!(parsed, tags);
+    }
+
+    #[test]
+    fn test_tags_from_xml_without_tag_set() {
+        let root = Element::parse("<Tagging></Tagging>".as_bytes()).unwrap();
+        assert!(Tags::from_xml(&root).unwrap().is_empty());
+        assert_eq!(Tags::new().to_xml(), "<Tagging></Tagging>");
+    }
+
+    #[test]
+    fn test_tags_iterate_in_key_order() {
+        let tags = Tags::try_from([("zeta", "1"), ("alpha", "2"), ("mid", "3")]).unwrap();
+        let keys: Vec<&str> = tags.iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, vec!["alpha", "mid", "zeta"]);
+    }
+}
@@ -17,6 +17,9 @@
 
 use super::s3_request::S3Request;
 use crate::s3::error::{Error, ValidationErr};
+use crate::s3::header_constants::{AUTHORIZATION, HOST, X_AMZ_DATE};
+use crate::s3::http::BaseUrl;
+use crate::s3::multimap_ext::{Multimap, MultimapExt};
 use async_trait::async_trait;
 use futures_util::Stream;
 
@@ -56,6 +59,58 @@ pub trait ToS3Request: Sized {
     fn to_s3request(self) -> Result<S3Request, ValidationErr>;
 }
 
+/// Headers computed by the client when signing, which [`RequestHeaders::header`] rejects.
+const RESERVED_HEADERS: [&str; 3] = [HOST, AUTHORIZATION, X_AMZ_DATE];
+
+/// Trait for adding single HTTP headers, such as `x-amz-meta-*` or
+/// `x-amz-write-offset-bytes`, to a request without building a [`Multimap`].
+///
+/// Headers are added to the extra headers of the request. `Host`,
+/// `Authorization` and `x-amz-date` are rejected with
+/// [`ValidationErr::ReservedHeader`], since the client computes them when
+/// signing. Headers passed with `extra_headers` are sent as given.
+///
+/// # Examples
+///
+/// ```no_run
+/// use minio::s3::MinioClient;
+/// use minio::s3::types::{RequestHeaders, S3Api};
+///
+/// # async fn example(client: MinioClient) -> Result<(), minio::s3::error::Error> {
+/// client
+///     .put_object_content("bucket", "object", "hello")?
+///     .build()
+///     .header("x-amz-meta-color", "blue")?
+///     .send()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait RequestHeaders: Sized {
+    /// Returns the extra headers of the request.
+    #[doc(hidden)]
+    fn extra_headers_mut(&mut self) -> &mut Option<Multimap>;
+
+    /// Adds an HTTP header to the request.
+    fn header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<Self, ValidationErr> {
+        let name = name.into();
+        if RESERVED_HEADERS
+            .iter()
+            .any(|v| name.eq_ignore_ascii_case(v))
+        {
+            return Err(ValidationErr::ReservedHeader(name));
+        }
+        self.extra_headers_mut()
+            .get_or_insert_default()
+            .add(name, value);
+        Ok(self)
+    }
+}
+
 /// Trait for converting HTTP responses into strongly typed S3 response objects.
 ///
 /// This trait is implemented by all S3 response types in the SDK and provides
@@ -135,6 +190,58 @@ pub trait S3Api: ToS3Request {
         let resp: Result<reqwest::Response, Error> = req.execute().await;
         Self::S3Response::from_s3response(req, resp).await
     }
+
+    /// Sends this request to `endpoint` instead of the client's base URL.
+    ///
+    /// The request is signed for the override host with the client's
+    /// credentials, which avoids constructing a second client for a single
+    /// request. The region is taken from the request, then from `endpoint`,
+    /// and defaults to `us-east-1`; no region lookup is done against the
+    /// override endpoint.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minio::s3::MinioClient;
+    /// use minio::s3::http::BaseUrl;
+    /// use minio::s3::types::S3Api;
+    ///
+    /// # async fn example(client: MinioClient) -> Result<(), minio::s3::error::Error> {
+    /// let other: BaseUrl = "https://gateway.example.com".parse()?;
+    /// let resp = client
+    ///     .get_object("bucket", "object")?
+    ///     .build()
+    ///     .endpoint_override(other)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn endpoint_override(self, endpoint: BaseUrl) -> EndpointOverride<Self> {
+        EndpointOverride {
+            inner: self,
+            endpoint,
+        }
+    }
+}
+
+/// Request sent to an explicit endpoint; see [`S3Api::endpoint_override`].
+#[derive(Clone, Debug)]
+pub struct EndpointOverride<T> {
+    inner: T,
+    endpoint: BaseUrl,
+}
+
+impl<T: ToS3Request> ToS3Request for EndpointOverride<T> {
+    fn to_s3request(self) -> Result<S3Request, ValidationErr> {
+        let mut req = self.inner.to_s3request()?;
+        req.endpoint_override = Some(self.endpoint);
+        Ok(req)
+    }
+}
+
+impl<T: S3Api + Send> S3Api for EndpointOverride<T> {
+    type S3Response = T::S3Response;
 }
 
 #[async_trait]
@@ -15,10 +15,11 @@
 
 use crate::s3::MinioClient;
 use crate::s3::error::ValidationErr;
+use crate::s3::header_constants::X_AMZ_REQUEST_PAYER;
 use crate::s3::multimap_ext::Multimap;
 use crate::s3::segmented_bytes::SegmentedBytes;
 use crate::s3::sse::{Sse, SseCustomerKey};
-use crate::s3::types::{BucketName, ObjectKey};
+use crate::s3::types::{BucketName, ObjectKey, Tags};
 use base64::engine::Engine as _;
 use chrono::{DateTime, Datelike, NaiveDateTime, Utc};
 use crc_fast::{CrcAlgorithm, Digest as CrcFastDigest, checksum as crc_fast_checksum};
@@ -423,9 +424,45 @@ pub fn compute_checksum_sb(algorithm: ChecksumAlgorithm, sb: &Arc<SegmentedBytes
     }
 }
 
+/// Computes the composite (checksum-of-checksums) value of a multipart upload.
+///
+/// The base64-decoded part checksums are concatenated in part order and checksummed
+/// with the same algorithm. The result is base64-encoded without the `-N` part count
+/// suffix, matching [`ObjectChecksum::value`](crate::s3::types::ObjectChecksum::value).
+///
+/// # Example
+///
+/// ```
+/// use minio::s3::utils::{compute_checksum, compute_composite_checksum, ChecksumAlgorithm};
+///
+/// let parts = [
+///     compute_checksum(ChecksumAlgorithm::CRC32C, b"part one"),
+///     compute_checksum(ChecksumAlgorithm::CRC32C, b"part two"),
+/// ];
+/// let composite = compute_composite_checksum(ChecksumAlgorithm::CRC32C, &parts).unwrap();
+/// println!("CRC32C: {composite}-{}", parts.len());
+/// ```
+pub fn compute_composite_checksum(
+    algorithm: ChecksumAlgorithm,
+    part_checksums: &[impl AsRef<str>],
+) -> Result<String, ValidationErr> {
+    let mut raw: Vec<u8> = Vec::new();
+    for checksum in part_checksums {
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(checksum.as_ref())
+            .map_err(|e| ValidationErr::StrError {
+                message: format!("invalid part checksum '{}'", checksum.as_ref()),
+                source: Some(Box::new(e)),
+            })?;
+        raw.extend_from_slice(&decoded);
+    }
+    Ok(compute_checksum(algorithm, &raw))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::s3::multimap_ext::MultimapExt;
     use bytes::Bytes;
     use std::collections::HashMap;
 
@@ -464,6 +501,44 @@ mod tests {
         assert_eq!(url_encode("a/b"), "a%2Fb");
     }
 
+    #[test]
+    fn test_canonical_query_string_escaping() {
+        let mut query = Multimap::new();
+        query.add("prefix", "my dir/a+b=c");
+        query.add("key with space", "v");
+        assert_eq!(
+            canonical_query_string(&query),
+            "key%20with%20space=v&prefix=my%20dir%2Fa%2Bb%3Dc"
+        );
+    }
+
+    #[test]
+    fn test_canonical_query_string_duplicate_keys_sorted_by_value() {
+        let mut query = Multimap::new();
+        query.add("tag", "b");
+        query.add("tag", "a");
+        query.add("list-type", "2");
+        assert_eq!(canonical_query_string(&query), "list-type=2&tag=a&tag=b");
+    }
+
+    #[test]
+    fn test_canonical_query_string_empty_values() {
+        let mut query = Multimap::new();
+        query.add("uploads", "");
+        query.add("acl", "");
+        assert_eq!(canonical_query_string(&query), "acl=&uploads=");
+        assert_eq!(canonical_query_string(&Multimap::new()), "");
+    }
+
+    #[test]
+    fn test_canonical_query_string_sorts_by_encoded_key() {
+        // '~' (0x7E) is unreserved and sorts after the '%' of an encoded byte.
+        let mut query = Multimap::new();
+        query.add("a~", "1");
+        query.add("a!", "2");
+        assert_eq!(canonical_query_string(&query), "a%21=2&a~=1");
+    }
+
     #[test]
     fn test_b64_encode() {
         assert_eq!(b64_encode("hello"), "aGVsbG8=");
@@ -613,6 +688,26 @@ mod tests {
         assert!("invalid".parse::<ChecksumAlgorithm>().is_err());
     }
 
+    #[test]
+    fn test_compute_composite_checksum() {
+        let parts = [
+            compute_checksum(ChecksumAlgorithm::CRC32C, b"part one"),
+            compute_checksum(ChecksumAlgorithm::CRC32C, b"part two"),
+        ];
+        let mut raw = crc32c_raw(b"part one").to_be_bytes().to_vec();
+        raw.extend_from_slice(&crc32c_raw(b"part two").to_be_bytes());
+        assert_eq!(
+            compute_composite_checksum(ChecksumAlgorithm::CRC32C, &parts).unwrap(),
+            b64_encode(crc32c_raw(&raw).to_be_bytes())
+        );
+
+        assert!(compute_composite_checksum(ChecksumAlgorithm::CRC32C, &["not base64!"]).is_err());
+    }
+
+    fn crc32c_raw(data: &[u8]) -> u32 {
+        crc_fast_checksum(CrcAlgorithm::Crc32Iscsi, data) as u32
+    }
+
     #[test]
     fn test_compute_checksum() {
         let data = b"hello world";
@@ -767,6 +862,62 @@ mod tests {
         assert_eq!(trim_quotes("\"".to_string()), "\"");
     }
 
+    #[test]
+    fn test_etag_equals() {
+        let etag = "5eb63bbbe01eeed093cb22bb8f5acdc3";
+        assert!(etag_equals(etag, &format!("\"{etag}\"")));
+        assert!(etag_equals(&format!("W/\"{etag}\""), etag));
+        assert!(etag_equals(etag, &etag.to_uppercase()));
+        assert!(etag_equals("\"abc-2\"", "abc-2"));
+        assert!(!etag_equals("abc-2", "abc"));
+        assert!(!etag_equals(etag, "0123456789abcdef0123456789abcdef"));
+    }
+
+    #[test]
+    fn test_is_multipart_etag() {
+        assert!(is_multipart_etag("\"d41d8cd98f00b204e9800998ecf8427e-3\""));
+        assert!(is_multipart_etag("d41d8cd98f00b204e9800998ecf8427e-10000"));
+        assert!(!is_multipart_etag("\"d41d8cd98f00b204e9800998ecf8427e\""));
+        assert!(!is_multipart_etag("d41d8cd98f00b204e9800998ecf8427e-"));
+        assert!(!is_multipart_etag("-3"));
+    }
+
+    #[test]
+    fn test_verify_single_part_md5() {
+        // MD5 of "hello world"
+        let etag = "\"5eb63bbbe01eeed093cb22bb8f5acdc3\"";
+        assert!(verify_single_part_md5(b"hello world", etag).unwrap());
+        assert!(matches!(
+            verify_single_part_md5(b"hello there", etag),
+            Err(ValidationErr::ChecksumMismatch { .. })
+        ));
+        // Multipart ETags are not MD5 digests and are skipped.
+        assert!(
+            !verify_single_part_md5(b"hello there", "\"5eb63bbbe01eeed093cb22bb8f5acdc3-2\"")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_compute_multipart_etag() {
+        let hex_decode = |s: &str| -> [u8; 16] {
+            std::array::from_fn(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+        };
+        // MD5 digests of the parts "hello" and "world".
+        let parts = [
+            hex_decode("5d41402abc4b2a76b9719d911017c592"),
+            hex_decode("7d793037a0760186574b0282f2f435e7"),
+        ];
+        let etag = "\"065947336a2f2a95ba8899f3675c3be6-2\"";
+        assert_eq!(compute_multipart_etag(&parts), etag);
+        assert_eq!(compute_multipart_etag_from_data(b"helloworld", 5), etag);
+        assert!(etag_equals(
+            &compute_multipart_etag_from_data(b"helloworld", 5),
+            "\"065947336A2F2A95BA8899F3675C3BE6-2\""
+        ));
+        assert_ne!(compute_multipart_etag_from_data(b"helloworld", 6), etag);
+    }
+
     #[test]
     fn test_copy_slice() {
         let src = [1, 2, 3, 4, 5];
@@ -795,18 +946,31 @@ mod tests {
 
     #[test]
     fn test_encode_tags() {
-        let mut tags = HashMap::new();
-        tags.insert("key1".to_string(), "value1".to_string());
-        tags.insert("key2".to_string(), "value2".to_string());
+        let mut tags = Tags::new();
+        tags.insert("key1", "value1").unwrap();
+        tags.insert("key2", "value2").unwrap();
         let encoded = encode_tags(&tags);
-        assert!(encoded.contains("key1=value1"));
-        assert!(encoded.contains("key2=value2"));
+        assert_eq!(encoded, "key1=value1&key2=value2");
+    }
+
+    #[test]
+    fn test_encode_tags_deterministic_order() {
+        let expected = "alpha=1&beta=2&gamma=3&zeta=4";
+        for _ in 0..32 {
+            // HashMap iteration order is randomized per instance.
+            let map: HashMap<String, String> =
+                [("zeta", "4"), ("beta", "2"), ("alpha", "1"), ("gamma", "3")]
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect();
+            assert_eq!(encode_tags(&map), expected);
+        }
     }
 
     #[test]
     fn test_encode_tags_special_chars() {
-        let mut tags = HashMap::new();
-        tags.insert("key with spaces".to_string(), "value&special".to_string());
+        let mut tags = Tags::new();
+        tags.insert("key with spaces", "value&special").unwrap();
         let encoded = encode_tags(&tags);
         assert!(encoded.contains("key%20with%20spaces=value%26special"));
     }
@@ -849,6 +1013,16 @@ mod tests {
         assert_eq!(urlencode_object_key("my/path/file.txt"), "my/path/file.txt");
         assert_eq!(urlencode_object_key("file name.txt"), "file%20name.txt");
         assert_eq!(urlencode_object_key("special&chars"), "special%26chars");
+        assert_eq!(
+            urlencode_object_key("my file (1).txt"),
+            "my%20file%20%281%29.txt"
+        );
+        assert_eq!(urlencode_object_key("a+b"), "a%2Bb");
+        assert_eq!(
+            urlencode_object_key("emoji-\u{1f642}.bin"),
+            "emoji-%F0%9F%99%82.bin"
+        );
+        assert_eq!(urlencode_object_key("dir/sub dir/obj"), "dir/sub%20dir/obj");
     }
 
     #[test]
@@ -1084,10 +1258,16 @@ pub fn to_amz_date(time: UtcTime) -> String {
     time.format("%Y%m%dT%H%M%SZ").to_string()
 }
 
+/// Parses AMZ date value (e.g. `20130524T000000Z`) to time.
+pub fn from_amz_date(s: &str) -> Result<UtcTime, ValidationErr> {
+    let dt = NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%SZ")?;
+    Ok(DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
+}
+
 /// Gets HTTP header value of given time.
 pub fn to_http_header_value(time: UtcTime) -> String {
     format!(
-        "{}, {} {} {} GMT",
+        "{}, {:02} {} {} GMT",
         time.weekday(),
         time.day(),
         match time.month() {
@@ -1152,6 +1332,31 @@ pub mod aws_date_format {
     }
 }
 
+/// Like [`aws_date_format`], for optional timestamps.
+pub mod aws_date_format_opt {
+    use super::{UtcTime, from_iso8601utc, to_iso8601utc};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(date: &Option<UtcTime>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match date {
+            Some(v) => serializer.serialize_some(&to_iso8601utc(*v)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<UtcTime>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|s| from_iso8601utc(&s).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
 pub fn parse_bool(value: &str) -> Result<bool, ValidationErr> {
     if value.eq_ignore_ascii_case("true") {
         Ok(true)
@@ -1310,12 +1515,19 @@ pub fn check_object_name(object: impl AsRef<str>) -> Result<(), ValidationErr> {
 }
 
 /// Validates SSE (Server-Side Encryption) settings.
-pub fn check_sse(sse: &Option<Arc<dyn Sse>>, client: &MinioClient) -> Result<(), ValidationErr> {
+pub fn check_sse(
+    sse: &Option<Arc<dyn Sse>>,
+    client: &MinioClient,
+    operation: &str,
+) -> Result<(), ValidationErr> {
     if let Some(v) = &sse
         && v.tls_required()
         && !client.is_secure()
     {
-        return Err(ValidationErr::SseTlsRequired(None));
+        return Err(ValidationErr::SseTlsRequired {
+            operation: operation.to_string(),
+            sse: v.name().to_string(),
+        });
     }
     Ok(())
 }
@@ -1324,9 +1536,15 @@ pub fn check_sse(sse: &Option<Arc<dyn Sse>>, client: &MinioClient) -> Result<(),
 pub fn check_ssec(
     ssec: &Option<SseCustomerKey>,
     client: &MinioClient,
+    operation: &str,
 ) -> Result<(), ValidationErr> {
-    if ssec.is_some() && !client.is_secure() {
-        return Err(ValidationErr::SseTlsRequired(None));
+    if let Some(v) = ssec
+        && !client.is_secure()
+    {
+        return Err(ValidationErr::SseTlsRequired {
+            operation: operation.to_string(),
+            sse: v.name().to_string(),
+        });
     }
     Ok(())
 }
@@ -1339,13 +1557,18 @@ pub fn check_ssec_with_log(
     object: &ObjectKey,
     version: &Option<String>,
 ) -> Result<(), ValidationErr> {
-    if ssec.is_some() && !client.is_secure() {
-        return Err(ValidationErr::SseTlsRequired(Some(format!(
-            "source {bucket}/{object}{}: ",
-            version
-                .as_ref()
-                .map_or(String::new(), |v| String::from("?versionId=") + v)
-        ))));
+    if let Some(v) = ssec
+        && !client.is_secure()
+    {
+        return Err(ValidationErr::SseTlsRequired {
+            operation: format!(
+                "ComposeObject source {bucket}/{object}{}",
+                version
+                    .as_ref()
+                    .map_or(String::new(), |v| String::from("?versionId=") + v)
+            ),
+            sse: v.name().to_string(),
+        });
     }
     Ok(())
 }
@@ -1388,6 +1611,100 @@ pub fn trim_quotes(mut s: String) -> String {
     s
 }
 
+/// Strips surrounding quotes and a weak validator prefix (`W/`) from an ETag.
+fn normalize_etag(etag: &str) -> &str {
+    let etag = etag.trim();
+    let etag = etag.strip_prefix("W/").unwrap_or(etag);
+    etag.strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(etag)
+}
+
+/// Compares two ETags, ignoring surrounding quotes and hex digit case.
+///
+/// A multipart suffix (`-N`) is part of the value: a multipart ETag never equals
+/// a single-part one.
+pub fn etag_equals(a: &str, b: &str) -> bool {
+    normalize_etag(a).eq_ignore_ascii_case(normalize_etag(b))
+}
+
+/// Returns true if the ETag has the `<hex>-<part count>` form of a multipart
+/// upload, which is not an MD5 digest of the content.
+pub fn is_multipart_etag(etag: &str) -> bool {
+    match normalize_etag(etag).rsplit_once('-') {
+        Some((hash, count)) => {
+            !hash.is_empty()
+                && hash.bytes().all(|b| b.is_ascii_hexdigit())
+                && !count.is_empty()
+                && count.bytes().all(|b| b.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+/// Verifies `body` against an ETag that is a plain MD5 digest.
+///
+/// Returns `Ok(true)` if the digest matches and `Ok(false)` if the ETag is not a
+/// plain MD5 (for example a multipart ETag) and no verification was done. A
+/// mismatching digest returns [`ValidationErr::ChecksumMismatch`].
+///
+/// Note that objects encrypted with SSE-C or SSE-KMS have ETags that look like an
+/// MD5 digest but are not; only call this for unencrypted or SSE-S3 objects.
+pub fn verify_single_part_md5(body: &[u8], etag: &str) -> Result<bool, ValidationErr> {
+    let expected = normalize_etag(etag);
+    if expected.len() != 32 || !expected.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Ok(false);
+    }
+    let computed = format!("{:x}", md5::compute(body));
+    if computed.eq_ignore_ascii_case(expected) {
+        Ok(true)
+    } else {
+        Err(ValidationErr::ChecksumMismatch {
+            expected: expected.to_string(),
+            computed,
+        })
+    }
+}
+
+/// Computes the ETag of a multipart upload from the MD5 digests of its parts,
+/// in part order: the hex MD5 of the concatenated digests followed by
+/// `-<part count>`, quoted as in the `ETag` header (`"<hex>-<count>"`).
+///
+/// Like [`verify_single_part_md5`], this only reproduces the server's ETag for
+/// unencrypted or SSE-S3 objects.
+pub fn compute_multipart_etag(part_md5s: &[[u8; 16]]) -> String {
+    let digest = md5::compute(part_md5s.concat());
+    format!("\"{digest:x}-{}\"", part_md5s.len())
+}
+
+/// Computes the multipart ETag of `data` uploaded in parts of `part_size` bytes,
+/// the last part holding the remainder. Compare the result to the server's ETag
+/// with [`etag_equals`].
+///
+/// # Panics
+///
+/// Panics if `part_size` is 0.
+pub fn compute_multipart_etag_from_data(data: &[u8], part_size: usize) -> String {
+    let part_md5s: Vec<[u8; 16]> = data.chunks(part_size).map(|v| md5::compute(v).0).collect();
+    compute_multipart_etag(&part_md5s)
+}
+
+/// Escapes the XML special characters `&`, `<`, `>`, `"` and `'` in text content.
+pub fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 /// Copies source byte slice into destination byte slice.
 pub fn copy_slice(dst: &mut [u8], src: &[u8]) -> usize {
     let mut c = 0;
@@ -1436,15 +1753,50 @@ fn escape(s: &str) -> String {
     utf8_percent_encode(s, QUERY_ESCAPE).collect()
 }
 
+/// Returns the canonical query string of AWS Signature V4.
+///
+/// Keys and values are percent-encoded with only unreserved characters left
+/// as-is (a space becomes `%20`, `=` in a value becomes `%3D`), and the pairs
+/// are sorted by encoded key and then by encoded value.
+pub fn canonical_query_string(query: &Multimap) -> String {
+    let mut pairs: Vec<(String, String)> = query
+        .iter_all()
+        .flat_map(|(key, values)| {
+            let key = escape(key);
+            values.iter().map(move |v| (key.clone(), escape(v)))
+        })
+        .collect();
+    pairs.sort_unstable();
+
+    let mut canonical = String::new();
+    for (key, value) in pairs {
+        if !canonical.is_empty() {
+            canonical.push('&');
+        }
+        canonical.push_str(&key);
+        canonical.push('=');
+        canonical.push_str(&value);
+    }
+    canonical
+}
+
 /// Encodes tags as URL-encoded query parameters for the `x-amz-tagging` header.
 ///
-/// Handles escaping compatible with MinIO server and AWS S3.
-pub fn encode_tags(h: &HashMap<String, String>) -> String {
-    let mut tags = Vec::with_capacity(h.len());
-    for (k, v) in h {
-        tags.push(format!("{}={}", escape(k), escape(v)));
+/// Handles escaping compatible with MinIO server and AWS S3. Accepts [`Tags`] as
+/// well as a `HashMap<String, String>`, by value or reference. Tags are emitted in
+/// key order, so the output is deterministic.
+pub fn encode_tags(tags: impl Into<Tags>) -> String {
+    let tags: Tags = tags.into();
+    let mut encoded = String::new();
+    for (k, v) in tags.iter() {
+        if !encoded.is_empty() {
+            encoded.push('&');
+        }
+        encoded.push_str(&escape(k));
+        encoded.push('=');
+        encoded.push_str(&escape(v));
     }
-    tags.join("&")
+    encoded
 }
 
 pub fn parse_tags(s: &str) -> Result<HashMap<String, String>, ValidationErr> {
@@ -1475,6 +1827,15 @@ pub fn parse_tags(s: &str) -> Result<HashMap<String, String>, ValidationErr> {
     Ok(tags)
 }
 
+/// Adds `x-amz-request-payer: requester` to the headers when `request_payer` is set.
+///
+/// Requests against requester-pays buckets are denied unless they carry this header.
+pub fn add_request_payer(headers: &mut Multimap, request_payer: bool) {
+    if request_payer {
+        headers.insert(X_AMZ_REQUEST_PAYER.into(), "requester".into());
+    }
+}
+
 /// Returns the consumed data and inserts a key into it with an empty value.
 #[must_use]
 pub fn insert(data: Option<Multimap>, key: impl Into<String>) -> Multimap {
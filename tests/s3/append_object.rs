@@ -582,3 +582,44 @@ async fn append_object_content_exact_part_size_multiple(ctx: TestContext, bucket
         .unwrap();
     assert_eq!(resp.size().unwrap(), initial_size + append_size);
 }
+
+/// Append a stream in chunks, each sent with its own write offset
+#[minio_macros::test(skip_if_not_express)]
+async fn append_object_stream_chunks(ctx: TestContext, bucket: BucketName) {
+    let object = rand_object_name();
+    create_object_helper("aaaa", &bucket, &object, &ctx).await;
+
+    let size: u64 = ctx
+        .client
+        .append_object_stream(&bucket, &object, ObjectContent::from("bbbbccccdd"))
+        .unwrap()
+        .chunk_size(4)
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(size, 14);
+
+    let resp: GetObjectResponse = ctx
+        .client
+        .get_object(&bucket, &object)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    let content = resp.content().unwrap().to_segmented_bytes().await.unwrap();
+    assert_eq!(content.to_bytes().as_ref(), b"aaaabbbbccccdd");
+
+    // Appending empty content sends nothing and reports the current size.
+    let size: u64 = ctx
+        .client
+        .append_object_stream(&bucket, &object, ObjectContent::from(""))
+        .unwrap()
+        .offset_bytes(14)
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(size, 14);
+}
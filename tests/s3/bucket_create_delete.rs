@@ -66,6 +66,21 @@ async fn bucket_create(ctx: TestContext) -> Result<(), Error> {
     Ok(())
 }
 
+#[minio_macros::test(no_bucket)]
+async fn bucket_ensure(ctx: TestContext) -> Result<(), Error> {
+    let bucket = rand_bucket_name();
+
+    // the first call creates the bucket, the second finds it already owned
+    assert!(ctx.client.ensure_bucket(&bucket).await?);
+    assert!(!ctx.client.ensure_bucket(&bucket).await?);
+
+    let resp: BucketExistsResponse = ctx.client.bucket_exists(&bucket)?.build().send().await?;
+    assert!(resp.exists());
+
+    ctx.client.delete_bucket(&bucket)?.build().send().await?;
+    Ok(())
+}
+
 #[minio_macros::test(no_bucket)]
 async fn bucket_delete(ctx: TestContext) -> Result<(), Error> {
     let bucket = rand_bucket_name();
@@ -0,0 +1,168 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_util::StreamExt;
+use minio::s3::builders::{ObjectContent, VersioningStatus};
+use minio::s3::response::{DeleteAllVersionsResponse, EmptyBucketResponse, ListObjectsResponse};
+use minio::s3::types::{BucketName, S3Api, ToStream};
+use minio_common::test_context::TestContext;
+use minio_common::utils::rand_object_name;
+
+#[minio_macros::test(skip_if_express)]
+async fn empty_bucket_versioned(ctx: TestContext, bucket: BucketName) {
+    ctx.client
+        .put_bucket_versioning(&bucket, VersioningStatus::Enabled)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let objects = [rand_object_name(), rand_object_name()];
+    for object in &objects {
+        for i in 0..3 {
+            ctx.client
+                .put_object_content(&bucket, object, ObjectContent::from(format!("version {i}")))
+                .unwrap()
+                .build()
+                .send()
+                .await
+                .unwrap();
+        }
+    }
+    // Add a delete marker on top of the versions of the first object.
+    ctx.client
+        .delete_object(&bucket, &objects[0])
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let resp: EmptyBucketResponse = ctx
+        .client
+        .empty_bucket(&bucket)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.bucket(), &bucket);
+    assert_eq!(resp.deleted(), 7);
+
+    let mut stream = ctx
+        .client
+        .list_objects(&bucket)
+        .unwrap()
+        .include_versions(true)
+        .recursive(true)
+        .build()
+        .to_stream()
+        .await;
+    let mut remaining = 0;
+    while let Some(items) = stream.next().await {
+        let items: ListObjectsResponse = items.unwrap();
+        remaining += items.contents.len();
+    }
+    assert_eq!(remaining, 0);
+}
+
+#[minio_macros::test(skip_if_express)]
+async fn empty_bucket_dry_run(ctx: TestContext, bucket: BucketName) {
+    ctx.client
+        .put_bucket_versioning(&bucket, VersioningStatus::Enabled)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let object = rand_object_name();
+    for i in 0..3 {
+        ctx.client
+            .put_object_content(
+                &bucket,
+                &object,
+                ObjectContent::from(format!("version {i}")),
+            )
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+    }
+    for _ in 0..5 {
+        ctx.client
+            .put_object_content(&bucket, rand_object_name(), ObjectContent::from("data"))
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+    }
+
+    let resp: EmptyBucketResponse = ctx
+        .client
+        .empty_bucket(&bucket)
+        .unwrap()
+        .dry_run(true)
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.deleted(), 0);
+    assert_eq!(resp.would_delete().len(), 8);
+
+    let resp: DeleteAllVersionsResponse = ctx
+        .client
+        .delete_all_versions(&bucket, &object)
+        .unwrap()
+        .dry_run(true)
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.deleted(), 0);
+    assert_eq!(resp.would_delete().len(), 3);
+    assert!(resp.would_delete().iter().all(|o| o.key() == &object));
+
+    // Nothing was deleted.
+    let mut stream = ctx
+        .client
+        .list_objects(&bucket)
+        .unwrap()
+        .include_versions(true)
+        .recursive(true)
+        .build()
+        .to_stream()
+        .await;
+    let mut remaining = 0;
+    while let Some(items) = stream.next().await {
+        let items: ListObjectsResponse = items.unwrap();
+        remaining += items.contents.len();
+    }
+    assert_eq!(remaining, 8);
+
+    let resp: DeleteAllVersionsResponse = ctx
+        .client
+        .delete_all_versions(&bucket, &object)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.deleted(), 3);
+    assert!(resp.would_delete().is_empty());
+}
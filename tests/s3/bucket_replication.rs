@@ -196,3 +196,25 @@ async fn bucket_replication_s3express(ctx: TestContext, bucket: BucketName) {
         v => panic!("Expected error S3Error(NotSupported): but got {v:?}"),
     }
 }
+
+/// Test that replication on a bucket without versioning fails before the request is sent.
+#[minio_macros::test(skip_if_express)]
+async fn bucket_replication_requires_versioning(ctx: TestContext, bucket: BucketName) {
+    let config: ReplicationConfig = create_bucket_replication_config_example(&bucket);
+    let resp = ctx
+        .client
+        .put_bucket_replication(&bucket)
+        .unwrap()
+        .replication_config(config)
+        .require_versioning(true)
+        .build()
+        .send()
+        .await;
+    match resp {
+        Err(Error::VersioningRequired { bucket: b, status }) => {
+            assert_eq!(b, bucket.as_str());
+            assert_eq!(status, "never enabled");
+        }
+        v => panic!("expected VersioningRequired, got {v:?}"),
+    }
+}
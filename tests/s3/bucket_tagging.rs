@@ -23,6 +23,7 @@ use minio::s3::response_traits::{HasBucket, HasRegion, HasTagging};
 use minio::s3::types::{BucketName, S3Api};
 use minio_common::example::create_tags_example;
 use minio_common::test_context::TestContext;
+use std::collections::HashMap;
 
 #[minio_macros::test(skip_if_express)]
 async fn bucket_tags_s3(ctx: TestContext, bucket: BucketName) {
@@ -76,6 +77,33 @@ async fn bucket_tags_s3(ctx: TestContext, bucket: BucketName) {
     assert_eq!(resp.region(), &*DEFAULT_REGION);
 }
 
+#[minio_macros::test(skip_if_express)]
+async fn bucket_tags_with_spaces(ctx: TestContext, bucket: BucketName) {
+    let tags: HashMap<String, String> = HashMap::from([
+        ("cost center".to_string(), "team a + b".to_string()),
+        ("path".to_string(), "a/b:c@d=e".to_string()),
+    ]);
+
+    ctx.client
+        .put_bucket_tagging(&bucket)
+        .unwrap()
+        .tags(tags.clone())
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let resp: GetBucketTaggingResponse = ctx
+        .client
+        .get_bucket_tagging(&bucket)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.tags().unwrap(), tags);
+}
+
 #[minio_macros::test(skip_if_not_express)]
 async fn bucket_tags_s3express(ctx: TestContext, bucket: BucketName) {
     let tags = create_tags_example();
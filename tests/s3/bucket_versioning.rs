@@ -101,3 +101,29 @@ async fn bucket_versioning_s3express(ctx: TestContext, bucket: BucketName) {
         v => panic!("Expected error S3Error(NotSupported): but got {v:?}"),
     }
 }
+
+#[minio_macros::test(skip_if_express)]
+async fn bucket_versioning_excluded_prefixes(ctx: TestContext, bucket: BucketName) {
+    ctx.client
+        .put_bucket_versioning(&bucket, VersioningStatus::Enabled)
+        .unwrap()
+        .exclude_prefix("scratch/")
+        .exclude_prefix("tmp/")
+        .exclude_folders(true)
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let resp: GetBucketVersioningResponse = ctx
+        .client
+        .get_bucket_versioning(&bucket)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status().unwrap(), Some(VersioningStatus::Enabled));
+    assert_eq!(resp.excluded_prefixes().unwrap(), ["scratch/", "tmp/"]);
+    assert!(resp.exclude_folders().unwrap());
+}
@@ -17,7 +17,7 @@ use bytes::Bytes;
 use futures_util::TryStreamExt;
 use minio::s3::response::{GetObjectResponse, PutObjectContentResponse};
 use minio::s3::response_traits::{HasBucket, HasObject};
-use minio::s3::types::{BucketName, ObjectKey, S3Api};
+use minio::s3::types::{BucketName, ObjectKey, RequestHeaders, S3Api};
 use minio_common::test_context::TestContext;
 use minio_common::utils::rand_object_name_utf8;
 
@@ -130,7 +130,7 @@ async fn get_object_into_boxed_stream(ctx: TestContext, bucket: BucketName) {
 
     // Get stream and content length
     let (stream, content_length) = resp.into_boxed_stream().unwrap();
-    assert_eq!(content_length, data.len() as u64);
+    assert_eq!(content_length, Some(data.len() as u64));
 
     // Collect all bytes from the stream
     let chunks: Vec<Bytes> = stream.try_collect().await.unwrap();
@@ -165,7 +165,7 @@ async fn get_object_into_boxed_stream_large(ctx: TestContext, bucket: BucketName
         .unwrap();
 
     let (stream, content_length) = resp.into_boxed_stream().unwrap();
-    assert_eq!(content_length, data.len() as u64);
+    assert_eq!(content_length, Some(data.len() as u64));
 
     // Collect and verify
     let chunks: Vec<Bytes> = stream.try_collect().await.unwrap();
@@ -203,3 +203,119 @@ async fn get_object_into_bytes_empty(ctx: TestContext, bucket: BucketName) {
     let got = resp.into_bytes().await.unwrap();
     assert!(got.is_empty());
 }
+
+#[minio_macros::test]
+async fn get_object_download_range_to(ctx: TestContext, bucket: BucketName) {
+    let object = rand_object_name_utf8(20);
+    let data: Bytes = (0..100_u8).collect::<Vec<u8>>().into();
+    ctx.client
+        .put_object_content(&bucket, &object, data.clone())
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let mut writer: Vec<u8> = Vec::new();
+    let written: u64 = ctx
+        .client
+        .get_object(&bucket, &object)
+        .unwrap()
+        .build()
+        .download_range_to(&mut writer, 10, 10)
+        .await
+        .unwrap();
+    assert_eq!(written, 10);
+    assert_eq!(writer, data[10..20]);
+
+    // A range running past the end of the object is truncated by the server.
+    let mut writer: Vec<u8> = Vec::new();
+    let resp = ctx
+        .client
+        .get_object(&bucket, &object)
+        .unwrap()
+        .build()
+        .download_range_to(&mut writer, 95, 10)
+        .await;
+    assert!(
+        resp.is_err(),
+        "expected truncated range to fail; got {resp:?}"
+    );
+}
+
+/// Test that content larger than the in-memory threshold is spilled to a temporary file.
+#[minio_macros::test]
+async fn get_object_buffered_content_spills_to_disk(ctx: TestContext, bucket: BucketName) {
+    let object = rand_object_name_utf8(20);
+    let data: Bytes = Bytes::from((0..10 * 1024).map(|i| (i % 251) as u8).collect::<Vec<u8>>());
+
+    ctx.client
+        .put_object_content(&bucket, &object, data.clone())
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let content = ctx
+        .client
+        .get_object(&bucket, &object)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap()
+        .buffered_content(1024)
+        .await
+        .unwrap();
+
+    let path = content.file_path().unwrap().to_path_buf();
+    assert!(path.starts_with(std::env::temp_dir()));
+
+    let got = content.to_segmented_bytes().await.unwrap().to_bytes();
+    assert_eq!(got, data);
+    assert!(!path.exists());
+}
+
+/// Test that `auto_decompress` decodes a gzip-encoded object.
+#[minio_macros::test]
+async fn get_object_auto_decompress(ctx: TestContext, bucket: BucketName) {
+    use futures_util::AsyncReadExt;
+
+    let object = rand_object_name_utf8(20);
+    let data: &[u8] = b"hello, compressed world";
+    let mut compressed = Vec::new();
+    async_compression::futures::bufread::GzipEncoder::new(data)
+        .read_to_end(&mut compressed)
+        .await
+        .unwrap();
+
+    ctx.client
+        .put_object_content(&bucket, &object, compressed.clone())
+        .unwrap()
+        .build()
+        .header("Content-Encoding", "gzip")
+        .unwrap()
+        .send()
+        .await
+        .unwrap();
+
+    for (auto_decompress, expected) in [(true, data), (false, &compressed[..])] {
+        let got = ctx
+            .client
+            .get_object(&bucket, &object)
+            .unwrap()
+            .auto_decompress(auto_decompress)
+            .build()
+            .send()
+            .await
+            .unwrap()
+            .content()
+            .unwrap()
+            .to_segmented_bytes()
+            .await
+            .unwrap()
+            .to_bytes();
+        assert_eq!(got, expected);
+    }
+}
@@ -25,12 +25,15 @@ mod object_checksums;
 mod object_compose;
 mod object_copy;
 mod object_delete;
+mod object_exists;
 mod object_put;
+mod stat_objects;
 mod test_checksums;
 mod upload_download_object;
 
 // Bucket operations
 mod bucket_create_delete;
+mod bucket_empty;
 mod bucket_exists;
 mod list_buckets;
 
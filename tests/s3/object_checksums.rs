@@ -14,7 +14,9 @@
 // limitations under the License.
 
 use bytes::Bytes;
-use minio::s3::builders::{ComposeSource, CopySource, ObjectContent, PutObject, UploadPart};
+use minio::s3::builders::{
+    ComposeSource, CopySource, MIN_PART_SIZE, ObjectContent, PutObject, UploadPart,
+};
 use minio::s3::response::{
     AppendObjectResponse, ComposeObjectResponse, CopyObjectResponse, GetObjectResponse,
     PutObjectContentResponse, PutObjectResponse,
@@ -22,7 +24,7 @@ use minio::s3::response::{
 use minio::s3::response_traits::{HasBucket, HasChecksumHeaders, HasObject, HasObjectSize};
 use minio::s3::segmented_bytes::SegmentedBytes;
 use minio::s3::types::{BucketName, ObjectKey, S3Api};
-use minio::s3::utils::ChecksumAlgorithm;
+use minio::s3::utils::{ChecksumAlgorithm, compute_checksum, compute_composite_checksum};
 use minio_common::rand_src::RandSrc;
 use minio_common::test_context::TestContext;
 use minio_common::utils::rand_object_name;
@@ -1085,6 +1087,51 @@ async fn upload_with_trailing_crc32c_checksum(ctx: TestContext, bucket: BucketNa
         .unwrap();
 }
 
+/// Test streaming content of unknown length with a trailing CRC32C checksum
+/// computed on the fly; the checksum echoed by the server must match.
+#[minio_macros::test]
+async fn put_object_content_unknown_size_with_trailing_crc32c(
+    ctx: TestContext,
+    bucket: BucketName,
+) {
+    let object = rand_object_name();
+    let chunks: Vec<std::io::Result<Bytes>> = vec![
+        Ok(Bytes::from_static(b"Streaming data of unknown length, ")),
+        Ok(Bytes::from_static(b"checksummed with a trailing CRC32C.")),
+    ];
+    let data: Vec<u8> = chunks
+        .iter()
+        .flat_map(|c| c.as_ref().unwrap().to_vec())
+        .collect();
+    let content = ObjectContent::new_from_stream(futures_util::stream::iter(chunks), None);
+
+    let resp: PutObjectContentResponse = ctx
+        .client
+        .put_object_content(&bucket, &object, content)
+        .unwrap()
+        .part_size(MIN_PART_SIZE)
+        .checksum_algorithm(ChecksumAlgorithm::CRC32C)
+        .use_trailing_checksum(true)
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(resp.object_size(), data.len() as u64);
+    assert_eq!(
+        resp.get_checksum(ChecksumAlgorithm::CRC32C),
+        Some(compute_checksum(ChecksumAlgorithm::CRC32C, &data))
+    );
+
+    ctx.client
+        .delete_object(&bucket, &object)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+}
+
 /// Test uploading an object with trailing CRC64NVME checksum
 #[minio_macros::test]
 async fn upload_with_trailing_crc64nvme_checksum(ctx: TestContext, bucket: BucketName) {
@@ -2013,3 +2060,64 @@ async fn compose_multiple_sources_with_checksum(ctx: TestContext, bucket: Bucket
         .await
         .unwrap();
 }
+
+/// Upload a non-seekable reader of unknown length in concurrent CRC32C-checksummed
+/// parts; the composite checksum of the object must match the downloaded content.
+#[minio_macros::test]
+async fn put_object_content_from_reader_with_composite_crc32c(
+    ctx: TestContext,
+    bucket: BucketName,
+) {
+    let object = rand_object_name();
+    let size: u64 = 40 * 1024 * 1024;
+    let n_parts = size.div_ceil(MIN_PART_SIZE) as u32;
+
+    let resp: PutObjectContentResponse = ctx
+        .client
+        .put_object_content(
+            &bucket,
+            &object,
+            ObjectContent::new_from_reader(RandSrc::new(size), None),
+        )
+        .unwrap()
+        .part_size(MIN_PART_SIZE)
+        .max_concurrent_parts(4)
+        .checksum_algorithm(ChecksumAlgorithm::CRC32C)
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.object_size(), size);
+
+    let get_resp: GetObjectResponse = ctx
+        .client
+        .get_object(&bucket, &object)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    let checksum = get_resp.checksum().unwrap();
+    assert!(checksum.is_composite());
+    assert_eq!(checksum.algorithm, ChecksumAlgorithm::CRC32C);
+    assert_eq!(checksum.part_count, Some(n_parts));
+
+    let data: Bytes = get_resp.into_bytes().await.unwrap();
+    assert_eq!(data.len() as u64, size);
+    let part_checksums: Vec<String> = data
+        .chunks(MIN_PART_SIZE as usize)
+        .map(|part| compute_checksum(ChecksumAlgorithm::CRC32C, part))
+        .collect();
+    assert_eq!(
+        checksum.value,
+        compute_composite_checksum(ChecksumAlgorithm::CRC32C, &part_checksums).unwrap()
+    );
+
+    ctx.client
+        .delete_object(&bucket, &object)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+}
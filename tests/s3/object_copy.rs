@@ -13,13 +13,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use bytes::Bytes;
 use minio::s3::builders::{CopySource, ObjectContent};
+use minio::s3::error::{Error, S3ServerError};
+use minio::s3::header_constants::X_AMZ_SERVER_SIDE_ENCRYPTION;
+use minio::s3::minio_error_response::MinioErrorCode;
 use minio::s3::response::{CopyObjectResponse, PutObjectContentResponse, StatObjectResponse};
-use minio::s3::response_traits::{HasBucket, HasObject};
-use minio::s3::types::{BucketName, ObjectKey, S3Api};
+use minio::s3::response_traits::{HasBucket, HasObject, HasS3Fields, HasTagging};
+use minio::s3::sse::{Sse, SseCustomerKey, SseKms, SseS3};
+use minio::s3::types::{BucketName, Directive, ObjectKey, S3Api};
 use minio_common::rand_src::RandSrc;
 use minio_common::test_context::TestContext;
-use minio_common::utils::rand_object_name_utf8;
+use minio_common::utils::{rand_object_name, rand_object_name_utf8};
+use std::collections::HashMap;
+use std::sync::Arc;
 
 async fn test_copy_object(
     ctx: &TestContext,
@@ -89,3 +96,267 @@ async fn copy_object_2(ctx: TestContext, bucket: BucketName) {
     )
     .await;
 }
+
+async fn copy_with_tagging_directive(
+    ctx: &TestContext,
+    bucket: &BucketName,
+    src: &ObjectKey,
+    tags: Option<HashMap<String, String>>,
+    directive: Directive,
+) -> HashMap<String, String> {
+    let dst = rand_object_name();
+    ctx.client
+        .copy_object(bucket, &dst)
+        .unwrap()
+        .source(CopySource::builder().bucket(bucket).object(src).build())
+        .tags(tags.map(Into::into))
+        .tagging_directive(directive)
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    ctx.client
+        .get_object_tagging(bucket, &dst)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap()
+        .tags()
+        .unwrap()
+}
+
+/// Test that copying an object keeps the source tags with the COPY tagging
+/// directive and applies the given tags with the REPLACE tagging directive.
+#[minio_macros::test(skip_if_express)]
+async fn copy_object_tagging_directive(ctx: TestContext, bucket: BucketName) {
+    let src = rand_object_name();
+    let size = 16_u64;
+    let source_tags = HashMap::from([(String::from("origin"), String::from("source object"))]);
+    ctx.client
+        .put_object_content(
+            &bucket,
+            &src,
+            ObjectContent::new_from_stream(RandSrc::new(size), Some(size)),
+        )
+        .unwrap()
+        .tags(Some(source_tags.clone().into()))
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let tags = copy_with_tagging_directive(&ctx, &bucket, &src, None, Directive::Copy).await;
+    assert_eq!(tags, source_tags);
+
+    let replacement = HashMap::from([
+        (String::from("origin"), String::from("copy")),
+        (String::from("stage"), String::from("a+b c")),
+    ]);
+    let tags = copy_with_tagging_directive(
+        &ctx,
+        &bucket,
+        &src,
+        Some(replacement.clone()),
+        Directive::Replace,
+    )
+    .await;
+    assert_eq!(tags, replacement);
+}
+
+async fn test_move_object(ctx: &TestContext, src_bucket: &BucketName, dst_bucket: &BucketName) {
+    let src = rand_object_name_utf8(20);
+    let dst = rand_object_name_utf8(20);
+    let size = 16_u64;
+    let mut user_metadata = minio::s3::multimap_ext::Multimap::new();
+    user_metadata.insert("x-amz-meta-origin".into(), "move-test".into());
+
+    ctx.client
+        .put_object_content(
+            src_bucket,
+            &src,
+            ObjectContent::new_from_stream(RandSrc::new(size), Some(size)),
+        )
+        .unwrap()
+        .user_metadata(Some(user_metadata))
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let resp: CopyObjectResponse = ctx
+        .client
+        .move_object(src_bucket, &src, dst_bucket, &dst)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.bucket(), Some(dst_bucket));
+    assert_eq!(resp.object(), Some(&dst));
+
+    let resp: StatObjectResponse = ctx
+        .client
+        .stat_object(dst_bucket, &dst)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.size().unwrap(), size);
+    assert_eq!(
+        resp.user_metadata()
+            .unwrap()
+            .get("origin")
+            .map(String::as_str),
+        Some("move-test")
+    );
+
+    let resp = ctx
+        .client
+        .stat_object(src_bucket, &src)
+        .unwrap()
+        .build()
+        .send()
+        .await;
+    match resp {
+        Err(Error::S3Server(S3ServerError::S3Error(e))) => {
+            assert_eq!(e.code(), MinioErrorCode::NoSuchKey)
+        }
+        v => panic!("source object should be gone; got {v:?}"),
+    }
+}
+
+/// Test moving an object within a bucket.
+#[minio_macros::test(skip_if_express)]
+async fn move_object_same_bucket(ctx: TestContext, bucket: BucketName) {
+    test_move_object(&ctx, &bucket, &bucket).await;
+}
+
+/// Test moving an object to another bucket.
+#[minio_macros::test(skip_if_express)]
+async fn move_object_across_buckets(ctx: TestContext, bucket: BucketName) {
+    let (bucket2, cleanup2) = ctx.create_bucket_helper().await;
+    test_move_object(&ctx, &bucket, &bucket2).await;
+    cleanup2.cleanup().await;
+}
+
+/// Test re-encrypting an SSE-S3 object with SSE-KMS.
+#[minio_macros::test(skip_if_express)]
+async fn reencrypt_object_sse_s3_to_kms(ctx: TestContext, bucket: BucketName) {
+    if !ctx.client.is_secure() {
+        println!("Skipping test because SSE-KMS requires HTTPS");
+        return;
+    }
+    let object = rand_object_name();
+    let size = 16_u64;
+
+    ctx.client
+        .put_object_content(
+            &bucket,
+            &object,
+            ObjectContent::new_from_stream(RandSrc::new(size), Some(size)),
+        )
+        .unwrap()
+        .sse(Some(Arc::new(SseS3::new()) as Arc<dyn Sse>))
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let resp: CopyObjectResponse = ctx
+        .client
+        .reencrypt_object(
+            &bucket,
+            &object,
+            Arc::new(SseKms::new("my-minio-key", None)),
+        )
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.object(), Some(&object));
+
+    let resp: StatObjectResponse = ctx
+        .client
+        .stat_object(&bucket, &object)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.size().unwrap(), size);
+    assert_eq!(
+        resp.headers()
+            .get(X_AMZ_SERVER_SIDE_ENCRYPTION)
+            .and_then(|v| v.to_str().ok()),
+        Some("aws:kms")
+    );
+}
+
+/// Test rotating the SSE-C key of an object by copying it onto itself.
+#[minio_macros::test(skip_if_express)]
+async fn copy_object_rotate_ssec_key(ctx: TestContext, bucket: BucketName) {
+    if !ctx.client.is_secure() {
+        println!("Skipping test because SSE-C requires HTTPS");
+        return;
+    }
+    let object = rand_object_name();
+    let data = Bytes::from("content encrypted with a customer-provided key");
+    let old_key = SseCustomerKey::new([1_u8; 32]).unwrap();
+    let new_key = SseCustomerKey::new([2_u8; 32]).unwrap();
+
+    ctx.client
+        .put_object_content(&bucket, &object, data.clone())
+        .unwrap()
+        .sse(Some(Arc::new(old_key.clone()) as Arc<dyn Sse>))
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let resp: CopyObjectResponse = ctx
+        .client
+        .copy_object(&bucket, &object)
+        .unwrap()
+        .source(
+            CopySource::builder()
+                .bucket(bucket.clone())
+                .object(object.clone())
+                .build(),
+        )
+        .metadata_directive(Directive::Copy)
+        .ssec_source(old_key.clone())
+        .ssec(new_key.clone())
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.object(), Some(&object));
+
+    let got = ctx
+        .client
+        .get_object(&bucket, &object)
+        .unwrap()
+        .ssec(Some(new_key))
+        .build()
+        .send()
+        .await
+        .unwrap()
+        .into_bytes()
+        .await
+        .unwrap();
+    assert_eq!(got, data);
+
+    let resp = ctx
+        .client
+        .get_object(&bucket, &object)
+        .unwrap()
+        .ssec(Some(old_key))
+        .build()
+        .send()
+        .await;
+    assert!(resp.is_err(), "object is still readable with the old key");
+}
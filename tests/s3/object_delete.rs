@@ -15,13 +15,16 @@
 
 use async_std::stream::StreamExt;
 use minio::s3::builders::ObjectToDelete;
+use minio::s3::error::{Error, ValidationErr};
 use minio::s3::response::{
-    DeleteObjectResponse, DeleteObjectsResponse, DeleteResult, PutObjectContentResponse,
+    DeleteObjectResponse, DeleteObjectsResponse, DeleteResult, ObjectExistsResponse,
+    PutObjectContentResponse,
 };
 use minio::s3::response_traits::{HasBucket, HasObject};
-use minio::s3::types::{BucketName, ObjectKey, S3Api, ToStream};
+use minio::s3::types::{BucketName, ObjectKey, RetentionMode, S3Api, ToStream};
+use minio::s3::utils::utc_now;
 use minio_common::test_context::TestContext;
-use minio_common::utils::rand_object_name_utf8;
+use minio_common::utils::{rand_object_name, rand_object_name_utf8};
 
 async fn create_object_helper(
     ctx: &TestContext,
@@ -129,3 +132,53 @@ async fn delete_objects_streaming(ctx: TestContext, bucket: BucketName) {
     }
     assert_eq!(del_count, OBJECT_COUNT);
 }
+
+#[minio_macros::test(skip_if_express, object_lock)]
+async fn delete_object_ensure_unlocked(ctx: TestContext, bucket: BucketName) {
+    let object = rand_object_name();
+    create_object_helper(&ctx, &bucket, &object).await;
+
+    // Keep the lock short so the bucket can still be cleaned up afterwards.
+    let lock_duration = std::time::Duration::from_secs(3);
+    let retain_until = utc_now() + lock_duration;
+    ctx.client
+        .put_object_retention(&bucket, &object)
+        .unwrap()
+        .retention_mode(RetentionMode::COMPLIANCE)
+        .retain_until_date(retain_until)
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let err = ctx
+        .client
+        .delete_object(&bucket, &object)
+        .unwrap()
+        .ensure_unlocked(true)
+        .build()
+        .send()
+        .await
+        .unwrap_err();
+    match err {
+        Error::Validation(ValidationErr::ObjectLocked {
+            mode, retain_until, ..
+        }) => {
+            assert_eq!(mode, Some(RetentionMode::COMPLIANCE));
+            assert!(retain_until.is_some());
+        }
+        e => panic!("expected ObjectLocked, got {e:?}"),
+    }
+
+    let resp: ObjectExistsResponse = ctx
+        .client
+        .object_exists(&bucket, &object)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert!(resp.exists());
+
+    tokio::time::sleep(lock_duration + std::time::Duration::from_secs(1)).await;
+}
@@ -0,0 +1,64 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use minio::s3::builders::ObjectContent;
+use minio::s3::response::ObjectExistsResponse;
+use minio::s3::response_traits::{HasBucket, HasObject};
+use minio::s3::types::{BucketName, S3Api};
+use minio_common::test_context::TestContext;
+use minio_common::utils::rand_object_name;
+
+#[minio_macros::test]
+async fn object_exists(ctx: TestContext, bucket: BucketName) {
+    let object = rand_object_name();
+
+    let resp: ObjectExistsResponse = ctx
+        .client
+        .object_exists(&bucket, &object)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert!(!resp.exists());
+    assert_eq!(resp.bucket(), Some(&bucket));
+    assert_eq!(resp.object(), Some(&object));
+
+    ctx.client
+        .put_object_content(&bucket, &object, ObjectContent::from("hello"))
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let resp: ObjectExistsResponse = ctx
+        .client
+        .object_exists(&bucket, &object)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert!(resp.exists());
+
+    ctx.client
+        .delete_object(&bucket, &object)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+}
@@ -14,11 +14,13 @@
 // limitations under the License.
 
 use minio::s3::client::DEFAULT_REGION;
+use minio::s3::error::{Error, S3ServerError};
+use minio::s3::minio_error_response::MinioErrorCode;
 use minio::s3::response::{
     DeleteObjectLockConfigResponse, GetObjectLockConfigResponse, PutObjectLockConfigResponse,
 };
 use minio::s3::response_traits::{HasBucket, HasRegion};
-use minio::s3::types::{BucketName, ObjectLockConfig, RetentionMode, S3Api};
+use minio::s3::types::{BucketName, ObjectLockConfig, RetentionMode, RetentionPeriod, S3Api};
 use minio_common::test_context::TestContext;
 
 #[minio_macros::test(skip_if_express, object_lock)]
@@ -79,3 +81,28 @@ async fn object_lock_config(ctx: TestContext, bucket: BucketName) {
     assert_eq!(resp.bucket(), Some(&bucket));
     assert_eq!(resp.region(), &*DEFAULT_REGION);
 }
+
+/// Object lock can only be configured on buckets created with object lock enabled.
+#[minio_macros::test(skip_if_express)]
+async fn object_lock_config_invalid_bucket_state(ctx: TestContext, bucket: BucketName) {
+    let config = ObjectLockConfig::with_default_retention(
+        RetentionMode::GOVERNANCE,
+        RetentionPeriod::Days(1),
+    );
+
+    let resp: Result<PutObjectLockConfigResponse, Error> = ctx
+        .client
+        .put_object_lock_config(&bucket)
+        .unwrap()
+        .config(config)
+        .build()
+        .send()
+        .await;
+    match resp {
+        Ok(v) => panic!("put object lock config should have failed; got value: {v:?}"),
+        Err(Error::S3Server(S3ServerError::S3Error(e))) => {
+            assert_eq!(e.code(), MinioErrorCode::InvalidBucketState);
+        }
+        Err(e) => panic!("put object lock config should have failed; got error: {e:?}"),
+    }
+}
@@ -14,15 +14,23 @@
 // limitations under the License.
 
 use http::header;
-use minio::s3::builders::{MIN_PART_SIZE, ObjectContent};
-use minio::s3::response::{DeleteObjectResponse, PutObjectContentResponse, StatObjectResponse};
+use minio::s3::builders::{FanOutEntry, MIN_PART_SIZE, ObjectContent};
+use minio::s3::error::{Error, S3ServerError};
+use minio::s3::minio_error_response::MinioErrorCode;
+use minio::s3::response::{
+    DeleteObjectResponse, PutObjectContentResponse, PutObjectFanOutResponse, PutObjectResponse,
+    StatObjectResponse,
+};
 use minio::s3::response_traits::{
     HasBucket, HasEtagFromHeaders, HasIsDeleteMarker, HasObject, HasS3Fields,
 };
+use minio::s3::segmented_bytes::SegmentedBytes;
 use minio::s3::types::{BucketName, ObjectKey, S3Api};
+use minio::s3::utils::ChecksumAlgorithm;
 use minio_common::rand_src::RandSrc;
 use minio_common::test_context::TestContext;
 use minio_common::utils::rand_object_name;
+use std::collections::HashMap;
 use tokio::sync::mpsc;
 
 async fn test_put_object(ctx: &TestContext, bucket: &str, object: &str) {
@@ -195,6 +203,32 @@ async fn put_object_content_2(ctx: TestContext, bucket: BucketName) {
     }
 }
 
+#[minio_macros::test]
+async fn put_object_content_verify_after_upload(ctx: TestContext, bucket: BucketName) {
+    let object = rand_object_name();
+    let sizes = [16_u64, 16 + MIN_PART_SIZE];
+
+    for size in sizes.iter() {
+        for checksum_algorithm in [None, Some(ChecksumAlgorithm::CRC32C)] {
+            let resp: PutObjectContentResponse = ctx
+                .client
+                .put_object_content(
+                    &bucket,
+                    &object,
+                    ObjectContent::new_from_stream(RandSrc::new(*size), Some(*size)),
+                )
+                .unwrap()
+                .checksum_algorithm(checksum_algorithm)
+                .verify_after_upload(true)
+                .build()
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(resp.object_size(), *size);
+        }
+    }
+}
+
 /// Test sending PutObject across async tasks.
 #[minio_macros::test]
 async fn put_object_content_3(ctx: TestContext, bucket: BucketName) {
@@ -252,3 +286,132 @@ async fn put_object_content_3(ctx: TestContext, bucket: BucketName) {
     sender_handle.await.unwrap();
     uploader_handler.await.unwrap();
 }
+
+/// Test that a put with `If-None-Match: *` only succeeds when the object does not exist yet.
+#[minio_macros::test]
+async fn put_object_if_none_match_star(ctx: TestContext, bucket: BucketName) {
+    let object = rand_object_name();
+
+    let resp: PutObjectResponse = ctx
+        .client
+        .put_object(&bucket, &object, SegmentedBytes::from("first".to_string()))
+        .unwrap()
+        .if_none_match_star(true)
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.object(), Some(&object));
+
+    let resp: Result<PutObjectResponse, Error> = ctx
+        .client
+        .put_object(&bucket, &object, SegmentedBytes::from("second".to_string()))
+        .unwrap()
+        .if_none_match_star(true)
+        .build()
+        .send()
+        .await;
+
+    match resp {
+        Ok(v) => panic!("put object if absent should have failed; got value: {v:?}"),
+        Err(Error::S3Server(S3ServerError::S3Error(e))) => {
+            assert_eq!(e.code(), MinioErrorCode::PreconditionFailed);
+        }
+        Err(e) => panic!("put object if absent should have failed; got error: {e:?}"),
+    }
+}
+
+/// Test that a put with `If-Match` only succeeds when the ETag of the existing object matches.
+#[minio_macros::test]
+async fn put_object_if_match(ctx: TestContext, bucket: BucketName) {
+    let object = rand_object_name();
+
+    let resp: PutObjectResponse = ctx
+        .client
+        .put_object(&bucket, &object, SegmentedBytes::from("first".to_string()))
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    let etag = resp.etag().unwrap();
+
+    let resp: PutObjectResponse = ctx
+        .client
+        .put_object(&bucket, &object, SegmentedBytes::from("second".to_string()))
+        .unwrap()
+        .if_match(etag.clone())
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_ne!(resp.etag().unwrap(), etag);
+
+    // The object was overwritten, so the original ETag no longer matches.
+    let resp: Result<PutObjectResponse, Error> = ctx
+        .client
+        .put_object(&bucket, &object, SegmentedBytes::from("third".to_string()))
+        .unwrap()
+        .if_match(etag)
+        .build()
+        .send()
+        .await;
+
+    match resp {
+        Ok(v) => panic!("put object if match should have failed; got value: {v:?}"),
+        Err(Error::S3Server(S3ServerError::S3Error(e))) => {
+            assert_eq!(e.code(), MinioErrorCode::PreconditionFailed);
+        }
+        Err(e) => panic!("put object if match should have failed; got error: {e:?}"),
+    }
+}
+
+/// Test writing one upload as three objects with a fan-out upload.
+#[minio_macros::test(skip_if_express)]
+async fn put_object_fan_out(ctx: TestContext, bucket: BucketName) {
+    let data = "fan-out content";
+    let variants = ["small", "medium", "large"];
+    let keys: Vec<ObjectKey> = variants.iter().map(|_| rand_object_name()).collect();
+    let entries: Vec<FanOutEntry> = keys
+        .iter()
+        .zip(variants)
+        .map(|(key, variant)| {
+            FanOutEntry::builder()
+                .key(key.clone())
+                .user_metadata(HashMap::from([("variant".into(), variant.to_string())]))
+                .build()
+        })
+        .collect();
+
+    let resp: PutObjectFanOutResponse = ctx
+        .client
+        .put_object_fan_out(&bucket, entries, data)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.results().len(), variants.len());
+    for result in resp.results() {
+        assert_eq!(result.error, None, "{}", result.key);
+    }
+
+    for (key, variant) in keys.iter().zip(variants) {
+        let resp: StatObjectResponse = ctx
+            .client
+            .stat_object(&bucket, key)
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.size().unwrap(), data.len() as u64);
+        assert_eq!(
+            resp.user_metadata()
+                .unwrap()
+                .get("variant")
+                .map(String::as_str),
+            Some(variant)
+        );
+    }
+}
@@ -0,0 +1,68 @@
+// MinIO Rust Library for Amazon S3 Compatible Cloud Storage
+// Copyright 2025 MinIO, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_util::StreamExt;
+use minio::s3::builders::ObjectContent;
+use minio::s3::error::{Error, S3ServerError};
+use minio::s3::minio_error_response::MinioErrorCode;
+use minio::s3::types::{BucketName, ObjectKey};
+use minio_common::test_context::TestContext;
+use minio_common::utils::rand_object_name;
+
+#[minio_macros::test]
+async fn stat_objects_mixed(ctx: TestContext, bucket: BucketName) {
+    let existing: Vec<ObjectKey> = (0..3).map(|_| rand_object_name()).collect();
+    for (i, object) in existing.iter().enumerate() {
+        ctx.client
+            .put_object_content(&bucket, object, ObjectContent::from("x".repeat(i + 1)))
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+    }
+    let missing: Vec<ObjectKey> = (0..2).map(|_| rand_object_name()).collect();
+
+    let keys = vec![
+        existing[0].clone(),
+        missing[0].clone(),
+        existing[1].clone(),
+        missing[1].clone(),
+        existing[2].clone(),
+    ];
+    let results: Vec<_> = ctx
+        .client
+        .stat_objects(&bucket, keys.clone().into_iter())
+        .unwrap()
+        .concurrency(2)
+        .to_stream()
+        .await
+        .collect()
+        .await;
+    assert_eq!(results.len(), keys.len());
+
+    for ((key, result), expected) in results.into_iter().zip(&keys) {
+        assert_eq!(&key, expected);
+        match existing.iter().position(|v| v == &key) {
+            Some(i) => assert_eq!(result.unwrap().size().unwrap(), i as u64 + 1),
+            None => match result {
+                Err(Error::S3Server(S3ServerError::S3Error(e))) => {
+                    assert_eq!(e.code(), MinioErrorCode::NoSuchKey)
+                }
+                v => panic!("expected NoSuchKey for {key}; got {v:?}"),
+            },
+        }
+    }
+}
@@ -15,7 +15,7 @@
 
 use async_std::io::ReadExt;
 use minio::s3::builders::ObjectContent;
-use minio::s3::response::{GetObjectResponse, PutObjectContentResponse};
+use minio::s3::response::{DownloadObjectsResponse, GetObjectResponse, PutObjectContentResponse};
 use minio::s3::response_traits::{HasBucket, HasObject};
 use minio::s3::types::{BucketName, ObjectKey, S3Api};
 use minio::s3::utils::hex_encode;
@@ -122,3 +122,102 @@ async fn upload_download_object_3(ctx: TestContext, bucket: BucketName) {
     let object = rand_object_name_utf8(20);
     test_upload_download_object(&ctx, bucket.as_str(), object.as_str(), 16 + 5 * 1024 * 1024).await;
 }
+
+/// Test downloading a multipart object with concurrent ranged GETs.
+#[minio_macros::test]
+async fn parallel_download_object(ctx: TestContext, bucket: BucketName) {
+    const SIZE: u64 = 32 * 1024 * 1024;
+    let object = rand_object_name_utf8(20);
+    let mut file = async_std::fs::File::create(object.as_str()).await.unwrap();
+    async_std::io::copy(&mut RandReader::new(SIZE), &mut file)
+        .await
+        .unwrap();
+    file.sync_all().await.unwrap();
+
+    let obj: ObjectContent = PathBuf::from(object.as_str()).as_path().into();
+    ctx.client
+        .put_object_content(&bucket, &object, obj)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+
+    let filename: String = rand_object_name_utf8(20).to_string();
+    let size = ctx
+        .client
+        .get_object(&bucket, &object)
+        .unwrap()
+        .build()
+        .parallel_download(PathBuf::from(&filename).as_path(), 8, SIZE / 8)
+        .await
+        .unwrap();
+    assert_eq!(size, SIZE);
+    assert_eq!(get_hash(object.as_str()).await, get_hash(&filename).await);
+
+    async_std::fs::remove_file(object.as_str()).await.unwrap();
+    async_std::fs::remove_file(&filename).await.unwrap();
+}
+
+/// Test downloading all objects under a prefix into a mirrored directory tree.
+#[minio_macros::test]
+async fn download_objects_by_prefix(ctx: TestContext, bucket: BucketName) {
+    let objects = [
+        ("tree/a.txt", "a"),
+        ("tree/sub/b.txt", "b"),
+        ("tree/sub/deeper/c.txt", "c"),
+        ("tree/folder/", ""),
+        ("other/d.txt", "d"),
+    ];
+    for (name, data) in objects {
+        ctx.client
+            .put_object_content(&bucket, name, ObjectContent::from(data))
+            .unwrap()
+            .build()
+            .send()
+            .await
+            .unwrap();
+    }
+
+    let local_dir = std::env::temp_dir().join(rand_object_name_utf8(8).as_str().replace('/', "_"));
+    let resp: DownloadObjectsResponse = ctx
+        .client
+        .download_objects_by_prefix(&bucket, "tree/", &local_dir)
+        .unwrap()
+        .build()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.bucket(), &bucket);
+
+    let mut downloaded: Vec<(String, PathBuf)> = resp
+        .into_result()
+        .into_iter()
+        .map(|r| match r {
+            Ok(o) => (o.name, o.path),
+            Err(e) => panic!("{}: {}", e.object_name, e.message),
+        })
+        .collect();
+    downloaded.sort();
+    assert_eq!(
+        downloaded,
+        vec![
+            ("tree/a.txt".to_string(), local_dir.join("a.txt")),
+            ("tree/sub/b.txt".to_string(), local_dir.join("sub/b.txt")),
+            (
+                "tree/sub/deeper/c.txt".to_string(),
+                local_dir.join("sub/deeper/c.txt")
+            ),
+        ]
+    );
+    for (path, data) in [
+        ("a.txt", "a"),
+        ("sub/b.txt", "b"),
+        ("sub/deeper/c.txt", "c"),
+    ] {
+        assert_eq!(std::fs::read_to_string(local_dir.join(path)).unwrap(), data);
+    }
+    assert!(!local_dir.join("folder").exists());
+
+    std::fs::remove_dir_all(&local_dir).unwrap();
+}